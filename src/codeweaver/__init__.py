@@ -737,6 +737,8 @@ if TYPE_CHECKING:
         ChunkGovernorDep,
         ChunkingServiceDep,
         ConfigChangeAnalyzerDep,
+        DependencyServiceDep,
+        ExactSearchServiceDep,
         ExtensionFilterDep,
         FailoverServiceDep,
         FailoverSettingsDep,
@@ -749,6 +751,7 @@ if TYPE_CHECKING:
         MigrationServiceDep,
         ProgressTrackerDep,
         SourceIdRegistryDep,
+        SymbolIndexServiceDep,
     )
     from codeweaver.engine.managers.checkpoint_manager import (
         ChangeImpact,
@@ -775,6 +778,14 @@ if TYPE_CHECKING:
         ConfigChangeAnalyzer,
         TransformationDetails,
     )
+    from codeweaver.engine.services.dependency_service import (
+        DependencyEdge,
+        DependencyGraph,
+        DependencyKind,
+        DependencyService,
+        PackageInfo,
+    )
+    from codeweaver.engine.services.exact_search_service import ExactHit, ExactSearchService
     from codeweaver.engine.services.failover_service import FailoverService
     from codeweaver.engine.services.indexing_service import IndexingService, ProgressCallback
     from codeweaver.engine.services.migration_service import (
@@ -793,6 +804,11 @@ if TYPE_CHECKING:
         VectorReconciliationService,
     )
     from codeweaver.engine.services.snapshot_service import QdrantSnapshotBackupService
+    from codeweaver.engine.services.symbol_index_service import (
+        SymbolIndex,
+        SymbolIndexService,
+        SymbolOccurrence,
+    )
     from codeweaver.engine.services.watching_service import FileWatchingService
     from codeweaver.engine.watcher._logging import WatchfilesLogManager
     from codeweaver.engine.watcher.progress import IndexingProgressUI
@@ -911,6 +927,7 @@ if TYPE_CHECKING:
         MemoryConfig,
         MemoryVectorStoreProviderSettings,
         QdrantVectorStoreProviderSettings,
+        SqliteVectorStoreProviderSettings,
         VectorStoreProviderSettings,
         VectorStoreProviderSettingsType,
     )
@@ -959,7 +976,11 @@ if TYPE_CHECKING:
         try_for_azure_endpoint,
         try_for_heroku_endpoint,
     )
-    from codeweaver.providers.config.clients.vector_store import GrpcParams, QdrantClientOptions
+    from codeweaver.providers.config.clients.vector_store import (
+        GrpcParams,
+        QdrantClientOptions,
+        SqliteClientOptions,
+    )
     from codeweaver.providers.config.profiles import (
         HAS_FASTEMBED,
         HAS_ST,
@@ -976,6 +997,7 @@ if TYPE_CHECKING:
         DefaultSparseEmbeddingProviderSettings,
         DefaultVectorStoreProviderSettings,
         DeterminedDefaults,
+        EmbeddingCacheSettings,
         ProviderCategorySettingsType,
         ProviderNameMap,
         ProviderSettings,
@@ -1262,6 +1284,11 @@ if TYPE_CHECKING:
         get_sparse_embedder,
         get_text_embedder,
     )
+    from codeweaver.providers.embedding.persistent_cache import (
+        EmbeddingCacheStats,
+        EmbeddingSpace,
+        PersistentEmbeddingCache,
+    )
     from codeweaver.providers.embedding.providers.base import (
         DEFAULT_MAX_BATCH_TOKENS,
         EmbeddingCustomDeps,
@@ -1484,6 +1511,8 @@ if TYPE_CHECKING:
         HybridVectorPayload,
         PayloadFieldDict,
         TransformationRecord,
+        path_prefixes,
+        payload_keyword,
     )
     from codeweaver.providers.types.vectors import VectorConfig, VectorRole, VectorSet
     from codeweaver.providers.vector_stores.base import MixedQueryInput, VectorStoreProvider
@@ -1511,9 +1540,11 @@ if TYPE_CHECKING:
     )
     from codeweaver.providers.vector_stores.search.filter_factory import (
         ArbitraryFilter,
+        SqliteFilter,
         make_filter,
         make_indexes,
         to_qdrant_filter,
+        to_sqlite_filter,
     )
     from codeweaver.providers.vector_stores.search.geo import (
         GeoBoundingBox,
@@ -1543,6 +1574,12 @@ if TYPE_CHECKING:
         make_partial_function,
         wrap_filters,
     )
+    from codeweaver.providers.vector_stores.sqlite import SqliteVectorStoreProvider
+    from codeweaver.providers.vector_stores.sqlite_client import (
+        SqliteCollectionInfo,
+        SqlitePoint,
+        SqliteVectorClient,
+    )
     from codeweaver.semantic.ast_grep import (
         AstGrepSearchTypes,
         AstThing,
@@ -1585,8 +1622,24 @@ if TYPE_CHECKING:
         Token,
     )
     from codeweaver.semantic.node_type_parser import NodeArray, NodeTypeFileLoader, NodeTypeParser
+    from codeweaver.semantic.outline import (
+        OUTLINE_LANGUAGES,
+        OutlineItem,
+        build_outline,
+        find_outline_items,
+        render_skeleton,
+    )
     from codeweaver.semantic.registry import ThingRegistry
     from codeweaver.semantic.scoring import SemanticScorer
+    from codeweaver.semantic.symbols import (
+        SYMBOL_LANGUAGES,
+        SymbolReference,
+        SymbolRefKind,
+        enclosing_items,
+        extract_symbol_references,
+        symbol_name,
+    )
+    from codeweaver.semantic.test_code import find_test_only_ranges, is_test_chunk, is_test_file
     from codeweaver.semantic.token_patterns import (
         JavaScriptFamily,
         JavaScriptLangs,
@@ -1603,18 +1656,46 @@ if TYPE_CHECKING:
         ThingKind,
         TokenPurpose,
     )
-    from codeweaver.server.agent_api.search import MatchedSection, find_code
+    from codeweaver.server.agent_api.search import (
+        MatchedSection,
+        find_code,
+        find_references,
+        get_outline,
+        read_code,
+    )
     from codeweaver.server.agent_api.search.intent import (
         IntentResult,
         IntentType,
         QueryComplexity,
         QueryIntent,
     )
+    from codeweaver.server.agent_api.search.outline import (
+        FileOutline,
+        OutlineResponse,
+        outline_file,
+        outline_path,
+        outline_symbol,
+        resolve_project_file,
+    )
+    from codeweaver.server.agent_api.search.reading import (
+        MAX_READ_LINES,
+        CodeSection,
+        Expansion,
+        ReadCodeResponse,
+        parse_expansion,
+        read_path,
+        read_section,
+        read_symbol,
+    )
     from codeweaver.server.agent_api.search.types import (
+        CodeKind,
         CodeMatch,
         CodeMatchType,
+        ElidedMatch,
         FindCodeResponseSummary,
         FindCodeSubmission,
+        SearchFilters,
+        SearchMode,
     )
     from codeweaver.server.config.helpers import get_settings_map, update_settings
     from codeweaver.server.config.mcp import (
@@ -1702,7 +1783,12 @@ if TYPE_CHECKING:
         register_tool,
     )
     from codeweaver.server.mcp.types import ToolAnnotationsDict, ToolRegistrationDict
-    from codeweaver.server.mcp.user_agent import find_code_tool
+    from codeweaver.server.mcp.user_agent import (
+        find_code_tool,
+        find_references_tool,
+        get_outline_tool,
+        read_code_tool,
+    )
     from codeweaver.server.server import CodeWeaverState
 
 _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
@@ -1838,6 +1924,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "LOCALHOST_URL": (__spec__.parent, "core.constants"),
     "LOGGERS_TO_SUPPRESS": (__spec__.parent, "core.constants"),
     "MAX_INLINE_CONTENT_SIZE": (__spec__.parent, "core.constants"),
+    "MAX_READ_LINES": (__spec__.parent, "server.agent_api.search.reading"),
     "MAX_REGEX_PATTERN_LENGTH": (__spec__.parent, "core.constants"),
     "MAX_RETRY_ATTEMPTS": (__spec__.parent, "core.constants"),
     "MAX_SEMANTIC_CHUNKER_RECURSION_DEPTH": (__spec__.parent, "core.constants"),
@@ -1870,6 +1957,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ONNX_CUDA_PROVIDER": (__spec__.parent, "core.constants"),
     "OPENAI": (__spec__.parent, "providers.env_registry.definitions.openai_compatible"),
     "OPENROUTER": (__spec__.parent, "providers.env_registry.definitions.openai_compatible"),
+    "OUTLINE_LANGUAGES": (__spec__.parent, "semantic.outline"),
     "OVHCLOUD": (__spec__.parent, "providers.env_registry.definitions.openai_compatible"),
     "PARALLEL_CHUNKING_THRESHOLD": (__spec__.parent, "core.constants"),
     "PERPLEXITY": (__spec__.parent, "providers.env_registry.definitions.openai_compatible"),
@@ -1906,6 +1994,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "SPACE": (__spec__.parent, "core.constants"),
     "STATE_ENDPOINT": (__spec__.parent, "core.constants"),
     "STATUS_ENDPOINT": (__spec__.parent, "core.constants"),
+    "SYMBOL_LANGUAGES": (__spec__.parent, "semantic.symbols"),
     "TAB": (__spec__.parent, "core.constants"),
     "TAVILY": (__spec__.parent, "providers.env_registry.definitions.specialized"),
     "TEN_MINUTES": (__spec__.parent, "core.constants"),
@@ -2063,6 +2152,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "BlakeStore": (__spec__.parent, "core.stores"),
     "Bm25Config": (__spec__.parent, "providers.config.types"),
     "Boundary": (__spec__.parent, "engine.chunker.delimiter_model"),
+    "build_outline": (__spec__.parent, "semantic.outline"),
     "CallHookTimingDict": (__spec__.parent, "core.types.statistics"),
     "Category": (__spec__.parent, "semantic.grammar"),
     "CategoryKey": (__spec__.parent, "core.types.statistics"),
@@ -2098,8 +2188,10 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "CodeChunk": (__spec__.parent, "core.chunks"),
     "CodeChunkDict": (__spec__.parent, "core.chunks"),
     "CodeFilter": (__spec__.parent, "engine.watcher.watch_filters"),
+    "CodeKind": (__spec__.parent, "server.agent_api.search.types"),
     "CodeMatch": (__spec__.parent, "server.agent_api.search.types"),
     "CodeMatchType": (__spec__.parent, "server.agent_api.search.types"),
+    "CodeSection": (__spec__.parent, "server.agent_api.search.reading"),
     "CodeWeaverCoreSettings": (__spec__.parent, "core.config.core_settings"),
     "CodeWeaverDeveloperError": (__spec__.parent, "core.exceptions"),
     "CodeWeaverEngineSettings": (__spec__.parent, "engine.config.root_settings"),
@@ -2194,9 +2286,14 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "DelimiterKind": (__spec__.parent, "core.types.delimiter"),
     "DelimiterMatch": (__spec__.parent, "engine.chunker.delimiter_model"),
     "DelimiterPattern": (__spec__.parent, "core.types.delimiter"),
+    "DependencyEdge": (__spec__.parent, "engine.services.dependency_service"),
+    "DependencyGraph": (__spec__.parent, "engine.services.dependency_service"),
     "DependencyInjectionError": (__spec__.parent, "core.exceptions"),
+    "DependencyKind": (__spec__.parent, "engine.services.dependency_service"),
     "DependencyNotAvailableError": (__spec__.parent, "core.exceptions"),
     "DependencyResolutionError": (__spec__.parent, "core.exceptions"),
+    "DependencyService": (__spec__.parent, "engine.services.dependency_service"),
+    "DependencyServiceDep": (__spec__.parent, "engine.dependencies"),
     "Depends": (__spec__.parent, "core.di.dependency"),
     "DependsPlaceholder": (__spec__.parent, "core.di.dependency"),
     "DeserializationKwargs": (__spec__.parent, "core.types.dataclasses"),
@@ -2223,9 +2320,12 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "DuckDuckGoProviderSettings": (__spec__.parent, "providers.config.categories.data"),
     "DuckDuckGoSearchTool": (__spec__.parent, "providers.data.duckduckgo"),
     "DuckDuckGoSearchToolConfig": (__spec__.parent, "providers.config.sdk.data"),
+    "ElidedMatch": (__spec__.parent, "server.agent_api.search.types"),
     "EmbeddingBatchInfo": (__spec__.parent, "core.types.embeddings"),
     "EmbeddingCacheManager": (__spec__.parent, "providers.embedding.cache_manager"),
     "EmbeddingCacheManagerDep": (__spec__.parent, "providers.dependencies.services"),
+    "EmbeddingCacheSettings": (__spec__.parent, "providers.config.providers"),
+    "EmbeddingCacheStats": (__spec__.parent, "providers.embedding.persistent_cache"),
     "EmbeddingCapabilitiesDict": (__spec__.parent, "providers.embedding.capabilities.types"),
     "EmbeddingCapabilityGroup": (__spec__.parent, "providers.types.embedding"),
     "EmbeddingCapabilityGroupDep": (__spec__.parent, "providers.dependencies.capabilities"),
@@ -2249,6 +2349,8 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "EmbeddingProviderSettingsType": (__spec__.parent, "providers.config.categories.embedding"),
     "EmbeddingRegistry": (__spec__.parent, "providers.embedding.registry"),
     "EmbeddingRegistryDep": (__spec__.parent, "providers.dependencies.services"),
+    "EmbeddingSpace": (__spec__.parent, "providers.embedding.persistent_cache"),
+    "enclosing_items": (__spec__.parent, "semantic.symbols"),
     "EndpointSettingsDict": (__spec__.parent, "server.config.types"),
     "Entry": (__spec__.parent, "providers.vector_stores.search.payload"),
     "EnvFormat": (__spec__.parent, "core.types.env"),
@@ -2263,6 +2365,9 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ExaClientOptions": (__spec__.parent, "providers.config.clients.data"),
     "ExaContentResult": (__spec__.parent, "providers.data.exa"),
     "ExaContentsOptions": (__spec__.parent, "providers.config.sdk.data"),
+    "ExactHit": (__spec__.parent, "engine.services.exact_search_service"),
+    "ExactSearchService": (__spec__.parent, "engine.services.exact_search_service"),
+    "ExactSearchServiceDep": (__spec__.parent, "engine.dependencies"),
     "ExaFindSimilarTool": (__spec__.parent, "providers.data.exa"),
     "ExaFindSimilarToolOptions": (__spec__.parent, "providers.config.sdk.data"),
     "ExaGetContentsTool": (__spec__.parent, "providers.data.exa"),
@@ -2273,11 +2378,13 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ExaSearchToolOptions": (__spec__.parent, "providers.config.sdk.data"),
     "ExaToolConfig": (__spec__.parent, "providers.config.sdk.data"),
     "ExaToolType": (__spec__.parent, "providers.data.exa"),
+    "Expansion": (__spec__.parent, "server.agent_api.search.reading"),
     "ExtCategory": (__spec__.parent, "core.metadata"),
     "ExtendedPointId": (__spec__.parent, "providers.vector_stores.search.condition"),
     "ExtensionFilter": (__spec__.parent, "engine.watcher.watch_filters"),
     "ExtensionFilterDep": (__spec__.parent, "engine.dependencies"),
     "ExtLangPair": (__spec__.parent, "core.metadata"),
+    "extract_symbol_references": (__spec__.parent, "semantic.symbols"),
     "ExtTestDef": (__spec__.parent, "core.metadata"),
     "FailoverDetector": (__spec__.parent, "engine.config.failover_detector"),
     "FailoverInfo": (__spec__.parent, "server.health.models"),
@@ -2324,6 +2431,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "FileManifestStats": (__spec__.parent, "engine.managers.manifest_manager"),
     "FileName": (__spec__.parent, "core.types.aliases"),
     "FileNameT": (__spec__.parent, "core.types.aliases"),
+    "FileOutline": (__spec__.parent, "server.agent_api.search.outline"),
     "FilePath": (__spec__.parent, "core.types.aliases"),
     "FilePathT": (__spec__.parent, "core.types.aliases"),
     "FileStatistics": (__spec__.parent, "core.statistics"),
@@ -2338,6 +2446,9 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "FilteredPaths": (__spec__.parent, "engine.config.indexer"),
     "FilteredReturn": (__spec__.parent, "core.types.enum"),
     "FiltersDict": (__spec__.parent, "core.config._logging"),
+    "find_outline_items": (__spec__.parent, "semantic.outline"),
+    "find_references": (__spec__.parent, "server.agent_api.search"),
+    "find_references_tool": (__spec__.parent, "server.mcp.user_agent"),
     "FindCodeResponseSummary": (__spec__.parent, "server.agent_api.search.types"),
     "FindCodeSubmission": (__spec__.parent, "server.agent_api.search.types"),
     "FormattersDict": (__spec__.parent, "core.config._logging"),
@@ -2350,6 +2461,8 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "GeoPoint": (__spec__.parent, "providers.vector_stores.search.geo"),
     "GeoPolygon": (__spec__.parent, "providers.vector_stores.search.geo"),
     "GeoRadius": (__spec__.parent, "providers.vector_stores.search.geo"),
+    "get_outline": (__spec__.parent, "server.agent_api.search"),
+    "get_outline_tool": (__spec__.parent, "server.mcp.user_agent"),
     "GoogleAgentProviderSettings": (__spec__.parent, "providers.config.categories.agent"),
     "GoogleClientOptions": (__spec__.parent, "providers.config.clients.multi"),
     "GoogleEmbeddingCapabilities": (__spec__.parent, "providers.embedding.capabilities.google"),
@@ -2421,6 +2534,8 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "InvalidEmbeddingModelError": (__spec__.parent, "core.exceptions"),
     "InvalidStateTransitionError": (__spec__.parent, "engine.services.migration_service"),
     "InvokeRequestDict": (__spec__.parent, "providers.embedding.providers.bedrock"),
+    "is_test_chunk": (__spec__.parent, "semantic.test_code"),
+    "is_test_file": (__spec__.parent, "semantic.test_code"),
     "IsEmptyCondition": (__spec__.parent, "providers.vector_stores.search.condition"),
     "IsNullCondition": (__spec__.parent, "providers.vector_stores.search.condition"),
     "JavaScriptFamily": (__spec__.parent, "semantic.token_patterns"),
@@ -2532,12 +2647,21 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "OpenRouterAgentProviderSettings": (__spec__.parent, "providers.config.categories.agent"),
     "OperationsKey": (__spec__.parent, "core.types.statistics"),
     "OptimizationDecisions": (__spec__.parent, "providers.optimize"),
+    "outline_file": (__spec__.parent, "server.agent_api.search.outline"),
+    "outline_path": (__spec__.parent, "server.agent_api.search.outline"),
+    "outline_symbol": (__spec__.parent, "server.agent_api.search.outline"),
+    "OutlineItem": (__spec__.parent, "semantic.outline"),
+    "OutlineResponse": (__spec__.parent, "server.agent_api.search.outline"),
     "OversizedChunkError": (__spec__.parent, "engine.chunker.exceptions"),
+    "PackageInfo": (__spec__.parent, "engine.services.dependency_service"),
+    "parse_expansion": (__spec__.parent, "server.agent_api.search.reading"),
     "ParseError": (__spec__.parent, "engine.chunker.exceptions"),
     "PartialCapabilities": (__spec__.parent, "providers.embedding.capabilities.types"),
     "PartialRerankingCapabilitiesDict": (__spec__.parent, "providers.reranking.capabilities.types"),
+    "path_prefixes": (__spec__.parent, "providers.types.vector_store"),
     "PathOrFalse": (__spec__.parent, "core.repo"),
     "PatternKey": (__spec__.parent, "engine.chunker.delimiters.families"),
+    "payload_keyword": (__spec__.parent, "providers.types.vector_store"),
     "PayloadField": (__spec__.parent, "providers.vector_stores.search.payload"),
     "PayloadFieldDict": (__spec__.parent, "providers.types.vector_store"),
     "PayloadMetadata": (__spec__.parent, "providers.vector_stores.search.payload"),
@@ -2545,6 +2669,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "PerformanceSettings": (__spec__.parent, "engine.config.chunker"),
     "PerformanceSettingsDict": (__spec__.parent, "engine.config.chunker"),
     "PersistenceError": (__spec__.parent, "core.exceptions"),
+    "PersistentEmbeddingCache": (__spec__.parent, "providers.embedding.persistent_cache"),
     "PoolLimits": (__spec__.parent, "providers.http_pool"),
     "PoolTimeouts": (__spec__.parent, "providers.http_pool"),
     "Position": (__spec__.parent, "semantic.ast_grep"),
@@ -2600,7 +2725,14 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "RateLimitingMiddleware": (__spec__.parent, "server.mcp.middleware.fastmcp"),
     "RateLimitingMiddlewareSettings": (__spec__.parent, "server.config.middleware"),
     "RawEmbeddingVectors": (__spec__.parent, "core.types.embeddings"),
+    "read_code": (__spec__.parent, "server.agent_api.search"),
+    "read_code_tool": (__spec__.parent, "server.mcp.user_agent"),
+    "read_path": (__spec__.parent, "server.agent_api.search.reading"),
+    "read_section": (__spec__.parent, "server.agent_api.search.reading"),
+    "read_symbol": (__spec__.parent, "server.agent_api.search.reading"),
+    "ReadCodeResponse": (__spec__.parent, "server.agent_api.search.reading"),
     "ReconciliationResult": (__spec__.parent, "engine.services.reconciliation_service"),
+    "render_skeleton": (__spec__.parent, "semantic.outline"),
     "RepairStats": (__spec__.parent, "engine.services.reconciliation_service"),
     "RepoChecklist": (__spec__.parent, "core.repo"),
     "RepoChecklistDict": (__spec__.parent, "core.repo"),
@@ -2625,6 +2757,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "RerankingResult": (__spec__.parent, "providers.reranking.providers.types"),
     "RerankingServiceInfo": (__spec__.parent, "server.health.models"),
     "ResolutionResult": (__spec__.parent, "core.di.container"),
+    "resolve_project_file": (__spec__.parent, "server.agent_api.search.outline"),
     "ResolvedGitBranchDep": (__spec__.parent, "core.dependencies.component_settings"),
     "ResolvedProjectNameDep": (__spec__.parent, "core.dependencies.component_settings"),
     "ResolvedProjectPathDep": (__spec__.parent, "core.dependencies.component_settings"),
@@ -2644,6 +2777,8 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ScopeViolationError": (__spec__.parent, "core.exceptions"),
     "ScoreValidation": (__spec__.parent, "semantic.classifications"),
     "SearchEvent": (__spec__.parent, "core.telemetry.events"),
+    "SearchFilters": (__spec__.parent, "server.agent_api.search.types"),
+    "SearchMode": (__spec__.parent, "server.agent_api.search.types"),
     "SearchPackage": (__spec__.parent, "providers.types.search"),
     "SearchPackageDep": (__spec__.parent, "providers.dependencies.providers"),
     "SearchResult": (__spec__.parent, "core.types.search"),
@@ -2745,6 +2880,16 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
         "providers.config.categories.sparse_embedding",
     ),
     "SparseEmbeddingServiceInfo": (__spec__.parent, "server.health.models"),
+    "SqliteClientOptions": (__spec__.parent, "providers.config.clients.vector_store"),
+    "SqliteCollectionInfo": (__spec__.parent, "providers.vector_stores.sqlite_client"),
+    "SqliteFilter": (__spec__.parent, "providers.vector_stores.search.filter_factory"),
+    "SqlitePoint": (__spec__.parent, "providers.vector_stores.sqlite_client"),
+    "SqliteVectorClient": (__spec__.parent, "providers.vector_stores.sqlite_client"),
+    "SqliteVectorStoreProvider": (__spec__.parent, "providers.vector_stores.sqlite"),
+    "SqliteVectorStoreProviderSettings": (
+        __spec__.parent,
+        "providers.config.categories.vector_store",
+    ),
     "StatisticsDep": (__spec__.parent, "core.dependencies.services"),
     "StatisticsInfo": (__spec__.parent, "server.health.models"),
     "StatisticsMiddleware": (__spec__.parent, "server.mcp.middleware.statistics"),
@@ -2760,6 +2905,13 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "StructuredDataInput": (__spec__.parent, "core.chunks"),
     "StructuredLoggingMiddleware": (__spec__.parent, "server.mcp.middleware.fastmcp"),
     "SummaryKey": (__spec__.parent, "core.types.statistics"),
+    "symbol_name": (__spec__.parent, "semantic.symbols"),
+    "SymbolIndex": (__spec__.parent, "engine.services.symbol_index_service"),
+    "SymbolIndexService": (__spec__.parent, "engine.services.symbol_index_service"),
+    "SymbolIndexServiceDep": (__spec__.parent, "engine.dependencies"),
+    "SymbolOccurrence": (__spec__.parent, "engine.services.symbol_index_service"),
+    "SymbolReference": (__spec__.parent, "semantic.symbols"),
+    "SymbolRefKind": (__spec__.parent, "semantic.symbols"),
     "TavilyClientOptions": (__spec__.parent, "providers.config.clients.data"),
     "TavilyProviderSettings": (__spec__.parent, "providers.config.categories.data"),
     "TavilyResults": (__spec__.parent, "providers.data.tavily"),
@@ -2788,6 +2940,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "TimingStatisticsDict": (__spec__.parent, "core.types.statistics"),
     "TitanEmbeddingV2RequestBody": (__spec__.parent, "providers.embedding.providers.bedrock"),
     "TitanEmbeddingV2Response": (__spec__.parent, "providers.embedding.providers.bedrock"),
+    "to_sqlite_filter": (__spec__.parent, "providers.vector_stores.search.filter_factory"),
     "Token": (__spec__.parent, "semantic.grammar"),
     "TokenCategory": (__spec__.parent, "core.statistics"),
     "TokenCost": (__spec__.parent, "core.statistics"),
@@ -2931,6 +3084,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "find_code_tool": (__spec__.parent, "server.mcp.user_agent"),
     "find_identifiable_info": (__spec__.parent, "core.telemetry.utils"),
     "find_qdrant_instance": (__spec__.parent, "core.utils.environment"),
+    "find_test_only_ranges": (__spec__.parent, "semantic.test_code"),
     "format_descriptor": (__spec__.parent, "core.utils.text"),
     "format_docstring": (__spec__.parent, "core.utils.text"),
     "format_file_link": (__spec__.parent, "core.utils.environment"),
@@ -3370,6 +3524,7 @@ __all__ = (
     "LOCAL_EMBEDDING_COST_PER_1K_TOKENS",
     "LOGGERS_TO_SUPPRESS",
     "MAX_INLINE_CONTENT_SIZE",
+    "MAX_READ_LINES",
     "MAX_REGEX_PATTERN_LENGTH",
     "MAX_RETRY_ATTEMPTS",
     "MAX_SEMANTIC_CHUNKER_RECURSION_DEPTH",
@@ -3399,6 +3554,7 @@ __all__ = (
     "ONNX_CUDA_PROVIDER",
     "OPENAI",
     "OPENROUTER",
+    "OUTLINE_LANGUAGES",
     "OVHCLOUD",
     "PARALLEL_CHUNKING_THRESHOLD",
     "PERPLEXITY",
@@ -3435,6 +3591,7 @@ __all__ = (
     "SPACE",
     "STATE_ENDPOINT",
     "STATUS_ENDPOINT",
+    "SYMBOL_LANGUAGES",
     "TAB",
     "TAVILY",
     "TEN_MINUTES",
@@ -3610,8 +3767,10 @@ __all__ = (
     "CodeChunk",
     "CodeChunkDict",
     "CodeFilter",
+    "CodeKind",
     "CodeMatch",
     "CodeMatchType",
+    "CodeSection",
     "CodeWeaverCoreSettings",
     "CodeWeaverDeveloperError",
     "CodeWeaverEngineSettings",
@@ -3708,9 +3867,14 @@ __all__ = (
     "DelimiterKind",
     "DelimiterMatch",
     "DelimiterPattern",
+    "DependencyEdge",
+    "DependencyGraph",
     "DependencyInjectionError",
+    "DependencyKind",
     "DependencyNotAvailableError",
     "DependencyResolutionError",
+    "DependencyService",
+    "DependencyServiceDep",
     "Depends",
     "DependsPlaceholder",
     "DeserializationKwargs",
@@ -3737,9 +3901,12 @@ __all__ = (
     "DuckDuckGoProviderSettings",
     "DuckDuckGoSearchTool",
     "DuckDuckGoSearchToolConfig",
+    "ElidedMatch",
     "EmbeddingBatchInfo",
     "EmbeddingCacheManager",
     "EmbeddingCacheManagerDep",
+    "EmbeddingCacheSettings",
+    "EmbeddingCacheStats",
     "EmbeddingCapabilitiesDict",
     "EmbeddingCapabilityGroup",
     "EmbeddingCapabilityGroupDep",
@@ -3763,6 +3930,7 @@ __all__ = (
     "EmbeddingProvidersDep",
     "EmbeddingRegistry",
     "EmbeddingRegistryDep",
+    "EmbeddingSpace",
     "EndpointSettingsDict",
     "Entry",
     "EnvFormat",
@@ -3787,6 +3955,10 @@ __all__ = (
     "ExaSearchToolOptions",
     "ExaToolConfig",
     "ExaToolType",
+    "ExactHit",
+    "ExactSearchService",
+    "ExactSearchServiceDep",
+    "Expansion",
     "ExtCategory",
     "ExtLangPair",
     "ExtTestDef",
@@ -3830,6 +4002,7 @@ __all__ = (
     "FileManifestStats",
     "FileName",
     "FileNameT",
+    "FileOutline",
     "FilePath",
     "FilePathT",
     "FileStatistics",
@@ -4037,7 +4210,10 @@ __all__ = (
     "OpenaiEmbeddingCapabilities",
     "OperationsKey",
     "OptimizationDecisions",
+    "OutlineItem",
+    "OutlineResponse",
     "OversizedChunkError",
+    "PackageInfo",
     "ParseError",
     "PartialCapabilities",
     "PartialRerankingCapabilitiesDict",
@@ -4050,6 +4226,7 @@ __all__ = (
     "PerformanceSettings",
     "PerformanceSettingsDict",
     "PersistenceError",
+    "PersistentEmbeddingCache",
     "PoolLimits",
     "PoolTimeouts",
     "Position",
@@ -4102,6 +4279,7 @@ __all__ = (
     "RateLimitingMiddleware",
     "RateLimitingMiddlewareSettings",
     "RawEmbeddingVectors",
+    "ReadCodeResponse",
     "ReconciliationResult",
     "RepairStats",
     "RepoChecklist",
@@ -4148,6 +4326,8 @@ __all__ = (
     "ScopeViolationError",
     "ScoreValidation",
     "SearchEvent",
+    "SearchFilters",
+    "SearchMode",
     "SearchPackage",
     "SearchPackageDep",
     "SearchResult",
@@ -4214,6 +4394,13 @@ __all__ = (
     "SparseEmbeddingProviderSettingsType",
     "SparseEmbeddingProvidersDep",
     "SparseEmbeddingServiceInfo",
+    "SqliteClientOptions",
+    "SqliteCollectionInfo",
+    "SqliteFilter",
+    "SqlitePoint",
+    "SqliteVectorClient",
+    "SqliteVectorStoreProvider",
+    "SqliteVectorStoreProviderSettings",
     "StatisticsDep",
     "StatisticsInfo",
     "StatisticsMiddleware",
@@ -4229,6 +4416,12 @@ __all__ = (
     "StructuredDataInput",
     "StructuredLoggingMiddleware",
     "SummaryKey",
+    "SymbolIndex",
+    "SymbolIndexService",
+    "SymbolIndexServiceDep",
+    "SymbolOccurrence",
+    "SymbolRefKind",
+    "SymbolReference",
     "TavilyClientOptions",
     "TavilyProviderSettings",
     "TavilyResults",
@@ -4323,6 +4516,7 @@ __all__ = (
     "bedrock_reranking_output_transformer",
     "bootstrap_settings",
     "build_data_tool",
+    "build_outline",
     "capture_search_event",
     "check_provider_package_available",
     "chunk_files_parallel",
@@ -4352,6 +4546,7 @@ __all__ = (
     "duckduckgo_search_tool",
     "effective_cpu_count",
     "elapsed_time_to_human_readable",
+    "enclosing_items",
     "ensure_container_initialized",
     "ensure_endpoint_version",
     "ensure_iterable",
@@ -4363,13 +4558,18 @@ __all__ = (
     "exa_get_contents_tool",
     "exa_search_tool",
     "expand_pattern",
+    "extract_symbol_references",
     "fastembed_output_transformer",
     "fastembed_sparse_output_transformer",
     "file_is_binary",
     "find_code",
     "find_code_tool",
     "find_identifiable_info",
+    "find_outline_items",
     "find_qdrant_instance",
+    "find_references",
+    "find_references_tool",
+    "find_test_only_ranges",
     "format_descriptor",
     "format_docstring",
     "format_file_link",
@@ -4440,6 +4640,8 @@ __all__ = (
     "get_openai_embedding_capabilities",
     "get_optimal_workers",
     "get_optimizations",
+    "get_outline",
+    "get_outline_tool",
     "get_possible_env_vars",
     "get_project_path",
     "get_provider",
@@ -4497,7 +4699,9 @@ __all__ = (
     "is_one_of_valid_types",
     "is_provider_registered",
     "is_pydantic_basemodel",
+    "is_test_chunk",
     "is_test_environment",
+    "is_test_file",
     "is_titan_response",
     "is_tty",
     "is_typeadapter",
@@ -4514,19 +4718,32 @@ __all__ = (
     "multi_client_provider",
     "normalize_ext",
     "openai_compatible_provider",
+    "outline_file",
+    "outline_path",
+    "outline_symbol",
+    "parse_expansion",
+    "path_prefixes",
+    "payload_keyword",
     "positional_args",
     "preprocess_for_qwen",
     "process_for_instruction_model",
     "provider_env_config_to_vars",
     "python_version",
     "qdrant_instance_live_at_port",
+    "read_code",
+    "read_code_tool",
+    "read_path",
+    "read_section",
+    "read_symbol",
     "redact_identifiable_info",
     "register_data_tool",
     "register_exa_tools",
     "register_tool",
+    "render_skeleton",
     "reset_container",
     "reset_http_pool",
     "reset_http_pool_sync",
+    "resolve_project_file",
     "return_type",
     "rpartial",
     "run",
@@ -4540,11 +4757,13 @@ __all__ = (
     "simple_provider_discriminator",
     "supported_language_count",
     "supported_languages",
+    "symbol_name",
     "takes_args",
     "takes_kwargs",
     "tavily_search_tool",
     "to_lowly_lowercase",
     "to_qdrant_filter",
+    "to_sqlite_filter",
     "to_tokens",
     "truncate_text",
     "try_for_azure_endpoint",
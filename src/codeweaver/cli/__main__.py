@@ -60,6 +60,7 @@ if ROOT_PACKAGE == "server":
     app.command("codeweaver.cli.commands.init:app", name="init")
     app.command("codeweaver.cli.commands.doctor:app", name="doctor")
     app.command("codeweaver.cli.commands.search:app", name="search")
+    app.command("codeweaver.cli.commands.eval:app", name="eval")
     app.command("codeweaver.cli.commands.server:app", name="server")
     app.command("codeweaver.cli.commands.start:app", name="start")
     app.command("codeweaver.cli.commands.stop:app", name="stop")
@@ -70,6 +71,7 @@ if ROOT_PACKAGE in ("engine", "server"):
     app.command("codeweaver.cli.commands.index:app", name="index")
 if ROOT_PACKAGE in ("provider", "engine", "server"):
     app.command("codeweaver.cli.commands.ls:app", name="list", alias="ls")
+    app.command("codeweaver.cli.commands.cache:app", name="cache")
 
 app.command("codeweaver.cli.commands.config:app", name="config")
 
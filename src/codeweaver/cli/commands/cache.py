@@ -0,0 +1,181 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""CodeWeaver CLI - Cache Command.
+
+Inspect and manage the persistent embedding cache.
+"""
+
+# sourcery skip: avoid-global-variables
+from __future__ import annotations
+
+from datetime import UTC, datetime, timedelta
+from pathlib import Path
+from typing import Annotated
+
+import cyclopts
+
+from cyclopts import App
+from pydantic import FilePath
+from rich.table import Table
+
+from codeweaver.cli.dependencies import setup_cli_di
+from codeweaver.cli.ui import (
+    CLIErrorHandler,
+    StatusDisplay,
+    get_display,
+    handle_keyboard_interrupt_gracefully,
+)
+from codeweaver.providers import PersistentEmbeddingCache, ProviderSettings
+
+
+_display: StatusDisplay = get_display()
+app = App(
+    "cache", help="Inspect and manage the persistent embedding cache.", console=_display.console
+)
+
+type ConfigFileOption = Annotated[
+    FilePath | None,
+    cyclopts.Parameter(name=["--config-file", "-c"], help="Path to a specific config file to use"),
+]
+type ProjectOption = Annotated[
+    Path | None, cyclopts.Parameter(name=["--project", "-p"], help="Path to project directory")
+]
+
+
+def _format_size(size_bytes: int) -> str:
+    """Format a byte count for display."""
+    size = float(size_bytes)
+    for unit in ("B", "KB", "MB", "GB"):
+        if size < 1024 or unit == "GB":
+            return f"{size:.0f} {unit}" if unit == "B" else f"{size:.1f} {unit}"
+        size /= 1024
+    return f"{size:.1f} GB"
+
+
+async def _open_cache(
+    config_file: Path | None, project_path: Path | None
+) -> PersistentEmbeddingCache:
+    """Open the cache at the configured location, whether or not caching is enabled."""
+    container = setup_cli_di(config_file, project_path)
+    settings = await container.resolve(ProviderSettings)
+    return PersistentEmbeddingCache.from_settings(settings.embedding_cache)
+
+
+@app.command
+async def stats(
+    *, config_file: ConfigFileOption = None, project_path: ProjectOption = None
+) -> None:
+    """Show the cache's size and contents per embedding model."""
+    error_handler = CLIErrorHandler(_display)
+    try:
+        cache = await _open_cache(config_file, project_path)
+        summary = cache.stats()
+    except Exception as e:
+        error_handler.handle_error(e, "Embedding cache", exit_code=1)
+        return
+
+    _display.print_info(f"Cache: {summary.path}")
+    _display.print_info(
+        f"{summary.entries} embeddings, {_format_size(summary.size_bytes)} "
+        f"of {_format_size(summary.max_size_bytes)}"
+    )
+    if not summary.spaces:
+        return
+    table = Table(show_header=True, header_style="bold blue")
+    table.add_column("Provider", style="cyan")
+    table.add_column("Model", style="green")
+    table.add_column("Dimension", justify="right")
+    table.add_column("Datatype")
+    table.add_column("Entries", style="yellow", justify="right")
+    table.add_column("Size", justify="right")
+    table.add_column("Last used", style="magenta")
+    for space_stats in summary.spaces:
+        space = space_stats.space
+        table.add_row(
+            space.provider,
+            space.model,
+            str(space.dimension) if space.dimension else "sparse",
+            space.dtype,
+            str(space_stats.entries),
+            _format_size(space_stats.size_bytes),
+            datetime.fromtimestamp(space_stats.last_used, tz=UTC)
+            .astimezone()
+            .strftime("%Y-%m-%d %H:%M"),
+        )
+    _display.print_table(table)
+
+
+@app.command
+async def prune(
+    *,
+    older_than_days: Annotated[
+        int | None,
+        cyclopts.Parameter(
+            name=["--older-than"], help="Remove embeddings not used in this many days"
+        ),
+    ] = None,
+    max_size_mb: Annotated[
+        int | None,
+        cyclopts.Parameter(
+            name=["--max-size"],
+            help="Evict least recently used embeddings down to this size in MB "
+            "(default: the configured limit)",
+        ),
+    ] = None,
+    config_file: ConfigFileOption = None,
+    project_path: ProjectOption = None,
+) -> None:
+    """Remove stale embeddings and evict down to the size limit."""
+    error_handler = CLIErrorHandler(_display)
+    try:
+        cache = await _open_cache(config_file, project_path)
+        removed = cache.prune(
+            max_size_bytes=max_size_mb * 1024 * 1024 if max_size_mb is not None else None,
+            older_than=timedelta(days=older_than_days) if older_than_days is not None else None,
+        )
+    except Exception as e:
+        error_handler.handle_error(e, "Embedding cache", exit_code=1)
+        return
+    _display.print_success(f"Removed {removed} embeddings from {cache.path}")
+
+
+@app.command
+async def clear(
+    *,
+    provider: Annotated[
+        str | None, cyclopts.Parameter(help="Only clear embeddings from this provider")
+    ] = None,
+    model: Annotated[
+        str | None, cyclopts.Parameter(help="Only clear embeddings from this model")
+    ] = None,
+    config_file: ConfigFileOption = None,
+    project_path: ProjectOption = None,
+) -> None:
+    """Clear the cache, or only one provider's or model's embeddings."""
+    error_handler = CLIErrorHandler(_display)
+    try:
+        cache = await _open_cache(config_file, project_path)
+        removed = cache.clear(provider=provider, model=model)
+    except Exception as e:
+        error_handler.handle_error(e, "Embedding cache", exit_code=1)
+        return
+    _display.print_success(f"Removed {removed} embeddings from {cache.path}")
+
+
+def main() -> None:
+    """Entry point for the cache CLI command."""
+    error_handler = CLIErrorHandler(_display)
+
+    with handle_keyboard_interrupt_gracefully():
+        try:
+            app()
+        except Exception as e:
+            error_handler.handle_error(e, "Cache command", exit_code=1)
+
+
+if __name__ == "__main__":
+    main()
+
+__all__ = ()
@@ -366,7 +366,9 @@ async def _qdrant_running_at_url(url: Any | None = None) -> bool:
         )
 
 
-type DeploymentType = Literal["local docker", "cloud", "local", "remote", "in-memory", "unknown"]
+type DeploymentType = Literal[
+    "local docker", "cloud", "local", "remote", "in-memory", "embedded", "unknown"
+]
 
 
 async def check_vector_store_config(settings: ProviderSettings) -> DoctorCheck:
@@ -396,7 +398,7 @@ async def check_vector_store_config(settings: ProviderSettings) -> DoctorCheck:
     url = None
     if (
         vector_config
-        and (provider := vector_config.provider) != Provider.MEMORY
+        and (provider := vector_config.provider) not in (Provider.MEMORY, Provider.SQLITE)
         and vector_config.client_options
         and (url := vector_config.client_options.url)
     ):
@@ -418,6 +420,8 @@ async def check_vector_store_config(settings: ProviderSettings) -> DoctorCheck:
         deployment_type = "local" if await _qdrant_running_at_url() else "unknown"
     elif provider == Provider.MEMORY:
         deployment_type = "local"
+    elif provider == Provider.SQLITE:
+        deployment_type = "embedded"
     else:
         deployment_type = "unknown"
     _display.console.print(
@@ -461,6 +465,11 @@ async def check_vector_store_config(settings: ProviderSettings) -> DoctorCheck:
                 "In-memory Qdrant detected",
                 "We don't recommend the in-memory store for general use. Use local or cloud Qdrant instead.",
             )
+        case "embedded":
+            path = vector_config.client_options.path if vector_config.client_options else None
+            _display.console.print(
+                f"  [green]✓[/green] Embedded SQLite store at {path or ':memory:'} (no server needed)"
+            )
         case "remote":
             if _has_auth_configured(provider, settings):
                 _print_vector_store_status(
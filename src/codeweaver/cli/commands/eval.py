@@ -0,0 +1,294 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""CodeWeaver CLI - Eval Command.
+
+Benchmarks search quality on an indexed project with a file of reference queries, and compares
+configurations: run once with `--against-config`, `--against-profile` or `--against-fusion` to
+evaluate both and show the difference, or save a report with `--output report.json` and compare
+a later run to it with `--baseline report.json`.
+"""
+
+from __future__ import annotations
+
+import json
+import sys
+
+from functools import partial
+from pathlib import Path
+from typing import TYPE_CHECKING, Annotated, Literal
+
+import cyclopts
+
+from cyclopts import App
+from pydantic import FilePath
+from rich.table import Table
+
+from codeweaver.cli.dependencies import setup_cli_di
+from codeweaver.cli.ui import (
+    CLIErrorHandler,
+    StatusDisplay,
+    get_display,
+    handle_keyboard_interrupt_gracefully,
+)
+from codeweaver.core import CodeWeaverError, FusionMethod
+from codeweaver.core.config.loader import CodeWeaverSettingsType
+from codeweaver.server.agent_api.search.evaluation import (
+    METRICS,
+    EvalQuery,
+    EvalReport,
+    MetricSummary,
+    evaluate,
+    find_code_files,
+    load_eval_queries,
+    metric_deltas,
+    query_changes,
+    render_markdown,
+)
+
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+
+_display: StatusDisplay = get_display()
+app = App("eval", help="Benchmark search quality with reference queries.")
+
+
+def _label(config_file: Path | None, profile: str | None, fusion: FusionMethod | None) -> str:
+    """Describe a configuration for reports."""
+    parts = [config_file.name if config_file else "default config"]
+    if profile:
+        parts.append(f"{profile} profile")
+    if fusion:
+        parts.append(f"{fusion.value} fusion")
+    return ", ".join(parts)
+
+
+async def _run(
+    queries: Sequence[EvalQuery],
+    *,
+    config_file: Path | None,
+    project_path: Path | None,
+    profile: str | None,
+    fusion: FusionMethod | None,
+    k: int | None,
+    max_results: int,
+    verbose: bool,
+) -> EvalReport:
+    """Configure CodeWeaver and run the benchmark queries."""
+    from codeweaver.core.di.container import reset_container_state
+
+    reset_container_state()
+    container = setup_cli_di(config_file, project_path, verbose=verbose)
+    settings = await container.resolve(CodeWeaverSettingsType)
+    if profile:
+        from codeweaver.providers.config.profiles import ProviderProfile
+        from codeweaver.providers.config.providers import ProviderSettings
+
+        settings.provider = ProviderSettings.model_validate(
+            ProviderProfile.from_string(profile).as_provider_settings()
+        )
+    if fusion:
+        from codeweaver.server.config.types import SearchSettings
+
+        search = getattr(settings, "search", None)
+        search = search if isinstance(search, SearchSettings) else SearchSettings()
+        settings.search = search.model_copy(update={"fusion": fusion})
+
+    label = _label(config_file, profile, fusion)
+    _display.print_info(f"Running {len(queries)} queries with {label}")
+
+    async def search(query: EvalQuery) -> list[str]:
+        return await find_code_files(query, max_results=max_results)
+
+    return await evaluate(queries, search, label=label, k=k)
+
+
+def _summary_table(report: EvalReport, baseline: EvalReport | None) -> Table:
+    """Metrics per intent, with changes from the baseline."""
+    deltas = metric_deltas(report, baseline) if baseline else None
+    table = Table(title=report.label, show_header=True, header_style="bold blue")
+    table.add_column("Intent", style="cyan")
+    table.add_column("Queries", justify="right")
+    for header in ("Precision@k", "Recall@k", "MRR", "nDCG@k"):
+        table.add_column(header, justify="right")
+
+    def add_row(name: str, summary: MetricSummary) -> None:
+        cells = []
+        for metric in METRICS:
+            cell = f"{getattr(summary, metric):.3f}"
+            if deltas is not None and (change := deltas[name][metric]):
+                style = "green" if change > 0 else "red"
+                cell += f" [{style}]({change:+.3f})[/{style}]"
+            cells.append(cell)
+        table.add_row(name, str(summary.queries), *cells)
+
+    add_row("overall", report.overall)
+    for intent, summary in report.by_intent.items():
+        add_row(intent, summary)
+    return table
+
+
+def _write_report(path: Path, report: EvalReport, baseline: EvalReport | None) -> None:
+    """Save a report as JSON (reusable with `--baseline`) or Markdown, by file extension."""
+    if path.suffix.lower() in {".md", ".markdown"}:
+        path.write_text(render_markdown(report, baseline), encoding="utf-8")
+        return
+    data = report.as_dict()
+    if baseline:
+        data["compared_to"] = {
+            "label": baseline.label,
+            "deltas": metric_deltas(report, baseline),
+            "changed_queries": [
+                {"query": query, "ndcg_before": before, "ndcg_after": after}
+                for query, before, after in query_changes(report, baseline)
+            ],
+        }
+    path.write_text(json.dumps(data, indent=2), encoding="utf-8")
+
+
+@app.default
+async def eval_queries(
+    queries_file: Annotated[
+        FilePath,
+        cyclopts.Parameter(
+            help="YAML file of reference queries, in the schema of tests/fixtures/reference_queries.yml"
+        ),
+    ],
+    *,
+    k: Annotated[
+        int | None,
+        cyclopts.Parameter(
+            name=["--k", "-k"],
+            help="Results scored per query (default: each query's precision_target, or 5)",
+        ),
+    ] = None,
+    max_results: Annotated[
+        int, cyclopts.Parameter(help="Results to ask find_code for, per query")
+    ] = 20,
+    profile: Annotated[
+        str | None,
+        cyclopts.Parameter(help="Use this provider profile instead of the configured providers"),
+    ] = None,
+    against_config: Annotated[
+        FilePath | None,
+        cyclopts.Parameter(help="Also run with this config file and compare it to the first"),
+    ] = None,
+    against_profile: Annotated[
+        str | None,
+        cyclopts.Parameter(help="Also run with this provider profile and compare it to the first"),
+    ] = None,
+    against_fusion: Annotated[
+        FusionMethod | None,
+        cyclopts.Parameter(help="Also run with this fusion method and compare it to the first"),
+    ] = None,
+    baseline: Annotated[
+        FilePath | None,
+        cyclopts.Parameter(help="Compare to a JSON report saved by an earlier `cw eval --output`"),
+    ] = None,
+    output: Annotated[
+        Path | None,
+        cyclopts.Parameter(
+            name=["--output", "-o"], help="Write the report to this .json or .md file"
+        ),
+    ] = None,
+    output_format: Literal["table", "json", "markdown"] = "table",
+    fail_on_regression: Annotated[
+        float | None,
+        cyclopts.Parameter(
+            help="Exit with an error if overall nDCG@k drops by more than this from the baseline (like 0.02)"
+        ),
+    ] = None,
+    project_path: Annotated[Path | None, cyclopts.Parameter(name=["--project", "-p"])] = None,
+    config_file: Annotated[
+        FilePath | None,
+        cyclopts.Parameter(
+            name=["--config-file", "-c"], help="Path to a specific config file to use"
+        ),
+    ] = None,
+    verbose: Annotated[
+        bool,
+        cyclopts.Parameter(name=["--verbose", "-v"], help="Enable verbose logging with timestamps"),
+    ] = False,
+    debug: Annotated[
+        bool, cyclopts.Parameter(name=["--debug", "-d"], help="Enable debug logging")
+    ] = False,
+) -> None:
+    """Benchmark search quality on an indexed project, and compare configurations.
+
+    Each query's results are scored against its expected files with precision@k, recall@k, MRR
+    and nDCG@k, averaged per intent. Comparisons index nothing themselves: a configuration with a
+    different embedding model searches its own index, so index with it first.
+    """
+    display = _display
+    error_handler = CLIErrorHandler(display, verbose=verbose, debug=debug)
+
+    try:
+        queries = load_eval_queries(queries_file)
+        comparison: EvalReport | None = None
+        if baseline:
+            comparison = EvalReport.from_dict(json.loads(baseline.read_text(encoding="utf-8")))
+
+        run = partial(
+            _run,
+            queries,
+            project_path=project_path,
+            k=k,
+            max_results=max_results,
+            verbose=verbose,
+        )
+        report = await run(config_file=config_file, profile=profile, fusion=None)
+        if against_config or against_profile or against_fusion:
+            # The first run is the baseline for the configuration under test
+            comparison = report
+            report = await run(
+                config_file=against_config or config_file,
+                profile=against_profile or profile,
+                fusion=against_fusion,
+            )
+
+        if output_format == "json":
+            display.console.print_json(json.dumps(report.as_dict()))
+        elif output_format == "markdown":
+            display.console.print(render_markdown(report, comparison), markup=False)
+        else:
+            display.print_info("")
+            if comparison:
+                display.print_info(f"Compared to: {comparison.label}")
+            display.print_table(_summary_table(report, comparison))
+        if output:
+            _write_report(output, report, comparison)
+            display.print_success(f"Report written to {output}")
+
+        if fail_on_regression is not None and comparison:
+            drop = comparison.overall.ndcg - report.overall.ndcg
+            if drop > fail_on_regression:
+                display.print_error(
+                    f"nDCG@k dropped by {drop:.3f} from {comparison.label} "
+                    f"(allowed: {fail_on_regression:.3f})"
+                )
+                sys.exit(1)
+    except CodeWeaverError as e:
+        error_handler.handle_error(e, "Eval", exit_code=1)
+    except Exception as e:
+        error_handler.handle_error(e, "Eval", exit_code=1)
+
+
+def main() -> None:
+    """Entry point for the eval CLI command."""
+    display = StatusDisplay()
+    error_handler = CLIErrorHandler(display, verbose=True, debug=True)
+
+    with handle_keyboard_interrupt_gracefully():
+        try:
+            app()
+        except Exception as e:
+            error_handler.handle_error(e, "Eval CLI", exit_code=1)
+
+
+if __name__ == "__main__":
+    main()
+
+__all__ = ()
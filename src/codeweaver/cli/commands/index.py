@@ -225,7 +225,12 @@ def _check_and_print_server_status(display: StatusDisplay):
 
 
 async def _run_standalone_indexing(
-    settings: CodeWeaverEngineSettings, *, force_reindex: bool, display: StatusDisplay
+    settings: CodeWeaverEngineSettings,
+    *,
+    force_reindex: bool,
+    display: StatusDisplay,
+    rev: str | None = None,
+    deps: bool = False,
 ) -> None:
     """Run standalone indexing operation.
 
@@ -233,6 +238,8 @@ async def _run_standalone_indexing(
         settings: Settings object containing configuration
         force_reindex: If True, force full reindex
         display: StatusDisplay for output
+        rev: Git commit, branch or tag to index instead of the working tree
+        deps: If True, index the dependency sources instead of the working tree
 
     Raises:
         CodeWeaverError: If indexing fails
@@ -295,10 +302,20 @@ async def _run_standalone_indexing(
 
     display.print_success("Starting indexing process...")
 
+    manifest = None
     with progress_tracker:
-        _ = await indexing_service.index_project(
-            force_reindex=force_reindex, progress_callback=progress_callback
-        )
+        if rev:
+            manifest = await indexing_service.index_revision(
+                rev, force_reindex=force_reindex, progress_callback=progress_callback
+            )
+        elif deps:
+            manifest = await indexing_service.index_dependencies(
+                force_reindex=force_reindex, progress_callback=progress_callback
+            )
+        else:
+            _ = await indexing_service.index_project(
+                force_reindex=force_reindex, progress_callback=progress_callback
+            )
         progress_tracker.complete()
 
     # Display final summary
@@ -306,6 +323,11 @@ async def _run_standalone_indexing(
     display.console.print()
     display.print_success("Indexing Complete!")
     display.console.print()
+    if manifest is not None and rev:
+        display.console.print(f"  Revision: [cyan]{rev}[/cyan] ({manifest.commit})")
+        display.console.print(f"  Files in revision: [cyan]{manifest.total_files}[/cyan]")
+    elif manifest is not None:
+        display.console.print(f"  Dependency packages: [cyan]{manifest.total_files}[/cyan]")
     display.console.print(f"  Files processed: [cyan]{stats.files_processed}[/cyan]")
     display.console.print(f"  Chunks created: [cyan]{stats.chunks_created}[/cyan]")
     display.console.print(f"  Chunks indexed: [cyan]{stats.chunks_indexed}[/cyan]")
@@ -358,6 +380,20 @@ async def index(
             name=["--yes", "-y"], help="Skip confirmation prompts (use with --clear)"
         ),
     ] = False,
+    rev: Annotated[
+        str | None,
+        cyclopts.Parameter(
+            name=["--rev"],
+            help="Index a git commit, branch or tag without checking it out (always standalone)",
+        ),
+    ] = None,
+    deps: Annotated[
+        bool,
+        cyclopts.Parameter(
+            name=["--deps"],
+            help="Index the sources of the project's dependencies that are on disk (always standalone)",
+        ),
+    ] = False,
     verbose: Annotated[
         bool,
         cyclopts.Parameter(name=["--verbose", "-v"], help="Enable verbose logging with timestamps"),
@@ -377,6 +413,8 @@ async def index(
         cw index --standalone     # Standalone indexing
         cw index --clear          # Clear vector store and re-index (with confirmation)
         cw index --clear --yes    # Clear and re-index without confirmation
+        cw index --rev v1.2.0     # Index a tag, searchable with `cw search --rev v1.2.0`
+        cw index --deps           # Index dependency sources, for `cw search --scope deps`
 
     Args:
         config_file: Optional path to CodeWeaver configuration file
@@ -385,6 +423,8 @@ async def index(
         standalone: If True, run indexing without checking for server
         clear: If True, clear vector store and checkpoints before indexing
         yes: If True, skip confirmation prompts
+        rev: Git commit, branch or tag to index, next to the working tree's index
+        deps: If True, index the dependency sources, next to the working tree's index
     """
     display = _display or get_display()
     error_handler = CLIErrorHandler(display, verbose=verbose, debug=debug)
@@ -412,6 +452,17 @@ async def index(
             )
             force_reindex = True  # Continue to reindex after clearing
 
+        # A revision's or the dependencies' index is separate from the server's, so it's always
+        # built here
+        if rev and deps:
+            display.print_error("--rev and --deps can't be combined")
+            sys.exit(1)
+        if rev or deps:
+            await _run_standalone_indexing(
+                settings, force_reindex=force_reindex, display=display, rev=rev, deps=deps
+            )
+            return
+
         # Check server status and decide whether to proceed
         if not await _handle_server_status(standalone=standalone, display=display):
             return  # Server is running, exit early
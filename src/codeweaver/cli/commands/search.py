@@ -30,10 +30,15 @@ from codeweaver.cli.ui import (
 )
 from codeweaver.core import CodeWeaverError
 from codeweaver.core.config.loader import CodeWeaverSettingsType
+from codeweaver.semantic.ast_grep import Strictness
 from codeweaver.server.agent_api.search import (
+    CodeKind,
     CodeMatch,
     FindCodeResponseSummary,
     IntentType,
+    SearchFilters,
+    SearchMode,
+    SearchScope,
     find_code,
 )
 
@@ -49,6 +54,97 @@ async def search(
     *,
     intent: IntentType | None = None,
     limit: int = 10,
+    crates: Annotated[
+        Sequence[str] | None,
+        cyclopts.Parameter(
+            name=["--crate"], help="Only return results from this Cargo crate (repeatable)"
+        ),
+    ] = None,
+    packages: Annotated[
+        Sequence[str] | None,
+        cyclopts.Parameter(
+            name=["--package"],
+            help="With --scope deps, only return results from this dependency (repeatable)",
+        ),
+    ] = None,
+    languages: Annotated[
+        Sequence[str] | None,
+        cyclopts.Parameter(
+            name=["--lang", "-l"], help="Only return results in this language (repeatable)"
+        ),
+    ] = None,
+    paths: Annotated[
+        Sequence[str] | None,
+        cyclopts.Parameter(
+            name=["--path"],
+            help="Only return results under this path or matching this glob, like crates/auth/** (repeatable)",
+        ),
+    ] = None,
+    exclude_paths: Annotated[
+        Sequence[str] | None,
+        cyclopts.Parameter(
+            name=["--exclude"],
+            help="Leave out results under this path or matching this glob (repeatable)",
+        ),
+    ] = None,
+    kinds: Annotated[
+        Sequence[CodeKind] | None,
+        cyclopts.Parameter(
+            name=["--kind", "-k"],
+            help="Only return this kind of code: function, type, test, config or docs (repeatable)",
+        ),
+    ] = None,
+    symbol: Annotated[
+        str | None,
+        cyclopts.Parameter(help="Only return results whose symbol starts with this"),
+    ] = None,
+    min_size: Annotated[
+        int | None,
+        cyclopts.Parameter(help="Only return results from files of at least this many bytes"),
+    ] = None,
+    max_size: Annotated[
+        int | None,
+        cyclopts.Parameter(help="Only return results from files of at most this many bytes"),
+    ] = None,
+    mode: Annotated[
+        SearchMode,
+        cyclopts.Parameter(
+            name=["--mode", "-m"],
+            help="How to read the query: semantic, or exactly as an ast-grep pattern, keyword, regex or path glob",
+        ),
+    ] = SearchMode.SEMANTIC,
+    strictness: Annotated[
+        Strictness | None,
+        cyclopts.Parameter(help="How closely code must match a --mode pattern query"),
+    ] = None,
+    expand: Annotated[
+        int,
+        cyclopts.Parameter(
+            help="Grow each result to its enclosing function, class or impl block, this many levels up"
+        ),
+    ] = 0,
+    rev: Annotated[
+        str | None,
+        cyclopts.Parameter(
+            help="Search a git commit, branch or tag indexed with `cw index --rev` instead of the working tree"
+        ),
+    ] = None,
+    scope: Annotated[
+        SearchScope,
+        cyclopts.Parameter(
+            help="Search the project's code, or the dependency sources indexed with `cw index --deps`"
+        ),
+    ] = SearchScope.PROJECT,
+    agentic: Annotated[
+        bool,
+        cyclopts.Parameter(
+            help="Have the context agent refine the query and drop results that don't answer it (needs an agent provider)"
+        ),
+    ] = False,
+    cursor: Annotated[
+        str | None,
+        cyclopts.Parameter(help="Get the next page of a search, with the cursor it printed"),
+    ] = None,
     project_path: Annotated[Path | None, cyclopts.Parameter(name=["--project", "-p"])] = None,
     config_file: Annotated[
         FilePath | None,
@@ -65,7 +161,10 @@ async def search(
         bool, cyclopts.Parameter(name=["--debug", "-d"], help="Enable debug logging")
     ] = False,
 ) -> None:
-    """Search your codebase from the command line with plain language."""
+    """Search your codebase from the command line with plain language.
+
+    Semantic queries can also include filter operators, like `lang:rust kind:fn`.
+    """
     from codeweaver.core import ConfigurationError
 
     display = _display
@@ -80,6 +179,10 @@ async def search(
 
         display.print_info(f"Searching in: {settings.project_path}")
         display.print_info(f"Query: {query}")
+        if rev:
+            display.print_info(f"Revision: {rev}")
+        if scope == SearchScope.DEPS:
+            display.print_info("Scope: dependency sources")
         display.print_info("")  # Empty line for spacing
 
         # Resolve Providers
@@ -91,6 +194,24 @@ async def search(
             intent=intent,
             token_limit=settings.token_limit or 30000,
             focus_languages=None,
+            crates=tuple(crates) if crates else None,
+            filters=SearchFilters(
+                languages=tuple(languages or ()),
+                packages=tuple(packages or ()),
+                paths=tuple(paths or ()),
+                exclude_paths=tuple(exclude_paths or ()),
+                kinds=tuple(kinds or ()),
+                symbol_prefix=symbol,
+                min_file_size=min_size,
+                max_file_size=max_size,
+            ),
+            mode=mode,
+            strictness=strictness,
+            expand=max(expand, 0),
+            rev=rev,
+            scope=scope,
+            agentic=agentic,
+            cursor=cursor,
             context=None,
         )
 
@@ -129,6 +250,9 @@ async def search(
         elif output_format == "markdown":
             _display_markdown_results(query, response, limited_matches, display)
 
+        if response.cursor and output_format != "json":
+            display.print_info(f"More results: add `--cursor {response.cursor}` to get them")
+
     except ConfigurationError as e:
         error_handler.handle_error(e, "Search configuration", exit_code=1)
     except CodeWeaverError as e:
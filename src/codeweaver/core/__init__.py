@@ -313,6 +313,12 @@ if TYPE_CHECKING:
         LlmTool,
         all_js_exts,
     )
+    from codeweaver.core.git_tree import (
+        SHORT_COMMIT_LENGTH,
+        GitBlob,
+        GitTree,
+        revision_collection_name,
+    )
     from codeweaver.core.language import (
         ConfigLanguage,
         ConfigNamePair,
@@ -475,6 +481,7 @@ if TYPE_CHECKING:
         FilteredReturn,
     )
     from codeweaver.core.types.env import EnvFormat, EnvVarInfo, ProviderEnvVars, VariableInfo
+    from codeweaver.core.types.fusion import FusedScore, FusionMethod, FusionStrategy
     from codeweaver.core.types.models import (
         BASEDMODEL_CONFIG,
         FROZEN_BASEDMODEL_CONFIG,
@@ -645,6 +652,7 @@ if TYPE_CHECKING:
         validate_regex_pattern,
         variable_to_env_var,
     )
+    from codeweaver.core.workspace import CargoWorkspace, CrateInfo, find_cargo_workspace
 
 _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "BACKUP_DENSE_VECTOR_NAME": (__spec__.parent, "constants"),
@@ -800,6 +808,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "SESSION_LOG_FILE": (__spec__.parent, "_logging"),
     "SETTINGS_ENDPOINT": (__spec__.parent, "constants"),
     "SHEBANG": (__spec__.parent, "constants"),
+    "SHORT_COMMIT_LENGTH": (__spec__.parent, "git_tree"),
     "SHUTDOWN_ENDPOINT": (__spec__.parent, "constants"),
     "SOTA_CONTEXT_AGENT_MODEL": (__spec__.parent, "constants"),
     "SOTA_EMBEDDING_MODEL": (__spec__.parent, "constants"),
@@ -835,6 +844,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "BlakeKey": (__spec__.parent, "types.aliases"),
     "BlakeStore": (__spec__.parent, "stores"),
     "CallHookTimingDict": (__spec__.parent, "types.statistics"),
+    "CargoWorkspace": (__spec__.parent, "workspace"),
     "CategoryKey": (__spec__.parent, "types.statistics"),
     "CategoryName": (__spec__.parent, "types.aliases"),
     "CategoryNameT": (__spec__.parent, "types.aliases"),
@@ -857,6 +867,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ConfigurationError": (__spec__.parent, "exceptions"),
     "ConfigurationLockError": (__spec__.parent, "exceptions"),
     "Container": (__spec__.parent, "di.container"),
+    "CrateInfo": (__spec__.parent, "workspace"),
     "DataclassSerializationMixin": (__spec__.parent, "types.dataclasses"),
     "DataType": (__spec__.parent, "types.embeddings"),
     "DatatypeMismatchError": (__spec__.parent, "exceptions"),
@@ -910,6 +921,11 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "FilteredReturn": (__spec__.parent, "types.enum"),
     "FiltersDict": (__spec__.parent, "config._logging"),
     "FormattersDict": (__spec__.parent, "config._logging"),
+    "FusedScore": (__spec__.parent, "types.fusion"),
+    "FusionMethod": (__spec__.parent, "types.fusion"),
+    "FusionStrategy": (__spec__.parent, "types.fusion"),
+    "GitBlob": (__spec__.parent, "git_tree"),
+    "GitTree": (__spec__.parent, "git_tree"),
     "HandlersDict": (__spec__.parent, "config._logging"),
     "HttpRequestsDict": (__spec__.parent, "types.statistics"),
     "Identifier": (__spec__.parent, "statistics"),
@@ -984,6 +1000,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ResolvedProjectPathDep": (__spec__.parent, "dependencies.component_settings"),
     "ResolvedProjectPathHashDep": (__spec__.parent, "dependencies.component_settings"),
     "ResourceUri": (__spec__.parent, "types.statistics"),
+    "revision_collection_name": (__spec__.parent, "git_tree"),
     "RichConsoleProgressReporter": (__spec__.parent, "ui_protocol"),
     "Role": (__spec__.parent, "types.aliases"),
     "RoleT": (__spec__.parent, "types.aliases"),
@@ -1069,6 +1086,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ensure_settings_initialized_async": (__spec__.parent, "dependencies.utils"),
     "file_is_binary": (__spec__.parent, "utils.checks"),
     "FilterID": (__spec__.parent, "config._logging"),
+    "find_cargo_workspace": (__spec__.parent, "workspace"),
     "find_config_paths": (__spec__.parent, "language"),
     "find_identifiable_info": (__spec__.parent, "telemetry.utils"),
     "find_qdrant_instance": (__spec__.parent, "utils.environment"),
@@ -1363,6 +1381,7 @@ __all__ = (
     "SESSION_LOG_FILE",
     "SETTINGS_ENDPOINT",
     "SHEBANG",
+    "SHORT_COMMIT_LENGTH",
     "SHUTDOWN_ENDPOINT",
     "SOTA_CONTEXT_AGENT_MODEL",
     "SOTA_EMBEDDING_MODEL",
@@ -1399,6 +1418,7 @@ __all__ = (
     "BlakeKey",
     "BlakeStore",
     "CallHookTimingDict",
+    "CargoWorkspace",
     "CategoryKey",
     "CategoryName",
     "CategoryNameT",
@@ -1421,6 +1441,7 @@ __all__ = (
     "ConfigurationError",
     "ConfigurationLockError",
     "Container",
+    "CrateInfo",
     "DataType",
     "DataclassSerializationMixin",
     "DatatypeMismatchError",
@@ -1476,6 +1497,11 @@ __all__ = (
     "FiltersDict",
     "FormatterID",
     "FormattersDict",
+    "FusedScore",
+    "FusionMethod",
+    "FusionStrategy",
+    "GitBlob",
+    "GitTree",
     "HandlerID",
     "HandlersDict",
     "HttpRequestsDict",
@@ -1639,6 +1665,7 @@ __all__ = (
     "ensure_settings_initialized",
     "ensure_settings_initialized_async",
     "file_is_binary",
+    "find_cargo_workspace",
     "find_config_paths",
     "find_identifiable_info",
     "find_qdrant_instance",
@@ -1738,6 +1765,7 @@ __all__ = (
     "reset_container",
     "reset_container_state",
     "return_type",
+    "revision_collection_name",
     "rpartial",
     "sanitize_unicode",
     "set_relative_path",
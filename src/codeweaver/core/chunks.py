@@ -59,6 +59,7 @@ if TYPE_CHECKING:
     from codeweaver.core.discovery import DiscoveredFile
     from codeweaver.core.types import AnonymityConversion
     from codeweaver.providers import EmbeddingRegistry
+    from codeweaver.semantic.classifications import ImportanceScores, SemanticClass
 
 # ---------------------------------------------------------------------------
 # *                    Code Search and Chunks
@@ -127,6 +128,11 @@ class CodeChunkDict(TypedDict, total=False):
     parent_id: NotRequired[UUID7 | None]
     metadata: NotRequired[Metadata | None]
     chunk_name: NotRequired[str | None]
+    crate: NotRequired[str | None]
+    module_path: NotRequired[str | None]
+    package: NotRequired[str | None]
+    package_version: NotRequired[str | None]
+    is_test: NotRequired[bool | None]
     _embeddings: NotRequired[dict[str, BatchKeys]]
     blake_hash: NotRequired[BlakeHashKey]
     name: NotRequired[str]
@@ -198,6 +204,34 @@ class CodeChunk(BasedModel):
             description="""Fully qualified chunk identifier (e.g., 'auth.py:UserAuth.validate')"""
         ),
     ] = None
+    crate: Annotated[
+        str | None,
+        Field(
+            description="""Name of the Cargo crate that owns the source file, for files in a Cargo workspace."""
+        ),
+    ] = None
+    module_path: Annotated[
+        str | None,
+        Field(
+            description="""Crate-relative Rust module path of the source file (e.g. 'crate::cache')."""
+        ),
+    ] = None
+    package: Annotated[
+        str | None,
+        Field(
+            description="""Name of the third-party package the chunk is from, for chunks of indexed dependency sources."""
+        ),
+    ] = None
+    package_version: Annotated[
+        str | None,
+        Field(description="""Version of the third-party package the chunk is from."""),
+    ] = None
+    is_test: Annotated[
+        bool | None,
+        Field(
+            description="""Whether the chunk is test code: in a test file, a test definition, or inside test-only code like a Rust `#[cfg(test)]` module. None if it wasn't checked."""
+        ),
+    ] = None
 
     _version: Annotated[str, Field(repr=True, init=False, serialization_alias="chunk_version")] = (
         "1.1.0"
@@ -220,6 +254,9 @@ class CodeChunk(BasedModel):
             FilteredKey("metadata"): AnonymityConversion.AGGREGATE,
             FilteredKey("_embeddings"): AnonymityConversion.COUNT,
             FilteredKey("chunk_name"): AnonymityConversion.BOOLEAN,
+            FilteredKey("crate"): AnonymityConversion.HASH,
+            FilteredKey("module_path"): AnonymityConversion.HASH,
+            FilteredKey("package"): AnonymityConversion.HASH,
             FilteredKey("name"): AnonymityConversion.HASH,
         }
 
@@ -235,12 +272,12 @@ class CodeChunk(BasedModel):
         """Serialize the CodeChunk to a dictionary."""
         return self.model_dump_json(round_trip=True, exclude_none=True)
 
-    def _construct_name(self) -> str:
+    def _construct_name(self, *, with_lines: bool = True) -> str:
         """Construct a name for the code chunk based on file path and line range."""
         parts: list[str] = []
         if self.file_path:
             parts.append(str(self.file_path))
-        if self.line_range:
+        if self.line_range and with_lines:
             parts.append(f"lines {self.line_range.start}-{self.line_range.end}")
         name = self.metadata.get("name") if self.metadata and self.metadata.get("name") else None
         semantic_meta = self.metadata.get("semantic_meta") if self.metadata else None
@@ -259,6 +296,53 @@ class CodeChunk(BasedModel):
         """Get or construct the name of the code chunk."""
         return self.chunk_name or self._construct_name()
 
+    def _classification_value(self, key: str, context_key: str | None = None) -> Any:
+        """Get a classification value from the semantic metadata.
+
+        Chunks indexed before the classification was part of the semantic metadata only have
+        it in their chunker context, under `context_key`.
+        """
+        if not self.metadata:
+            return None
+        meta = self.metadata.get("semantic_meta")
+        value = meta.get(key) if isinstance(meta, dict) else getattr(meta, key, None)
+        if value is None and context_key and (context := self.metadata.get("context")):
+            value = context.get(context_key)
+        return value
+
+    @property
+    def semantic_class(self) -> SemanticClass | None:
+        """The semantic class of the chunk's AST node, for semantically chunked code."""
+        if not (value := self._classification_value("semantic_class", "classification")):
+            return None
+        from codeweaver.semantic.classifications import SemanticClass
+
+        if isinstance(value, SemanticClass):
+            return value
+        try:
+            return SemanticClass.from_string(str(value))
+        except (ValueError, AttributeError, KeyError):
+            return None
+
+    @property
+    def importance_scores(self) -> ImportanceScores | None:
+        """How important the chunk's AST node is for each kind of agent task."""
+        if not (value := self._classification_value("importance_scores", "importance_scores")):
+            return None
+        from codeweaver.semantic.classifications import ImportanceScores
+
+        if isinstance(value, ImportanceScores):
+            return value
+        try:
+            return ImportanceScores.from_dict(**value)
+        except (ValueError, TypeError):
+            return None
+
+    @property
+    def classification_confidence(self) -> float | None:
+        """How confident the classification of the chunk's AST node is, from 0 to 1."""
+        return self._classification_value("classification_confidence")
+
     @property
     def _serialization_order(self) -> tuple[str, ...]:
         """Define the order of fields during serialization."""
@@ -324,6 +408,8 @@ class CodeChunk(BasedModel):
             "file_path": str(self.file_path) if self.file_path else None,
             "language": str(self.language) if self.language else None,
             "source": str(self.source) if self.source else None,
+            "crate": self.crate,
+            "package": self.package,
             "chunk_version": self._version,
         }
 
@@ -338,6 +424,41 @@ class CodeChunk(BasedModel):
         # Use pydantic_core.to_json for fast, reliable serialization
         return to_json({k: v for k, v in data.items() if v is not None}).decode("utf-8")
 
+    @property
+    def embedding_hash(self) -> BlakeHashKey:
+        """Hash of what the chunk's embedding depends on, wherever the chunk is in its file.
+
+        Unlike a hash of `serialize_for_embedding()`, it leaves out line numbers and the per-parse
+        IDs of syntax nodes, so a chunk that an edit only moved hashes the same and can keep its
+        embeddings.
+        """
+        from codeweaver.core.stores import get_blake_hash
+
+        metadata = {
+            k: v.model_dump(
+                mode="json",
+                exclude={"thing", "positional_connections", "thing_id", "parent_thing_id"},
+                exclude_none=True,
+            )
+            if hasattr(v, "model_dump")
+            else v
+            for k, v in (self.metadata or {}).items()
+            if k in ("name", "tags", "semantic_meta")
+        }
+        data = {
+            "title": self.title,
+            "name": self.chunk_name or self._construct_name(with_lines=False),
+            "content": self.content,
+            "file_path": str(self.file_path) if self.file_path else None,
+            "language": str(self.language) if self.language else None,
+            "source": str(self.source) if self.source else None,
+            "crate": self.crate,
+            "package": self.package,
+            "chunk_version": self._version,
+            "metadata": metadata or None,
+        }
+        return get_blake_hash(to_json({k: v for k, v in data.items() if v is not None}))
+
     @property
     def _base_excludes(self) -> set[str]:
         """Get the base fields to exclude during serialization."""
@@ -417,6 +538,8 @@ class CodeChunk(BasedModel):
             ),
             "source": ChunkSource.FILE,
             "parent_id": file.source_id,
+            "crate": file.crate,
+            "module_path": file.module_path,
         })
 
     @computed_field
@@ -31,12 +31,21 @@ FIND_CODE_DESCRIPTION = dedent("""
         # Using `find_code`
 
         **One Required Argument:**
-            - query: Provide a natural language query describing what you are looking for.
+            - query: Provide a natural language query describing what you are looking for. In `semantic` mode it can include filter operators, which are taken out of the query before searching: `lang:rust`, `path:crates/auth/**` (or `-path:` to leave a path out), `kind:fn` (also `type`, `test`, `config`, `docs`), `symbol:parse` for symbols starting with `parse`, `size:<10kb`, `crate:my-core`, and `package:serde` for `deps` searches. Values can be comma separated, like `lang:rust,python`.
 
         **Optional Arguments:**
             - intent: Specify an intent to help narrow down the search results. Choose from: `understand`, `implement`, `debug`, `optimize`, `test`, `configure`, `document`.
             - token_limit: Set a maximum number of tokens to return (default is 30000).
             - focus_languages: Filter results by programming language(s). A list of languages using their common names (like "python", "javascript", etc.). CodeWeaver supports over 166 programming languages.
+            - crates: For Rust workspaces, restrict results to one or more Cargo crates by package name (like ["my-core", "my-cli"]).
+            - filters: Structured filters, the same as the query operators: `languages`, `paths` and `exclude_paths` (project-relative paths or globs like `crates/*/src/**/*.rs`), `kinds` (`function`, `type`, `test`, `config`, `docs`), `symbol_prefix`, `min_file_size` and `max_file_size` in bytes, `modified_since` (an ISO date), `crates`, and `packages` (dependency package names, for `deps` searches).
+            - mode: How to read the query. `semantic` (default) for natural language. For exact answers, use `pattern` for an ast-grep structural pattern (like `impl $T for $U { $$$ }` or `$X.unwrap()`; `$X` matches one node, `$$$` any number), `keyword` for a literal string, `regex` for a regular expression, or `path` for a file path glob (like `crates/*/src/**/*.rs`). Exact modes search the files directly and list every match first, then the ranked results for the same query. The same filters apply to both.
+            - strictness: For `pattern` mode, how closely code must match the pattern. One of `cst`, `smart` (default), `ast`, `relaxed`, `signature`.
+            - expand: Grow each match to its enclosing code: 1 for the enclosing function or class, 2 for the next level up (like an impl block), and so on until the whole file. Default 0 returns matches as found.
+            - rev: Search the project as of a git commit, branch or tag (like `v1.2.0` or `main`) instead of the working tree, for comparing with a past release or another branch. The revision must have been indexed with `cw index --rev <rev>`. Semantic mode only.
+            - scope: `project` (default) searches the project's own code. `deps` searches the sources of its third-party dependencies (registry crates from `Cargo.lock`, `node_modules` packages, and locked Python packages in its virtual environment), to see how a dependency's API actually works. Dependency sources must have been indexed with `cw index --deps`. Semantic mode only, without `rev`.
+            - agentic: Set to true to have CodeWeaver's own context agent check the intent, rewrite or split the query into focused searches, run them, and drop matches that don't answer the query. It's slower and spends tokens on the configured agent model, but returns tighter results for broad or multi-part questions. Semantic mode only.
+            - cursor: The `cursor` from a previous response, to get the next page of its results. Pass it with the same query and arguments. A cursor stops working when the codebase is re-indexed; if you get a "stale cursor" error, run the query again without one.
 
         RETURNS:
             A detailed summary of ranked matches and metadata. Including:
@@ -45,10 +54,87 @@ FIND_CODE_DESCRIPTION = dedent("""
                 - file: The file and associated metadata where the snippet was found.
                 - span: A span object indicating the exact location of the snippet within the file.
                 - relevance_score: A numerical score indicating how relevant the snippet is to the query, normalized between 0 and 1. If all results have the same score, this is because they are ranked using reciprocal rank fusion and their scores are not directly comparable.
+                - referenced_signatures: Signature lines of types the snippet uses that are defined elsewhere.
+            - elided: Matches that were cut short or left out to fit `token_limit`. Overlapping and adjacent matches in a file are merged before the budget is filled.
+            - cursor: If there are more results, pass this back as `cursor` to get the next page instead of rephrasing the query. It's empty on the last page.
         """)
 
 FIND_CODE_INSTRUCTION = ""
 
+FIND_REFERENCES_TITLE = "CodeWeaver find_references Tool"
+
+FIND_REFERENCES_DESCRIPTION = dedent("""
+        CodeWeaver's `find_references` tool answers structural questions about a symbol -- who calls it, what it calls, what implements it -- from a symbol index built while indexing the codebase. It does not use a vector search, so it is fast and exact for names, and complements `find_code` when you already know the symbol you care about.
+
+        # Using `find_references`
+
+        **One Required Argument:**
+            - symbol: The function, method, type, trait, interface or class name. Paths like `Cache::get` or `cache.get` are accepted; only the last segment is matched.
+
+        **Optional Arguments:**
+            - relation: What to find. One of `callers` (default), `callees`, `implementors`, `definitions`, `importers`.
+            - token_limit: Set a maximum number of tokens to return (default is 30000).
+
+        RETURNS:
+            The same response shape as `find_code`. Each match is one occurrence -- a call site, an `impl`/`extends`/`implements` declaration, a definition or an import -- in file and line order. `related_symbols` names the caller and callee, or the implementing type and the implemented trait.
+
+            Matching is by name, so symbols that share a name (two types with a `get` method) are both returned.
+        """)
+
+GET_OUTLINE_TITLE = "CodeWeaver get_outline Tool"
+
+GET_OUTLINE_DESCRIPTION = dedent("""
+        CodeWeaver's `get_outline` tool shows the shape of a file or a symbol without its implementation: the tree of its items -- types, traits, interfaces, classes, impl blocks, modules, functions and methods -- each with its signature, the first line of its docs and its line range. An outline is a small fraction of the file's tokens, so use it to learn a module's API before reading the parts you need. Supports Rust, Python, JavaScript, TypeScript and Go.
+
+        # Using `get_outline`
+
+        **One Required Argument:**
+            - target: A file path, relative to the project root (like `src/cache.rs`), or a symbol name (like `Cache` or `Cache::insert`). For a symbol, every file that defines it is searched for items with that name; a type's impl blocks are named by the type, so a Rust type comes with its impls and their methods.
+
+        RETURNS:
+            - outlines: One outline per file, with its path, language, line count and items. Each item has a `name`, its grammar `kind`, a one-line `signature`, a `doc` summary, `line` and `end_line`, and the `children` declared in its body.
+            - summary: What was outlined, or why nothing was.
+        """)
+
+READ_CODE_TITLE = "CodeWeaver read_code Tool"
+
+READ_CODE_DESCRIPTION = dedent("""
+        CodeWeaver's `read_code` tool opens code in the project: a whole file, a line range of it, or the definitions of a symbol. Use it after `find_code`, `find_references` or `get_outline` to read the exact lines around a match, or the whole function, type or impl block it's in. It only reads files inside the project that pass CodeWeaver's ignore rules -- the same files that are indexed.
+
+        # Using `read_code`
+
+        **Arguments (give a path, a symbol, or both):**
+            - path: A file path, relative to the project root (like `src/cache.rs`).
+            - span: The `[start, end]` lines to read from `path`, 1-based and inclusive, like a match's span. Without it the whole file is read.
+            - symbol: A symbol name (like `Cache` or `Cache::insert`) whose definitions to read, found through the symbol index. With `path`, only definitions in that file are read.
+            - expand: `"item"` widens the lines to the whole item they're in (a function, method, type or impl block); `"lines:N"` adds N lines on each side. Symbols are read as whole items unless you say otherwise.
+
+        RETURNS:
+            - sections: One per file or definition read, each with its `path`, `language`, `span` (the lines read), `content`, and the `item` signature if it was widened to an item. Sections over 400 lines are cut short and marked `truncated`; read the rest with a `span`.
+            - summary: What was read, or why nothing was.
+        """)
+
+CONTEXT_AGENT_SEARCH_TITLE = "CodeWeaver context agent search Tool"
+
+CONTEXT_AGENT_SEARCH_DESCRIPTION = dedent("""
+        Search the codebase with a natural language query. This is `find_code` in semantic mode for CodeWeaver's context agent: the same ranking and filters, without another agentic pass. Returns ranked matches with their file, span and content.
+        """)
+
+CONTEXT_AGENT_PLAN_INSTRUCTIONS = dedent("""
+        You plan code searches for a coding agent. You get its query and the intent a keyword heuristic guessed for it.
+
+        1. Check the intent. Keep it if it fits the query; otherwise choose the one that does: `understand`, `implement`, `debug`, `optimize`, `test`, `configure` or `document`. If the intent was set by the caller, keep it.
+        2. Write the searches that will find the code the query needs. Rewrite a vague query into a specific one, naming the likely functions, types, files or error messages. Split a query that asks about several things into one focused search for each. A query that's already specific can stay as it is.
+
+        Keep each search short and in plain language, and write no more searches than the query needs.
+        """)
+
+CONTEXT_AGENT_REVIEW_INSTRUCTIONS = dedent("""
+        You choose the code a coding agent gets back for its query. You get the query, its intent, the planned searches, and the matches found so far, each with an ID.
+
+        Run the planned searches with `search_code`, and any others you need when the matches don't cover the query. Then return the IDs of the matches that help answer the query, most useful first. Leave out matches that are off topic, duplicates of others, or only mention a name the query uses. Don't keep a match just because it was found; fewer relevant matches are better than many loose ones.
+        """)
+
 USER_AGENT_TAGS = {"user", "external"}
 
 CONTEXT_AGENT_TAGS = {"context", "internal", "data"}
@@ -59,6 +145,27 @@ DEFAULT_DENSE_WEIGHT = 0.65
 
 DEFAULT_SPARSE_WEIGHT = 0.35
 
+DEFAULT_RRF_K = 2
+"""Reciprocal rank fusion's rank constant; small values favor results near the top of a ranking."""
+
+DEFAULT_INTENT_DENSE_WEIGHTS = {
+    "understand": 0.7,
+    "implement": 0.65,
+    "debug": 0.45,
+    "optimize": 0.6,
+    "test": 0.55,
+    "configure": 0.4,
+    "document": 0.75,
+}
+"""Dense weight for hybrid search, by query intent; the sparse weight is the rest.
+
+Conceptual questions lean on dense (semantic) similarity. Debugging and configuration queries
+tend to quote exact identifiers, error messages and keys, which sparse (lexical) matching finds.
+"""
+
+DEFAULT_SEMANTIC_BOOST = 0.2
+"""How much a chunk's importance for the agent's task raises its score, at most (0.2 = 20%)."""
+
 # ===========================================================================
 # *                           TIME Constants
 # ===========================================================================
@@ -339,6 +446,10 @@ DEFAULT_VECTOR_STORE_MAX_RESULTS = 30
 DEFAULT_VECTOR_STORE_BATCH_SIZE = 64
 """Default batch size for vector store operations."""
 
+DEFAULT_SQLITE_EXACT_SEARCH_POINTS = 20_000
+"""Default size past which a SQLite vector store collection's dense vectors get an IVF index
+instead of an exact scan."""
+
 # ======= Server Defaults
 
 DEFAULT_HOST = "127.0.0.1"
@@ -413,6 +524,15 @@ DEFAULT_MAX_RESULTS = 10
 DEFAULT_RERANKING_MAX_RESULTS = 15
 """Default maximum number of results to consider for reranking in `find_code`."""
 
+DEFAULT_CONTEXT_AGENT_MAX_SEARCHES = 4
+"""Default most searches the context agent runs for one `find_code` query."""
+
+DEFAULT_RANKED_QUERIES = 32
+"""Default number of queries whose ranked candidates `find_code` keeps for paging with cursors."""
+
+DEFAULT_RANKED_RESULTS_TTL = TEN_MINUTES
+"""Default seconds `find_code` keeps a query's ranked candidates for paging with cursors."""
+
 # ===========================================================================
 # *                   MCP Middleware Defaults
 # ===========================================================================
@@ -848,7 +968,11 @@ __all__ = (
     "CONTEXT_AGENT_COST_PER_1K_TOKENS",
     "CONTEXT_AGENT_EXPLANATORY_TASK_CONFIG",
     "CONTEXT_AGENT_INTENT_AND_TASK_VALIDATION_CONFIG",
+    "CONTEXT_AGENT_PLAN_INSTRUCTIONS",
     "CONTEXT_AGENT_RESULT_REVIEW_CONFIG",
+    "CONTEXT_AGENT_REVIEW_INSTRUCTIONS",
+    "CONTEXT_AGENT_SEARCH_DESCRIPTION",
+    "CONTEXT_AGENT_SEARCH_TITLE",
     "CONTEXT_AGENT_TAGS",
     "DATATYPE_FIELDS",
     "DEFAULT_AGENT_TEMPERATURE",
@@ -857,6 +981,7 @@ __all__ = (
     "DEFAULT_BLAKE_STORE_MAX_SIZE",
     "DEFAULT_CHECKPOINT_SUBPATH",
     "DEFAULT_COLLECTION_NAME_PREFIX",
+    "DEFAULT_CONTEXT_AGENT_MAX_SEARCHES",
     "DEFAULT_DAEMON_STARTUP_CHECK_INTERVAL",
     "DEFAULT_DAEMON_STARTUP_WAIT",
     "DEFAULT_DENSE_WEIGHT",
@@ -871,6 +996,7 @@ __all__ = (
     "DEFAULT_HTTPX_KEEPALIVE_EXPIRY",
     "DEFAULT_HTTPX_MAX_CONNECTIONS",
     "DEFAULT_HTTPX_MAX_KEEPALIVE_CONNECTIONS",
+    "DEFAULT_INTENT_DENSE_WEIGHTS",
     "DEFAULT_LOCAL_EMBEDDING_BATCH_SIZE",
     "DEFAULT_LOG_LEVEL",
     "DEFAULT_MANAGEMENT_PORT",
@@ -893,16 +1019,21 @@ __all__ = (
     "DEFAULT_POOL_CONNECTION_TIMEOUT",
     "DEFAULT_POOL_READ_TIMEOUT",
     "DEFAULT_POOL_WRITE_TIMEOUT",
+    "DEFAULT_RANKED_QUERIES",
+    "DEFAULT_RANKED_RESULTS_TTL",
     "DEFAULT_RECONCILIATION_SERVICE_BATCH_SIZE",
     "DEFAULT_RERANKING_MAX_RESULTS",
     "DEFAULT_RERANKING_MAX_RESULTS",
     "DEFAULT_RERANKING_TIMEOUT",
     "DEFAULT_RICH_HANDLER_OPTIONS",
+    "DEFAULT_RRF_K",
     "DEFAULT_SEMANTIC_IDENTIFICATION_CONFIDENCE_THRESHOLD",
     "DEFAULT_SERVER_SHUTDOWN_TIMEOUT",
     "DEFAULT_SNAPSHOT_RETENTION_COUNT",
     "DEFAULT_SNAPSHOT_RETENTION_COUNT",
+    "DEFAULT_SEMANTIC_BOOST",
     "DEFAULT_SPARSE_WEIGHT",
+    "DEFAULT_SQLITE_EXACT_SEARCH_POINTS",
     "DEFAULT_USER_DIR_NAME",
     "DEFAULT_UUID_STORE_MAX_SIZE",
     "DEFAULT_VECTOR_STORE_BATCH_SIZE",
@@ -923,7 +1054,11 @@ __all__ = (
     "FIND_CODE_DESCRIPTION",
     "FIND_CODE_INSTRUCTION",
     "FIND_CODE_TITLE",
+    "FIND_REFERENCES_DESCRIPTION",
+    "FIND_REFERENCES_TITLE",
     "FIVE_MINUTES",
+    "GET_OUTLINE_DESCRIPTION",
+    "GET_OUTLINE_TITLE",
     "HEALTH_ENDPOINT",
     "INDEXER_WINDDOWN_TIMEOUT",
     "INTROSPECTION_ATTRIBUTES",
@@ -961,6 +1096,8 @@ __all__ = (
     "PYDANTIC_AI_MODEL_CAPABILITIES_PROVIDERS",
     "PYTHON_SHEBANG",
     "QDRANT_MEMORY_LOCATION",
+    "READ_CODE_DESCRIPTION",
+    "READ_CODE_TITLE",
     "RECOMMENDED_CLOUD_CONTEXT_AGENT_MODEL",
     "RECOMMENDED_CLOUD_CONTEXT_AGENT_MODEL_BARE",
     "RECOMMENDED_CLOUD_EMBEDDING_MODEL",
@@ -180,6 +180,16 @@ class DiscoveredFile(BasedModel):
     _git_branch: Annotated[
         str | Missing, Field(description="Git branch the file was discovered in, if detected.")
     ] = MISSING
+    _package_version: Annotated[
+        str | None,
+        Field(
+            description="Version of the third-party package the file is from, for dependency sources."
+        ),
+    ] = None
+    _crate_location: Annotated[
+        tuple[str | None, str | None] | None,
+        Field(description="Cached Cargo crate name and module path, once looked up."),
+    ] = None
     source_id: Annotated[
         UUID7,
         Field(
@@ -210,9 +220,14 @@ class DiscoveredFile(BasedModel):
         git_branch: str | None = None,
         project_path: ResolvedProjectPathDep = INJECTED,
         registry: SourceIdRegistry = INJECTED,
+        package_version: str | None = None,
         **kwargs: Any,
     ) -> None:
-        """Initialize DiscoveredFile with optional file_hash and git_branch."""
+        """Initialize DiscoveredFile with optional file_hash and git_branch.
+
+        A file from a third-party package's sources (with a `package_version`) isn't in the
+        project's git repository, so it has no git branch.
+        """
         object.__setattr__(self, "path", path)
         object.__setattr__(self, "project_path", project_path)
         resolved_ext = ext_category or ExtCategory.from_file(path)
@@ -228,7 +243,11 @@ class DiscoveredFile(BasedModel):
             )
         else:
             object.__setattr__(self, "_file_hash", None)
-        if git_branch and git_branch is not MISSING:
+        object.__setattr__(self, "_package_version", package_version)
+        object.__setattr__(self, "_crate_location", None)
+        if package_version:
+            object.__setattr__(self, "_git_branch", MISSING)
+        elif git_branch and git_branch is not MISSING:
             object.__setattr__(self, "_git_branch", git_branch)
         elif path.exists():
             object.__setattr__(self, "_git_branch", get_git_branch(path) or MISSING)
@@ -246,6 +265,8 @@ class DiscoveredFile(BasedModel):
             "source_id": self.source_id,
             "_file_hash": self._file_hash,
             "_git_branch": self._git_branch,
+            "_package_version": self._package_version,
+            "_crate_location": self._crate_location,
         }
 
     def __setstate__(self, state: dict[str, Any]) -> None:
@@ -305,10 +326,15 @@ class DiscoveredFile(BasedModel):
     @property
     def git_branch(self) -> str | Missing:
         """Return the git branch the file was discovered in, if available."""
-        if self._git_branch is MISSING:
+        if self._git_branch is MISSING and not self._package_version:
             return get_git_branch(self.path.parent) or MISSING
         return self._git_branch
 
+    @property
+    def package_version(self) -> str | None:
+        """Return the version of the third-party package the file is from, if it's from one."""
+        return self._package_version
+
     @property
     def absolute_path(self) -> Path:
         """Return the absolute path to the file."""
@@ -323,6 +349,33 @@ class DiscoveredFile(BasedModel):
         except FileNotFoundError:
             return self.path
 
+    def crate_location(self) -> tuple[str | None, str | None]:
+        """Return the owning Cargo crate's name and the file's crate-relative module path.
+
+        Both are None when the project has no root `Cargo.toml` or the file is outside every
+        workspace member. The module path is None for files that aren't Rust sources. Looked up
+        once per file.
+        """
+        if self._crate_location is not None:
+            return self._crate_location
+        from codeweaver.core.workspace import find_cargo_workspace
+
+        location: tuple[str | None, str | None] = (None, None)
+        if self.project_path and (workspace := find_cargo_workspace(self.project_path)):
+            location = workspace.locate(self.path)
+        object.__setattr__(self, "_crate_location", location)
+        return location
+
+    @property
+    def crate(self) -> str | None:
+        """Return the name of the Cargo crate that owns the file, if any."""
+        return self.crate_location()[0]
+
+    @property
+    def module_path(self) -> str | None:
+        """Return the file's crate-relative Rust module path (e.g. `crate::cache`), if any."""
+        return self.crate_location()[1]
+
     @computed_field
     @property
     def size(self) -> NonNegativeInt:
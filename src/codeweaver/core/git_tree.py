@@ -0,0 +1,176 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Read a project's files as of any git revision, straight from the object database.
+
+Indexing a revision (`cw index --rev v1.2.0`) doesn't check anything out: `git ls-tree` lists
+the revision's files with their blob IDs and sizes, and `git cat-file --batch` reads the blobs.
+A blob ID names exact file content, so two revisions that share a blob at the same path share
+its chunks and embeddings too.
+"""
+
+from __future__ import annotations
+
+import logging
+import shutil
+import subprocess
+
+from collections.abc import Iterable, Iterator, Mapping
+from pathlib import Path
+from typing import NamedTuple
+
+from codeweaver.core.exceptions import IndexingError
+
+
+logger = logging.getLogger(__name__)
+
+SHORT_COMMIT_LENGTH = 12
+"""Abbreviated commits (in collection and manifest names) are this many hex digits long."""
+
+_SYMLINK_MODE = "120000"
+
+
+class GitBlob(NamedTuple):
+    """A file in a git tree."""
+
+    path: Path
+    """Path relative to the project root."""
+    blob_id: str
+    size: int
+
+
+def _git(root: Path, *args: str, stdin: bytes | None = None) -> bytes:
+    """Run a git command in `root` and return its output."""
+    if not (git := shutil.which("git")):
+        raise IndexingError(
+            "Indexing a git revision requires git, but it isn't installed",
+            suggestions=["Install git, or index the working tree with `cw index`"],
+        )
+    result = subprocess.run(  # noqa: S603
+        [git, *args], cwd=root, input=stdin, capture_output=True, check=False
+    )
+    if result.returncode != 0:
+        raise IndexingError(
+            f"git {args[0]} failed: {result.stderr.decode('utf-8', 'replace').strip()}",
+            details={"command": ["git", *args], "cwd": str(root)},
+        )
+    return result.stdout
+
+
+def revision_collection_name(collection_name: str, commit: str) -> str:
+    """The vector store collection for a revision's index, next to the working tree's."""
+    return f"{collection_name}-rev-{commit[:SHORT_COMMIT_LENGTH]}"
+
+
+class GitTree:
+    """The files of one git commit, readable without a checkout."""
+
+    def __init__(self, root: Path, rev: str, commit: str, blobs: Mapping[Path, GitBlob]) -> None:
+        """Initialize a tree; use `GitTree.resolve` to read one from a repository.
+
+        Args:
+            root: The project root (the repository or a directory in it)
+            rev: The revision as the user named it (commit, branch or tag)
+            commit: The full commit ID `rev` resolved to
+            blobs: The tree's files under `root`, by path relative to `root`
+        """
+        self.root = root
+        self.rev = rev
+        self.commit = commit
+        self.blobs = dict(blobs)
+
+    @classmethod
+    def resolve(cls, root: Path, rev: str) -> GitTree:
+        """Resolve a commit, branch or tag and list its files under `root`.
+
+        Raises:
+            IndexingError: If git isn't available or `rev` doesn't name a commit
+        """
+        try:
+            commit = (
+                _git(root, "rev-parse", "--verify", "--end-of-options", f"{rev}^{{commit}}")
+                .decode()
+                .strip()
+            )
+        except IndexingError as e:
+            raise IndexingError(
+                f"'{rev}' isn't a commit, branch or tag in this repository",
+                details={"rev": rev, "project_path": str(root)},
+                suggestions=[
+                    f"Check the name with `git rev-parse {rev}`",
+                    "Fetch remote branches and tags first with `git fetch`",
+                ],
+            ) from e
+        # Without --full-tree, ls-tree lists only `root`'s subtree, relative to it
+        listing = _git(root, "ls-tree", "-r", "-l", "-z", commit)
+        return cls(root, rev, commit, dict(_parse_ls_tree(listing)))
+
+    @property
+    def short_commit(self) -> str:
+        """The abbreviated commit ID."""
+        return self.commit[:SHORT_COMMIT_LENGTH]
+
+    def __len__(self) -> int:
+        """Return the number of files in the tree."""
+        return len(self.blobs)
+
+    def __iter__(self) -> Iterator[GitBlob]:
+        """Iterate over the tree's files."""
+        return iter(self.blobs.values())
+
+    def read(self, blobs: Iterable[GitBlob]) -> dict[Path, bytes]:
+        """Read the content of files, by path, in one `git cat-file` call."""
+        wanted = list(blobs)
+        if not wanted:
+            return {}
+        output = _git(
+            self.root,
+            "cat-file",
+            "--batch",
+            stdin="".join(f"{blob.blob_id}\n" for blob in wanted).encode(),
+        )
+        contents: dict[Path, bytes] = {}
+        position = 0
+        for blob in wanted:
+            header_end = output.index(b"\n", position)
+            header = output[position:header_end].decode()
+            position = header_end + 1
+            if header.endswith(" missing"):
+                logger.debug("Skipping %s: blob %s is missing", blob.path, blob.blob_id)
+                continue
+            _, kind, raw_size = header.rsplit(" ", 2)
+            start, size = position, int(raw_size)
+            position = start + size + 1
+            if kind == "blob":
+                contents[blob.path] = output[start : start + size]
+        return contents
+
+    def read_text(self, path: Path) -> str:
+        """Read one file as text, by absolute path or path relative to the root.
+
+        Raises:
+            FileNotFoundError: If the tree has no file at `path`
+        """
+        relative = path.relative_to(self.root) if path.is_relative_to(self.root) else path
+        blob = self.blobs.get(relative)
+        content = self.read([blob]).get(relative) if blob else None
+        if content is None:
+            raise FileNotFoundError(f"{path} isn't in {self.rev} ({self.short_commit})")
+        return content.decode("utf-8", "ignore")
+
+
+def _parse_ls_tree(listing: bytes) -> Iterator[tuple[Path, GitBlob]]:
+    """Parse `git ls-tree -r -l -z` output, skipping symlinks and submodules."""
+    for record in listing.split(b"\0"):
+        if not record:
+            continue
+        meta, _, raw_path = record.partition(b"\t")
+        mode, kind, blob_id, size = meta.decode().split(maxsplit=3)
+        if kind != "blob" or mode == _SYMLINK_MODE:
+            continue
+        path = Path(raw_path.decode("utf-8", "surrogateescape"))
+        yield path, GitBlob(path, blob_id, int(size))
+
+
+__all__ = ("SHORT_COMMIT_LENGTH", "GitBlob", "GitTree", "revision_collection_name")
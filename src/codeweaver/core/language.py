@@ -73,7 +73,7 @@ class LanguageConfigFile(NamedTuple):
 
     dependency_key_paths: tuple[tuple[str, ...], ...] | None = None
     """
-    A tuple consisting of tuples. Each inner tuple represents a path to the package dependencies in the config file (not dev, build, or any other dependency groups -- just package dependencies).
+    A tuple consisting of tuples. Each inner tuple represents a path to a dependency table in the config file. Dev and build dependency groups are included where the ecosystem has them; the dependency extraction service infers the dependency kind from the path (e.g. `dev-dependencies`, `devDependencies`, `require-dev`).
 
     For example, in `pyproject.toml`, there are at least two paths to package dependencies:
 
@@ -90,6 +90,9 @@ class LanguageConfigFile(NamedTuple):
     Some cases me just be a single path with a single key, like:
         - `(("dependencies",),)`
 
+    A `"*"` segment matches any key, for tables keyed by something arbitrary, like Cargo's `[target.'cfg(unix)'.dependencies]`:
+        - `(("target", "*", "dependencies"),)`
+
     Makefiles don't really have keys, per-se, but we instead use the `dependency_key_paths` to indicate which variable is used for dependencies, like `CXXFLAGS` or `LDFLAGS`:
     - `dependency_key_paths=(("CXXFLAGS",),)`  # for C++ Makefiles
     - `dependency_key_paths=(("LDFLAGS",),)`   # for C Makefiles
@@ -370,7 +373,7 @@ class SemanticSearchLanguage(str, BaseEnum):
         """
         Returns the LanguageConfigFiles associated with this language.
 
-        # We haven't implemented dependency extraction yet, but when we do, we want to make sure these paths are correct.
+        `codeweaver.engine.services.dependency_service` reads these to build the project's dependency graph.
         """
         match self:
             case SemanticSearchLanguage.C_LANG:
@@ -512,7 +515,7 @@ class SemanticSearchLanguage(str, BaseEnum):
                         language=self,
                         path=Path("package.json"),
                         language_type=ConfigLanguage.JSON,
-                        dependency_key_paths=(("dependencies",),),
+                        dependency_key_paths=(("dependencies",), ("devDependencies",)),
                     ),
                 )
             case SemanticSearchLanguage.KOTLIN:
@@ -579,7 +582,7 @@ class SemanticSearchLanguage(str, BaseEnum):
                         language=self,
                         path=Path("composer.json"),
                         language_type=ConfigLanguage.JSON,
-                        dependency_key_paths=(("require",),),
+                        dependency_key_paths=(("require",), ("require-dev",)),
                     ),
                 )
             case SemanticSearchLanguage.PYTHON:
@@ -639,7 +642,15 @@ class SemanticSearchLanguage(str, BaseEnum):
                         language=self,
                         path=Path("Cargo.toml"),
                         language_type=ConfigLanguage.TOML,
-                        dependency_key_paths=(("dependencies",),),
+                        dependency_key_paths=(
+                            ("dependencies",),
+                            ("dev-dependencies",),
+                            ("build-dependencies",),
+                            ("target", "*", "dependencies"),
+                            ("target", "*", "dev-dependencies"),
+                            ("target", "*", "build-dependencies"),
+                            ("workspace", "dependencies"),
+                        ),
                     ),
                 )
             case SemanticSearchLanguage.SCALA:
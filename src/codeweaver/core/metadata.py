@@ -68,6 +68,7 @@ class ChunkSource(BaseEnum):
     FILE = "file"  # the whole file is the chunk
     SEMANTIC = "semantic"  # semantic chunking, e.g. from AST nodes
     EXTERNAL = "external"  # from internet or similar external sources, not from code files
+    SKELETON = "skeleton"  # a file's signatures and docs, with function bodies elided
 
 
 class Metadata(TypedDict, total=False):
@@ -124,6 +125,14 @@ class Metadata(TypedDict, total=False):
     semantic_meta: NotRequired[
         Annotated[SemanticMetadata | None, Field(description="""Semantic metadata""")]
     ]
+    generation: NotRequired[
+        Annotated[
+            str | None,
+            Field(
+                description="""The index generation the chunk was stored in. A file's chunks from older generations are removed once a new generation is stored."""
+            ),
+        ]
+    ]
     context: Annotated[
         dict[str, Any] | None,
         Field(
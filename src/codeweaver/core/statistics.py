@@ -1302,6 +1302,7 @@ class SessionStatistics(BasedModel):
         user_agent_received: NonNegativeInt = 0,
         search_results: NonNegativeInt = 0,
         saved_by_reranking: NonNegativeInt = 0,
+        saved_by_context_agent: NonNegativeInt = 0,
     ) -> None:
         """Add token usage statistics."""
         if self.token_statistics is None:
@@ -1314,6 +1315,7 @@ class SessionStatistics(BasedModel):
         self.token_statistics[TokenCategory.USER_AGENT] += user_agent_received
         self.token_statistics[TokenCategory.SEARCH_RESULTS] += search_results
         self.token_statistics[TokenCategory.SAVED_BY_RERANKING] += saved_by_reranking
+        self.token_statistics[TokenCategory.SAVED_BY_CONTEXT_AGENT] += saved_by_context_agent
 
     def get_token_usage(self) -> TokenCounter:
         """Get the current token usage statistics."""
@@ -118,6 +118,7 @@ if TYPE_CHECKING:
         FilteredReturn,
     )
     from codeweaver.core.types.env import EnvFormat, EnvVarInfo, ProviderEnvVars, VariableInfo
+    from codeweaver.core.types.fusion import FusedScore, FusionMethod, FusionStrategy
     from codeweaver.core.types.models import (
         BASEDMODEL_CONFIG,
         FILTERED_KEYS,
@@ -243,6 +244,9 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "FilteredKey": (__spec__.parent, "aliases"),
     "FilteredKeyT": (__spec__.parent, "aliases"),
     "FilteredReturn": (__spec__.parent, "enum"),
+    "FusedScore": (__spec__.parent, "fusion"),
+    "FusionMethod": (__spec__.parent, "fusion"),
+    "FusionStrategy": (__spec__.parent, "fusion"),
     "HttpRequestsDict": (__spec__.parent, "statistics"),
     "LanguageFamily": (__spec__.parent, "delimiter"),
     "LanguageName": (__spec__.parent, "aliases"),
@@ -375,6 +379,9 @@ __all__ = (
     "FilteredKey",
     "FilteredKeyT",
     "FilteredReturn",
+    "FusedScore",
+    "FusionMethod",
+    "FusionStrategy",
     "HttpRequestsDict",
     "LanguageFamily",
     "LanguageName",
@@ -0,0 +1,121 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Rank fusion: combining hybrid search's dense and sparse rankings into one.
+
+Dense (cosine) and sparse (BM25-like) scores aren't on comparable scales, so a fusion strategy
+first puts each ranking on a common 0-1 scale -- by rank (reciprocal rank fusion), by the
+ranking's score range (min-max), or by its score distribution (z-score) -- and then blends
+them with a dense weight. Vector stores run the fusion server-side when they can (see
+`FusionStrategy.server_side`) and call `FusionStrategy.fuse` otherwise.
+"""
+
+from __future__ import annotations
+
+import math
+import statistics
+
+from collections.abc import Hashable, Sequence
+from typing import NamedTuple
+
+from codeweaver.core.constants import DEFAULT_DENSE_WEIGHT, DEFAULT_RRF_K
+from codeweaver.core.types.enum import BaseEnum
+
+
+class FusionMethod(str, BaseEnum):
+    """How a fusion strategy puts dense and sparse scores on a common scale."""
+
+    RRF = "rrf"
+    """Reciprocal rank fusion: scores are replaced by `1 / (k + rank)`, so only ranks matter."""
+    MIN_MAX = "min_max"
+    """Scores are scaled so each ranking's best is 1 and its worst 0."""
+    Z_SCORE = "z_score"
+    """Distribution-based: scores are scaled from three standard deviations below the ranking's
+    mean (0) to three above it (1). Qdrant calls this DBSF."""
+
+    __slots__ = ()
+
+
+class FusedScore[K: Hashable](NamedTuple):
+    """A result's fused score, with the raw scores it was fused from."""
+
+    key: K
+    score: float
+    dense_score: float | None
+    sparse_score: float | None
+
+
+class FusionStrategy(NamedTuple):
+    """How hybrid search combines its dense and sparse rankings."""
+
+    method: FusionMethod = FusionMethod.RRF
+    dense_weight: float = DEFAULT_DENSE_WEIGHT
+    """Share of the fused score from the dense ranking; the sparse ranking gets the rest."""
+    rrf_k: int = DEFAULT_RRF_K
+
+    @property
+    def sparse_weight(self) -> float:
+        """Share of the fused score from the sparse ranking."""
+        return 1 - self.dense_weight
+
+    @property
+    def is_balanced(self) -> bool:
+        """Whether the dense and sparse rankings are weighted equally."""
+        return math.isclose(self.dense_weight, 0.5)
+
+    @property
+    def server_side(self) -> bool:
+        """Whether Qdrant's query API can run this fusion itself.
+
+        Qdrant fuses with (weighted) RRF and with unweighted DBSF; min-max and weighted z-score
+        fusion run here, over the separately fetched rankings.
+        """
+        return self.method == FusionMethod.RRF or (
+            self.method == FusionMethod.Z_SCORE and self.is_balanced
+        )
+
+    def fuse[K: Hashable](
+        self,
+        dense: Sequence[tuple[K, float]],
+        sparse: Sequence[tuple[K, float]],
+        limit: int | None = None,
+    ) -> list[FusedScore[K]]:
+        """Fuse two rankings of `(key, score)` pairs, each sorted best first.
+
+        Returns:
+            Fused scores, best first, for every key in either ranking
+        """
+        raw_dense, raw_sparse = dict(dense), dict(sparse)
+        scaled_dense, scaled_sparse = self._scale(dense), self._scale(sparse)
+        fused = [
+            FusedScore(
+                key,
+                self.dense_weight * scaled_dense.get(key, 0.0)
+                + self.sparse_weight * scaled_sparse.get(key, 0.0),
+                raw_dense.get(key),
+                raw_sparse.get(key),
+            )
+            for key in dict.fromkeys([*raw_dense, *raw_sparse])
+        ]
+        fused.sort(key=lambda result: result.score, reverse=True)
+        return fused[:limit] if limit is not None else fused
+
+    def _scale[K: Hashable](self, ranking: Sequence[tuple[K, float]]) -> dict[K, float]:
+        """Put a ranking's scores on a 0-1 scale."""
+        if not ranking:
+            return {}
+        if self.method == FusionMethod.RRF:
+            return {key: 1 / (self.rrf_k + rank + 1) for rank, (key, _) in enumerate(ranking)}
+        scores = [score for _, score in ranking]
+        if self.method == FusionMethod.MIN_MAX:
+            low, spread = min(scores), max(scores) - min(scores)
+        else:
+            deviation = statistics.pstdev(scores)
+            low, spread = statistics.fmean(scores) - 3 * deviation, 6 * deviation
+        if not spread:
+            return dict.fromkeys((key for key, _ in ranking), 1.0)
+        return {key: min(max((score - low) / spread, 0.0), 1.0) for key, score in ranking}
+
+
+__all__ = ("FusedScore", "FusionMethod", "FusionStrategy")
@@ -104,6 +104,7 @@ class SDKClient(BaseEnum):
     PYDANTIC_GATEWAY = "gateway"
     QDRANT = "qdrant"
     SENTENCE_TRANSFORMERS = "sentence_transformers"
+    SQLITE = "sqlite"
     TAVILY = "tavily"
     VOYAGE = "voyage"
     X_AI = "x_ai"
@@ -285,6 +286,7 @@ class Provider(str, BaseEnum):  # noqa: SLOT000
     QDRANT = "qdrant"
     SAMBANOVA = "sambanova"
     SENTENCE_TRANSFORMERS = "sentence-transformers"
+    SQLITE = "sqlite"
     TAVILY = "tavily"
     TOGETHER = "together"
     VERCEL = "vercel"
@@ -499,6 +501,7 @@ type LiteralSDKClient = Literal[
     SDKClient.PYDANTIC_GATEWAY,
     SDKClient.QDRANT,
     SDKClient.SENTENCE_TRANSFORMERS,
+    SDKClient.SQLITE,
     SDKClient.TAVILY,
     SDKClient.VOYAGE,
     SDKClient.X_AI,
@@ -544,6 +547,7 @@ type LiteralProvider = Literal[
     Provider.PYDANTIC_GATEWAY,
     Provider.QDRANT,
     Provider.SENTENCE_TRANSFORMERS,
+    Provider.SQLITE,
     Provider.TAVILY,
     Provider.TOGETHER,
     Provider.VERCEL,
@@ -18,13 +18,14 @@ from codeweaver.core.discovery import DiscoveredFile
 from codeweaver.core.metadata import Metadata
 from codeweaver.core.types.embeddings import CodeWeaverSparseEmbedding
 from codeweaver.core.types.enum import BaseEnum
+from codeweaver.core.types.fusion import FusionMethod, FusionStrategy
 from codeweaver.core.types.models import BasedModel
 from codeweaver.core.types.utils import generate_field_title
 from codeweaver.core.utils import set_relative_path
 
 
 if TYPE_CHECKING:
-    from qdrant_client.http.models import FusionQuery, Prefetch
+    from qdrant_client.http.models import FusionQuery, Prefetch, RrfQuery
 
     from codeweaver.core.types import AnonymityConversion, FilteredKeyT
 
@@ -35,6 +36,7 @@ class SearchStrategy(BaseEnum):
     COMMIT_SEARCH = "commit_search"
     FILE_DISCOVERY = "file_discovery"
     LANGUAGE_SEARCH = "language_search"
+    STRUCTURAL_SEARCH = "structural_search"
     SYMBOL_SEARCH = "symbol_search"
     TEXT_SEARCH = "text_search"
     HYBRID_SEARCH = "hybrid_search"
@@ -42,6 +44,7 @@ class SearchStrategy(BaseEnum):
     SPARSE_ONLY = "sparse_only"
     DENSE_ONLY = "dense_only"
     KEYWORD_FALLBACK = "keyword_fallback"
+    CONTEXT_AGENT = "context_agent"
 
     # Alias for HYBRID_SEARCH for backward compatibility
     HYBRID = HYBRID_SEARCH
@@ -60,6 +63,13 @@ class StrategizedQuery(NamedTuple):
         SearchStrategy,
         Field(description="Search strategy to use", field_title_generator=generate_field_title),
     ]
+    fusion: Annotated[
+        FusionStrategy,
+        Field(
+            description="How hybrid search fuses the dense and sparse rankings",
+            field_title_generator=generate_field_title,
+        ),
+    ] = FusionStrategy()
 
     def is_empty(self) -> bool:
         """Check if both dense and sparse embeddings are None or empty."""
@@ -83,11 +93,39 @@ class StrategizedQuery(NamedTuple):
         """Check if both dense and sparse embeddings are present and non-empty."""
         return self.has_dense() and self.has_sparse()
 
+    def fusion_query(self) -> FusionQuery | RrfQuery:
+        """The Qdrant query that fuses the hybrid prefetches with this query's fusion strategy.
+
+        Raises:
+            QueryError: If Qdrant can't run the fusion (see `FusionStrategy.server_side`)
+        """
+        from qdrant_client.http.models import Fusion, FusionQuery, Rrf, RrfQuery
+
+        from codeweaver.core import QueryError
+
+        if not self.fusion.server_side:
+            raise QueryError(
+                f"Qdrant can't fuse with {self.fusion.method.variable} at these weights",
+                details={
+                    "method": self.fusion.method.variable,
+                    "dense_weight": self.fusion.dense_weight,
+                },
+                suggestions=["Fuse the separately fetched rankings with `FusionStrategy.fuse`"],
+            )
+        if self.fusion.method == FusionMethod.Z_SCORE:
+            return FusionQuery(fusion=Fusion.DBSF)
+        weights = (
+            None
+            if self.fusion.is_balanced
+            else [self.fusion.dense_weight, self.fusion.sparse_weight]
+        )
+        return RrfQuery(rrf=Rrf(k=self.fusion.rrf_k, weights=weights))
+
     def to_hybrid_query(
         self, query_options: dict[str, Any], kwargs: dict[str, Any]
-    ) -> dict[str, FusionQuery | list[Prefetch] | Any]:
-        """Convert to a FusionQuery for hybrid search."""
-        from qdrant_client.http.models import Prefetch, Rrf, RrfQuery, SparseVector
+    ) -> dict[str, FusionQuery | RrfQuery | list[Prefetch] | Any]:
+        """Convert to a fused query over dense and sparse prefetches for hybrid search."""
+        from qdrant_client.http.models import Prefetch, SparseVector
 
         from codeweaver.core import QueryError
 
@@ -141,7 +179,7 @@ class StrategizedQuery(NamedTuple):
         # Use bare vectors with 'using' parameter for named vector search in Prefetch
         assert self.dense is not None  # noqa: S101
         return {
-            "query": RrfQuery(rrf=Rrf(k=2)),
+            "query": self.fusion_query(),
             "prefetch": [
                 Prefetch(query=list(self.dense), using="primary", **prefetch_params),
                 Prefetch(query=sparse_vector, using="sparse", **prefetch_params),
@@ -103,6 +103,7 @@ type ProviderLiteralString = Literal[
     "qdrant",
     "sambanova",
     "sentence_transformers",
+    "sqlite",
     "tavily",
     "together",
     "vercel",
@@ -126,6 +127,7 @@ type SDKClientLiteralString = Literal[
     "openai",
     "qdrant",
     "sentence_transformers",
+    "sqlite",
     "tavily",
     "voyage",
     "x_ai",
@@ -169,7 +171,7 @@ def _local_only_providers() -> set[ProviderLiteralString]:
     Returns:
         A set of provider literals that are local-only.
     """
-    return {"fastembed", "sentence_transformers", "memory"}
+    return {"fastembed", "sentence_transformers", "memory", "sqlite"}
 
 
 def _sometimes_local_providers() -> set[ProviderLiteralString]:
@@ -275,7 +277,7 @@ def _data_providers():
 
 
 def _vector_providers():
-    return {"memory", "qdrant"}
+    return {"memory", "qdrant", "sqlite"}
 
 
 def _sparse_providers():
@@ -1392,10 +1394,11 @@ def _build_data_provider_cards() -> list[ServiceCard]:
 
 
 def _build_vector_store_cards() -> list[ServiceCard]:
-    """Build service cards for vector store providers (Qdrant, Memory).
+    """Build service cards for vector store providers (Qdrant, Memory, SQLite).
 
     Vector stores are used for storing and querying embeddings.
-    Both use AsyncQdrantClient, but Memory is in-memory with JSON persistence.
+    Qdrant and Memory both use AsyncQdrantClient, but Memory is in-memory with JSON persistence.
+    SQLite is embedded: its client is a single database file, with no server to run.
     """
     return [
         service_card_factory(
@@ -1412,6 +1415,13 @@ def _build_vector_store_cards() -> list[ServiceCard]:
             lateimport("qdrant_client", "AsyncQdrantClient"),
             "qdrant",
         ),
+        service_card_factory(
+            "sqlite",
+            "vector_store",
+            lateimport("codeweaver.providers.vector_stores.sqlite", "SqliteVectorStoreProvider"),
+            lateimport("codeweaver.providers.vector_stores.sqlite_client", "SqliteVectorClient"),
+            "sqlite",
+        ),
     ]
 
 
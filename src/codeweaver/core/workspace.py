@@ -0,0 +1,246 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Cargo workspace discovery.
+
+Reads the project's root `Cargo.toml` (the workspace definition described by Rust's
+`RepoConventions`), expands `[workspace] members` globs minus `exclude`, and maps files to
+the crate that owns them, along with their crate-relative module path.
+"""
+
+from __future__ import annotations
+
+import contextlib
+import logging
+import tomllib
+
+from functools import lru_cache
+from pathlib import Path
+from typing import TYPE_CHECKING, Annotated, Any
+
+from pydantic import ConfigDict, Field
+
+from codeweaver.core.types.models import BasedModel
+
+
+if TYPE_CHECKING:
+    from codeweaver.core.types import AnonymityConversion, FilteredKeyT
+
+
+logger = logging.getLogger(__name__)
+
+_CRATE_ROOT_STEMS = frozenset({"lib", "main", "mod"})
+_TARGET_DIRS = frozenset({"tests", "benches", "examples"})
+"""Cargo target directories whose top-level files are each their own crate root."""
+_TARGET_ROOT_STEMS = frozenset({"lib", "main"})
+"""Crate roots of targets with a directory of their own, like `examples/server/main.rs`."""
+
+
+def rust_module_path(file_path: Path | None) -> str:
+    """Get the crate-relative module path for a Rust source file.
+
+    Paths are anchored on the first `src` directory, or without one, the first target directory
+    (`tests`, `benches`, `examples`), so a module named `tests` inside `src` is still a module.
+
+    Examples:
+        `src/lib.rs` -> `crate`, `src/cache/mod.rs` -> `crate::cache`,
+        `src/cache/store.rs` -> `crate::cache::store`, `tests/common/mod.rs` -> `crate::common`,
+        `examples/server/main.rs` -> `crate`.
+    """
+    if file_path is None:
+        return "crate"
+    parts = Path(file_path).with_suffix("").parts
+    marker = next((i for i, part in enumerate(parts) if part == "src"), None)
+    if marker is None:
+        marker = next((i for i, part in enumerate(parts) if part in _TARGET_DIRS), None)
+    if marker is None:
+        # files outside `src/` and target dirs (like `build.rs`) are crate roots
+        return "crate"
+    rest = parts[marker + 1 :]
+    if parts[marker] == "src" and rest[:1] == ("bin",):
+        # each `src/bin/<name>.rs` and `src/bin/<name>/` is a crate of its own
+        rest = rest[2:]
+    elif parts[marker] in _TARGET_DIRS and (
+        len(rest) == 1 or (len(rest) == 2 and rest[1] in _TARGET_ROOT_STEMS)
+    ):
+        # so is each top-level file in a target dir, and each target directory's `main.rs`
+        return "crate"
+    if rest and rest[-1] in _CRATE_ROOT_STEMS:
+        rest = rest[:-1]
+    return "::".join(("crate", *rest))
+
+
+class CrateInfo(BasedModel):
+    """A crate in a Cargo workspace."""
+
+    model_config = BasedModel.model_config | ConfigDict(frozen=True)
+
+    name: Annotated[str, Field(description="""The package name from the crate's Cargo.toml""")]
+    path: Annotated[
+        Path, Field(description="""The crate's directory, relative to the workspace root""")
+    ]
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        from codeweaver.core.types import AnonymityConversion, FilteredKey
+
+        return {
+            FilteredKey("name"): AnonymityConversion.HASH,
+            FilteredKey("path"): AnonymityConversion.HASH,
+        }
+
+    def contains(self, relative_path: Path) -> bool:
+        """Check if a workspace-relative path is inside this crate's directory."""
+        return self.path == Path() or relative_path.is_relative_to(self.path)
+
+    def module_path(self, relative_path: Path) -> str | None:
+        """Get the module path of a workspace-relative Rust file in this crate."""
+        if relative_path.suffix != ".rs":
+            return None
+        return rust_module_path(relative_path.relative_to(self.path))
+
+
+class CargoWorkspace(BasedModel):
+    """The crates defined by a project's root `Cargo.toml`."""
+
+    model_config = BasedModel.model_config | ConfigDict(frozen=True)
+
+    root: Annotated[Path, Field(description="""The workspace root directory""")]
+    crates: Annotated[
+        tuple[CrateInfo, ...],
+        Field(description="""Workspace member crates (and the root package, if any)"""),
+    ] = ()
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        from codeweaver.core.types import AnonymityConversion, FilteredKey
+
+        return {FilteredKey("root"): AnonymityConversion.HASH}
+
+    @property
+    def crate_names(self) -> frozenset[str]:
+        """Names of all crates in the workspace."""
+        return frozenset(crate.name for crate in self.crates)
+
+    def crate_for(self, path: Path) -> CrateInfo | None:
+        """Get the crate that owns a file (the member with the deepest matching directory).
+
+        Args:
+            path: A workspace-relative or absolute path
+
+        Returns:
+            The owning crate, or None if the file isn't inside any member
+        """
+        relative = path
+        if path.is_absolute():
+            try:
+                relative = path.relative_to(self.root)
+            except ValueError:
+                return None
+        owners = [crate for crate in self.crates if crate.contains(relative)]
+        return max(owners, key=lambda crate: len(crate.path.parts), default=None)
+
+    def locate(self, path: Path) -> tuple[str | None, str | None]:
+        """Get the crate name and crate-relative module path for a file."""
+        if (crate := self.crate_for(path)) is None:
+            return None, None
+        relative = path.relative_to(self.root) if path.is_absolute() else path
+        return crate.name, crate.module_path(relative)
+
+    @classmethod
+    def from_root(cls, root: Path) -> CargoWorkspace | None:
+        """Parse the workspace defined by `root/Cargo.toml`.
+
+        Handles `[workspace] members` (with globs), `exclude`, and a root `[package]`. A root
+        manifest with only `[package]` is treated as a single-crate workspace.
+
+        Returns:
+            The workspace, or None if there is no readable root `Cargo.toml`
+        """
+        if (manifest := _read_manifest(root / "Cargo.toml")) is None:
+            return None
+        crates: dict[Path, CrateInfo] = {}
+        if name := _package_name(manifest):
+            crates[Path()] = CrateInfo(name=name, path=Path())
+        workspace = manifest.get("workspace", {})
+        excluded = {
+            member.relative_to(root)
+            for pattern in workspace.get("exclude", ())
+            for member in _expand_member(root, pattern)
+        }
+        for pattern in workspace.get("members", ()):
+            for member in _expand_member(root, pattern):
+                relative = member.relative_to(root)
+                if relative in excluded or relative in crates:
+                    continue
+                member_manifest = _read_manifest(member / "Cargo.toml")
+                if member_manifest and (name := _package_name(member_manifest)):
+                    crates[relative] = CrateInfo(name=name, path=relative)
+        return cls(root=root, crates=tuple(crates.values()))
+
+
+def _read_manifest(path: Path) -> dict[str, Any] | None:
+    """Read a Cargo manifest, returning None if it's missing or invalid."""
+    if not path.is_file():
+        return None
+    try:
+        return tomllib.loads(path.read_text(encoding="utf-8"))
+    except (OSError, tomllib.TOMLDecodeError) as e:
+        logger.warning("Could not read Cargo manifest %s: %s", path, e)
+        return None
+
+
+def _package_name(manifest: dict[str, Any]) -> str | None:
+    """Get the package name from a manifest, if it defines one."""
+    package = manifest.get("package")
+    return package.get("name") if isinstance(package, dict) else None
+
+
+def _expand_member(root: Path, pattern: str) -> list[Path]:
+    """Expand a workspace member pattern (which may be a glob) into crate directories."""
+    if any(char in pattern for char in "*?["):
+        return sorted(p for p in root.glob(pattern) if (p / "Cargo.toml").is_file())
+    member = root / pattern
+    return [member] if member.is_dir() else []
+
+
+@lru_cache(maxsize=8)
+def _member_patterns(root: Path, mtime_ns: int) -> tuple[str, ...]:
+    """Get the root manifest's `[workspace] members` patterns, cached per manifest version."""
+    workspace = (_read_manifest(root / "Cargo.toml") or {}).get("workspace", {})
+    return tuple(workspace.get("members", ()))
+
+
+def _manifest_stamps(root: Path, mtime_ns: int) -> tuple[tuple[str, int], ...]:
+    """Get the mtimes of the root manifest and every member manifest it currently matches."""
+    stamps = [("Cargo.toml", mtime_ns)]
+    for pattern in _member_patterns(root, mtime_ns):
+        for member in _expand_member(root, pattern):
+            with contextlib.suppress(OSError):
+                manifest = member / "Cargo.toml"
+                stamps.append((str(manifest.relative_to(root)), manifest.stat().st_mtime_ns))
+    return tuple(stamps)
+
+
+@lru_cache(maxsize=8)
+def _cached_workspace(root: Path, stamps: tuple[tuple[str, int], ...]) -> CargoWorkspace | None:
+    """Cache parsed workspaces per root; any manifest's mtime, or a new member, invalidates."""
+    return CargoWorkspace.from_root(root)
+
+
+def find_cargo_workspace(project_path: Path) -> CargoWorkspace | None:
+    """Get the Cargo workspace rooted at the project path, if the project has one.
+
+    Args:
+        project_path: The project root
+
+    Returns:
+        The parsed workspace, or None for projects without a root `Cargo.toml`
+    """
+    root = project_path.resolve()
+    with contextlib.suppress(OSError):
+        mtime_ns = (root / "Cargo.toml").stat().st_mtime_ns
+        return _cached_workspace(root, _manifest_stamps(root, mtime_ns))
+    return None
+
+
+__all__ = ("CargoWorkspace", "CrateInfo", "find_cargo_workspace", "rust_module_path")
@@ -77,6 +77,8 @@ if TYPE_CHECKING:
         ChunkGovernorDep,
         ChunkingServiceDep,
         ConfigChangeAnalyzerDep,
+        DependencyServiceDep,
+        ExactSearchServiceDep,
         ExtensionFilterDep,
         FailoverServiceDep,
         FailoverSettingsDep,
@@ -89,6 +91,7 @@ if TYPE_CHECKING:
         MigrationServiceDep,
         ProgressTrackerDep,
         SourceIdRegistryDep,
+        SymbolIndexServiceDep,
     )
     from codeweaver.engine.managers.checkpoint_manager import (
         ChangeImpact,
@@ -115,6 +118,20 @@ if TYPE_CHECKING:
         ConfigChangeAnalyzer,
         TransformationDetails,
     )
+    from codeweaver.engine.services.dependency_service import (
+        DependencyEdge,
+        DependencyGraph,
+        DependencyKind,
+        DependencyService,
+        PackageInfo,
+    )
+    from codeweaver.engine.services.dependency_sources import (
+        DependencyEcosystem,
+        DependencySource,
+        DependencySourceLocator,
+        default_cargo_home,
+    )
+    from codeweaver.engine.services.exact_search_service import ExactHit, ExactSearchService
     from codeweaver.engine.services.failover_service import FailoverService
     from codeweaver.engine.services.indexing_service import IndexingService, ProgressCallback
     from codeweaver.engine.services.migration_service import (
@@ -134,6 +151,11 @@ if TYPE_CHECKING:
         VectorReconciliationService,
     )
     from codeweaver.engine.services.snapshot_service import QdrantSnapshotBackupService
+    from codeweaver.engine.services.symbol_index_service import (
+        SymbolIndex,
+        SymbolIndexService,
+        SymbolOccurrence,
+    )
     from codeweaver.engine.services.watching_service import FileWatchingService
     from codeweaver.engine.watcher._logging import WatchfilesLogManager
     from codeweaver.engine.watcher.progress import IndexingProgressUI
@@ -188,7 +210,18 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "Delimiter": (__spec__.parent, "chunker.delimiter_model"),
     "DelimiterChunker": (__spec__.parent, "chunker.delimiter"),
     "DelimiterMatch": (__spec__.parent, "chunker.delimiter_model"),
+    "DependencyEcosystem": (__spec__.parent, "services.dependency_sources"),
+    "DependencyEdge": (__spec__.parent, "services.dependency_service"),
+    "DependencyGraph": (__spec__.parent, "services.dependency_service"),
+    "DependencyKind": (__spec__.parent, "services.dependency_service"),
+    "DependencyService": (__spec__.parent, "services.dependency_service"),
+    "DependencyServiceDep": (__spec__.parent, "dependencies"),
+    "DependencySource": (__spec__.parent, "services.dependency_sources"),
+    "DependencySourceLocator": (__spec__.parent, "services.dependency_sources"),
     "DocsFilter": (__spec__.parent, "watcher.watch_filters"),
+    "ExactHit": (__spec__.parent, "services.exact_search_service"),
+    "ExactSearchService": (__spec__.parent, "services.exact_search_service"),
+    "ExactSearchServiceDep": (__spec__.parent, "dependencies"),
     "ExtensionFilter": (__spec__.parent, "watcher.watch_filters"),
     "ExtensionFilterDep": (__spec__.parent, "dependencies"),
     "FailoverDetector": (__spec__.parent, "config.failover_detector"),
@@ -229,6 +262,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "MigrationServiceDep": (__spec__.parent, "dependencies"),
     "MigrationState": (__spec__.parent, "services.migration_service"),
     "OversizedChunkError": (__spec__.parent, "chunker.exceptions"),
+    "PackageInfo": (__spec__.parent, "services.dependency_service"),
     "ParseError": (__spec__.parent, "chunker.exceptions"),
     "PatternKey": (__spec__.parent, "chunker.delimiters.families"),
     "PerformanceSettings": (__spec__.parent, "config.chunker"),
@@ -244,6 +278,10 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "SourceIdRegistry": (__spec__.parent, "chunker.registry"),
     "SourceIdRegistryDep": (__spec__.parent, "dependencies"),
     "StringParseState": (__spec__.parent, "chunker.delimiter"),
+    "SymbolIndex": (__spec__.parent, "services.symbol_index_service"),
+    "SymbolIndexService": (__spec__.parent, "services.symbol_index_service"),
+    "SymbolIndexServiceDep": (__spec__.parent, "dependencies"),
+    "SymbolOccurrence": (__spec__.parent, "services.symbol_index_service"),
     "TransformationDetails": (__spec__.parent, "services.config_analyzer"),
     "ValidationError": (__spec__.parent, "services.migration_service"),
     "VectorReconciliationService": (__spec__.parent, "services.reconciliation_service"),
@@ -253,6 +291,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ASTDepthExceededError": (__spec__.parent, "chunker.exceptions"),
     "chunk_files_parallel": (__spec__.parent, "chunker.parallel"),
     "chunk_files_parallel_dict": (__spec__.parent, "chunker.parallel"),
+    "default_cargo_home": (__spec__.parent, "services.dependency_sources"),
     "detect_family_characteristics": (__spec__.parent, "chunker.delimiters.families"),
     "detect_language_family": (__spec__.parent, "chunker.delimiters.families"),
     "expand_pattern": (__spec__.parent, "chunker.delimiters.patterns"),
@@ -303,7 +342,18 @@ __all__ = (
     "Delimiter",
     "DelimiterChunker",
     "DelimiterMatch",
+    "DependencyEcosystem",
+    "DependencyEdge",
+    "DependencyGraph",
+    "DependencyKind",
+    "DependencyService",
+    "DependencyServiceDep",
+    "DependencySource",
+    "DependencySourceLocator",
     "DocsFilter",
+    "ExactHit",
+    "ExactSearchService",
+    "ExactSearchServiceDep",
     "ExtensionFilter",
     "ExtensionFilterDep",
     "FailoverDetector",
@@ -345,6 +395,7 @@ __all__ = (
     "MigrationServiceDep",
     "MigrationState",
     "OversizedChunkError",
+    "PackageInfo",
     "ParseError",
     "PatternKey",
     "PerformanceSettings",
@@ -360,6 +411,10 @@ __all__ = (
     "SourceIdRegistry",
     "SourceIdRegistryDep",
     "StringParseState",
+    "SymbolIndex",
+    "SymbolIndexService",
+    "SymbolIndexServiceDep",
+    "SymbolOccurrence",
     "TransformationDetails",
     "ValidationError",
     "VectorReconciliationService",
@@ -368,6 +423,7 @@ __all__ = (
     "WorkItem",
     "chunk_files_parallel",
     "chunk_files_parallel_dict",
+    "default_cargo_home",
     "detect_family_characteristics",
     "detect_language_family",
     "expand_pattern",
@@ -0,0 +1,271 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Rust-specific node grouping for the semantic chunker.
+
+tree-sitter-rust parses outer attributes (`#[derive(...)]`) and outer doc comments (`///`)
+as *siblings* of the item they decorate, so a naive node-per-chunk split separates them
+from their item. Methods also lose their `impl` context. This module:
+- collects the leading attributes and doc comments of an item so they stay in its chunk,
+- records the enclosing `impl` block's self type, trait and generics,
+- builds a Rust path for the item (e.g. `crate::cache::Cache<T>::insert`), and
+- records macro context: a `macro_rules!` definition's matcher arms, the macros a node invokes
+  (resolved to their definitions when they're local), and the traits an item derives.
+"""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, NamedTuple
+
+from codeweaver.core.workspace import rust_module_path
+from codeweaver.semantic.symbols import derived_traits
+
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from ast_grep_py import SgNode
+
+
+_ATTRIBUTE_KIND = "attribute_item"
+_COMMENT_KINDS = frozenset({"line_comment", "block_comment"})
+_MACRO_DEFINITION_KIND = "macro_definition"
+_MACRO_INVOCATION_KIND = "macro_invocation"
+_DERIVING_KINDS = frozenset({"enum_item", "struct_item", "union_item"})
+
+# Invocations with these path prefixes (or none) can name a macro defined in the crate
+_LOCAL_MACRO_PREFIXES = frozenset({"", "crate", "$crate", "self", "super"})
+
+_NAMED_ITEM_KINDS = frozenset({
+    "const_item",
+    "enum_item",
+    "function_item",
+    "function_signature_item",
+    "macro_definition",
+    "mod_item",
+    "static_item",
+    "struct_item",
+    "trait_item",
+    "type_item",
+    "union_item",
+})
+
+
+class RustItemContext(NamedTuple):
+    """Rust-specific context for a chunked node."""
+
+    content: str
+    """The node's text with its leading attributes and doc comments."""
+    start_line: int
+    """1-based line where the content starts (the first attribute or doc comment, if any)."""
+    path: str | None
+    """The item's Rust path, or None for nodes that aren't named items."""
+    impl_type: str | None = None
+    """Self type of the enclosing (or this) `impl` block, e.g. `Cache<T>`."""
+    impl_trait: str | None = None
+    """Trait implemented by the enclosing (or this) `impl` block, e.g. `Cacheable`."""
+    impl_generics: str | None = None
+    """Generic parameters of the enclosing (or this) `impl` block, e.g. `<T: Cacheable>`."""
+    derives: tuple[str, ...] = ()
+    """Traits the item derives, as written, e.g. `("Debug", "serde::Serialize")`."""
+    macro_arms: tuple[str, ...] = ()
+    """For `macro_rules!` definitions, each arm's matcher, e.g. `($id:expr, $value:expr)`."""
+    macro_invocations: tuple[tuple[str, str | None], ...] = ()
+    """Macros invoked in the node: each macro's path as written, and the Rust path of the local
+    definition it resolves to (None for macros from other crates)."""
+
+
+def is_outer_doc_comment(node: SgNode) -> bool:
+    """Check if a comment node is an outer doc comment (`///` or `/** */`)."""
+    if node.kind() not in _COMMENT_KINDS:
+        return False
+    text = node.text()
+    if text.startswith("///"):
+        return not text.startswith("////")
+    return text.startswith("/**") and not text.startswith("/***") and text != "/**/"
+
+
+def is_leading_trivia(node: SgNode) -> bool:
+    """Check if a node is an outer attribute or doc comment that belongs to the next item."""
+    return node.kind() == _ATTRIBUTE_KIND or is_outer_doc_comment(node)
+
+
+def leading_trivia(node: SgNode) -> list[SgNode]:
+    """Get the attributes and doc comments that directly precede an item, in source order."""
+    trivia: list[SgNode] = []
+    previous = node.prev()
+    while previous is not None and is_leading_trivia(previous):
+        trivia.append(previous)
+        previous = previous.prev()
+    return trivia[::-1]
+
+
+def _join_with_layout(nodes: list[SgNode]) -> str:
+    """Join sibling nodes' text, restoring the line breaks and indentation between them."""
+    text = nodes[0].text()
+    for before, after in zip(nodes, nodes[1:], strict=False):
+        end, start = before.range().end, after.range().start
+        if start.line > end.line:
+            text += "\n" * (start.line - end.line) + " " * start.column
+        else:
+            text += " " * max(start.column - end.column, 0)
+        text += after.text()
+    return text
+
+
+def _field_text(node: SgNode, field: str) -> str | None:
+    """Get the text of a node's field, if present."""
+    return child.text() if (child := node.field(field)) is not None else None
+
+
+def _impl_owner(impl_node: SgNode, prefix: str) -> str:
+    """Get the path that methods of an impl block hang off of.
+
+    Inherent impls use the self type (`crate::Cache<T>`); trait impls use a qualified path
+    (`<crate::DataItem as Cacheable>`).
+    """
+    self_type = f"{prefix}::{_field_text(impl_node, 'type')}"
+    if trait := _field_text(impl_node, "trait"):
+        return f"<{self_type} as {trait}>"
+    return self_type
+
+
+def rust_item_path(node: SgNode, file_path: Path | None) -> str | None:
+    """Build the Rust path of an item node, or None if the node isn't a named item or impl."""
+    kind = node.kind()
+    if kind not in _NAMED_ITEM_KINDS and kind != "impl_item":
+        return None
+    segments = [rust_module_path(file_path)]
+    owner: str | None = None
+    for ancestor in reversed(list(node.ancestors())):
+        match ancestor.kind():
+            case "mod_item" if name := _field_text(ancestor, "name"):
+                segments.append(name)
+            case "impl_item":
+                owner = _impl_owner(ancestor, "::".join(segments))
+            case "trait_item" if name := _field_text(ancestor, "name"):
+                owner = "::".join((*segments, name))
+            case _:
+                continue
+    if kind == "impl_item":
+        return _impl_owner(node, "::".join(segments))
+    if (name := _field_text(node, "name")) is None:
+        return None
+    return f"{owner}::{name}" if owner else "::".join((*segments, name))
+
+
+def _is_macro_export(node: SgNode) -> bool:
+    return node.kind() == _ATTRIBUTE_KIND and node.text().replace(" ", "").startswith(
+        "#[macro_export"
+    )
+
+
+def macro_path(definition: SgNode, file_path: Path | None) -> str | None:
+    """Build the Rust path of a `macro_rules!` definition.
+
+    `#[macro_export]` macros live at the crate root (`crate::data_item`), wherever they're
+    defined; other macros are named like any other item.
+    """
+    if (name := _field_text(definition, "name")) is None:
+        return None
+    if any(_is_macro_export(attribute) for attribute in leading_trivia(definition)):
+        return f"crate::{name}"
+    return rust_item_path(definition, file_path)
+
+
+def macro_arms(definition: SgNode) -> tuple[str, ...]:
+    """Get the matchers of a `macro_rules!` definition's arms, in order."""
+    return tuple(
+        left.text()
+        for rule in definition.children()
+        if rule.kind() == "macro_rule" and (left := rule.field("left")) is not None
+    )
+
+
+def macro_invocations(node: SgNode, file_path: Path | None) -> tuple[tuple[str, str | None], ...]:
+    """Get the macros invoked in a node, each with the local definition it resolves to.
+
+    An invocation resolves to the `macro_rules!` definition of the same name in its file,
+    unless its path leads out of the crate (like `tokio::select!`). Macros defined in other
+    files of the crate are left to the symbol index, which links them by name.
+
+    Returns:
+        `(macro path as written, definition's Rust path or None)` pairs, in source order
+    """
+    paths = dict.fromkeys(
+        path
+        for invocation in (node, *node.find_all(kind=_MACRO_INVOCATION_KIND))
+        if invocation.kind() == _MACRO_INVOCATION_KIND
+        and (path := _field_text(invocation, "macro"))
+    )
+    if not paths:
+        return ()
+    definitions = {
+        name: definition
+        for definition in node.get_root().root().find_all(kind=_MACRO_DEFINITION_KIND)
+        if (name := _field_text(definition, "name"))
+    }
+
+    def resolve(path: str) -> str | None:
+        prefix, _, name = path.rpartition("::")
+        if prefix not in _LOCAL_MACRO_PREFIXES or (definition := definitions.get(name)) is None:
+            return None
+        return macro_path(definition, file_path)
+
+    return tuple((path, resolve(path)) for path in paths)
+
+
+def _nearest_impl(node: SgNode) -> SgNode | None:
+    """Get the impl block a node belongs to (the node itself if it is one)."""
+    if node.kind() == "impl_item":
+        return node
+    for ancestor in node.ancestors():
+        if ancestor.kind() == "impl_item":
+            return ancestor
+        if ancestor.kind() in ("mod_item", "trait_item"):
+            return None
+    return None
+
+
+def rust_item_context(node: SgNode, file_path: Path | None) -> RustItemContext:
+    """Gather the Rust-specific context for a node that is about to become a chunk.
+
+    Args:
+        node: The ast-grep node being chunked
+        file_path: The node's source file, used to derive its module path
+
+    Returns:
+        The node's content (with leading attributes and doc comments), start line, Rust path,
+        impl context and macro context
+    """
+    trivia = leading_trivia(node)
+    content = _join_with_layout([*trivia, node]) if trivia else node.text()
+    start_line = (trivia[0] if trivia else node).range().start.line + 1
+    impl_node = _nearest_impl(node)
+    is_macro_definition = node.kind() == _MACRO_DEFINITION_KIND
+    path = macro_path(node, file_path) if is_macro_definition else rust_item_path(node, file_path)
+    return RustItemContext(
+        content=content,
+        start_line=start_line,
+        path=path,
+        impl_type=_field_text(impl_node, "type") if impl_node else None,
+        impl_trait=_field_text(impl_node, "trait") if impl_node else None,
+        impl_generics=_field_text(impl_node, "type_parameters") if impl_node else None,
+        derives=derived_traits(node) if node.kind() in _DERIVING_KINDS else (),
+        macro_arms=macro_arms(node) if is_macro_definition else (),
+        macro_invocations=macro_invocations(node, file_path),
+    )
+
+
+__all__ = (
+    "RustItemContext",
+    "is_leading_trivia",
+    "is_outer_doc_comment",
+    "leading_trivia",
+    "macro_arms",
+    "macro_invocations",
+    "macro_path",
+    "rust_item_context",
+    "rust_item_path",
+)
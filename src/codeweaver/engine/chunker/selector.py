@@ -111,7 +111,7 @@ class ChunkerSelector:
             return self.select_for_file(discovered_file)
         return DelimiterChunker(self.governor, language="unknown")
 
-    def select_for_file(self, file: DiscoveredFile) -> BaseChunker:
+    def select_for_file(self, file: DiscoveredFile, *, size: int | None = None) -> BaseChunker:
         """Select best chunker for given file (creates fresh instance).
 
         Analyzes the file's extension to determine the appropriate chunking
@@ -120,6 +120,8 @@ class ChunkerSelector:
 
         Args:
             file: DiscoveredFile with path attribute for language detection
+            size: The file's size in bytes, for content that isn't read from the file on
+                disk (like a file in a git revision); by default the file is stat'ed
 
         Returns:
             Fresh BaseChunker instance appropriate for file's language.
@@ -153,7 +155,7 @@ class ChunkerSelector:
         if self.governor.settings is not None:
             max_size_bytes = self.governor.settings.performance.max_file_size_mb * 1024 * 1024
             try:
-                file_size = file.absolute_path.stat().st_size
+                file_size = file.absolute_path.stat().st_size if size is None else size
             except OSError as e:
                 logger.warning(
                     "Could not stat file %s: %s",
@@ -174,7 +176,7 @@ class ChunkerSelector:
                     )
         is_large_file = False
         with contextlib.suppress(OSError, AttributeError):
-            if file.absolute_path.stat().st_size > 500 * 1024:
+            if (file.absolute_path.stat().st_size if size is None else size) > 500 * 1024:
                 is_large_file = True
         language = self._detect_language(file)
         if isinstance(language, SemanticSearchLanguage) and (not is_large_file):
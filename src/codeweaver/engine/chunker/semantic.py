@@ -63,9 +63,11 @@ from codeweaver.core.constants import (
     ONE_MILLISECOND_IN_MICROSECONDS,
     SEMANTIC_CHUNKER_PERFORMANCE_THRESHOLD_MS,
 )
+from codeweaver.engine.chunker._rust import RustItemContext, is_leading_trivia, rust_item_context
 from codeweaver.engine.chunker.base import BaseChunker
 from codeweaver.engine.chunker.exceptions import ASTDepthExceededError, BinaryFileError, ParseError
 from codeweaver.engine.chunker.governance import ResourceGovernor
+from codeweaver.semantic.outline import render_skeleton
 from codeweaver.semantic.types import SemanticMetadata
 
 
@@ -107,6 +109,12 @@ class SemanticChunker(BaseChunker):
     - Comprehensive edge case handling (empty, binary, whitespace, single-line)
     - Resource governance (timeout and chunk count limits)
     - Rich metadata optimized for AI context delivery
+    - Rust items keep their attributes and doc comments, record their impl context, and are
+      named by Rust path (e.g. `crate::cache::Cache<T>::insert`)
+    - Rust macros: definitions record their matcher arms, invocations are tagged with the
+      macro and its local definition, and derives are recorded as implied trait impls
+    - With `skeleton_chunks` on, a skeleton of each file (signatures, types and docs, with
+      bodies elided) as an extra `ChunkSource.SKELETON` chunk, for languages with outline rules
 
     Attributes:
         language: Target language for semantic parsing
@@ -330,7 +338,9 @@ class SemanticChunker(BaseChunker):
         )
 
         # Convert nodes to chunks using cached depths
-        return self._convert_nodes_to_chunks(nodes, file_path, source_id, governor, node_depths)
+        chunks = self._convert_nodes_to_chunks(nodes, file_path, source_id, governor, node_depths)
+        chunks.extend(self._create_skeleton_chunks(root, content, file_path, source_id))
+        return chunks
 
     def _convert_nodes_to_chunks(
         self,
@@ -685,6 +695,10 @@ class SemanticChunker(BaseChunker):
         Returns:
             True if node should be included in chunking
         """
+        # Rust attributes and doc comments are merged into the item they decorate
+        if self.language == SemanticSearchLanguage.RUST and is_leading_trivia(node.sg_node):
+            return False
+
         # Allow composite nodes even if classification is unknown
         if not node.classification:
             return node.is_composite
@@ -753,25 +767,113 @@ class SemanticChunker(BaseChunker):
             CodeChunk with semantic metadata
         """
         range_obj = node.range
-        metadata = self._build_metadata(node, depth)
+        # ast-grep uses 0-based line numbers, Span uses 1-based
+        content, start_line = node.text, range_obj.start.line + 1
+        rust_context = None
+        named: dict[str, str] = {}
+        if self.language == SemanticSearchLanguage.RUST:
+            rust_context = rust_item_context(node.sg_node, file_path)
+            content, start_line = rust_context.content, rust_context.start_line
+            named["chunk_name"] = rust_context.path
+        metadata = self._build_metadata(node, depth, content=content, rust_context=rust_context)
 
         # Use model_construct to bypass validation since dependencies may not be fully defined
         return CodeChunk.model_construct(
-            content=node.text,
+            content=content,
             line_range=Span(
-                # ast-grep uses 0-based line numbers, Span uses 1-based
-                range_obj.start.line + 1,
-                range_obj.end.line + 1,
-                source_id,
+                start_line, range_obj.end.line + 1, source_id
             ),  # All spans from same file share source_id
             ext_category=ExtCategory.from_file(file_path) if file_path else None,
             file_path=file_path,
             language=self.language,
             source=ChunkSource.SEMANTIC,
             metadata=metadata,
+            **named,
         )
 
-    def _build_metadata(self, node: AstThing[SgNode], depth: int | None = None) -> Metadata:
+    def _create_skeleton_chunks(
+        self, root: FileThing[SgRoot], content: str, file_path: Path | None, source_id: UUID7
+    ) -> list[CodeChunk]:
+        """Create chunks of the file's skeleton: its source with function bodies elided.
+
+        The skeleton spans the whole file. One that is over the chunk limit is split into
+        consecutive parts at line boundaries.
+
+        Args:
+            root: The parsed file
+            content: The file's source, for its line count
+            file_path: Optional file path for chunk context
+            source_id: Shared source ID for all chunks from this file
+
+        Returns:
+            The skeleton's chunks, or an empty list when `skeleton_chunks` is off, for languages
+            without outline rules, and for files with no bodies to elide
+        """
+        if self.governor.settings is None or not self.governor.settings.skeleton_chunks:
+            return []
+        try:
+            skeleton = render_skeleton(root.root)
+        except Exception as e:
+            logger.debug("Could not render a skeleton of %s: %s", file_path or "content", e)
+            return []
+        if not skeleton:
+            return []
+
+        parts: list[list[str]] = [[]]
+        used = 0
+        for line in skeleton.splitlines():
+            cost = (
+                cast(Tokenizer, self.tokenizer).estimate(f"{line}\n")
+                if self._has_tokenizer
+                else len(line) // 4 + 1
+            )
+            if parts[-1] and used + cost > self.chunk_limit:
+                parts.append([])
+                used = 0
+            parts[-1].append(line)
+            used += cost
+
+        label = file_path.name if file_path else "content"
+        span = Span(1, max(len(content.splitlines()), 1), source_id)
+        chunks: list[CodeChunk] = []
+        for number, lines in enumerate(parts, start=1):
+            part = "\n".join(lines)
+            chunk_name = (
+                f"{label} (skeleton, part {number} of {len(parts)})"
+                if len(parts) > 1
+                else f"{label} (skeleton)"
+            )
+            metadata: Metadata = {
+                "chunk_id": uuid7(),
+                "created_at": datetime.now(UTC).timestamp(),
+                "name": chunk_name,
+                "context": {
+                    "chunker_type": "semantic",
+                    "content_hash": self._compute_content_hash(part),
+                },
+            }
+            chunks.append(
+                CodeChunk.model_construct(
+                    content=part,
+                    line_range=span,
+                    ext_category=ExtCategory.from_file(file_path) if file_path else None,
+                    file_path=file_path,
+                    language=self.language,
+                    source=ChunkSource.SKELETON,
+                    metadata=metadata,
+                    chunk_name=chunk_name,
+                )
+            )
+        return chunks
+
+    def _build_metadata(
+        self,
+        node: AstThing[SgNode],
+        depth: int | None = None,
+        *,
+        content: str | None = None,
+        rust_context: RustItemContext | None = None,
+    ) -> Metadata:
         """Build metadata using existing Metadata TypedDict structure.
 
         Creates comprehensive metadata optimized for AI context delivery,
@@ -781,12 +883,17 @@ class SemanticChunker(BaseChunker):
         Args:
             node: AstThing node to extract metadata from
             depth: Optional cached depth to avoid ancestor traversal
+            content: Optional chunk content, if it differs from the node text (e.g. Rust items
+                with their attributes); used for the content hash
+            rust_context: Optional Rust item context with impl information
 
         Returns:
             Metadata TypedDict with semantic and context information
         """
         # Use existing SemanticMetadata.from_node() factory
         semantic_meta = SemanticMetadata.from_node(node, self.language)
+        if rust_context and (rust_updates := self._rust_metadata(rust_context)):
+            semantic_meta = semantic_meta.model_copy(update=rust_updates)
 
         # Extract simple name from node - try to get identifier field first
         simple_name = None
@@ -810,7 +917,7 @@ class SemanticChunker(BaseChunker):
             "context": {
                 # Chunker-specific context in flexible dict
                 "chunker_type": "semantic",
-                "content_hash": self._compute_content_hash(node.text),
+                "content_hash": self._compute_content_hash(content or node.text),
                 "classification": node.classification.name if node.classification else None,
                 "kind": node.primary_category
                 if hasattr(node, "primary_category")
@@ -825,6 +932,24 @@ class SemanticChunker(BaseChunker):
 
         return metadata
 
+    @staticmethod
+    def _rust_metadata(rust_context: RustItemContext) -> dict[str, Any]:
+        """Get the semantic metadata fields a Rust item's context fills in."""
+        updates: dict[str, Any] = {}
+        if rust_context.impl_type:
+            updates |= {
+                "impl_type": rust_context.impl_type,
+                "impl_trait": rust_context.impl_trait,
+                "impl_generics": rust_context.impl_generics,
+            }
+        if rust_context.derives:
+            updates["derived_traits"] = rust_context.derives
+        if rust_context.macro_arms:
+            updates["macro_arms"] = rust_context.macro_arms
+        if rust_context.macro_invocations:
+            updates["macro_invocations"] = dict(rust_context.macro_invocations)
+        return updates
+
     def _handle_oversized_node(
         self,
         node: AstThing[SgNode],
@@ -76,6 +76,7 @@ class ChunkerSettingsDict(TypedDict, total=False):
     custom_delimiters: Annotated[list[CustomDelimiter] | None, NotRequired]
     custom_languages: Annotated[list[CustomLanguage] | None, NotRequired]
     semantic_importance_threshold: NotRequired[PositiveFloat | None]
+    skeleton_chunks: NotRequired[bool | None]
     performance: NotRequired[PerformanceSettingsDict | None]
     concurrency: NotRequired[ConcurrencySettingsDict | None]
 
@@ -245,6 +246,13 @@ class ChunkerSettings(BasedModel):
         ),
     ] = 0.3
 
+    skeleton_chunks: Annotated[
+        bool,
+        Field(
+            description="""Also index a skeleton of each Rust, Python, JavaScript/TypeScript and Go file: its source with function and method bodies elided, so its signatures, types and docs are searchable as one chunk. Skeletons are indexed with the `skeleton` chunk source, and only for files that have bodies to elide. Off by default: turning it on adds at least one chunk per such file, which the next index run embeds, and skeletons then compete with the files' other chunks in search results."""
+        ),
+    ] = False
+
     # Adaptive chunk sizing settings
     target_chunk_tokens: Annotated[
         PositiveInt | None,
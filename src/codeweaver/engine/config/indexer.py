@@ -15,6 +15,7 @@ import logging
 import re
 
 from collections.abc import Callable
+from fnmatch import fnmatch
 from functools import cache, cached_property
 from pathlib import Path
 from typing import TYPE_CHECKING, Annotated, Any, NamedTuple, NotRequired, TypedDict, cast
@@ -105,6 +106,7 @@ class IndexerSettingsDict(TypedDict, total=False):
     include_tooling_dirs: NotRequired[bool]
     rignore_options: NotRequired[RignoreSettings | Unset]
     only_index_on_command: NotRequired[bool]
+    skip_formatting_changes: NotRequired[bool]
 
 
 def get_storage_path() -> DirectoryPath:
@@ -300,6 +302,20 @@ class IndexerSettings(BasedModel):
         ),
     ] = False
 
+    skip_formatting_changes: Annotated[
+        bool,
+        Field(
+            description="""Disabled by default. When enabled, a changed file whose syntax tree is unchanged (like after a formatter run) keeps its stored embeddings; only the chunks' text and line ranges are updated. Chunks whose text changed beyond whitespace, like an edited comment or docstring, are still embedded again. Only applies to languages CodeWeaver parses, and to files indexed since it was enabled."""
+        ),
+    ] = False
+
+    index_dependency_sources: Annotated[
+        bool,
+        Field(
+            description="""Disabled by default. When enabled, indexing the project also indexes the sources of its third-party dependencies that are already on disk: registry crates locked in `Cargo.lock` (from `~/.cargo/registry/src`), the direct dependencies in `node_modules`, and locked Python packages in the project's virtual environment. They go into a separate collection, tagged with each package's name and version, and are searched with `find_code(..., scope="deps")`. Each package version is indexed once. `cw index --deps` indexes them on demand."""
+        ),
+    ] = False
+
     _index_cache_dir: Annotated[
         Path | None,
         Field(
@@ -515,11 +531,7 @@ class IndexerSettings(BasedModel):
         }
 
         # Build set of tooling directory names for fast lookup
-        tooling_dirs: set[str] = set()
-        if self.include_github_dir:
-            tooling_dirs.update({".github", ".circleci"})
-        if self.include_tooling_dirs:
-            tooling_dirs.update(self.hidden_tool_paths)
+        tooling_dirs = self._tooling_dirs()
 
         double_suffix_extensions = tuple({ext for ext in known_extensions if ext.count(".") > 1})
 
@@ -575,6 +587,44 @@ class IndexerSettings(BasedModel):
 
         return filter_func
 
+    def _tooling_dirs(self) -> set[str]:
+        """Hidden directories whose files are indexed anyway."""
+        tooling_dirs: set[str] = set()
+        if self.include_github_dir:
+            tooling_dirs.update({".github", ".circleci"})
+        if self.include_tooling_dirs:
+            tooling_dirs.update(self.hidden_tool_paths)
+        return tooling_dirs
+
+    def construct_path_filter(self) -> Callable[[Path], bool]:
+        """Construct a filter for project-relative file paths that aren't on disk.
+
+        Files in a git revision are filtered like the walker filters the working tree, except
+        for ignore files -- a revision's files are all tracked. The returned function returns
+        True for paths that should be INCLUDED.
+        """
+        exclude_entry = self.construct_filter()
+        tooling_dirs = self._tooling_dirs()
+        forced = {str(p) for p in self.forced_includes}
+        excludes = [str(e) for e in self.excludes if str(e) not in forced]
+
+        def path_filter(path: Path) -> bool:
+            parents = path.parts[:-1]
+            if self.ignore_hidden and any(
+                part.startswith(".") and part not in tooling_dirs for part in parents
+            ):
+                return False
+            posix = path.as_posix()
+            for exclude in excludes:
+                if "/" in exclude or "*" in exclude:
+                    if fnmatch(posix, exclude) or fnmatch(posix, exclude.removeprefix("**/")):
+                        return False
+                elif exclude in parents:
+                    return False
+            return not exclude_entry(path)
+
+        return path_filter
+
     @property
     def filter(self) -> Callable[[Path], bool]:
         """Cached property for the filter function."""
@@ -48,9 +48,12 @@ from codeweaver.engine.managers.manifest_manager import FileManifestManager
 from codeweaver.engine.managers.progress_tracker import IndexingProgressTracker, IndexingStats
 from codeweaver.engine.services.chunking_service import ChunkingService
 from codeweaver.engine.services.config_analyzer import ConfigChangeAnalyzer
+from codeweaver.engine.services.dependency_service import DependencyService
+from codeweaver.engine.services.exact_search_service import ExactSearchService
 from codeweaver.engine.services.failover_service import FailoverService
 from codeweaver.engine.services.indexing_service import IndexingService
 from codeweaver.engine.services.migration_service import MigrationService
+from codeweaver.engine.services.symbol_index_service import SymbolIndexService
 from codeweaver.engine.services.watching_service import FileWatchingService
 from codeweaver.engine.watcher.watch_filters import ExtensionFilter, IgnoreFilter
 from codeweaver.providers import (
@@ -196,6 +199,41 @@ type ChunkingServiceDep = Annotated[
 ]
 
 
+@dependency_provider(SymbolIndexService, scope="singleton")
+def _create_symbol_index_service(
+    project_path: ResolvedProjectPathDep = INJECTED,
+    project_name: ResolvedProjectNameDep = INJECTED,
+    settings: IndexerSettingsDep = INJECTED,
+) -> SymbolIndexService:
+    """Factory for the symbol reference index service."""
+    return SymbolIndexService(
+        project_path=project_path,
+        project_name=project_name,
+        index_dir=settings.cache_dir / "symbols",
+    )
+
+
+type SymbolIndexServiceDep = Annotated[
+    "SymbolIndexService", depends(_create_symbol_index_service, scope="singleton")
+]
+
+
+@dependency_provider(ExactSearchService, scope="singleton")
+def _create_exact_search_service(
+    project_path: ResolvedProjectPathDep = INJECTED, settings: IndexerSettingsDep = INJECTED
+) -> ExactSearchService:
+    """Factory for the exact (pattern, text and path) search service."""
+    return ExactSearchService(
+        project_path=project_path,
+        walker_settings=settings._as_settings(project_path=project_path),
+    )
+
+
+type ExactSearchServiceDep = Annotated[
+    "ExactSearchService", depends(_create_exact_search_service, scope="singleton")
+]
+
+
 @dependency_provider(IndexingService, scope="singleton")
 def _create_indexing_service(
     chunking_service: ChunkingServiceDep = INJECTED,
@@ -207,6 +245,7 @@ def _create_indexing_service(
     progress_tracker: ProgressTrackerDep = INJECTED,
     checkpoint_manager: CheckpointManagerDep = INJECTED,
     manifest_manager: ManifestManagerDep = INJECTED,
+    symbol_index: SymbolIndexServiceDep = INJECTED,
     project_path: ResolvedProjectPathDep = INJECTED,
 ) -> IndexingService:
     """Factory for indexing service."""
@@ -220,6 +259,7 @@ def _create_indexing_service(
         progress_tracker=progress_tracker,
         checkpoint_manager=checkpoint_manager,
         manifest_manager=manifest_manager,
+        symbol_index=symbol_index,
         project_path=project_path,
     )
 
@@ -305,6 +345,17 @@ type ConfigChangeAnalyzerDep = Annotated[
 ]
 
 
+@dependency_provider(DependencyService, scope="singleton")
+def _create_dependency_service(project_path: ResolvedProjectPathDep = INJECTED) -> DependencyService:
+    """Factory for the dependency extraction service."""
+    return DependencyService(project_path=project_path)
+
+
+type DependencyServiceDep = Annotated[
+    DependencyService, depends(_create_dependency_service, scope="singleton")
+]
+
+
 @dependency_provider(MigrationService, scope="singleton")
 def _create_migration_service(
     vector_store: PrimaryVectorStoreProviderDep = INJECTED,
@@ -369,6 +420,8 @@ __all__ = (
     "ChunkerSettingsDep",
     "ChunkingServiceDep",
     "ConfigChangeAnalyzerDep",
+    "DependencyServiceDep",
+    "ExactSearchServiceDep",
     "ExtensionFilterDep",
     "FailoverServiceDep",
     "FailoverSettingsDep",
@@ -381,4 +434,5 @@ __all__ = (
     "MigrationServiceDep",
     "ProgressTrackerDep",
     "SourceIdRegistryDep",
+    "SymbolIndexServiceDep",
 )
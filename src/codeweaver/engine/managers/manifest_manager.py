@@ -25,6 +25,7 @@ from pydantic import Field, NonNegativeInt, computed_field
 from pydantic_core import from_json
 
 from codeweaver.core import BasedModel, BlakeHashKey, get_blake_hash
+from codeweaver.core.git_tree import SHORT_COMMIT_LENGTH
 
 
 if TYPE_CHECKING:
@@ -52,6 +53,23 @@ class FileManifestEntry(TypedDict):
     has_dense_embeddings: NotRequired[bool]
     has_sparse_embeddings: NotRequired[bool]
 
+    # Optional field for files indexed from a git revision rather than the working tree
+    blob_id: NotRequired[str | None]  # Git object ID of the file's content
+
+    # Optional field for reusing unchanged chunks' embeddings when a file changes
+    chunk_hashes: NotRequired[dict[str, str]]  # Chunk ID -> hash of its embedding input
+
+    # Optional field for swapping in a file's new chunks only once they're stored
+    generation: NotRequired[str | None]  # The index generation of the file's live chunks
+
+    # Optional field for reindexing formatting-only changes, which leave the content hash (of
+    # the file's syntax tree, where it has one) as it was
+    raw_content_hash: NotRequired[str | None]  # Blake3 hash of the file's bytes
+
+    # Optional field for keeping embeddings through formatting-only changes, which leave
+    # chunks' text the same but for whitespace
+    chunk_text_hashes: NotRequired[dict[str, str]]  # Chunk ID -> hash of its unspaced text
+
 
 class FileManifestStats(TypedDict):
     """Statistics about the file manifest."""
@@ -78,6 +96,19 @@ class IndexFileManifest(BasedModel):
     total_chunks: Annotated[NonNegativeInt, Field(ge=0)] = 0
     manifest_version: Annotated[str, Field()] = "1.1.0"
 
+    commit: Annotated[
+        str | None,
+        Field(
+            description="""The git commit this manifest indexes, for an index of a revision rather than the working tree"""
+        ),
+    ] = None
+    rev: Annotated[
+        str | None,
+        Field(
+            description="""The revision (commit, branch or tag) as it was given to `cw index --rev`"""
+        ),
+    ] = None
+
     def add_file(
         self,
         path: Path,
@@ -142,6 +173,11 @@ class IndexFileManifest(BasedModel):
                 - sparse_embedding_model: str | None (optional)
                 - has_dense_embeddings: bool (optional)
                 - has_sparse_embeddings: bool (optional)
+                - blob_id: str | None (optional, for files indexed from a git revision)
+                - chunk_hashes: dict[str, str] (optional, chunk ID -> embedding input hash)
+                - generation: str | None (optional, the index generation of the file's chunks)
+                - raw_content_hash: str | None (optional, hash of the file's bytes)
+                - chunk_text_hashes: dict[str, str] (optional, chunk ID -> text hash)
         """
         now = datetime.now(UTC)
         iso_timestamp = now.isoformat()
@@ -181,11 +217,33 @@ class IndexFileManifest(BasedModel):
                 has_dense_embeddings=entry.get("has_dense_embeddings", False),
                 has_sparse_embeddings=entry.get("has_sparse_embeddings", False),
             )
+            if blob_id := entry.get("blob_id"):
+                self.files[raw_path]["blob_id"] = blob_id
+            if chunk_hashes := entry.get("chunk_hashes"):
+                self.files[raw_path]["chunk_hashes"] = chunk_hashes
+            if generation := entry.get("generation"):
+                self.files[raw_path]["generation"] = generation
+            if raw_content_hash := entry.get("raw_content_hash"):
+                self.files[raw_path]["raw_content_hash"] = str(raw_content_hash)
+            if chunk_text_hashes := entry.get("chunk_text_hashes"):
+                self.files[raw_path]["chunk_text_hashes"] = chunk_text_hashes
             self.total_files += 1
             self.total_chunks += chunk_count
 
         self.last_updated = now
 
+    def index_generation(self) -> str:
+        """A token for the chunks the manifest has live, which changes when any file's do.
+
+        It's built from each file's live index generation (or, for entries from before those
+        were recorded, its content hash), so saving a manifest without changes keeps it.
+        """
+        live = sorted(
+            f"{path}\0{entry.get('generation') or entry['content_hash']}"
+            for path, entry in self.files.items()
+        )
+        return get_blake_hash("\n".join(live).encode("utf-8"))
+
     def remove_file(self, path: Path) -> FileManifestEntry | None:
         """Remove a file from the manifest."""
         if path is None:
@@ -239,6 +297,22 @@ class IndexFileManifest(BasedModel):
         entry = self.get_file(path)
         return entry["chunk_ids"] if entry else []
 
+    def get_chunk_hashes_for_file(self, path: Path) -> dict[str, str]:
+        """Get the embedding input hashes of a file's chunks.
+
+        Args:
+            path: Path to the file
+
+        Returns:
+            Map of chunk ID to the hash of its embedding input, empty if the file isn't in the
+            manifest or was indexed before chunk hashes were recorded
+        """
+        if path is None:
+            raise ValueError("Path cannot be None")
+
+        entry = self.get_file(path)
+        return dict(entry.get("chunk_hashes") or {}) if entry else {}
+
     def get_all_chunk_ids(self) -> set[str]:
         """Get all chunk IDs from all files in the manifest.
 
@@ -369,8 +443,13 @@ class IndexFileManifest(BasedModel):
         current_dense_model: str | None = None,
         current_sparse_provider: str | None = None,
         current_sparse_model: str | None = None,
+        current_raw_hash: BlakeHashKey | None = None,
     ) -> tuple[bool, str]:
-        """Check if file needs reindexing."""
+        """Check if file needs reindexing.
+
+        With `current_raw_hash`, a file whose bytes changed but whose content hash didn't (a
+        formatting-only change) needs it too, if its entry recorded the hash of its bytes.
+        """
         if path is None:
             raise ValueError("Path cannot be None")
 
@@ -384,6 +463,13 @@ class IndexFileManifest(BasedModel):
         if entry["content_hash"] != str(current_hash):
             return True, "content_changed"
 
+        if (
+            current_raw_hash
+            and (raw_content_hash := entry.get("raw_content_hash"))
+            and raw_content_hash != str(current_raw_hash)
+        ):
+            return True, "formatting_changed"
+
         # Check for embedding model changes
         manifest_dense_provider = entry.get("dense_embedding_provider")
         manifest_dense_model = entry.get("dense_embedding_model")
@@ -427,7 +513,9 @@ class IndexFileManifest(BasedModel):
         return {
             "files": {
                 get_blake_hash(path): {
-                    key: value for key, value in entry.items() if key not in {"path", "chunk_ids"}
+                    key: value
+                    for key, value in entry.items()
+                    if key not in {"path", "chunk_ids", "chunk_hashes", "chunk_text_hashes"}
                 }
                 for path, entry in _serialized_self.get("files", {}).items()
             }
@@ -437,7 +525,10 @@ class IndexFileManifest(BasedModel):
         """Telemetry keys for the manifest."""
         from codeweaver.core import AnonymityConversion, FilteredKey
 
-        return {FilteredKey("project_path"): AnonymityConversion.HASH}
+        return {
+            FilteredKey("project_path"): AnonymityConversion.HASH,
+            FilteredKey("rev"): AnonymityConversion.HASH,
+        }
 
 
 class FileManifestManager:
@@ -446,24 +537,71 @@ class FileManifestManager:
     PURE state management.
     """
 
-    def __init__(self, project_path: Path, project_name: str, manifest_dir: Path):
+    def __init__(
+        self,
+        project_path: Path,
+        project_name: str,
+        manifest_dir: Path,
+        *,
+        commit: str | None = None,
+        rev: str | None = None,
+        dependencies: bool = False,
+    ):
         """Initialize manifest manager with required paths.
 
         Args:
             project_path: Path to indexed codebase
             project_name: Name of the project (for filename)
             manifest_dir: Directory for manifest files
+            commit: The git commit, for the manifest of a revision's index instead of the
+                working tree's
+            rev: The revision as the user named it, recorded in new manifests
+            dependencies: For the manifest of the dependency sources' index instead of the
+                working tree's
         """
         self.project_path = Path(project_path).resolve()
         self.project_name = project_name
         self.manifest_dir = Path(manifest_dir).resolve()
+        self.commit = commit
+        self.rev = rev
+        self.dependencies = dependencies
 
         # Add path hash to filename to avoid collisions between projects with same name
         path_hash = get_blake_hash(str(self.project_path).encode("utf-8"))[:16]
-        self.manifest_file = (
-            self.manifest_dir / f"file_manifest_{self.project_name}_{path_hash}.json"
+        self._file_stem = f"file_manifest_{self.project_name}_{path_hash}"
+        suffix = f"_rev_{commit[:SHORT_COMMIT_LENGTH]}" if commit else ""
+        if dependencies:
+            suffix = "_deps"
+        self.manifest_file = self.manifest_dir / f"{self._file_stem}{suffix}.json"
+
+    def for_revision(self, commit: str, rev: str | None = None) -> FileManifestManager:
+        """Get the manager for the manifest of a git revision's index."""
+        return type(self)(
+            self.project_path, self.project_name, self.manifest_dir, commit=commit, rev=rev
         )
 
+    def for_dependencies(self) -> FileManifestManager:
+        """Get the manager for the manifest of the dependency sources' index.
+
+        Its entries are packages rather than files: each entry's path is the package version's
+        `DependencySource.key`, like `cargo/serde@1.0.203`.
+        """
+        return type(self)(
+            self.project_path, self.project_name, self.manifest_dir, dependencies=True
+        )
+
+    async def load_revisions(self) -> list[IndexFileManifest]:
+        """Load the manifests of every indexed git revision of the project."""
+        manifests: list[IndexFileManifest] = []
+        async for path in AsyncPath(self.manifest_dir).glob(f"{self._file_stem}_rev_*.json"):
+            try:
+                manifest = IndexFileManifest.model_validate(from_json(await path.read_bytes()))
+            except (OSError, ValueError):
+                logger.warning("Failed to load file manifest from %s", path)
+            else:
+                manifests.append(manifest)
+        return manifests
+
     async def save(self, manifest: IndexFileManifest) -> bool:
         """Save manifest to disk."""
         manifest.last_updated = datetime.now(UTC)
@@ -502,7 +640,7 @@ class FileManifestManager:
 
     def create_new(self) -> IndexFileManifest:
         """Create a new empty manifest."""
-        return IndexFileManifest(project_path=self.project_path)
+        return IndexFileManifest(project_path=self.project_path, commit=self.commit, rev=self.rev)
 
 
 __all__ = ("FileManifestEntry", "FileManifestManager", "FileManifestStats", "IndexFileManifest")
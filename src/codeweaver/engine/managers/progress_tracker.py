@@ -44,6 +44,7 @@ class IndexingStats(BasedModel):
     chunks_created: int = Field(0)
     chunks_embedded: int = Field(0)
     chunks_indexed: int = Field(0)
+    chunks_reused: int = Field(0)
     start_time: float = Field(default_factory=time.time)
     stopwatch_time: int = Field(default_factory=lambda: int(time.monotonic()))
     files_with_errors: list[Path] = Field(default_factory=list)
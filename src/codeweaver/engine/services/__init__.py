@@ -24,6 +24,20 @@ if TYPE_CHECKING:
         ConfigChangeAnalyzer,
         TransformationDetails,
     )
+    from codeweaver.engine.services.dependency_service import (
+        DependencyEdge,
+        DependencyGraph,
+        DependencyKind,
+        DependencyService,
+        PackageInfo,
+    )
+    from codeweaver.engine.services.dependency_sources import (
+        DependencyEcosystem,
+        DependencySource,
+        DependencySourceLocator,
+        default_cargo_home,
+    )
+    from codeweaver.engine.services.exact_search_service import ExactHit, ExactSearchService
     from codeweaver.engine.services.failover_service import FailoverService
     from codeweaver.engine.services.indexing_service import IndexingService, ProgressCallback
     from codeweaver.engine.services.migration_service import (
@@ -43,6 +57,11 @@ if TYPE_CHECKING:
         VectorReconciliationService,
     )
     from codeweaver.engine.services.snapshot_service import QdrantSnapshotBackupService
+    from codeweaver.engine.services.symbol_index_service import (
+        SymbolIndex,
+        SymbolIndexService,
+        SymbolOccurrence,
+    )
     from codeweaver.engine.services.watching_service import USE_RICH, FileWatchingService
 
 _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
@@ -51,6 +70,15 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ChunkResult": (__spec__.parent, "migration_service"),
     "ConfigChangeAnalysis": (__spec__.parent, "config_analyzer"),
     "ConfigChangeAnalyzer": (__spec__.parent, "config_analyzer"),
+    "DependencyEcosystem": (__spec__.parent, "dependency_sources"),
+    "DependencyEdge": (__spec__.parent, "dependency_service"),
+    "DependencyGraph": (__spec__.parent, "dependency_service"),
+    "DependencyKind": (__spec__.parent, "dependency_service"),
+    "DependencyService": (__spec__.parent, "dependency_service"),
+    "DependencySource": (__spec__.parent, "dependency_sources"),
+    "DependencySourceLocator": (__spec__.parent, "dependency_sources"),
+    "ExactHit": (__spec__.parent, "exact_search_service"),
+    "ExactSearchService": (__spec__.parent, "exact_search_service"),
     "FailoverService": (__spec__.parent, "failover_service"),
     "FileWatchingService": (__spec__.parent, "watching_service"),
     "IndexingService": (__spec__.parent, "indexing_service"),
@@ -60,14 +88,19 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "MigrationResult": (__spec__.parent, "migration_service"),
     "MigrationService": (__spec__.parent, "migration_service"),
     "MigrationState": (__spec__.parent, "migration_service"),
+    "PackageInfo": (__spec__.parent, "dependency_service"),
     "ProgressCallback": (__spec__.parent, "indexing_service"),
     "QdrantSnapshotBackupService": (__spec__.parent, "snapshot_service"),
     "ReconciliationResult": (__spec__.parent, "reconciliation_service"),
     "RepairStats": (__spec__.parent, "reconciliation_service"),
+    "SymbolIndex": (__spec__.parent, "symbol_index_service"),
+    "SymbolIndexService": (__spec__.parent, "symbol_index_service"),
+    "SymbolOccurrence": (__spec__.parent, "symbol_index_service"),
     "TransformationDetails": (__spec__.parent, "config_analyzer"),
     "ValidationError": (__spec__.parent, "migration_service"),
     "VectorReconciliationService": (__spec__.parent, "reconciliation_service"),
     "WorkItem": (__spec__.parent, "migration_service"),
+    "default_cargo_home": (__spec__.parent, "dependency_sources"),
 })
 
 __getattr__ = create_late_getattr(_dynamic_imports, globals(), __name__)
@@ -78,6 +111,15 @@ __all__ = (
     "ChunkingService",
     "ConfigChangeAnalysis",
     "ConfigChangeAnalyzer",
+    "DependencyEcosystem",
+    "DependencyEdge",
+    "DependencyGraph",
+    "DependencyKind",
+    "DependencyService",
+    "DependencySource",
+    "DependencySourceLocator",
+    "ExactHit",
+    "ExactSearchService",
     "FailoverService",
     "FileWatchingService",
     "IndexingService",
@@ -87,14 +129,19 @@ __all__ = (
     "MigrationResult",
     "MigrationService",
     "MigrationState",
+    "PackageInfo",
     "ProgressCallback",
     "QdrantSnapshotBackupService",
     "ReconciliationResult",
     "RepairStats",
+    "SymbolIndex",
+    "SymbolIndexService",
+    "SymbolOccurrence",
     "TransformationDetails",
     "ValidationError",
     "VectorReconciliationService",
     "WorkItem",
+    "default_cargo_home",
 )
 
 
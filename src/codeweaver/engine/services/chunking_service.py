@@ -15,17 +15,20 @@ from typing import TYPE_CHECKING
 
 from codeweaver.core import SemanticSearchLanguage
 from codeweaver.core.constants import PARALLEL_CHUNKING_THRESHOLD
+from codeweaver.core.workspace import find_cargo_workspace
 from codeweaver.engine.chunker import ChunkerSelector, chunk_files_parallel
 from codeweaver.engine.chunker.delimiter import DelimiterChunker
 from codeweaver.engine.chunker.exceptions import ChunkingError, FileTooLargeError
+from codeweaver.semantic.test_code import find_test_only_ranges, is_test_chunk
 
 
 if TYPE_CHECKING:
-    from collections.abc import AsyncIterator
+    from collections.abc import AsyncIterator, Mapping
 
     from codeweaver_tokenizers.base import Tokenizer
 
     from codeweaver.core import CodeChunk, DiscoveredFile
+    from codeweaver.core.workspace import CargoWorkspace
     from codeweaver.engine.chunker.base import ChunkGovernor
     from codeweaver.engine.config import ChunkerSettings
 
@@ -44,6 +47,22 @@ class ChunkingService:
         self.tokenizer = tokenizer
         self.settings = settings
         self._selector = ChunkerSelector(governor, tokenizer)
+        # Cargo workspaces by project root, resolved once rather than for every file
+        self._cargo_workspaces: dict[Path | None, CargoWorkspace | None] = {}
+
+    def reset_cargo_workspaces(self) -> None:
+        """Forget the Cargo workspaces resolved so far, so manifest changes are seen.
+
+        Called at the start of each indexing run, and when the watcher sees a `Cargo.toml` change.
+        """
+        self._cargo_workspaces.clear()
+
+    def _cargo_workspace(self, file: DiscoveredFile) -> CargoWorkspace | None:
+        """Get the Cargo workspace of a file's project, resolving each project root once."""
+        root = file.project_path
+        if root not in self._cargo_workspaces:
+            self._cargo_workspaces[root] = find_cargo_workspace(root) if root else None
+        return self._cargo_workspaces[root]
 
     async def chunk_files(
         self,
@@ -53,34 +72,46 @@ class ChunkingService:
         executor_type: str | None = None,
         force_parallel: bool = False,
         source_chunks: dict[Path, list[CodeChunk]] | None = None,
+        contents: Mapping[Path, bytes] | None = None,
     ) -> AsyncIterator[tuple[Path, list[CodeChunk]]]:
-        """Chunk multiple files with optional parallel processing."""
+        """Chunk multiple files with optional parallel processing.
+
+        `contents` maps file paths to their content, for files that aren't read from disk
+        (like the files of a git revision); they're always chunked in this process.
+        """
         if not files:
             return
 
         # Normal chunking logic
-        if force_parallel or (len(files) >= PARALLEL_CHUNKING_THRESHOLD):
-            async for result in chunk_files_parallel(
+        if contents is None and (force_parallel or (len(files) >= PARALLEL_CHUNKING_THRESHOLD)):
+            files_by_path = {file.path: file for file in files}
+            async for path, chunks in chunk_files_parallel(
                 files,
                 self.governor,
                 max_workers=max_workers,
                 executor_type=executor_type,
                 tokenizer=self.tokenizer,
             ):
-                yield result
+                yield (path, self._tag_chunks(files_by_path.get(path), chunks))
         else:
-            async for result in self._chunk_sequential(files):
+            async for result in self._chunk_sequential(files, contents):
                 yield result
 
     async def _chunk_sequential(
-        self, files: list[DiscoveredFile]
+        self, files: list[DiscoveredFile], contents: Mapping[Path, bytes] | None = None
     ) -> AsyncIterator[tuple[Path, list[CodeChunk]]]:
         """Sequential chunking with fallback."""
         for file in files:
             try:
-                chunker = self._selector.select_for_file(file)
-                # Offload blocking I/O to a thread
-                content = await asyncio.to_thread(file.absolute_path.read_text, "utf-8", "ignore")
+                if contents is not None and (raw := contents.get(file.path)) is not None:
+                    chunker = self._selector.select_for_file(file, size=len(raw))
+                    content = raw.decode("utf-8", "ignore")
+                else:
+                    chunker = self._selector.select_for_file(file)
+                    # Offload blocking I/O to a thread
+                    content = await asyncio.to_thread(
+                        file.absolute_path.read_text, "utf-8", "ignore"
+                    )
 
                 try:
                     chunks = chunker.chunk(content, file=file)
@@ -93,7 +124,7 @@ class ChunkingService:
                     fallback_chunker = DelimiterChunker(self.governor, language=language)
                     chunks = fallback_chunker.chunk(content, file=file)
 
-                yield (file.path, chunks)
+                yield (file.path, self._tag_chunks(file, chunks, content))
             except FileTooLargeError as e:
                 logger.info(
                     "Skipping oversized file: %s (%s)",
@@ -104,6 +135,31 @@ class ChunkingService:
             except Exception:
                 logger.warning("Skipping file %s: chunking failed", file.path, exc_info=True)
 
+    def _tag_chunks(
+        self, file: DiscoveredFile | None, chunks: list[CodeChunk], content: str | None = None
+    ) -> list[CodeChunk]:
+        """Tag a file's chunks as test code or not, and with the Cargo crate and module path.
+
+        Test-only code is found in the file's `content`, or in the file on disk without it.
+        """
+        if file is None or not chunks:
+            return chunks
+        workspace = self._cargo_workspace(file)
+        crate, module_path = workspace.locate(file.path) if workspace else (None, None)
+        location = {"crate": crate, "module_path": module_path} if crate is not None else {}
+        test_ranges = find_test_only_ranges(file.absolute_path, content)
+        return [
+            chunk.model_copy(
+                update={
+                    **location,
+                    "is_test": is_test_chunk(
+                        chunk, chunk.file_path or file.path, test_ranges=test_ranges
+                    ),
+                }
+            )
+            for chunk in chunks
+        ]
+
     async def chunk_file(self, file: DiscoveredFile) -> list[CodeChunk]:
         """Chunk a single file."""
         async for _, chunks in self._chunk_sequential([file]):
@@ -0,0 +1,551 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Dependency extraction service.
+
+Parses the dependency manifests described by `SemanticSearchLanguage.config_files` (following
+each file's `dependency_key_paths`) into a graph of the project's own packages and the
+dependencies they declare. For Rust, every Cargo workspace member is read, `workspace = true`
+entries are resolved against `[workspace.dependencies]`, and versions are resolved from
+`Cargo.lock`.
+
+ARCHITECTURE: Plain class with no DI in constructor (factory handles DI).
+"""
+
+from __future__ import annotations
+
+import contextlib
+import json
+import logging
+import re
+import tomllib
+
+from collections import defaultdict
+from collections.abc import Iterator
+from pathlib import Path
+from typing import Annotated, Any, NamedTuple
+
+from pydantic import ConfigDict, Field
+
+from codeweaver.core import BasedModel, BaseEnum, ConfigLanguage, SemanticSearchLanguage
+from codeweaver.core.language import LanguageConfigFile
+from codeweaver.core.workspace import find_cargo_workspace
+
+
+logger = logging.getLogger(__name__)
+
+_WILDCARD = "*"
+_DEV_KEYS = frozenset({"dev-dependencies", "devdependencies", "dev_dependencies", "require-dev"})
+_BUILD_KEYS = frozenset({"build-dependencies", "build_dependencies"})
+_PARSEABLE = frozenset({ConfigLanguage.TOML, ConfigLanguage.JSON, ConfigLanguage.YAML})
+_REQUIREMENT_NAME = re.compile(r"^\s*(@?[A-Za-z0-9][A-Za-z0-9._/@-]*)")
+_GO_REQUIRE = re.compile(r"^\s*(?:require\s+)?([^\s()]+)\s+(v[^\s]+)")
+# Non-package entries that share tables with real dependencies
+_PSEUDO_DEPENDENCIES = frozenset({"python", "php"})
+
+
+def normalize_package_name(name: str) -> str:
+    """Normalize a package name for comparison (Cargo and PyPI treat `-` and `_` alike)."""
+    return name.strip().lower().replace("_", "-")
+
+
+class DependencyKind(BaseEnum):
+    """The dependency group a dependency was declared in."""
+
+    NORMAL = "normal"
+    DEV = "dev"
+    BUILD = "build"
+
+    @classmethod
+    def from_key_path(cls, key_path: tuple[str, ...]) -> DependencyKind:
+        """Infer the kind from the manifest key path the dependency was found under."""
+        key = key_path[-1].lower()
+        if key in _DEV_KEYS:
+            return cls.DEV
+        return cls.BUILD if key in _BUILD_KEYS else cls.NORMAL
+
+
+class PackageInfo(BasedModel):
+    """A package defined in the project (a Cargo crate, an npm package, a Python project...)."""
+
+    model_config = BasedModel.model_config | ConfigDict(frozen=True)
+
+    name: Annotated[str, Field(description="""The package name""")]
+    language: Annotated[
+        SemanticSearchLanguage, Field(description="""The language the manifest belongs to""")
+    ]
+    manifest: Annotated[
+        Path, Field(description="""The package's manifest, relative to the project root""")
+    ]
+    version: Annotated[str | None, Field(description="""The package's own version""")] = None
+
+    def _telemetry_keys(self) -> None:
+        return None
+
+
+class DependencyEdge(BasedModel):
+    """A dependency declared by one of the project's packages."""
+
+    model_config = BasedModel.model_config | ConfigDict(frozen=True)
+
+    package: Annotated[str, Field(description="""The declaring package's name""")]
+    name: Annotated[
+        str,
+        Field(description="""The dependency's package name (after resolving any renames)"""),
+    ]
+    alias: Annotated[
+        str | None,
+        Field(
+            description="""The name the dependency was declared under, if it was renamed (Cargo's `package = "..."`)"""
+        ),
+    ] = None
+    requirement: Annotated[
+        str | None,
+        Field(description="""The declared version requirement, path or git source"""),
+    ] = None
+    version: Annotated[
+        str | None, Field(description="""The resolved version, from a lockfile if available""")
+    ] = None
+    kind: Annotated[DependencyKind, Field(description="""The dependency group""")] = (
+        DependencyKind.NORMAL
+    )
+    target: Annotated[
+        str | None,
+        Field(description="""Platform target the dependency is limited to, e.g. `cfg(unix)`"""),
+    ] = None
+    internal: Annotated[
+        bool, Field(description="""Whether the dependency is another package in the project""")
+    ] = False
+    language: Annotated[SemanticSearchLanguage, Field(description="""The manifest's language""")]
+    manifest: Annotated[
+        Path, Field(description="""The declaring manifest, relative to the project root""")
+    ]
+    line: Annotated[
+        int | None, Field(description="""1-based line of the declaration in the manifest""")
+    ] = None
+
+    def _telemetry_keys(self) -> None:
+        return None
+
+    @property
+    def declared_name(self) -> str:
+        """The key the dependency was declared under in the manifest."""
+        return self.alias or self.name
+
+
+class DependencyGraph(BasedModel):
+    """The project's packages and the dependencies they declare."""
+
+    model_config = BasedModel.model_config | ConfigDict(frozen=True)
+
+    packages: Annotated[
+        tuple[PackageInfo, ...], Field(description="""Packages defined in the project""")
+    ] = ()
+    edges: Annotated[
+        tuple[DependencyEdge, ...], Field(description="""Dependencies declared by the packages""")
+    ] = ()
+
+    def _telemetry_keys(self) -> None:
+        return None
+
+    @property
+    def internal_packages(self) -> frozenset[str]:
+        """Names of the project's own packages."""
+        return frozenset(package.name for package in self.packages)
+
+    @property
+    def external_dependencies(self) -> frozenset[str]:
+        """Names of dependencies that aren't packages in the project."""
+        return frozenset(edge.name for edge in self.edges if not edge.internal)
+
+    def find(self, name: str) -> str | None:
+        """Get the canonical spelling of a package or dependency name, if the graph knows it."""
+        normalized = normalize_package_name(name)
+        names = (*(package.name for package in self.packages), *(e.name for e in self.edges))
+        return next((n for n in names if normalize_package_name(n) == normalized), None)
+
+    def is_internal(self, name: str) -> bool:
+        """Check if a name is one of the project's own packages."""
+        normalized = normalize_package_name(name)
+        return any(normalize_package_name(n) == normalized for n in self.internal_packages)
+
+    def dependents_of(self, name: str) -> tuple[DependencyEdge, ...]:
+        """Get the declarations of packages that depend on `name`."""
+        normalized = normalize_package_name(name)
+        return tuple(e for e in self.edges if normalize_package_name(e.name) == normalized)
+
+    def dependencies_of(
+        self, package: str, *, internal_only: bool = False
+    ) -> tuple[DependencyEdge, ...]:
+        """Get the dependencies a package declares."""
+        normalized = normalize_package_name(package)
+        return tuple(
+            e
+            for e in self.edges
+            if normalize_package_name(e.package) == normalized and (e.internal or not internal_only)
+        )
+
+
+class _CargoLock(NamedTuple):
+    """Resolved versions from a `Cargo.lock`."""
+
+    versions: dict[str, tuple[str, ...]]
+    """Normalized package name -> locked versions."""
+    resolved: dict[tuple[str, str], str]
+    """(normalized package, normalized dependency) -> version, where the lock disambiguates."""
+
+    @classmethod
+    def read(cls, path: Path) -> _CargoLock:
+        versions: defaultdict[str, list[str]] = defaultdict(list)
+        resolved: dict[tuple[str, str], str] = {}
+        for package in (_load_toml(path) or {}).get("package", ()):
+            name = normalize_package_name(package.get("name", ""))
+            if version := package.get("version"):
+                versions[name].append(version)
+            for dependency in package.get("dependencies", ()):
+                dep_name, _, rest = dependency.partition(" ")
+                if rest:
+                    resolved[name, normalize_package_name(dep_name)] = rest.split(" ")[0]
+        return cls({k: tuple(v) for k, v in versions.items()}, resolved)
+
+    def resolve(self, package: str, dependency: str) -> str | None:
+        """Get the locked version of a package's dependency."""
+        key = (normalize_package_name(package), normalize_package_name(dependency))
+        if version := self.resolved.get(key):
+            return version
+        candidates = self.versions.get(key[1], ())
+        return candidates[0] if len(candidates) == 1 else None
+
+
+def _load_toml(path: Path) -> dict[str, Any] | None:
+    try:
+        return tomllib.loads(path.read_text(encoding="utf-8"))
+    except (OSError, UnicodeDecodeError, tomllib.TOMLDecodeError) as e:
+        logger.debug("Could not parse %s: %s", path, e)
+        return None
+
+
+def _load_manifest(path: Path, language_type: ConfigLanguage) -> Any:
+    """Load a structured manifest, returning None if it can't be parsed."""
+    if language_type == ConfigLanguage.TOML:
+        return _load_toml(path)
+    try:
+        text = path.read_text(encoding="utf-8")
+        if language_type == ConfigLanguage.JSON:
+            return json.loads(text)
+        import yaml
+
+        return yaml.safe_load(text)
+    except Exception as e:
+        logger.debug("Could not parse %s: %s", path, e)
+        return None
+
+
+def _walk_key_path(
+    data: Any, key_path: tuple[str, ...], wildcard: str | None = None
+) -> Iterator[tuple[str | None, Any]]:
+    """Yield the values at a key path, with the key a `*` segment matched (if any)."""
+    if not key_path:
+        yield wildcard, data
+        return
+    if not isinstance(data, dict):
+        return
+    head, *rest = key_path
+    if head == _WILDCARD:
+        for key, value in data.items():
+            yield from _walk_key_path(value, tuple(rest), key)
+    elif head in data:
+        yield from _walk_key_path(data[head], tuple(rest), wildcard)
+
+
+def _iter_declarations(table: Any) -> Iterator[tuple[str, Any]]:
+    """Yield `(declared name, spec)` pairs from a dependency table or list."""
+    if isinstance(table, dict):
+        yield from table.items()
+    elif isinstance(table, list):
+        for item in table:
+            if isinstance(item, str) and (match := _REQUIREMENT_NAME.match(item)):
+                yield match[1], item[match.end() :].strip() or None
+            elif isinstance(item, dict) and isinstance(item.get("name"), str):
+                yield item["name"], item
+
+
+def _requirement(spec: Any) -> str | None:
+    """Get a human-readable requirement from a dependency spec."""
+    if spec is None or isinstance(spec, str):
+        return spec
+    if isinstance(spec, dict):
+        if version := spec.get("version"):
+            return str(version)
+        for source in ("path", "git"):
+            if location := spec.get(source):
+                return f"{source} {location}"
+    return None
+
+
+def _declaration_line(lines: list[str], name: str) -> int | None:
+    """Find the (1-based) line a dependency is declared on."""
+    escaped = re.escape(name)
+    patterns = (
+        # `name = ...`, `"name": ...`, `name.workspace = true`, `[dependencies.name]`
+        re.compile(rf"""^\s*(?:\[[^\]]*?\.)?["']?{escaped}["']?\s*[=:.\]]"""),
+        # list entries: `"name>=1.0",`, `- name >= 2`, `\tgithub.com/x/name v1.0.0`
+        re.compile(rf"""^\s*(?:-\s*)?["']?{escaped}(?![\w.-])"""),
+    )
+    for pattern in patterns:
+        for number, line in enumerate(lines, start=1):
+            if pattern.search(line):
+                return number
+    return None
+
+
+class DependencyService:
+    """Builds and caches the project's dependency graph.
+
+    The graph is rebuilt whenever a manifest (or `Cargo.lock`) is added, removed or modified.
+    """
+
+    def __init__(self, project_path: Path) -> None:
+        """Initialize the service for a project root."""
+        self._project_path = project_path
+        self._graph: DependencyGraph | None = None
+        self._fingerprint: tuple[tuple[Path, int], ...] | None = None
+
+    @property
+    def project_path(self) -> Path:
+        """The project root that manifest paths in the graph are relative to."""
+        return self._project_path
+
+    def graph(self) -> DependencyGraph:
+        """Get the dependency graph, rebuilding it if any manifest changed."""
+        manifests = self._manifests()
+        fingerprint = tuple(
+            (path, path.stat().st_mtime_ns)
+            for path in (*(path for path, _ in manifests), self._project_path / "Cargo.lock")
+            if path.is_file()
+        )
+        if self._graph is None or fingerprint != self._fingerprint:
+            self._graph = self.build_graph(manifests)
+            self._fingerprint = fingerprint
+        return self._graph
+
+    def _manifests(self) -> list[tuple[Path, LanguageConfigFile]]:
+        """Find the project's dependency manifests, including every Cargo workspace member's."""
+        found: dict[Path, LanguageConfigFile] = {}
+        for language in SemanticSearchLanguage:
+            for config_file in language.config_files or ():
+                if config_file.path is None or not config_file.dependency_key_paths:
+                    continue
+                candidates = [self._project_path / config_file.path]
+                if language == SemanticSearchLanguage.RUST and (
+                    workspace := find_cargo_workspace(self._project_path)
+                ):
+                    candidates.extend(
+                        self._project_path / crate.path / "Cargo.toml"
+                        for crate in workspace.crates
+                    )
+                for candidate in candidates:
+                    if candidate.is_file():
+                        found.setdefault(candidate, config_file)
+        return list(found.items())
+
+    def build_graph(
+        self, manifests: list[tuple[Path, LanguageConfigFile]] | None = None
+    ) -> DependencyGraph:
+        """Parse the project's manifests into a dependency graph.
+
+        Args:
+            manifests: Manifest paths and their config file definitions; found automatically if
+                not provided
+
+        Returns:
+            The project's packages and their declared dependencies
+        """
+        packages: list[PackageInfo] = []
+        edges: list[DependencyEdge] = []
+        cargo_root = _load_toml(self._project_path / "Cargo.toml") or {}
+        lock = _CargoLock.read(self._project_path / "Cargo.lock")
+        for path, config_file in manifests if manifests is not None else self._manifests():
+            if path.name == "go.mod":
+                parsed = self._parse_go_mod(path, config_file)
+            elif config_file.language_type not in _PARSEABLE:
+                logger.debug("Dependency extraction isn't supported for %s yet", path.name)
+                continue
+            elif config_file.language == SemanticSearchLanguage.RUST:
+                parsed = self._parse_cargo_manifest(path, config_file, cargo_root, lock)
+            else:
+                parsed = self._parse_manifest(path, config_file)
+            if parsed is not None:
+                package, package_edges = parsed
+                if package is not None:
+                    packages.append(package)
+                edges.extend(package_edges)
+        internal = {normalize_package_name(package.name) for package in packages}
+        return DependencyGraph(
+            packages=tuple(packages),
+            edges=tuple(
+                edge
+                if edge.internal or normalize_package_name(edge.name) not in internal
+                else edge.model_copy(update={"internal": True})
+                for edge in edges
+            ),
+        )
+
+    def _relative(self, path: Path) -> Path:
+        with contextlib.suppress(ValueError):
+            return path.relative_to(self._project_path)
+        return path
+
+    def _parse_cargo_manifest(
+        self,
+        path: Path,
+        config_file: LanguageConfigFile,
+        cargo_root: dict[str, Any],
+        lock: _CargoLock,
+    ) -> tuple[PackageInfo | None, list[DependencyEdge]] | None:
+        """Parse a `Cargo.toml`, resolving `workspace = true` entries and locked versions.
+
+        Args:
+            path: The manifest
+            config_file: Rust's `Cargo.toml` config file definition
+            cargo_root: The parsed root `Cargo.toml`, which holds `[workspace.dependencies]`
+            lock: Versions from the workspace's `Cargo.lock`
+        """
+        if (data := _load_toml(path)) is None:
+            return None
+        package = data.get("package")
+        if not isinstance(package, dict) or not (name := package.get("name")):
+            # virtual manifests only declare `[workspace.dependencies]` for members to inherit
+            return None, []
+        workspace = cargo_root.get("workspace", {})
+        inherited = workspace.get("dependencies", {})
+        version = package.get("version")
+        if isinstance(version, dict) and version.get("workspace"):
+            version = workspace.get("package", {}).get("version")
+        lines = path.read_text(encoding="utf-8").splitlines()
+        relative = self._relative(path)
+        edges: list[DependencyEdge] = []
+        for key_path in config_file.dependency_key_paths or ():
+            if key_path[0] == "workspace":
+                continue
+            for target, table in _walk_key_path(data, key_path):
+                for declared, raw_spec in _iter_declarations(table):
+                    if not isinstance(raw_spec, str | dict):
+                        continue
+                    spec = {"version": raw_spec} if isinstance(raw_spec, str) else dict(raw_spec)
+                    if spec.pop("workspace", False):
+                        base = inherited.get(declared, {})
+                        spec = ({"version": base} if isinstance(base, str) else dict(base)) | spec
+                    dependency = spec.get("package", declared)
+                    edges.append(
+                        DependencyEdge(
+                            package=name,
+                            name=dependency,
+                            alias=declared if dependency != declared else None,
+                            requirement=_requirement(spec),
+                            version=lock.resolve(name, dependency),
+                            kind=DependencyKind.from_key_path(key_path),
+                            target=target,
+                            internal="path" in spec,
+                            language=config_file.language,
+                            manifest=relative,
+                            line=_declaration_line(lines, declared),
+                        )
+                    )
+        return (
+            PackageInfo(
+                name=name, language=config_file.language, manifest=relative, version=version
+            ),
+            edges,
+        )
+
+    def _parse_manifest(
+        self, path: Path, config_file: LanguageConfigFile
+    ) -> tuple[PackageInfo | None, list[DependencyEdge]] | None:
+        """Parse a JSON, TOML or YAML manifest by following its dependency key paths."""
+        if (data := _load_manifest(path, config_file.language_type)) is None:
+            return None
+        name = self._package_name(data) or (path.parent.name or self._project_path.name)
+        lines = path.read_text(encoding="utf-8").splitlines()
+        relative = self._relative(path)
+        edges = [
+            DependencyEdge(
+                package=name,
+                name=declared,
+                requirement=_requirement(spec),
+                kind=DependencyKind.from_key_path(key_path),
+                target=target,
+                language=config_file.language,
+                manifest=relative,
+                line=_declaration_line(lines, declared),
+            )
+            for key_path in config_file.dependency_key_paths or ()
+            for target, table in _walk_key_path(data, key_path)
+            for declared, spec in _iter_declarations(table)
+            if declared.lower() not in _PSEUDO_DEPENDENCIES
+        ]
+        return PackageInfo(name=name, language=config_file.language, manifest=relative), edges
+
+    @staticmethod
+    def _package_name(data: Any) -> str | None:
+        """Get the package name from a JSON/TOML/YAML manifest."""
+        if not isinstance(data, dict):
+            return None
+        candidates = (
+            data,
+            *_walk_key_path(data, ("project",)),
+            *_walk_key_path(data, ("tool", "poetry")),
+        )
+        names = (table.get("name") for table in candidates if isinstance(table, dict))
+        return next((name for name in names if isinstance(name, str) and name), None)
+
+    def _parse_go_mod(
+        self, path: Path, config_file: LanguageConfigFile
+    ) -> tuple[PackageInfo | None, list[DependencyEdge]] | None:
+        """Parse the `require` directives of a `go.mod`."""
+        try:
+            lines = path.read_text(encoding="utf-8").splitlines()
+        except OSError as e:
+            logger.debug("Could not read %s: %s", path, e)
+            return None
+        module = next(
+            (line.split()[1] for line in lines if re.match(r"module\s+\S", line)),
+            self._project_path.name,
+        )
+        relative = self._relative(path)
+        edges: list[DependencyEdge] = []
+        in_require = False
+        for number, line in enumerate(lines, start=1):
+            stripped = line.split("//")[0].strip()
+            if stripped.startswith("require ("):
+                in_require = True
+                continue
+            if in_require and stripped == ")":
+                in_require = False
+                continue
+            if (in_require or stripped.startswith("require ")) and (
+                match := _GO_REQUIRE.match(stripped)
+            ):
+                edges.append(
+                    DependencyEdge(
+                        package=module,
+                        name=match[1],
+                        requirement=match[2],
+                        version=match[2],
+                        language=config_file.language,
+                        manifest=relative,
+                        line=number,
+                    )
+                )
+        return PackageInfo(name=module, language=config_file.language, manifest=relative), edges
+
+
+__all__ = (
+    "DependencyEdge",
+    "DependencyGraph",
+    "DependencyKind",
+    "DependencyService",
+    "PackageInfo",
+    "normalize_package_name",
+)
@@ -0,0 +1,278 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Locate the sources of the project's third-party dependencies on disk.
+
+Dependency sources are indexed apart from the project (with `cw index --deps`, or the
+`index_dependency_sources` indexer setting), into their own collection that
+`find_code(..., scope="deps")` searches. Only sources that are already on disk are indexed;
+nothing is downloaded:
+- **Rust**: the registry crates locked in `Cargo.lock`, as Cargo unpacked them into
+  `$CARGO_HOME/registry/src`
+- **JavaScript and TypeScript**: the direct dependencies of each `package.json`, from the
+  nearest `node_modules`
+- **Python**: the packages locked in `uv.lock`, `poetry.lock` or `pdm.lock` (or without a lock
+  file, those declared in `pyproject.toml`), from the project's virtual environment
+
+Declared dependencies come from the `DependencyService` graph, which reads each manifest by its
+`LanguageConfigFile.dependency_key_paths`.
+
+ARCHITECTURE: Plain class with no DI in constructor (factory handles DI).
+"""
+
+from __future__ import annotations
+
+import contextlib
+import csv
+import json
+import logging
+import os
+import re
+import tomllib
+
+from collections.abc import Callable, Iterator, Sequence
+from pathlib import Path
+from typing import Any, NamedTuple
+
+from codeweaver.core import BaseEnum, SemanticSearchLanguage
+from codeweaver.engine.services.dependency_service import (
+    DependencyGraph,
+    DependencyService,
+    normalize_package_name,
+)
+
+
+logger = logging.getLogger(__name__)
+
+_REGISTRY_SOURCES = ("registry+", "sparse+")
+_PYTHON_LOCK_FILES = ("uv.lock", "poetry.lock", "pdm.lock")
+_VIRTUAL_ENV_DIRS = (".venv", "venv")
+# Vendored dependencies of dependencies, and build output
+_SKIPPED_DIRS = frozenset({"node_modules", "__pycache__", "target"})
+
+
+class DependencyEcosystem(BaseEnum):
+    """The package ecosystem a dependency comes from."""
+
+    CARGO = "cargo"
+    NPM = "npm"
+    PYPI = "pypi"
+
+
+class DependencySource(NamedTuple):
+    """A dependency whose source is on disk."""
+
+    ecosystem: DependencyEcosystem
+    name: str
+    version: str
+    root: Path
+    """The directory the package's files are in."""
+    files: tuple[Path, ...] | None = None
+    """The package's files relative to `root`, when it shares `root` with other packages (like
+    `site-packages`); None if everything under `root` is the package's."""
+
+    @property
+    def key(self) -> str:
+        """The package version's ID, like `cargo/serde@1.0.203`; its source never changes."""
+        return f"{self.ecosystem.variable}/{self.name}@{self.version}"
+
+    def iter_files(self, exclude: Callable[[Path], bool]) -> Iterator[Path]:
+        """Yield the absolute paths of the package's files.
+
+        Args:
+            exclude: Returns True for files to leave out, like `IndexerSettings.filter`
+        """
+        if self.files is not None:
+            for relative in self.files:
+                path = self.root / relative
+                if not exclude(path) and path.is_file():
+                    yield path
+            return
+        for directory, dirnames, filenames in os.walk(self.root):
+            dirnames[:] = sorted(
+                name for name in dirnames if name not in _SKIPPED_DIRS and not name.startswith(".")
+            )
+            for filename in sorted(filenames):
+                if not exclude(path := Path(directory) / filename):
+                    yield path
+
+
+def _read_toml(path: Path) -> dict[str, Any] | None:
+    try:
+        return tomllib.loads(path.read_text(encoding="utf-8"))
+    except FileNotFoundError:
+        return None
+    except (OSError, UnicodeDecodeError, tomllib.TOMLDecodeError) as e:
+        logger.debug("Could not parse %s: %s", path, e)
+        return None
+
+
+def _distribution_name(name: str) -> str:
+    """Normalize a Python distribution name, as PEP 503 does."""
+    return normalize_package_name(re.sub(r"[-_.]+", "-", name))
+
+
+def _record_files(dist_info: Path) -> tuple[Path, ...]:
+    """The files an installed Python distribution put in `site-packages`, from its `RECORD`."""
+    try:
+        rows = csv.reader((dist_info / "RECORD").read_text(encoding="utf-8").splitlines())
+    except (OSError, UnicodeDecodeError):
+        return ()
+    files: list[Path] = []
+    for row in rows:
+        if not row or not (path := Path(row[0])).parts:
+            continue
+        # scripts are installed outside `site-packages`, as `../../../bin/...`
+        if path.is_absolute() or ".." in path.parts or "__pycache__" in path.parts:
+            continue
+        if path.parts[0].endswith((".dist-info", ".data")):
+            continue
+        files.append(path)
+    return tuple(files)
+
+
+def default_cargo_home() -> Path:
+    """Cargo's home directory: `$CARGO_HOME`, or `~/.cargo`."""
+    return Path(os.environ.get("CARGO_HOME") or Path.home() / ".cargo")
+
+
+class DependencySourceLocator:
+    """Finds the on-disk sources of the project's locked and declared dependencies."""
+
+    def __init__(
+        self,
+        project_path: Path,
+        dependency_service: DependencyService | None = None,
+        *,
+        cargo_home: Path | None = None,
+        environments: Sequence[Path] | None = None,
+    ) -> None:
+        """Initialize the locator for a project root.
+
+        Args:
+            project_path: The project root
+            dependency_service: The project's dependency graph; built from the manifests under
+                `project_path` if not provided
+            cargo_home: Cargo's home directory (default: `default_cargo_home()`)
+            environments: Python virtual environments to look in (default: `.venv` and `venv`
+                in the project, and `$VIRTUAL_ENV`)
+        """
+        self._project_path = project_path.resolve()
+        self._dependency_service = dependency_service or DependencyService(self._project_path)
+        self._cargo_home = cargo_home or default_cargo_home()
+        self._environments = environments
+
+    def locate(self) -> list[DependencySource]:
+        """Find every dependency source on disk, once per package version."""
+        graph = self._dependency_service.graph()
+        sources = (*self._cargo_sources(), *self._npm_sources(graph), *self._python_sources(graph))
+        return list({source.key: source for source in sources}.values())
+
+    def _cargo_sources(self) -> Iterator[DependencySource]:
+        """Registry crates locked in `Cargo.lock`, from Cargo's unpacked registry sources."""
+        if not (lock := _read_toml(self._project_path / "Cargo.lock")):
+            return
+        registry_src = self._cargo_home / "registry" / "src"
+        registries = sorted(path for path in registry_src.glob("*") if path.is_dir())
+        if not registries:
+            logger.debug("No unpacked crates in %s", registry_src)
+            return
+        for package in lock.get("package", ()):
+            name, version = package.get("name"), package.get("version")
+            if not name or not version or not package.get("source", "").startswith(
+                _REGISTRY_SOURCES
+            ):
+                continue  # workspace members, path and git dependencies
+            if root := next(
+                (
+                    candidate
+                    for registry in registries
+                    if (candidate := registry / f"{name}-{version}").is_dir()
+                ),
+                None,
+            ):
+                yield DependencySource(DependencyEcosystem.CARGO, name, version, root)
+
+    def _npm_sources(self, graph: DependencyGraph) -> Iterator[DependencySource]:
+        """The direct dependencies of each `package.json`, from the nearest `node_modules`."""
+        for edge in graph.edges:
+            if edge.internal or edge.manifest.name != "package.json":
+                continue
+            directory = self._project_path / edge.manifest.parent
+            if (root := self._node_module(directory, edge.name)) and (
+                version := self._npm_version(root)
+            ):
+                yield DependencySource(DependencyEcosystem.NPM, edge.name, version, root)
+
+    def _node_module(self, directory: Path, name: str) -> Path | None:
+        """Resolve a package like Node does, up to the project root (for hoisted workspaces)."""
+        while True:
+            if (candidate := directory / "node_modules" / name / "package.json").is_file():
+                return candidate.parent
+            if directory == self._project_path or directory.parent == directory:
+                return None
+            directory = directory.parent
+
+    @staticmethod
+    def _npm_version(root: Path) -> str | None:
+        with contextlib.suppress(OSError, ValueError):
+            version = json.loads((root / "package.json").read_text(encoding="utf-8")).get("version")
+            return version if isinstance(version, str) and version else None
+        return None
+
+    def _python_sources(self, graph: DependencyGraph) -> Iterator[DependencySource]:
+        """Locked (or declared) Python packages installed in the project's environments."""
+        wanted = self._locked_python_packages() or {
+            _distribution_name(edge.name)
+            for edge in graph.edges
+            if edge.language == SemanticSearchLanguage.PYTHON and not edge.internal
+        }
+        wanted -= {_distribution_name(package) for package in graph.internal_packages}
+        for site_packages in self._site_packages():
+            for dist_info in sorted(site_packages.glob("*.dist-info")):
+                name, _, version = dist_info.name.removesuffix(".dist-info").partition("-")
+                if not version or (normalized := _distribution_name(name)) not in wanted:
+                    continue
+                if files := _record_files(dist_info):
+                    # the first environment with the package wins
+                    wanted.discard(normalized)
+                    yield DependencySource(
+                        DependencyEcosystem.PYPI, name, version, site_packages, files
+                    )
+
+    def _locked_python_packages(self) -> set[str]:
+        """Normalized names of the packages in the project's Python lock file, if it has one."""
+        for lock_file in _PYTHON_LOCK_FILES:
+            if lock := _read_toml(self._project_path / lock_file):
+                return {
+                    _distribution_name(package["name"])
+                    for package in lock.get("package", ())
+                    if isinstance(package.get("name"), str)
+                }
+        return set()
+
+    def _site_packages(self) -> list[Path]:
+        """The `site-packages` directories of the project's Python virtual environments."""
+        if (environments := self._environments) is None:
+            environments = [self._project_path / name for name in _VIRTUAL_ENV_DIRS]
+            if virtual_env := os.environ.get("VIRTUAL_ENV"):
+                environments.append(Path(virtual_env))
+        found: list[Path] = []
+        for environment in environments:
+            candidates = [
+                *sorted(environment.glob("lib/python*/site-packages")),
+                environment / "Lib" / "site-packages",  # Windows
+            ]
+            for path in candidates:
+                if path.is_dir() and (resolved := path.resolve()) not in found:
+                    found.append(resolved)
+        return found
+
+
+__all__ = (
+    "DependencyEcosystem",
+    "DependencySource",
+    "DependencySourceLocator",
+    "default_cargo_home",
+)
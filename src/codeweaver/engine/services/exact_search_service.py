@@ -0,0 +1,289 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Exact search over the project's files: ast-grep patterns, literal text, regexes and paths.
+
+Semantic search answers "where do we handle retries?"; it can't reliably answer "every
+`.unwrap()` in crate X". This service walks the same files the indexer would (honoring ignore
+files and the indexer's filters) and matches them exactly:
+- **patterns**: ast-grep structural patterns like `impl $T for $U { $$$ }` or `$X.unwrap()`,
+  matched with a configurable `Strictness`
+- **text**: literal strings or regular expressions, with a few lines of context
+- **paths**: glob patterns over the project-relative path
+
+ARCHITECTURE: Plain class with no DI in constructor (factory handles DI).
+"""
+
+from __future__ import annotations
+
+import contextlib
+import fnmatch
+import logging
+import re
+
+from collections.abc import Iterable, Iterator
+from pathlib import Path
+from typing import TYPE_CHECKING, NamedTuple
+
+import rignore
+
+from codeweaver.core import SemanticSearchLanguage, language_from_path
+from codeweaver.core.workspace import find_cargo_workspace
+from codeweaver.semantic.ast_grep import Strictness
+
+
+if TYPE_CHECKING:
+    from codeweaver.engine.config.indexer import RignoreSettings
+
+
+logger = logging.getLogger(__name__)
+
+DEFAULT_CONTEXT_LINES = 2
+DEFAULT_PREVIEW_LINES = 20
+
+
+class ExactHit(NamedTuple):
+    """A matched region of a file."""
+
+    path: Path
+    """The file, relative to the project root."""
+    start_line: int
+    """1-based first line of the match (including context)."""
+    end_line: int
+    """1-based last line of the match (including context)."""
+    content: str
+    """The text of the matched lines."""
+
+
+def _language_names(path: Path) -> frozenset[str]:
+    """The names a file's language goes by, as display name and as variable name."""
+    if (language := language_from_path(path)) is None:
+        return frozenset()
+    return frozenset({str(language), getattr(language, "variable", str(language))})
+
+
+def _merge_ranges(ranges: Iterable[tuple[int, int]]) -> list[tuple[int, int]]:
+    """Merge overlapping or adjacent 1-based line ranges."""
+    merged: list[tuple[int, int]] = []
+    for start, end in sorted(ranges):
+        if merged and start <= merged[-1][1] + 1:
+            merged[-1] = (merged[-1][0], max(end, merged[-1][1]))
+        else:
+            merged.append((start, end))
+    return merged
+
+
+class ExactSearchService:
+    """Finds exact structural, textual and path matches in the project's files."""
+
+    def __init__(self, project_path: Path, walker_settings: RignoreSettings | None = None) -> None:
+        """Initialize the service.
+
+        Args:
+            project_path: The project root
+            walker_settings: `rignore.Walker` settings; defaults to a plain walk honoring
+                ignore files
+        """
+        self._project_path = project_path.resolve()
+        self._walker_settings = walker_settings
+
+    @property
+    def project_path(self) -> Path:
+        """The project root that hit paths are relative to."""
+        return self._project_path
+
+    def iter_files(
+        self,
+        *,
+        languages: Iterable[str] | None = None,
+        crates: Iterable[str] | None = None,
+        glob: str | None = None,
+    ) -> Iterator[Path]:
+        """Walk the project's files, yielding paths relative to the project root.
+
+        Args:
+            languages: Only yield files in these languages (by name, e.g. `rust`)
+            crates: Only yield files in these Cargo workspace crates
+            glob: Only yield files whose relative path matches this glob
+        """
+        wanted_languages = frozenset(languages or ())
+        wanted_crates = frozenset(crates) if crates else None
+        workspace = find_cargo_workspace(self._project_path) if wanted_crates else None
+        settings = self._walker_settings or {"path": self._project_path, "ignore_hidden": True}
+        for path in rignore.Walker(**settings):
+            if not path.is_file():
+                continue
+            with contextlib.suppress(ValueError):
+                relative = path.resolve().relative_to(self._project_path)
+                if glob and not fnmatch.fnmatchcase(relative.as_posix(), glob):
+                    continue
+                if wanted_languages and wanted_languages.isdisjoint(_language_names(relative)):
+                    continue
+                if wanted_crates is not None and (
+                    workspace is None
+                    or (crate := workspace.crate_for(relative)) is None
+                    or crate.name not in wanted_crates
+                ):
+                    continue
+                yield relative
+
+    def includes(self, relative: Path) -> bool:
+        """Whether a file, relative to the project root, passes the walk's ignore rules."""
+        return any(path == relative for path in self.iter_files())
+
+    def _read(self, relative: Path) -> str | None:
+        try:
+            return (self._project_path / relative).read_text("utf-8")
+        except (OSError, UnicodeDecodeError):
+            return None
+
+    def find_pattern(
+        self,
+        pattern: str,
+        *,
+        strictness: Strictness = Strictness.SMART,
+        languages: Iterable[str] | None = None,
+        crates: Iterable[str] | None = None,
+        glob: str | None = None,
+        limit: int = 100,
+    ) -> list[ExactHit]:
+        """Find the nodes matching an ast-grep pattern.
+
+        Patterns are parsed per language; files in languages the pattern doesn't parse in are
+        skipped.
+
+        Args:
+            pattern: An ast-grep pattern, e.g. `$X.unwrap()`
+            strictness: How closely nodes must match the pattern
+            languages: Only search these languages (default: every language ast-grep supports)
+            crates: Only search these Cargo workspace crates
+            glob: Only search files whose relative path matches this glob
+            limit: Maximum number of hits
+
+        Returns:
+            One hit per matched node, in walk order
+        """
+        from ast_grep_py import SgRoot
+
+        rule = {"pattern": {"context": pattern, "strictness": strictness.value}}
+        failed: set[SemanticSearchLanguage] = set()
+        hits: list[ExactHit] = []
+        for relative in self.iter_files(languages=languages, crates=crates, glob=glob):
+            language = SemanticSearchLanguage.from_extension(relative.suffix or relative.name)
+            if language is None or language in failed:
+                continue
+            if (content := self._read(relative)) is None:
+                continue
+            try:
+                nodes = SgRoot(content, language.variable).root().find_all(**rule)
+            except BaseException as e:
+                # Invalid patterns raise, or panic in ast-grep's Rust backend
+                # (pyo3_runtime.PanicException derives from BaseException)
+                if not isinstance(e, Exception) and type(e).__module__ != "pyo3_runtime":
+                    raise
+                logger.debug("Pattern %r doesn't parse as %s: %s", pattern, language.variable, e)
+                failed.add(language)
+                continue
+            for node in nodes:
+                node_range = node.range()
+                hits.append(
+                    ExactHit(
+                        relative,
+                        node_range.start.line + 1,
+                        node_range.end.line + 1,
+                        node.text(),
+                    )
+                )
+                if len(hits) >= limit:
+                    return hits
+        return hits
+
+    def find_text(
+        self,
+        query: str,
+        *,
+        regex: bool = False,
+        case_sensitive: bool = True,
+        context_lines: int = DEFAULT_CONTEXT_LINES,
+        languages: Iterable[str] | None = None,
+        crates: Iterable[str] | None = None,
+        glob: str | None = None,
+        limit: int = 100,
+    ) -> list[ExactHit]:
+        """Find a literal string or regular expression.
+
+        Nearby hits in the same file are merged into one region with `context_lines` of
+        surrounding text.
+
+        Args:
+            query: The text or regex to find
+            regex: Treat `query` as a regular expression (Python syntax, multiline mode)
+            case_sensitive: Match case exactly
+            context_lines: Lines of context around each hit
+            languages: Only search files in these languages
+            crates: Only search these Cargo workspace crates
+            glob: Only search files whose relative path matches this glob
+            limit: Maximum number of hits
+
+        Returns:
+            One hit per matched region, in walk order
+
+        Raises:
+            ValueError: If `regex` is set and `query` isn't a valid regular expression
+        """
+        flags = re.MULTILINE | (0 if case_sensitive else re.IGNORECASE)
+        try:
+            compiled = re.compile(query if regex else re.escape(query), flags)
+        except re.error as e:
+            raise ValueError(f"Invalid regular expression {query!r}: {e}") from e
+        hits: list[ExactHit] = []
+        for relative in self.iter_files(languages=languages, crates=crates, glob=glob):
+            if (content := self._read(relative)) is None or not compiled.search(content):
+                continue
+            lines = content.splitlines()
+            ranges = []
+            for match in compiled.finditer(content):
+                first = content.count("\n", 0, match.start()) + 1
+                last = first + match.group().count("\n")
+                ranges.append((
+                    max(1, first - context_lines),
+                    min(len(lines), last + context_lines),
+                ))
+            for start, end in _merge_ranges(ranges):
+                hits.append(ExactHit(relative, start, end, "\n".join(lines[start - 1 : end])))
+                if len(hits) >= limit:
+                    return hits
+        return hits
+
+    def find_paths(
+        self,
+        glob: str,
+        *,
+        languages: Iterable[str] | None = None,
+        crates: Iterable[str] | None = None,
+        preview_lines: int = DEFAULT_PREVIEW_LINES,
+        limit: int = 100,
+    ) -> list[ExactHit]:
+        """Find files whose project-relative path matches a glob.
+
+        Args:
+            glob: A glob like `crates/*/src/**/*.rs`; `*` also matches across directories
+            languages: Only return files in these languages
+            crates: Only return files in these Cargo workspace crates
+            preview_lines: Lines from the top of each file to include as its content
+            limit: Maximum number of hits
+
+        Returns:
+            One hit per file, covering its first `preview_lines` lines
+        """
+        hits: list[ExactHit] = []
+        for relative in self.iter_files(languages=languages, crates=crates, glob=glob):
+            lines = (self._read(relative) or "").splitlines()[:preview_lines]
+            hits.append(ExactHit(relative, 1, max(1, len(lines)), "\n".join(lines)))
+            if len(hits) >= limit:
+                break
+        return hits
+
+
+__all__ = ("ExactHit", "ExactSearchService")
@@ -12,12 +12,22 @@ import logging
 import multiprocessing
 import threading
 
+from collections import defaultdict
 from pathlib import Path
-from typing import TYPE_CHECKING, Any, Protocol
+from typing import TYPE_CHECKING, Any, NamedTuple, Protocol, cast
+from uuid import UUID
 
 import rignore
 
-from codeweaver.core import INJECTED, DiscoveredFile, get_blake_hash, set_relative_path
+from codeweaver.core import (
+    INJECTED,
+    ChunkSource,
+    DiscoveredFile,
+    GitTree,
+    get_blake_hash,
+    set_relative_path,
+    uuid7,
+)
 from codeweaver.core.constants import (
     FILE_BATCH_SIZE,
     MAX_INLINE_CONTENT_SIZE,
@@ -25,17 +35,21 @@ from codeweaver.core.constants import (
     ZERO,
 )
 from codeweaver.core.discovery import compute_semantic_file_hash
+from codeweaver.engine.services.dependency_sources import DependencySource, DependencySourceLocator
 from codeweaver.providers import EmbeddingRegistryDep
 
 
 if TYPE_CHECKING:
-    from codeweaver.core import CodeChunk
+    from collections.abc import Mapping
+
+    from codeweaver.core import CodeChunk, GitBlob
     from codeweaver.core.ui_protocol import ProgressReporter
     from codeweaver.engine.config import IndexerSettings
     from codeweaver.engine.managers.checkpoint_manager import CheckpointManager, IndexingCheckpoint
     from codeweaver.engine.managers.manifest_manager import FileManifestManager, IndexFileManifest
     from codeweaver.engine.managers.progress_tracker import IndexingProgressTracker, IndexingStats
     from codeweaver.engine.services.chunking_service import ChunkingService
+    from codeweaver.engine.services.symbol_index_service import SymbolIndexService
     from codeweaver.engine.watcher.types import FileChange
     from codeweaver.providers import EmbeddingProvider, VectorStoreProvider
 
@@ -53,13 +67,80 @@ class ProgressCallback(Protocol):
         ...
 
 
+class _ChunkDiff(NamedTuple):
+    """How a batch's new chunks compare to the chunks indexed for their files before."""
+
+    replaced_files: list[Path]
+    """Files with nothing to compare, whose indexed chunks are all replaced."""
+    vanished: list[UUID]
+    """IDs of indexed chunks that no new chunk matches."""
+    changed: list[CodeChunk]
+    """New chunks to embed."""
+    unchanged: list[CodeChunk]
+    """New chunks that match an indexed chunk, with that chunk's ID."""
+    moved_from: list[Path]
+    """Paths that moved files were indexed at before, relative to the project root."""
+
+
+def _line_order(chunk: CodeChunk) -> tuple[int, int]:
+    """Sort key for a file's chunks, in the order of their lines."""
+    return chunk.line_range.start, chunk.line_range.end
+
+
+def _stamped(chunk: CodeChunk, generation: str, chunk_id: UUID | None = None) -> CodeChunk:
+    """Copy a chunk into an index generation, taking over the stored chunk with `chunk_id`."""
+    metadata = dict(chunk.metadata.items()) if chunk.metadata else {}
+    metadata["generation"] = generation
+    if chunk_id is None:
+        return chunk.model_copy(update={"metadata": metadata})
+    metadata["chunk_id"] = chunk_id
+    return chunk.model_copy(update={"chunk_id": chunk_id, "metadata": metadata})
+
+
+def _text_hash(chunk: CodeChunk) -> str:
+    """Hash of a chunk's text without its whitespace, which only formatting changes keep."""
+    return str(get_blake_hash("".join(chunk.content.split()).encode("utf-8")))
+
+
+def _file_fields(file: DiscoveredFile, content: bytes | None) -> dict[str, Any]:
+    """The size and modification time of a chunked file, for its chunks' payload fields.
+
+    A file that wasn't read from disk (like a git revision's) has no modification time.
+    """
+    if content is not None:
+        return {"file_size": len(content)}
+    try:
+        stat = file.absolute_path.stat()
+    except OSError:
+        return {}
+    return {"file_size": stat.st_size, "file_mtime": stat.st_mtime}
+
+
+def _with_file_fields(chunks: list[CodeChunk], fields: Mapping[str, Any]) -> list[CodeChunk]:
+    """Copy a file's chunks with its size and modification time in their metadata."""
+    if not fields:
+        return chunks
+    return [
+        chunk.model_copy(update={"metadata": {**(chunk.metadata or {}), **fields}})
+        for chunk in chunks
+    ]
+
+
+def _as_dependency_chunk(chunk: CodeChunk, source: DependencySource) -> CodeChunk:
+    """Tag a chunk with the dependency it's from; all but skeleton chunks become EXTERNAL."""
+    update: dict[str, Any] = {"package": source.name, "package_version": source.version}
+    if chunk.source != ChunkSource.SKELETON:
+        update["source"] = ChunkSource.EXTERNAL
+    return chunk.model_copy(update=update)
+
+
 class IndexingService:
     """Orchestrates the indexing workflow.
 
     Responsibilities:
     - Coordinate file discovery → chunking → embedding → storage
     - Manage checkpointing at intervals
-    - Update file manifest
+    - Update file manifest and symbol index
     - Report progress
     """
 
@@ -75,6 +156,7 @@ class IndexingService:
         checkpoint_manager: CheckpointManager,
         manifest_manager: FileManifestManager,
         project_path: Path,
+        symbol_index: SymbolIndexService | None = None,
     ):
         """Initialize indexing service with required dependencies."""
         self._chunking_service = chunking_service
@@ -86,6 +168,7 @@ class IndexingService:
         self._progress_tracker = progress_tracker
         self._checkpoint_manager = checkpoint_manager
         self._manifest_manager = manifest_manager
+        self._symbol_index = symbol_index
         self._project_path = project_path.resolve()
 
         # Operational state
@@ -95,6 +178,8 @@ class IndexingService:
         self._duplicate_dense_count = ZERO
         self._duplicate_sparse_count = ZERO
         self._deleted_files: list[Path] = []
+        # Files the watcher saw move: new path -> old path, both relative to the project root
+        self._moved_files: dict[Path, Path] = {}
 
         # Concurrency control
         self._max_concurrent_batches = settings.max_concurrent_batches
@@ -116,6 +201,28 @@ class IndexingService:
         """Get current indexing statistics."""
         return self._progress_tracker.get_stats()
 
+    async def index_generation(self) -> str | None:
+        """A token for what's indexed, which changes whenever files are indexed or removed.
+
+        Reads the saved manifest if this service hasn't loaded one; None if the project hasn't
+        been indexed.
+        """
+        manifest = await self.live_manifest()
+        return manifest.index_generation() if manifest else None
+
+    async def live_manifest(self) -> IndexFileManifest | None:
+        """The manifest of the working tree's index, whose chunks are the ones searches see.
+
+        Reads the saved manifest if this service hasn't loaded one; None if the project hasn't
+        been indexed.
+        """
+        return self._file_manifest or await self._manifest_manager.load()
+
+    async def dependency_index_generation(self) -> str | None:
+        """Like `index_generation`, for the dependency sources' index; None if it isn't built."""
+        manifest = await self._manifest_manager.for_dependencies().load()
+        return manifest.index_generation() if manifest else None
+
     async def process_changes(self, changes: list[FileChange]) -> int:
         """Process a batch of file changes (incremental indexing)."""
         from watchfiles import Change
@@ -135,18 +242,90 @@ class IndexingService:
                 files_to_delete.append(path)
             else:
                 files_to_index.append(path)
+        # A changed manifest can add, remove or rename crates
+        if any(path.name == "Cargo.toml" for path in (*files_to_index, *files_to_delete)):
+            self._chunking_service.reset_cargo_workspaces()
+
+        # A deleted file and a new one with the same content are a move; the new file takes
+        # over the old one's chunks instead of being embedded again
+        if moved := await self._pair_moved_files(files_to_delete, files_to_index):
+            logger.info("Detected %d moved files", len(moved))
+            self._moved_files.update(moved)
+        moved_from = set(moved.values())
 
         # Handle deletions
-        if files_to_delete:
-            self._deleted_files.extend(files_to_delete)
+        if deleted := [
+            path
+            for path in files_to_delete
+            if set_relative_path(path, base_path=self._project_path) not in moved_from
+        ]:
+            self._deleted_files.extend(deleted)
             await self._cleanup_deleted_files()
 
         # Handle updates
-        if files_to_index:
-            await self._perform_batch_indexing(files_to_index, progress_callback=None)
+        try:
+            if files_to_index:
+                await self._perform_batch_indexing(files_to_index, progress_callback=None)
+        finally:
+            # A move whose new path wasn't indexed (it was skipped, or indexing failed) leaves
+            # the old path's chunks live, so the old path is deleted after all
+            for new_path, old_path in moved.items():
+                self._moved_files.pop(new_path, None)
+                if self._file_manifest.has_file(old_path):
+                    self._deleted_files.append(self._project_path / old_path)
+            await self._cleanup_deleted_files()
+
+        if self._symbol_index:
+            await self._symbol_index.save()
 
         return len(files_to_index) + len(files_to_delete)
 
+    async def _pair_moved_files(self, deleted: list[Path], added: list[Path]) -> dict[Path, Path]:
+        """Match deleted files to new files with the content they were indexed with.
+
+        Only deleted files whose indexed chunks can be reused are matched, and only new files
+        that aren't indexed yet (a changed file isn't a move's destination).
+
+        Returns:
+            Map of each moved file's new path to its old one, both relative to the project root
+        """
+        if not deleted or not added or not self._file_manifest:
+            return {}
+        manifest = self._file_manifest
+        models = self._get_current_embedding_models()
+        old_paths: dict[str, list[Path]] = defaultdict(list)
+        for path in deleted:
+            if (
+                (rel_path := set_relative_path(path, base_path=self._project_path))
+                and (entry := manifest.get_file(rel_path))
+                and self._indexed_chunk_hashes(rel_path, models) is not None
+            ):
+                old_paths[str(entry["content_hash"])].append(rel_path)
+        new_paths = {
+            path: rel_path
+            for path in added
+            if (rel_path := set_relative_path(path, base_path=self._project_path))
+            and not manifest.has_file(rel_path)
+        }
+        if not old_paths or not new_paths:
+            return {}
+
+        def _hash_new_files() -> dict[Path, str]:
+            hashes: dict[Path, str] = {}
+            for path, rel_path in new_paths.items():
+                file = path if path.is_absolute() else self._project_path / path
+                try:
+                    hashes[rel_path] = str(compute_semantic_file_hash(file.read_bytes(), file))
+                except OSError:
+                    continue  # gone again, or not a file
+            return hashes
+
+        moved: dict[Path, Path] = {}
+        for rel_path, content_hash in (await asyncio.to_thread(_hash_new_files)).items():
+            if candidates := old_paths.get(content_hash):
+                moved[rel_path] = candidates.pop(0)
+        return moved
+
     async def index_project(
         self,
         *,
@@ -167,6 +346,8 @@ class IndexingService:
             Number of files indexed
         """
         self._progress_tracker.update_phase("discovery")
+        # Each run resolves the project's Cargo workspace afresh, once
+        self._chunking_service.reset_cargo_workspaces()
 
         # 1. Load manifest
         if not force_reindex:
@@ -174,6 +355,8 @@ class IndexingService:
 
         if self._file_manifest is None:
             self._file_manifest = self._manifest_manager.create_new()
+            if self._symbol_index:
+                await self._symbol_index.clear()
 
         # 2. Setup streaming pipeline
         # Now passing a tuple of (Path, bytes | None) to avoid redundant I/O
@@ -202,12 +385,320 @@ class IndexingService:
 
         # 6. Finalize
         await self._manifest_manager.save(self._file_manifest)
+        if self._symbol_index:
+            await self._symbol_index.save()
         await self._checkpoint_manager.delete()  # Clear checkpoint on success
 
+        # 7. Index dependency sources, if opted in (only new package versions are indexed)
+        if self._settings.index_dependency_sources:
+            try:
+                await self.index_dependencies(progress_callback=progress_callback)
+            except Exception:
+                logger.warning("Failed to index dependency sources", exc_info=True)
+
         self._progress_tracker.update_phase("complete")
 
         return self.stats.files_discovered
 
+    async def index_revision(
+        self,
+        rev: str,
+        *,
+        force_reindex: bool = False,
+        progress_callback: ProgressCallback | None = None,
+    ) -> IndexFileManifest:
+        """Index the project as of a git commit, branch or tag, without checking it out.
+
+        The revision gets its own collection and manifest next to the working tree's, so
+        indexing it never changes the working tree's index. Files are read from git objects. A
+        file whose blob is already indexed for another revision has its chunks and embeddings
+        copied from that revision's collection instead of being chunked and embedded again.
+
+        Args:
+            rev: The commit, branch or tag to index
+            force_reindex: If True, reindex every file even if the revision was indexed before
+            progress_callback: Optional granular progress callback
+
+        Returns:
+            The revision's manifest, which records the commit it indexes
+
+        Raises:
+            IndexingError: If git isn't available or `rev` doesn't name a commit
+        """
+        self._progress_tracker.update_phase("discovery")
+        tree = await asyncio.to_thread(GitTree.resolve, self._project_path, rev)
+        manifest_manager = self._manifest_manager.for_revision(tree.commit, rev)
+        manifest = (None if force_reindex else await manifest_manager.load()) or (
+            manifest_manager.create_new()
+        )
+        store = self._revision_store(tree.commit)
+        if force_reindex and store:
+            await store.delete_collection(cast(str, store.config.collection.collection_name))
+
+        path_filter = self._settings.construct_path_filter()
+        blobs = [blob for blob in tree if path_filter(blob.path)]
+        self.stats.files_discovered += len(blobs)
+        if progress_callback:
+            progress_callback("discovery", len(blobs), len(blobs))
+
+        # A revision's files never change, but the filters might have since it was indexed
+        if stale := manifest.get_all_file_paths() - {blob.path for blob in blobs}:
+            if store:
+                await store.delete_by_files(sorted(stale))
+            for path in stale:
+                manifest.remove_file(path)
+
+        pending = [
+            blob
+            for blob in blobs
+            if (entry := manifest.get_file(blob.path)) is None
+            or entry.get("blob_id") != blob.blob_id
+        ]
+        if store and pending:
+            await store.delete_by_files([blob.path for blob in pending])
+        reused = await self._reuse_indexed_blobs(pending, manifest, manifest_manager, store)
+
+        self._progress_tracker.update_phase("indexing")
+        to_index = [blob for blob in pending if blob.path not in reused]
+        for start in range(0, len(to_index), FILE_BATCH_SIZE):
+            batch = to_index[start : start + FILE_BATCH_SIZE]
+            contents = await asyncio.to_thread(tree.read, batch)
+            await self._index_revision_batch(
+                tree, batch, contents, manifest, store, progress_callback
+            )
+            if progress_callback:
+                progress_callback("indexing", start + len(batch), len(to_index))
+
+        await manifest_manager.save(manifest)
+        self._progress_tracker.update_phase("complete")
+        logger.info(
+            "Indexed %s (%s): %d files, %d reused from other revisions, %d unchanged",
+            rev,
+            tree.short_commit,
+            len(to_index),
+            len(reused),
+            len(blobs) - len(pending),
+        )
+        return manifest
+
+    async def index_dependencies(
+        self,
+        *,
+        force_reindex: bool = False,
+        progress_callback: ProgressCallback | None = None,
+        locator: DependencySourceLocator | None = None,
+    ) -> IndexFileManifest:
+        """Index the sources of the project's third-party dependencies that are on disk.
+
+        Dependencies get their own collection and manifest next to the project's, so
+        `find_code` only searches them with `scope="deps"`. A package version's source doesn't
+        change, so each one is indexed once; versions the project no longer uses, and packages
+        embedded with other models than the current ones, are removed.
+
+        Args:
+            force_reindex: If True, reindex every package even if it was indexed before
+            progress_callback: Optional granular progress callback
+            locator: Finds the dependency sources (default: a `DependencySourceLocator` for the
+                project)
+
+        Returns:
+            The dependency sources' manifest, with an entry per package version
+        """
+        self._progress_tracker.update_phase("discovery")
+        locator = locator or DependencySourceLocator(self._project_path)
+        sources = {Path(source.key): source for source in await asyncio.to_thread(locator.locate)}
+        manifest_manager = self._manifest_manager.for_dependencies()
+        manifest = (None if force_reindex else await manifest_manager.load()) or (
+            manifest_manager.create_new()
+        )
+        store = self._dependency_store()
+        if force_reindex and store:
+            await store.delete_collection(cast(str, store.config.collection.collection_name))
+        if progress_callback:
+            progress_callback("discovery", len(sources), len(sources))
+
+        models = self._get_current_embedding_models()
+        removed = [
+            key
+            for key in manifest.get_all_file_paths()
+            if key not in sources
+            or (entry := manifest.get_file(key)) is None
+            or entry.get("dense_embedding_model") != models["dense_model"]
+            or entry.get("sparse_embedding_model") != models["sparse_model"]
+        ]
+        if removed:
+            if store:
+                await store.delete_by_id([
+                    UUID(chunk_id)
+                    for key in removed
+                    if (entry := manifest.get_file(key))
+                    for chunk_id in entry["chunk_ids"]
+                ])
+            for key in removed:
+                manifest.remove_file(key)
+
+        self._progress_tracker.update_phase("indexing")
+        pending = [source for key, source in sources.items() if manifest.get_file(key) is None]
+        for number, source in enumerate(pending, start=1):
+            await self._index_dependency_source(source, manifest, store, progress_callback)
+            if progress_callback:
+                progress_callback("indexing", number, len(pending))
+
+        await manifest_manager.save(manifest)
+        self._progress_tracker.update_phase("complete")
+        logger.info(
+            "Indexed dependency sources: %d packages, %d unchanged, %d removed",
+            len(pending),
+            len(sources) - len(pending),
+            len(removed),
+        )
+        return manifest
+
+    def _dependency_store(self) -> VectorStoreProvider | None:
+        """Get the vector store for the dependency sources' collection."""
+        return self._vector_store.for_dependencies() if self._vector_store else None
+
+    async def _index_dependency_source(
+        self,
+        source: DependencySource,
+        manifest: IndexFileManifest,
+        store: VectorStoreProvider | None,
+        progress_callback: ProgressCallback | None,
+    ) -> None:
+        """Chunk, embed and store a dependency's files, tagged with its name and version."""
+        exclude = self._settings.filter
+
+        def _read_files() -> dict[Path, bytes]:
+            contents: dict[Path, bytes] = {}
+            for path in source.iter_files(exclude):
+                try:
+                    content = path.read_bytes()
+                except OSError:
+                    continue
+                if b"\0" not in content[:8192]:  # skip binaries
+                    contents[path] = content
+            return contents
+
+        contents = await asyncio.to_thread(_read_files)
+        discovered_files = [
+            DiscoveredFile(
+                path=path,
+                file_hash=get_blake_hash(content),
+                project_path=source.root,
+                package_version=source.version,
+            )
+            for path, content in contents.items()
+        ]
+        chunks = [
+            _as_dependency_chunk(chunk, source)
+            for chunk in await self._chunk_files(discovered_files, progress_callback, contents)
+        ]
+        stored = await self._embed_and_store(chunks, store)
+        model_info = self._get_current_embedding_models()
+        async with self._manifest_lock:
+            manifest.add_file(
+                Path(source.key),
+                get_blake_hash("\n".join(f"{df.path}:{df.file_hash}" for df in discovered_files)),
+                [str(chunk.chunk_id) for chunk in stored],
+                dense_embedding_provider=model_info["dense_provider"],
+                dense_embedding_model=model_info["dense_model"],
+                sparse_embedding_provider=model_info["sparse_provider"],
+                sparse_embedding_model=model_info["sparse_model"],
+                has_dense_embeddings=bool(self._embedding_provider),
+                has_sparse_embeddings=bool(self._sparse_provider),
+            )
+
+    def _revision_store(self, commit: str) -> VectorStoreProvider | None:
+        """Get the vector store for a revision's collection."""
+        return self._vector_store.for_revision(commit) if self._vector_store else None
+
+    async def _reuse_indexed_blobs(
+        self,
+        blobs: list[GitBlob],
+        manifest: IndexFileManifest,
+        manifest_manager: FileManifestManager,
+        store: VectorStoreProvider | None,
+    ) -> set[Path]:
+        """Copy files that another revision indexed with the same content.
+
+        Returns:
+            The paths of the files that were copied and don't need indexing
+        """
+        if not blobs or not store:
+            return set()
+        wanted = {blob.path: blob.blob_id for blob in blobs}
+        models = self._get_current_embedding_models()
+        reused: set[Path] = set()
+        for other in await manifest_manager.load_revisions():
+            if not other.commit or other.commit == manifest.commit:
+                continue
+            entries = [
+                entry
+                for path, blob_id in wanted.items()
+                if path not in reused
+                and (entry := other.get_file(path))
+                and entry.get("blob_id") == blob_id
+                and entry.get("dense_embedding_model") == models["dense_model"]
+                and entry.get("sparse_embedding_model") == models["sparse_model"]
+            ]
+            if not entries:
+                continue
+            paths = [Path(entry["path"]) for entry in entries]
+            try:
+                source = cast("VectorStoreProvider", self._revision_store(other.commit))
+                copied = await store.copy_files_from(
+                    cast(str, source.config.collection.collection_name), paths
+                )
+            except NotImplementedError:
+                return reused
+            except Exception:
+                logger.warning("Could not copy chunks from %s", other.rev, exc_info=True)
+                continue
+            if copied < sum(entry["chunk_count"] for entry in entries):
+                # The other revision's collection is incomplete; index these files instead
+                await store.delete_by_files(paths)
+                continue
+            async with self._manifest_lock:
+                manifest.add_files_batch([dict(entry) for entry in entries])
+            reused.update(paths)
+            self.stats.chunks_indexed += copied
+        return reused
+
+    async def _index_revision_batch(
+        self,
+        tree: GitTree,
+        blobs: list[GitBlob],
+        contents: dict[Path, bytes],
+        manifest: IndexFileManifest,
+        store: VectorStoreProvider | None,
+        progress_callback: ProgressCallback | None,
+    ) -> None:
+        """Chunk, embed and store a batch of files read from a revision."""
+        discovered_files: list[DiscoveredFile] = []
+        file_contents: dict[Path, bytes] = {}
+        for blob in blobs:
+            if (content := contents.get(blob.path)) is None or b"\0" in content[:8192]:
+                continue  # missing or binary
+            df = DiscoveredFile(
+                path=self._project_path / blob.path,
+                file_hash=get_blake_hash(content),
+                git_branch=tree.rev,
+                project_path=self._project_path,
+            )
+            discovered_files.append(df)
+            file_contents[df.path] = content
+        if not discovered_files:
+            return
+
+        _, updated_chunks = await self._chunk_embed_and_store(
+            discovered_files, store, progress_callback, contents=file_contents
+        )
+        if manifest_updates := self._manifest_updates(
+            discovered_files, updated_chunks, blob_ids={blob.path: blob.blob_id for blob in blobs}
+        ):
+            async with self._manifest_lock:
+                manifest.add_files_batch(manifest_updates)
+
     async def _discovery_worker(
         self,
         queue: asyncio.Queue[tuple[Path, bytes | None] | None],
@@ -323,6 +814,7 @@ class IndexingService:
                 current_dense_model=current_models["dense_model"],
                 current_sparse_provider=current_models["sparse_provider"],
                 current_sparse_model=current_models["sparse_model"],
+                current_raw_hash=get_blake_hash(content_bytes),
             )
 
             if needs_reindex:
@@ -413,6 +905,10 @@ class IndexingService:
         self, batch: list[tuple[Path, bytes | None]], progress_callback: ProgressCallback | None
     ) -> None:
         """Index a single batch of files."""
+        # The manifest records files' content hashes (of their syntax trees, where they have
+        # them), and the hashes of their bytes, so formatting-only changes are reindexed too
+        content_hashes: dict[Path, str] = {}
+        raw_hashes: dict[Path, str] = {}
 
         def _collect_discovered_files() -> list[DiscoveredFile]:
             results = []
@@ -429,12 +925,16 @@ class IndexingService:
                         project_path=self._project_path,
                     )
                     results.append(df)
+                    content_hashes[df.path] = str(compute_semantic_file_hash(content, path))
+                    raw_hashes[df.path] = str(df.file_hash)
                 else:
-                    # Fallback for large files or watcher updates
+                    # Fallback for large files or watcher updates; the file's hash is semantic
                     if DiscoveredFile.is_path_text(path) and (
                         df := DiscoveredFile.from_path(path, project_path=self._project_path)
                     ):
                         results.append(df)
+                        content_hashes[df.path] = str(df.file_hash)
+                        raw_hashes[df.path] = str(get_blake_hash(path.read_bytes()))
             return results
 
         discovered_files = await asyncio.to_thread(_collect_discovered_files)
@@ -442,17 +942,89 @@ class IndexingService:
         if not discovered_files:
             return
 
-        # Clean up old chunks for files being re-indexed
+        all_chunks = await self._chunk_files(discovered_files, progress_callback)
+        moved = {
+            rel_path: self._moved_files.pop(rel_path)
+            for df in discovered_files
+            if (rel_path := set_relative_path(df.path, base_path=self._project_path))
+            and rel_path in self._moved_files
+        }
+        generation = uuid7().hex
+        diff = self._diff_chunks(
+            discovered_files,
+            all_chunks,
+            generation,
+            moved=moved,
+            moved_hashes=await self._hash_at_old_paths(discovered_files, all_chunks, moved),
+            semantic_hashes=content_hashes if self._settings.skip_formatting_changes else None,
+        )
+
+        # Store the new generation before removing the chunks it replaces, so searches find
+        # each file's previous chunks until its new ones are in
+        previous: list[CodeChunk] = []
+        try:
+            stored_chunks = await self._embed_and_store(diff.changed, self._vector_store)
+            previous = await self._stored_chunks(diff.unchanged, self._vector_store)
+            stored_chunks += await self._update_unchanged_chunks(diff, self._vector_store)
+        except Exception as e:
+            await self._discard_generation(discovered_files, diff, e, previous=previous)
+            raise
+
+        # Clean up old chunks: all of a file's when there's nothing to compare them to, and
+        # otherwise only the ones the file no longer has
         if self._vector_store:
-            # OPTIMIZATION: Batch deletion of chunks for the entire file list
-            await self._vector_store.delete_by_files([df.path for df in discovered_files])
+            if diff.replaced_files:
+                # OPTIMIZATION: Batch deletion of chunks for the entire file list
+                await self._vector_store.delete_by_files(
+                    diff.replaced_files, keep_generation=generation
+                )
+            if diff.vanished:
+                await self._vector_store.delete_by_id(diff.vanished)
+
+        # Update manifest
+        if manifest_updates := self._manifest_updates(
+            discovered_files,
+            stored_chunks,
+            generation=generation,
+            content_hashes=content_hashes,
+            raw_hashes=raw_hashes,
+            record_text_hashes=self._settings.skip_formatting_changes,
+        ):
+            if not self._file_manifest:
+                self._file_manifest = self._manifest_manager.create_new()
+            async with self._manifest_lock:
+                for old_path in diff.moved_from:
+                    self._file_manifest.remove_file(old_path)
+                self._file_manifest.add_files_batch(manifest_updates)
+
+        # Update symbol references, reusing the chunker's syntax trees
+        if self._symbol_index:
+            if diff.moved_from:
+                await self._symbol_index.remove_files(diff.moved_from)
+            await self._symbol_index.update_files(discovered_files, all_chunks)
 
+    async def _chunk_files(
+        self,
+        discovered_files: list[DiscoveredFile],
+        progress_callback: ProgressCallback | None,
+        contents: Mapping[Path, bytes] | None = None,
+    ) -> list[CodeChunk]:
+        """Chunk files."""
         self.stats.files_processed += len(discovered_files)
 
-        # Chunk
         all_chunks: list[CodeChunk] = []
-        async for _, chunks in self._chunking_service.chunk_files(discovered_files):
-            all_chunks.extend(chunks)
+        chunked = (
+            self._chunking_service.chunk_files(discovered_files)
+            if contents is None
+            else self._chunking_service.chunk_files(discovered_files, contents=contents)
+        )
+        files_by_path = {file.path: file for file in discovered_files}
+        async for path, chunks in chunked:
+            if (file := files_by_path.get(path)) is None:
+                all_chunks.extend(chunks)
+                continue
+            fields = _file_fields(file, contents.get(path) if contents is not None else None)
+            all_chunks.extend(_with_file_fields(chunks, fields))
 
         self.stats.chunks_created += len(all_chunks)
 
@@ -463,27 +1035,334 @@ class IndexingService:
                 len(discovered_files),
                 extra={"chunks_created": len(all_chunks)},
             )
+        return all_chunks
+
+    async def _embed_and_store(
+        self, chunks: list[CodeChunk], store: VectorStoreProvider | None
+    ) -> list[CodeChunk]:
+        """Embed and upsert chunks.
+
+        Returns:
+            The chunks as stored (with their embeddings' batch keys)
+        """
+        if not chunks:
+            return []
 
         # Embed
         if self._embedding_provider or self._sparse_provider:
-            await self._embed_chunks(all_chunks)
+            await self._embed_chunks(chunks)
 
-        self.stats.chunks_embedded += len(all_chunks)
+        self.stats.chunks_embedded += len(chunks)
 
         # Retrieve embedded chunks from registry
         from codeweaver.providers.embedding import get_embedding_registry
 
         registry = get_embedding_registry()
         updated_chunks = [
-            registry[chunk.chunk_id].chunk for chunk in all_chunks if chunk.chunk_id in registry
-        ] or all_chunks
+            registry[chunk.chunk_id].chunk for chunk in chunks if chunk.chunk_id in registry
+        ] or chunks
 
         # Index
-        if self._vector_store:
-            await self._vector_store.upsert(updated_chunks)
+        if store:
+            await store.upsert(updated_chunks)
             self.stats.chunks_indexed += len(updated_chunks)
 
-        # Update manifest
+        return updated_chunks
+
+    async def _chunk_embed_and_store(
+        self,
+        discovered_files: list[DiscoveredFile],
+        store: VectorStoreProvider | None,
+        progress_callback: ProgressCallback | None,
+        contents: Mapping[Path, bytes] | None = None,
+    ) -> tuple[list[CodeChunk], list[CodeChunk]]:
+        """Chunk, embed and upsert files.
+
+        Returns:
+            The chunks as chunked, and as stored (with their embeddings' batch keys)
+        """
+        all_chunks = await self._chunk_files(discovered_files, progress_callback, contents)
+        return all_chunks, await self._embed_and_store(all_chunks, store)
+
+    def _indexed_chunk_hashes(
+        self, rel_path: Path, models: Mapping[str, str | None]
+    ) -> dict[str, str] | None:
+        """The embedding input hashes of a file's indexed chunks, if their vectors are reusable.
+
+        Returns:
+            Map of chunk ID to hash, or None if the file wasn't indexed, was indexed before chunk
+            hashes were recorded, or was embedded with other models than the current ones
+        """
+        if not self._file_manifest or not (entry := self._file_manifest.get_file(rel_path)):
+            return None
+        if (
+            entry.get("dense_embedding_model") != models["dense_model"]
+            or entry.get("sparse_embedding_model") != models["sparse_model"]
+            or entry.get("has_dense_embeddings", False) != bool(self._embedding_provider)
+            or entry.get("has_sparse_embeddings", False) != bool(self._sparse_provider)
+        ):
+            return None
+        hashes = self._file_manifest.get_chunk_hashes_for_file(rel_path)
+        # Every indexed chunk needs a hash, or some would be left behind
+        return hashes if hashes and set(hashes) == set(entry["chunk_ids"]) else None
+
+    async def _hash_at_old_paths(
+        self,
+        discovered_files: list[DiscoveredFile],
+        chunks: list[CodeChunk],
+        moved: Mapping[Path, Path],
+    ) -> dict[UUID, str]:
+        """Hash moved files' new chunks as they would have been chunked at their old paths.
+
+        A chunk's embedding input includes its file's path, so a moved file's new chunks can't
+        match the hashes indexed for its old path. The file's content chunked at the old path
+        can, and those chunks are paired with the new ones by position. A file that chunks
+        differently there (because its extension changed, say) has no pairs.
+
+        Returns:
+            Map of new chunk ID to the hash the chunk would have had at its file's old path
+        """
+        if not moved:
+            return {}
+        old_files: list[DiscoveredFile] = []
+        new_paths: dict[Path, Path] = {}
+        contents: dict[Path, bytes] = {}
+        for df in discovered_files:
+            rel_path = set_relative_path(df.path, base_path=self._project_path)
+            if not rel_path or rel_path not in moved:
+                continue
+            try:
+                content = await asyncio.to_thread(df.absolute_path.read_bytes)
+            except OSError:
+                continue
+            old_file = DiscoveredFile(
+                path=moved[rel_path], file_hash=df.file_hash, project_path=self._project_path
+            )
+            old_files.append(old_file)
+            new_paths[old_file.path] = rel_path
+            contents[old_file.path] = content
+
+        chunks_by_file: dict[Path | None, list[CodeChunk]] = defaultdict(list)
+        for chunk in chunks:
+            chunks_by_file[chunk.file_path].append(chunk)
+        hashes: dict[UUID, str] = {}
+        async for old_path, old_chunks in self._chunking_service.chunk_files(
+            old_files, contents=contents
+        ):
+            new_chunks = chunks_by_file.get(new_paths[old_path], [])
+            if len(new_chunks) != len(old_chunks) or any(
+                (new.line_range.start, new.line_range.end)
+                != (old.line_range.start, old.line_range.end)
+                for new, old in zip(new_chunks, old_chunks, strict=True)
+            ):
+                continue
+            hashes.update(
+                (new.chunk_id, str(old.embedding_hash))
+                for new, old in zip(new_chunks, old_chunks, strict=True)
+            )
+        return hashes
+
+    def _diff_chunks(
+        self,
+        discovered_files: list[DiscoveredFile],
+        chunks: list[CodeChunk],
+        generation: str,
+        *,
+        moved: Mapping[Path, Path] | None = None,
+        moved_hashes: Mapping[UUID, str] | None = None,
+        semantic_hashes: Mapping[Path, str] | None = None,
+    ) -> _ChunkDiff:
+        """Match the new chunks of changed files to the chunks indexed for them before.
+
+        A new chunk whose embedding input matches an indexed chunk's takes that chunk's ID, and
+        so its stored vectors; indexed chunks that no new chunk matches have vanished. Files with
+        no indexed chunk hashes to compare are replaced outright. Every new chunk is stamped
+        with the index generation it's stored in.
+
+        A moved file (in `moved`, new path to old) is compared to the chunks indexed at its old
+        path, using the hashes its chunks would have had there (`moved_hashes`). A file whose
+        syntax tree hash (in `semantic_hashes`) hasn't changed keeps its indexed chunks whose
+        text is the same but for whitespace; the rest, like chunks whose comments changed, are
+        embedded again.
+        """
+        models = self._get_current_embedding_models()
+        moved = moved or {}
+        moved_hashes = moved_hashes or {}
+        semantic_hashes = semantic_hashes or {}
+        chunks_by_file: dict[Path | None, list[CodeChunk]] = defaultdict(list)
+        for chunk in chunks:
+            chunks_by_file[chunk.file_path].append(chunk)
+
+        diff = _ChunkDiff([], [], [], [], [])
+        for df in discovered_files:
+            rel_path = set_relative_path(df.path, base_path=self._project_path)
+            file_chunks = chunks_by_file.pop(rel_path or df.path, [])
+            old_path = moved.get(rel_path) if rel_path else None
+            if old_path:
+                diff.moved_from.append(old_path)
+            indexed_path = old_path or rel_path
+            indexed = self._indexed_chunk_hashes(indexed_path, models) if indexed_path else None
+            if indexed is None:
+                diff.replaced_files.extend((df.path, old_path) if old_path else (df.path,))
+                diff.changed.extend(_stamped(chunk, generation) for chunk in file_chunks)
+                continue
+            if not old_path and (
+                reformatted := self._reformatted_chunk_hashes(
+                    indexed_path, semantic_hashes.get(df.path), len(file_chunks)
+                )
+            ):
+                for chunk, (chunk_id, text_hash) in zip(
+                    sorted(file_chunks, key=_line_order), reformatted.items(), strict=True
+                ):
+                    if _text_hash(chunk) == text_hash:
+                        diff.unchanged.append(_stamped(chunk, generation, UUID(chunk_id)))
+                    else:
+                        diff.changed.append(_stamped(chunk, generation))
+                        diff.vanished.append(UUID(chunk_id))
+                continue
+            ids_by_hash: dict[str, list[str]] = defaultdict(list)
+            for chunk_id, chunk_hash in indexed.items():
+                ids_by_hash[chunk_hash].append(chunk_id)
+            for chunk in file_chunks:
+                chunk_hash = (
+                    moved_hashes.get(chunk.chunk_id) if old_path else str(chunk.embedding_hash)
+                )
+                if chunk_hash and (ids := ids_by_hash.get(chunk_hash)):
+                    diff.unchanged.append(_stamped(chunk, generation, UUID(ids.pop(0))))
+                else:
+                    diff.changed.append(_stamped(chunk, generation))
+            diff.vanished.extend(UUID(chunk_id) for ids in ids_by_hash.values() for chunk_id in ids)
+        # Chunks that don't belong to any of the files (there shouldn't be any) are just new
+        diff.changed.extend(
+            _stamped(chunk, generation) for rest in chunks_by_file.values() for chunk in rest
+        )
+        return diff
+
+    def _reformatted_chunk_hashes(
+        self, rel_path: Path, semantic_hash: str | None, chunk_count: int
+    ) -> dict[str, str] | None:
+        """The text hashes of a file's indexed chunks, in line order, if its syntax is unchanged.
+
+        That's when the file's syntax tree hashes to its indexed content hash, and the file
+        still chunks into as many chunks, which then pair up with the indexed ones in order.
+        The syntax tree leaves out comments, so each pair's text still has to match.
+
+        Returns:
+            Map of chunk ID to the hash of its text without whitespace, or None if the syntax
+            changed or the file was indexed without text hashes
+        """
+        if not semantic_hash or not self._file_manifest:
+            return None
+        entry = self._file_manifest.get_file(rel_path)
+        if (
+            not entry
+            or entry["content_hash"] != semantic_hash
+            or len(entry["chunk_ids"]) != chunk_count
+        ):
+            return None
+        text_hashes = entry.get("chunk_text_hashes") or {}
+        if set(text_hashes) != set(entry["chunk_ids"]):
+            return None
+        return {chunk_id: text_hashes[chunk_id] for chunk_id in entry["chunk_ids"]}
+
+    async def _update_unchanged_chunks(
+        self, diff: _ChunkDiff, store: VectorStoreProvider | None
+    ) -> list[CodeChunk]:
+        """Update the line ranges of stored chunks that an edit moved, without embedding them.
+
+        Unchanged chunks the store doesn't have (like points removed from it by hand) can't be
+        reused; they're moved to the diff's changed chunks and embedded again.
+
+        Returns:
+            The chunks as stored
+        """
+        chunks = diff.unchanged
+        if not chunks or not store:
+            return chunks
+        try:
+            missing = set(await store.update_payloads(chunks))
+        except NotImplementedError:
+            logger.debug("%s can't update payloads; embedding moved chunks again", store.name)
+            return await self._embed_and_store(chunks, store)
+        reused = [chunk for chunk in chunks if chunk.chunk_id not in missing]
+        self.stats.chunks_reused += len(reused)
+        if len(reused) == len(chunks):
+            return chunks
+        lost = [chunk for chunk in chunks if chunk.chunk_id in missing]
+        logger.warning(
+            "%d unchanged chunks are missing from %s; embedding them again", len(lost), store.name
+        )
+        diff.unchanged[:] = reused
+        diff.changed.extend(lost)
+        return reused + await self._embed_and_store(lost, store)
+
+    async def _stored_chunks(
+        self, chunks: list[CodeChunk], store: VectorStoreProvider | None
+    ) -> list[CodeChunk]:
+        """Get chunks as they're stored now, or none if the store can't read them back."""
+        if not chunks or not store:
+            return []
+        try:
+            return await store.get_chunks([chunk.chunk_id for chunk in chunks])
+        except NotImplementedError:
+            return []
+
+    async def _discard_generation(
+        self,
+        discovered_files: list[DiscoveredFile],
+        diff: _ChunkDiff,
+        error: Exception,
+        *,
+        previous: list[CodeChunk] | None = None,
+    ) -> None:
+        """Remove what a failed batch stored, leaving its files' previous chunks live.
+
+        Args:
+            discovered_files: The batch's files
+            diff: The batch's chunks
+            error: Why the batch failed
+            previous: Unchanged chunks as they were stored before the batch, whose payloads
+                are put back in case the batch had already rewritten them
+        """
+        logger.warning(
+            "Indexing %d files failed; their previous chunks stay searchable: %s",
+            len(discovered_files),
+            error,
+        )
+        for df in discovered_files:
+            self.stats.add_error(df.path, error, "indexing")
+        if not self._vector_store:
+            return
+        if diff.changed:
+            try:
+                await self._vector_store.delete_by_id([chunk.chunk_id for chunk in diff.changed])
+            except Exception:
+                logger.warning("Could not remove a failed batch's chunks", exc_info=True)
+        if previous:
+            try:
+                await self._vector_store.update_payloads(previous)
+            except Exception:
+                logger.warning(
+                    "Could not restore the payloads of a failed batch's unchanged chunks",
+                    exc_info=True,
+                )
+
+    def _manifest_updates(
+        self,
+        discovered_files: list[DiscoveredFile],
+        chunks: list[CodeChunk],
+        blob_ids: Mapping[Path, str] | None = None,
+        generation: str | None = None,
+        content_hashes: Mapping[Path, str] | None = None,
+        raw_hashes: Mapping[Path, str] | None = None,
+        *,
+        record_text_hashes: bool = False,
+    ) -> list[dict[str, Any]]:
+        """Build manifest entries for indexed files.
+
+        Files' content hashes default to their discovered hashes. Chunks are recorded in the
+        order of their lines, with the hashes of their text when `record_text_hashes` is set.
+        """
         model_info = self._get_current_embedding_models()
         manifest_updates = []
 
@@ -492,24 +1371,29 @@ class IndexingService:
             if not rel_path:
                 continue
 
-            file_chunk_ids = [str(c.chunk_id) for c in updated_chunks if c.file_path == df.path]
+            # Chunk paths are relative to the project root
+            file_chunks = sorted(
+                (c for c in chunks if c.file_path in (df.path, rel_path)), key=_line_order
+            )
             manifest_updates.append({
                 "path": rel_path,
-                "content_hash": df.file_hash,
-                "chunk_ids": file_chunk_ids,
+                "content_hash": (content_hashes or {}).get(df.path, df.file_hash),
+                "chunk_ids": [str(c.chunk_id) for c in file_chunks],
+                "chunk_hashes": {str(c.chunk_id): str(c.embedding_hash) for c in file_chunks},
                 "dense_embedding_provider": model_info["dense_provider"],
-                "dense_model": model_info["dense_model"],
-                "sparse_provider": model_info["sparse_provider"],
-                "sparse_model": model_info["sparse_model"],
+                "dense_embedding_model": model_info["dense_model"],
+                "sparse_embedding_provider": model_info["sparse_provider"],
+                "sparse_embedding_model": model_info["sparse_model"],
                 "has_dense_embeddings": bool(self._embedding_provider),
                 "has_sparse_embeddings": bool(self._sparse_provider),
+                "blob_id": blob_ids.get(rel_path) if blob_ids else None,
+                "generation": generation,
+                "raw_content_hash": (raw_hashes or {}).get(df.path),
+                "chunk_text_hashes": {str(c.chunk_id): _text_hash(c) for c in file_chunks}
+                if record_text_hashes
+                else None,
             })
-
-        if manifest_updates:
-            if not self._file_manifest:
-                self._file_manifest = self._manifest_manager.create_new()
-            async with self._manifest_lock:
-                self._file_manifest.add_files_batch(manifest_updates)
+        return manifest_updates
 
     async def _embed_chunks(self, chunks: list[CodeChunk]) -> None:
         """Generate embeddings for chunks."""
@@ -554,6 +1438,9 @@ class IndexingService:
                 async with self._manifest_lock:
                     self._file_manifest.remove_file(rel_path)
 
+        if self._symbol_index:
+            await self._symbol_index.remove_files(rel_paths)
+
         self._deleted_files = []
 
     async def reconcile_from_backup(self, backup_store: VectorStoreProvider) -> None:
@@ -676,8 +1563,6 @@ class IndexingService:
         Converts Qdrant sparse vectors to SparseEmbedding format and registers
         them for reuse during reconciliation.
         """
-        from codeweaver.core import uuid7
-
         migration_batch_id = uuid7()
 
         for i, chunk in enumerate(chunks):
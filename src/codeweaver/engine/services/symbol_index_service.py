@@ -0,0 +1,305 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Symbol reference index service.
+
+Keeps a per-project index of symbol definitions, call sites, implementations and imports,
+extracted with `codeweaver.semantic.symbols` from the same ast-grep trees the semantic chunker
+parses. The index is updated per file as files are indexed or deleted, and persisted next to
+the file manifest so unchanged files don't need to be re-parsed on the next run.
+
+It answers structural questions -- who calls `X`, what does `X` call, what implements `Y` --
+without a vector search.
+
+ARCHITECTURE: Plain class with no DI in constructor (factory handles DI).
+"""
+
+from __future__ import annotations
+
+import logging
+
+from collections import defaultdict
+from collections.abc import Iterable, Sequence
+from datetime import UTC, datetime
+from pathlib import Path
+from typing import TYPE_CHECKING, Annotated, NamedTuple
+
+from anyio import Path as AsyncPath
+from pydantic import Field, PrivateAttr
+from pydantic_core import from_json
+
+from codeweaver.core import BasedModel, SemanticSearchLanguage, get_blake_hash
+from codeweaver.semantic.symbols import (
+    SYMBOL_LANGUAGES,
+    SymbolRefKind,
+    SymbolReference,
+    extract_symbol_references,
+    symbol_name,
+)
+
+
+if TYPE_CHECKING:
+    from ast_grep_py import SgNode
+
+    from codeweaver.core import AnonymityConversion, CodeChunk, DiscoveredFile, FilteredKeyT
+
+
+logger = logging.getLogger(__name__)
+
+
+class SymbolOccurrence(NamedTuple):
+    """A symbol reference and the file it occurs in."""
+
+    path: Path
+    """The file, relative to the project root."""
+    reference: SymbolReference
+
+
+class SymbolIndex(BasedModel):
+    """Symbol references of every indexed file in a project."""
+
+    project_path: Annotated[Path, Field(description="""Path to the indexed codebase""")]
+    last_updated: datetime = Field(
+        description="When the index was last updated", default_factory=lambda: datetime.now(UTC)
+    )
+    files: dict[str, tuple[SymbolReference, ...]] = Field(
+        default_factory=dict, description="Map of relative file paths to their symbol references"
+    )
+    index_version: Annotated[str, Field()] = "1.0.0"
+
+    _by_name: dict[str, list[SymbolOccurrence]] | None = PrivateAttr(default=None)
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        from codeweaver.core import AnonymityConversion, FilteredKey
+
+        return {
+            FilteredKey("project_path"): AnonymityConversion.HASH,
+            FilteredKey("files"): AnonymityConversion.COUNT,
+        }
+
+    def set_file(self, path: Path, references: Sequence[SymbolReference]) -> None:
+        """Replace a file's references."""
+        self.files[str(path)] = tuple(references)
+        self._by_name = None
+
+    def remove_file(self, path: Path) -> bool:
+        """Forget a file's references. Returns whether the file was indexed."""
+        removed = self.files.pop(str(path), None) is not None
+        if removed:
+            self._by_name = None
+        return removed
+
+    def _lookup(self) -> dict[str, list[SymbolOccurrence]]:
+        if self._by_name is None:
+            by_name: dict[str, list[SymbolOccurrence]] = defaultdict(list)
+            for path, references in self.files.items():
+                for reference in references:
+                    by_name[reference.name].append(SymbolOccurrence(Path(path), reference))
+            self._by_name = dict(by_name)
+        return self._by_name
+
+    def occurrences(
+        self, symbol: str, kind: SymbolRefKind | None = None
+    ) -> tuple[SymbolOccurrence, ...]:
+        """All occurrences of a symbol, optionally of one kind."""
+        return tuple(
+            occurrence
+            for occurrence in self._lookup().get(symbol_name(symbol), ())
+            if kind is None or occurrence.reference.kind == kind
+        )
+
+    def definitions(self, symbol: str) -> tuple[SymbolOccurrence, ...]:
+        """Where a symbol is defined."""
+        return self.occurrences(symbol, SymbolRefKind.DEFINITION)
+
+    def callers(self, symbol: str) -> tuple[SymbolOccurrence, ...]:
+        """Call sites of a symbol; each reference's `container` is the calling function."""
+        return self.occurrences(symbol, SymbolRefKind.CALL)
+
+    def implementors(self, symbol: str) -> tuple[SymbolOccurrence, ...]:
+        """Implementations of a trait, interface or base class; `target` is the implementor."""
+        return self.occurrences(symbol, SymbolRefKind.IMPL)
+
+    def importers(self, symbol: str) -> tuple[SymbolOccurrence, ...]:
+        """Files that import a symbol."""
+        return self.occurrences(symbol, SymbolRefKind.IMPORT)
+
+    def callees(self, symbol: str) -> tuple[SymbolOccurrence, ...]:
+        """Calls made from within any definition of a symbol."""
+        found: list[SymbolOccurrence] = []
+        for definition in self.definitions(symbol):
+            found.extend(
+                SymbolOccurrence(definition.path, reference)
+                for reference in self.files.get(str(definition.path), ())
+                if reference.kind == SymbolRefKind.CALL
+                and definition.reference.line <= reference.line <= definition.reference.end_line
+            )
+        return tuple(found)
+
+    def definitions_in(self, path: Path, start: int, end: int) -> tuple[SymbolReference, ...]:
+        """Definitions in a file that start within a line range."""
+        return tuple(
+            reference
+            for reference in self.files.get(str(path), ())
+            if reference.kind == SymbolRefKind.DEFINITION and start <= reference.line <= end
+        )
+
+    def related_symbols(
+        self, path: Path, start: int, end: int, *, limit: int = 12
+    ) -> tuple[str, ...]:
+        """Callers, callees and implementors of the symbols defined in a line range.
+
+        Callers are listed by the name of the calling function, implementors by the
+        implementing type, and callees by name, each only once.
+        """
+        related: dict[str, None] = {}
+        defined = self.definitions_in(path, start, end)
+        names = {reference.name for reference in defined}
+        for reference in defined:
+            for occurrence in self.callers(reference.name):
+                if (caller := occurrence.reference.container) and caller not in names:
+                    related.setdefault(caller)
+            for occurrence in self.implementors(reference.name):
+                if occurrence.reference.target:
+                    related.setdefault(occurrence.reference.target)
+        for reference in self.files.get(str(path), ()):
+            if (
+                reference.kind == SymbolRefKind.CALL
+                and start <= reference.line <= end
+                and reference.name not in names
+            ):
+                related.setdefault(reference.name)
+        return tuple(related)[:limit]
+
+
+def _language_for(path: Path) -> SemanticSearchLanguage | None:
+    language = SemanticSearchLanguage.from_extension(path.suffix or path.name)
+    return language if language in SYMBOL_LANGUAGES else None
+
+
+def _parsed_root(chunks: Iterable[CodeChunk]) -> SgNode | None:
+    """Reuse the syntax tree the semantic chunker already parsed, if it's still attached."""
+    for chunk in chunks:
+        semantic_meta = (chunk.metadata or {}).get("semantic_meta")
+        if (thing := getattr(semantic_meta, "thing", None)) is not None and (
+            node := getattr(thing, "_node", None)
+        ) is not None:
+            return node.get_root().root()
+    return None
+
+
+class SymbolIndexService:
+    """Maintains and persists the project's symbol reference index."""
+
+    def __init__(self, project_path: Path, project_name: str, index_dir: Path) -> None:
+        """Initialize the service.
+
+        Args:
+            project_path: Path to the indexed codebase
+            project_name: Name of the project (for the filename)
+            index_dir: Directory for symbol index files
+        """
+        self._project_path = Path(project_path).resolve()
+        self._index_dir = Path(index_dir).resolve()
+        path_hash = get_blake_hash(str(self._project_path).encode("utf-8"))[:16]
+        self.index_file = self._index_dir / f"symbols_{project_name}_{path_hash}.json"
+        self._index: SymbolIndex | None = None
+
+    @property
+    def project_path(self) -> Path:
+        """The project root that paths in the index are relative to."""
+        return self._project_path
+
+    async def get_index(self) -> SymbolIndex:
+        """Get the index, loading it from disk on first use."""
+        if self._index is None:
+            self._index = await self.load() or SymbolIndex(project_path=self._project_path)
+        return self._index
+
+    async def load(self) -> SymbolIndex | None:
+        """Load the index from disk if available."""
+        async_file = AsyncPath(self.index_file)
+        if not await async_file.exists():
+            return None
+        try:
+            return SymbolIndex.model_validate(from_json(await async_file.read_bytes()))
+        except (OSError, ValueError):
+            logger.warning("Failed to load symbol index from %s", self.index_file)
+            return None
+
+    async def save(self) -> bool:
+        """Save the index to disk."""
+        if self._index is None:
+            return False
+        self._index.last_updated = datetime.now(UTC)
+        await AsyncPath(self._index_dir).mkdir(parents=True, exist_ok=True)
+        try:
+            await AsyncPath(self.index_file).write_text(self._index.model_dump_json())
+        except OSError:
+            logger.warning("Failed to save symbol index", exc_info=True)
+            return False
+        else:
+            logger.debug("Saved symbol index to %s", self.index_file)
+            return True
+
+    def extract(
+        self, path: Path, chunks: Sequence[CodeChunk] = (), content: str | None = None
+    ) -> tuple[SymbolReference, ...] | None:
+        """Extract a file's symbol references.
+
+        Args:
+            path: Absolute path of the file
+            chunks: The file's chunks; their syntax tree is reused when available
+            content: The file's text, read from disk if needed
+
+        Returns:
+            The file's references, or None if its language isn't supported
+        """
+        if (language := _language_for(path)) is None:
+            return None
+        if (root := _parsed_root(chunks)) is None:
+            from ast_grep_py import SgRoot
+
+            if content is None:
+                content = path.read_text("utf-8", "ignore")
+            root = SgRoot(content, language.variable).root()
+        return extract_symbol_references(root, language)
+
+    async def update_files(
+        self, files: Sequence[DiscoveredFile], chunks: Sequence[CodeChunk]
+    ) -> None:
+        """Re-extract the references of freshly indexed files.
+
+        Args:
+            files: The indexed files
+            chunks: The files' chunks; their syntax trees are reused when still attached
+        """
+        index = await self.get_index()
+        chunks_by_file: dict[Path, list[CodeChunk]] = defaultdict(list)
+        for chunk in chunks:
+            if chunk.file_path is not None:
+                chunks_by_file[Path(chunk.file_path)].append(chunk)
+        for file in files:
+            try:
+                references = self.extract(file.absolute_path, chunks_by_file.get(file.path, ()))
+            except Exception as e:
+                logger.debug("Symbol extraction failed for %s: %s", file.path, e)
+                references = None
+            if references is None:
+                index.remove_file(file.path)
+            else:
+                index.set_file(file.path, references)
+
+    async def remove_files(self, rel_paths: Iterable[Path]) -> None:
+        """Forget the references of deleted files."""
+        index = await self.get_index()
+        for rel_path in rel_paths:
+            index.remove_file(rel_path)
+
+    async def clear(self) -> None:
+        """Start over with an empty index."""
+        self._index = SymbolIndex(project_path=self._project_path)
+
+
+__all__ = ("SymbolIndex", "SymbolIndexService", "SymbolOccurrence")
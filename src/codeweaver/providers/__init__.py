@@ -122,6 +122,7 @@ if TYPE_CHECKING:
         MemoryConfig,
         MemoryVectorStoreProviderSettings,
         QdrantVectorStoreProviderSettings,
+        SqliteVectorStoreProviderSettings,
         VectorStoreProviderSettings,
         VectorStoreProviderSettingsType,
     )
@@ -170,7 +171,11 @@ if TYPE_CHECKING:
         try_for_azure_endpoint,
         try_for_heroku_endpoint,
     )
-    from codeweaver.providers.config.clients.vector_store import GrpcParams, QdrantClientOptions
+    from codeweaver.providers.config.clients.vector_store import (
+        GrpcParams,
+        QdrantClientOptions,
+        SqliteClientOptions,
+    )
     from codeweaver.providers.config.profiles import (
         HAS_FASTEMBED,
         HAS_ST,
@@ -187,6 +192,7 @@ if TYPE_CHECKING:
         DefaultSparseEmbeddingProviderSettings,
         DefaultVectorStoreProviderSettings,
         DeterminedDefaults,
+        EmbeddingCacheSettings,
         ProviderCategorySettingsType,
         ProviderNameMap,
         ProviderSettings,
@@ -473,6 +479,11 @@ if TYPE_CHECKING:
         get_sparse_embedder,
         get_text_embedder,
     )
+    from codeweaver.providers.embedding.persistent_cache import (
+        EmbeddingCacheStats,
+        EmbeddingSpace,
+        PersistentEmbeddingCache,
+    )
     from codeweaver.providers.embedding.providers.base import (
         DEFAULT_MAX_BATCH_TOKENS,
         EmbeddingCustomDeps,
@@ -695,6 +706,8 @@ if TYPE_CHECKING:
         HybridVectorPayload,
         PayloadFieldDict,
         TransformationRecord,
+        path_prefixes,
+        payload_keyword,
     )
     from codeweaver.providers.types.vectors import VectorConfig, VectorRole, VectorSet
     from codeweaver.providers.vector_stores.base import MixedQueryInput, VectorStoreProvider
@@ -722,9 +735,11 @@ if TYPE_CHECKING:
     )
     from codeweaver.providers.vector_stores.search.filter_factory import (
         ArbitraryFilter,
+        SqliteFilter,
         make_filter,
         make_indexes,
         to_qdrant_filter,
+        to_sqlite_filter,
     )
     from codeweaver.providers.vector_stores.search.geo import (
         GeoBoundingBox,
@@ -754,6 +769,12 @@ if TYPE_CHECKING:
         make_partial_function,
         wrap_filters,
     )
+    from codeweaver.providers.vector_stores.sqlite import SqliteVectorStoreProvider
+    from codeweaver.providers.vector_stores.sqlite_client import (
+        SqliteCollectionInfo,
+        SqlitePoint,
+        SqliteVectorClient,
+    )
 
 _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "AGENT_PROVIDER_CLASSES": (__spec__.parent, "agent.providers"),
@@ -947,6 +968,8 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "DuckDuckGoSearchToolConfig": (__spec__.parent, "config.sdk.data"),
     "EmbeddingCacheManager": (__spec__.parent, "embedding.cache_manager"),
     "EmbeddingCacheManagerDep": (__spec__.parent, "dependencies.services"),
+    "EmbeddingCacheSettings": (__spec__.parent, "config.providers"),
+    "EmbeddingCacheStats": (__spec__.parent, "embedding.persistent_cache"),
     "EmbeddingCapabilitiesDict": (__spec__.parent, "embedding.capabilities.types"),
     "EmbeddingCapabilityGroup": (__spec__.parent, "types.embedding"),
     "EmbeddingCapabilityGroupDep": (__spec__.parent, "dependencies.capabilities"),
@@ -966,6 +989,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "EmbeddingProviderSettingsType": (__spec__.parent, "config.categories.embedding"),
     "EmbeddingRegistry": (__spec__.parent, "embedding.registry"),
     "EmbeddingRegistryDep": (__spec__.parent, "dependencies.services"),
+    "EmbeddingSpace": (__spec__.parent, "embedding.persistent_cache"),
     "Entry": (__spec__.parent, "vector_stores.search.payload"),
     "EnvVarConfig": (__spec__.parent, "env_registry.models"),
     "ExaAnswerResult": (__spec__.parent, "data.exa"),
@@ -1082,6 +1106,8 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "OptimizationDecisions": (__spec__.parent, "optimize"),
     "PartialCapabilities": (__spec__.parent, "embedding.capabilities.types"),
     "PartialRerankingCapabilitiesDict": (__spec__.parent, "reranking.capabilities.types"),
+    "path_prefixes": (__spec__.parent, "types.vector_store"),
+    "payload_keyword": (__spec__.parent, "types.vector_store"),
     "PayloadField": (__spec__.parent, "vector_stores.search.payload"),
     "PayloadFieldDict": (__spec__.parent, "types.vector_store"),
     "PayloadMetadata": (__spec__.parent, "vector_stores.search.payload"),
@@ -1089,6 +1115,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "LiteralProvider": ("codeweaver.core", "types.provider"),
     "LiteralProviderCategory": ("codeweaver.core", "types.provider"),
     "LiteralSDKClient": ("codeweaver.core", "types.provider"),
+    "PersistentEmbeddingCache": (__spec__.parent, "embedding.persistent_cache"),
     "PoolLimits": (__spec__.parent, "http_pool"),
     "PoolTimeouts": (__spec__.parent, "http_pool"),
     "PrimaryEmbeddingProviderDep": (__spec__.parent, "dependencies.providers"),
@@ -1189,6 +1216,13 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "SparseEmbeddingProviderSettings": (__spec__.parent, "config.categories.sparse_embedding"),
     "SparseEmbeddingProviderSettingsDep": (__spec__.parent, "dependencies.config"),
     "SparseEmbeddingProviderSettingsType": (__spec__.parent, "config.categories.sparse_embedding"),
+    "SqliteClientOptions": (__spec__.parent, "config.clients.vector_store"),
+    "SqliteCollectionInfo": (__spec__.parent, "vector_stores.sqlite_client"),
+    "SqliteFilter": (__spec__.parent, "vector_stores.search.filter_factory"),
+    "SqlitePoint": (__spec__.parent, "vector_stores.sqlite_client"),
+    "SqliteVectorClient": (__spec__.parent, "vector_stores.sqlite_client"),
+    "SqliteVectorStoreProvider": (__spec__.parent, "vector_stores.sqlite"),
+    "SqliteVectorStoreProviderSettings": (__spec__.parent, "config.categories.vector_store"),
     "TavilyClientOptions": (__spec__.parent, "config.clients.data"),
     "TavilyProviderSettings": (__spec__.parent, "config.categories.data"),
     "TavilyResults": (__spec__.parent, "data.tavily"),
@@ -1199,6 +1233,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ThenlperProvider": (__spec__.parent, "embedding.capabilities.thenlper"),
     "TitanEmbeddingV2RequestBody": (__spec__.parent, "embedding.providers.bedrock"),
     "TitanEmbeddingV2Response": (__spec__.parent, "embedding.providers.bedrock"),
+    "to_sqlite_filter": (__spec__.parent, "vector_stores.search.filter_factory"),
     "TokenizerDep": (__spec__.parent, "dependencies.capabilities"),
     "TransformationRecord": (__spec__.parent, "types.vector_store"),
     "ValuesCount": (__spec__.parent, "vector_stores.search.condition"),
@@ -1558,6 +1593,8 @@ __all__ = (
     "DuckDuckGoSearchToolConfig",
     "EmbeddingCacheManager",
     "EmbeddingCacheManagerDep",
+    "EmbeddingCacheSettings",
+    "EmbeddingCacheStats",
     "EmbeddingCapabilitiesDict",
     "EmbeddingCapabilityGroup",
     "EmbeddingCapabilityGroupDep",
@@ -1577,6 +1614,7 @@ __all__ = (
     "EmbeddingProvidersDep",
     "EmbeddingRegistry",
     "EmbeddingRegistryDep",
+    "EmbeddingSpace",
     "Entry",
     "EnvVarConfig",
     "ExaAnswerResult",
@@ -1701,6 +1739,7 @@ __all__ = (
     "PayloadFieldDict",
     "PayloadMetadata",
     "PayloadSchemaType",
+    "PersistentEmbeddingCache",
     "PoolLimits",
     "PoolTimeouts",
     "PrimaryEmbeddingProviderDep",
@@ -1777,6 +1816,13 @@ __all__ = (
     "SparseEmbeddingProviderSettingsDep",
     "SparseEmbeddingProviderSettingsType",
     "SparseEmbeddingProvidersDep",
+    "SqliteClientOptions",
+    "SqliteCollectionInfo",
+    "SqliteFilter",
+    "SqlitePoint",
+    "SqliteVectorClient",
+    "SqliteVectorStoreProvider",
+    "SqliteVectorStoreProviderSettings",
     "TavilyClientOptions",
     "TavilyProviderSettings",
     "TavilyResults",
@@ -1908,6 +1954,8 @@ __all__ = (
     "merge_agent_model_settings",
     "multi_client_provider",
     "openai_compatible_provider",
+    "path_prefixes",
+    "payload_keyword",
     "preprocess_for_qwen",
     "process_for_instruction_model",
     "provider_env_config_to_vars",
@@ -1921,6 +1969,7 @@ __all__ = (
     "simple_provider_discriminator",
     "tavily_search_tool",
     "to_qdrant_filter",
+    "to_sqlite_filter",
     "try_for_azure_endpoint",
     "try_for_heroku_endpoint",
     "voyage_context_output_transformer",
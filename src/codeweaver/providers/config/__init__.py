@@ -136,6 +136,7 @@ if TYPE_CHECKING:
         MemoryConfig,
         MemoryVectorStoreProviderSettings,
         QdrantVectorStoreProviderSettings,
+        SqliteVectorStoreProviderSettings,
         VectorStoreProviderSettings,
         VectorStoreProviderSettingsType,
     )
@@ -184,7 +185,11 @@ if TYPE_CHECKING:
         try_for_azure_endpoint,
         try_for_heroku_endpoint,
     )
-    from codeweaver.providers.config.clients.vector_store import GrpcParams, QdrantClientOptions
+    from codeweaver.providers.config.clients.vector_store import (
+        GrpcParams,
+        QdrantClientOptions,
+        SqliteClientOptions,
+    )
     from codeweaver.providers.config.profiles import (
         HAS_FASTEMBED,
         HAS_ST,
@@ -201,6 +206,7 @@ if TYPE_CHECKING:
         DefaultSparseEmbeddingProviderSettings,
         DefaultVectorStoreProviderSettings,
         DeterminedDefaults,
+        EmbeddingCacheSettings,
         ProviderCategorySettingsType,
         ProviderNameMap,
         ProviderSettings,
@@ -380,6 +386,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "DuckDuckGoClientOptions": (__spec__.parent, "clients.data"),
     "DuckDuckGoProviderSettings": (__spec__.parent, "categories.data"),
     "DuckDuckGoSearchToolConfig": (__spec__.parent, "sdk.data"),
+    "EmbeddingCacheSettings": (__spec__.parent, "providers"),
     "EmbeddingConfigT": (__spec__.parent, "sdk.embedding"),
     "EmbeddingMixin": (__spec__.parent, "sdk.embedding"),
     "EmbeddingProviderSettings": (__spec__.parent, "categories.embedding"),
@@ -458,6 +465,8 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "SparseEmbeddingConfigT": (__spec__.parent, "sdk.sparse_embedding"),
     "SparseEmbeddingProviderSettings": (__spec__.parent, "categories.sparse_embedding"),
     "SparseEmbeddingProviderSettingsType": (__spec__.parent, "categories.sparse_embedding"),
+    "SqliteClientOptions": (__spec__.parent, "clients.vector_store"),
+    "SqliteVectorStoreProviderSettings": (__spec__.parent, "categories.vector_store"),
     "TavilyClientOptions": (__spec__.parent, "clients.data"),
     "TavilyProviderSettings": (__spec__.parent, "categories.data"),
     "TavilySearchContextToolConfig": (__spec__.parent, "sdk.data"),
@@ -583,6 +592,7 @@ __all__ = (
     "DuckDuckGoClientOptions",
     "DuckDuckGoProviderSettings",
     "DuckDuckGoSearchToolConfig",
+    "EmbeddingCacheSettings",
     "EmbeddingConfigT",
     "EmbeddingMixin",
     "EmbeddingProviderSettings",
@@ -666,6 +676,8 @@ __all__ = (
     "SparseEmbeddingConfigT",
     "SparseEmbeddingProviderSettings",
     "SparseEmbeddingProviderSettingsType",
+    "SqliteClientOptions",
+    "SqliteVectorStoreProviderSettings",
     "TavilyClientOptions",
     "TavilyProviderSettings",
     "TavilySearchContextToolConfig",
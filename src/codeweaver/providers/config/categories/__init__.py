@@ -112,6 +112,7 @@ if TYPE_CHECKING:
         MemoryConfig,
         MemoryVectorStoreProviderSettings,
         QdrantVectorStoreProviderSettings,
+        SqliteVectorStoreProviderSettings,
         VectorStoreProviderSettings,
         VectorStoreProviderSettingsType,
     )
@@ -179,6 +180,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "SentenceTransformersRerankingProviderSettings": (__spec__.parent, "reranking"),
     "SparseEmbeddingProviderSettings": (__spec__.parent, "sparse_embedding"),
     "SparseEmbeddingProviderSettingsType": (__spec__.parent, "sparse_embedding"),
+    "SqliteVectorStoreProviderSettings": (__spec__.parent, "vector_store"),
     "TavilyProviderSettings": (__spec__.parent, "data"),
     "VectorStoreProviderSettings": (__spec__.parent, "vector_store"),
     "VectorStoreProviderSettingsType": (__spec__.parent, "vector_store"),
@@ -254,6 +256,7 @@ __all__ = (
     "SentenceTransformersRerankingProviderSettings",
     "SparseEmbeddingProviderSettings",
     "SparseEmbeddingProviderSettingsType",
+    "SqliteVectorStoreProviderSettings",
     "TavilyProviderSettings",
     "VectorStoreProviderSettings",
     "VectorStoreProviderSettingsType",
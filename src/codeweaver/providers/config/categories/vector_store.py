@@ -29,7 +29,10 @@ from codeweaver.providers.config.categories.base import (
 )
 from codeweaver.providers.config.categories.utils import PROVIDER_DISCRIMINATOR
 from codeweaver.providers.config.clients.base import ClientOptions
-from codeweaver.providers.config.clients.vector_store import QdrantClientOptions
+from codeweaver.providers.config.clients.vector_store import (
+    QdrantClientOptions,
+    SqliteClientOptions,
+)
 from codeweaver.providers.config.sdk.vector_store import CollectionConfig, get_embedding_group
 from codeweaver.providers.types.embedding import EmbeddingCapabilityGroup
 from codeweaver.providers.types.vector_store import CollectionMetadata
@@ -44,7 +47,7 @@ if has_package("codeweaver.engine"):
 class BaseVectorStoreProviderSettings(BaseProviderCategorySettings):
     """Base settings for vector store providers."""
 
-    provider: Literal[Provider.QDRANT, Provider.MEMORY]
+    provider: Literal[Provider.QDRANT, Provider.MEMORY, Provider.SQLITE]
 
     batch_size: Annotated[
         PositiveInt | None,
@@ -386,9 +389,96 @@ class MemoryVectorStoreProviderSettings(_BaseQdrantVectorStoreProviderSettings):
         )
 
 
+class SqliteVectorStoreProviderSettings(VectorStoreProviderSettings):
+    """Settings for the embedded SQLite vector store provider.
+
+    The store is a single database file under your user state directory (one per collection
+    unless you set `client_options.path`), so there is no server to install or run.
+    """
+
+    provider: Literal[Provider.SQLITE]
+    client_options: Annotated[
+        SqliteClientOptions | None, Field(description="Client options for the provider's client.")
+    ] = None
+    collection: Annotated[
+        CollectionConfig, Field(description="Collection configuration for the vector store.")
+    ]
+
+    def __init__(
+        self,
+        provider: Literal[Provider.SQLITE] = Provider.SQLITE,
+        connection: ConnectionConfiguration | None = None,
+        client_options: SqliteClientOptions | None = None,
+        collection: CollectionConfig | None = None,
+        batch_size: PositiveInt | None = DEFAULT_VECTOR_STORE_BATCH_SIZE,
+        *,
+        project_name: str | None = None,
+        project_path: Path | None = None,
+    ) -> None:
+        """Initialize SQLite vector store provider settings.
+
+        Args:
+            provider: The vector store provider (always SQLite).
+            connection: Connection configuration (unused; the store is embedded).
+            client_options: Client options for the provider's client.
+            collection: Collection configuration for the vector store.
+            batch_size: Batch size for bulk upsert operations.
+            project_name: The name of the project.
+            project_path: The path to the project.
+        """
+        if collection is None:
+            collection_data = {}
+        elif isinstance(collection, CollectionConfig):
+            collection_data = collection.model_dump(exclude_none=True)
+        else:
+            collection_data = collection
+        prepared_collection = CollectionConfig.model_validate(
+            {
+                "collection_name": generate_collection_name(
+                    project_name=project_name, project_path=project_path
+                )
+            }
+            | collection_data
+        )
+        prepared_client_options = client_options or SqliteClientOptions()
+        if prepared_client_options.path is None:
+            prepared_client_options = prepared_client_options.model_copy(
+                update={
+                    "path": str(self._default_path(prepared_collection.collection_name or "default"))
+                }
+            )
+        super().__init__(
+            provider=provider,
+            connection=connection,
+            client_options=prepared_client_options,
+            collection=prepared_collection,  # ty: ignore[unknown-argument]
+            batch_size=batch_size,
+        )
+
+    @staticmethod
+    def _default_path(collection_name: str) -> Path:
+        """Return the default database file for a collection."""
+        return (
+            Path(get_user_state_dir())
+            / DEFAULT_VECTOR_STORE_PERSIST_SUBPATH
+            / f"{collection_name}.sqlite3"
+        )
+
+    def is_cloud(self) -> bool:
+        """Return False; the SQLite store is always local."""
+        return False
+
+    @computed_field
+    @property
+    def client(self) -> Literal[SDKClient.SQLITE]:
+        """Return the SQLite SDKClient enum member."""
+        return SDKClient.SQLITE
+
+
 type VectorStoreProviderSettingsType = Annotated[
     Annotated[QdrantVectorStoreProviderSettings, Tag("qdrant")]
-    | Annotated[MemoryVectorStoreProviderSettings, Tag("memory")],
+    | Annotated[MemoryVectorStoreProviderSettings, Tag("memory")]
+    | Annotated[SqliteVectorStoreProviderSettings, Tag("sqlite")],
     Field(
         description="The settings for a vector store provider, which includes the provider type and its specific configuration.",
         discriminator=PROVIDER_DISCRIMINATOR,
@@ -400,6 +490,7 @@ __all__ = (
     "MemoryConfig",
     "MemoryVectorStoreProviderSettings",
     "QdrantVectorStoreProviderSettings",
+    "SqliteVectorStoreProviderSettings",
     "VectorStoreProviderSettings",
     "VectorStoreProviderSettingsType",
 )
@@ -63,7 +63,11 @@ if TYPE_CHECKING:
         try_for_azure_endpoint,
         try_for_heroku_endpoint,
     )
-    from codeweaver.providers.config.clients.vector_store import GrpcParams, QdrantClientOptions
+    from codeweaver.providers.config.clients.vector_store import (
+        GrpcParams,
+        QdrantClientOptions,
+        SqliteClientOptions,
+    )
 
 _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ANTHROPIC_CLIENT_OPTIONS_AGENT_DISCRIMINATOR": (__spec__.parent, "utils"),
@@ -93,6 +97,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "SentenceTransformersClientOptions": (__spec__.parent, "multi"),
     "SentenceTransformersModelOptions": (__spec__.parent, "multi"),
     "SimpleAgentClientOptionsType": (__spec__.parent, "agent"),
+    "SqliteClientOptions": (__spec__.parent, "vector_store"),
     "TavilyClientOptions": (__spec__.parent, "data"),
     "VoyageClientOptions": (__spec__.parent, "multi"),
     "discriminate_anthropic_agent_client_options": (__spec__.parent, "agent"),
@@ -139,6 +144,7 @@ __all__ = (
     "SentenceTransformersClientOptions",
     "SentenceTransformersModelOptions",
     "SimpleAgentClientOptionsType",
+    "SqliteClientOptions",
     "TavilyClientOptions",
     "VoyageClientOptions",
     "XAIClientOptions",
@@ -21,7 +21,12 @@ from pydantic import (
     model_validator,
 )
 
-from codeweaver.core.constants import LOCALHOST_INDICATORS, LOCALHOST_URL, QDRANT_MEMORY_LOCATION
+from codeweaver.core.constants import (
+    DEFAULT_SQLITE_EXACT_SEARCH_POINTS,
+    LOCALHOST_INDICATORS,
+    LOCALHOST_URL,
+    QDRANT_MEMORY_LOCATION,
+)
 from codeweaver.core.exceptions import ConfigurationError
 from codeweaver.core.types import AnonymityConversion, FilteredKey, FilteredKeyT
 from codeweaver.core.types.provider import Provider
@@ -303,4 +308,32 @@ class QdrantClientOptions(ClientOptions):
         return self
 
 
-__all__ = ("GrpcParams", "QdrantClientOptions")
+class SqliteClientOptions(ClientOptions):
+    """Client options for the embedded SQLite vector store provider.
+
+    The whole store is one database file; nothing runs besides CodeWeaver itself. Dense search
+    scans every vector of collections up to `exact_search_points` chunks, and goes through an
+    approximate IVF index past that.
+    """
+
+    _core_provider: Provider = Provider.SQLITE
+    _providers: tuple[Provider, ...] = (Provider.SQLITE,)
+
+    path: str | None = Field(
+        default=None,
+        description="Path to the database file. `:memory:` (or None, unless the provider settings fill it in) keeps the store in memory.",
+    )
+    timeout: PositiveFloat = Field(
+        default=30.0,
+        description="Seconds to wait for another process to release its write lock on the database.",
+    )
+    exact_search_points: PositiveInt = Field(
+        default=DEFAULT_SQLITE_EXACT_SEARCH_POINTS,
+        description=f"Collections with up to this many points (chunks) score every dense vector on each query, which is exact. Past it, a collection gets an approximate IVF index that scans only the vectors nearest the query, and is retrained each time the collection doubles. The default ({DEFAULT_SQLITE_EXACT_SEARCH_POINTS:,}) keeps an exact scan to about 80 MB of 1,024-dimension vectors, read in batches.",
+    )
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        return {FilteredKey("path"): AnonymityConversion.HASH}
+
+
+__all__ = ("GrpcParams", "QdrantClientOptions", "SqliteClientOptions")
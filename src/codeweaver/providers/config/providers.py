@@ -18,7 +18,9 @@ from __future__ import annotations
 import logging
 import os
 
+from pathlib import Path
 from typing import (
+    TYPE_CHECKING,
     Annotated,
     Any,
     ClassVar,
@@ -30,7 +32,7 @@ from typing import (
     is_typeddict,
 )
 
-from pydantic import Field, SecretStr, computed_field, model_validator
+from pydantic import Field, PositiveInt, SecretStr, computed_field, model_validator
 from pydantic_ai.settings import ModelSettings as AgentModelSettings
 from pydantic_ai.settings import merge_model_settings
 
@@ -57,7 +59,7 @@ from codeweaver.core.types import (
     ProviderCategory,
     ProviderCategoryLiteralString,
 )
-from codeweaver.core.utils import has_package
+from codeweaver.core.utils import get_user_cache_dir, has_package
 from codeweaver.providers import AnthropicAgentProviderSettings, VoyageClientOptions
 from codeweaver.providers.config.categories import (
     AgentProviderSettingsType,
@@ -100,6 +102,10 @@ from codeweaver.providers.config.sdk import (
 )
 
 
+if TYPE_CHECKING:
+    from codeweaver.core.types import AnonymityConversion, FilteredKeyT
+
+
 logger = logging.getLogger(__name__)
 
 
@@ -474,6 +480,45 @@ class ProviderNameMap(TypedDict):
     agent: Provider | tuple[Provider, ...] | None
 
 
+class EmbeddingCacheSettings(BasedModel):
+    """Settings for the persistent on-disk embedding cache.
+
+    The cache keeps document embeddings between runs, keyed by chunk content and the model that
+    produced them, so re-indexing unchanged code doesn't call the embedding provider again.
+    """
+
+    enabled: Annotated[
+        bool,
+        Field(
+            description="""Whether to keep document embeddings in the persistent cache. Disabled by default. Enable with `CODEWEAVER_EMBEDDING_CACHE=true`."""
+        ),
+    ] = os.environ.get("CODEWEAVER_EMBEDDING_CACHE", "false").lower() in ENV_EXPLICIT_TRUE_VALUES
+
+    path: Annotated[
+        Path | None,
+        Field(
+            description="""Location of the cache database. Defaults to `embeddings.sqlite` in your user cache directory. The cache is safe to share between projects and concurrent CodeWeaver processes."""
+        ),
+    ] = None
+
+    max_size_mb: Annotated[
+        PositiveInt,
+        Field(
+            description="""Maximum size of the cached vectors in megabytes. When the cache grows past this, the least recently used embeddings are evicted."""
+        ),
+    ] = 1024
+
+    @property
+    def cache_path(self) -> Path:
+        """Effective location of the cache database."""
+        return self.path or get_user_cache_dir() / "embeddings.sqlite"
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        from codeweaver.core.types import AnonymityConversion, FilteredKey
+
+        return {FilteredKey("path"): AnonymityConversion.BOOLEAN}
+
+
 class ProviderSettings(BasedModel):
     """Settings for provider configuration."""
 
@@ -587,6 +632,14 @@ class ProviderSettings(BasedModel):
         in ENV_EXPLICIT_TRUE_VALUES
     )
 
+    embedding_cache: Annotated[
+        EmbeddingCacheSettings,
+        Field(
+            default_factory=EmbeddingCacheSettings,
+            description="""Persistent embedding cache configuration. Manage the cache with `cw cache`.""",
+        ),
+    ]
+
     _PROVIDER_CATEGORY_FIELDS: ClassVar[frozenset[str]] = frozenset({
         "embedding",
         "sparse_embedding",
@@ -932,6 +985,7 @@ __all__ = (
     "DefaultSparseEmbeddingProviderSettings",
     "DefaultVectorStoreProviderSettings",
     "DeterminedDefaults",
+    "EmbeddingCacheSettings",
     "ProviderCategorySettingsType",
     "ProviderNameMap",
     "ProviderSettings",
@@ -5,6 +5,8 @@
 
 from __future__ import annotations
 
+import logging
+
 from typing import Annotated
 
 from codeweaver.core.dependencies.utils import ensure_container_initialized
@@ -12,12 +14,17 @@ from codeweaver.core.dependencies.utils import ensure_container_initialized
 
 ensure_container_initialized()
 
+from codeweaver.core.di import INJECTED
 from codeweaver.core.di.dependency import depends
 from codeweaver.core.di.utils import dependency_provider
+from codeweaver.providers.dependencies.config import ProviderSettingsDep
 from codeweaver.providers.embedding.cache_manager import EmbeddingCacheManager
+from codeweaver.providers.embedding.persistent_cache import PersistentEmbeddingCache
 from codeweaver.providers.embedding.registry import EmbeddingRegistry, get_embedding_registry
 
 
+logger = logging.getLogger(__name__)
+
 # NOTE: EmbeddingRegistry is already registered by registry.py via @dependency_provider.
 # Do NOT add a second registration here — it would create a fresh EmbeddingRegistry()
 # (separate from the global _main_registry), causing the cache_manager to write embeddings
@@ -28,12 +35,22 @@ type EmbeddingRegistryDep = Annotated[EmbeddingRegistry, depends(get_embedding_r
 
 
 @dependency_provider(EmbeddingCacheManager, scope="singleton")
-def _get_embedding_cache_manager() -> EmbeddingCacheManager:
+def _get_embedding_cache_manager(
+    settings: ProviderSettingsDep = INJECTED,
+) -> EmbeddingCacheManager:
     """Factory for creating an EmbeddingCacheManager instance."""
     # Use get_embedding_registry() (global singleton) so the cache_manager's registry
     # is the same instance that the DI container resolves for EmbeddingRegistry.
     registry = get_embedding_registry()
-    return EmbeddingCacheManager(registry=registry)
+    persistent_cache = None
+    if (cache_settings := settings.embedding_cache).enabled:
+        try:
+            persistent_cache = PersistentEmbeddingCache.from_settings(cache_settings)
+        except Exception as e:
+            logger.warning(
+                "Persistent embedding cache unavailable at %s: %s", cache_settings.cache_path, e
+            )
+    return EmbeddingCacheManager(registry=registry, persistent_cache=persistent_cache)
 
 
 type EmbeddingCacheManagerDep = Annotated[
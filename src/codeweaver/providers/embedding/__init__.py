@@ -136,6 +136,11 @@ if TYPE_CHECKING:
         get_sparse_embedder,
         get_text_embedder,
     )
+    from codeweaver.providers.embedding.persistent_cache import (
+        EmbeddingCacheStats,
+        EmbeddingSpace,
+        PersistentEmbeddingCache,
+    )
     from codeweaver.providers.embedding.providers.base import (
         DEFAULT_MAX_BATCH_TOKENS,
         EmbeddingCustomDeps,
@@ -219,6 +224,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "CohereEmbeddingResponse": (__spec__.parent, "providers.bedrock"),
     "CohereRequestHandler": (__spec__.parent, "providers.bedrock"),
     "EmbeddingCacheManager": (__spec__.parent, "cache_manager"),
+    "EmbeddingCacheStats": (__spec__.parent, "persistent_cache"),
     "EmbeddingCapabilitiesDict": (__spec__.parent, "capabilities.types"),
     "EmbeddingCapabilityResolver": (__spec__.parent, "capabilities.resolver"),
     "EmbeddingCustomDeps": (__spec__.parent, "providers.base"),
@@ -227,6 +233,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "EmbeddingModelCapabilities": (__spec__.parent, "capabilities.base"),
     "EmbeddingProvider": (__spec__.parent, "providers.base"),
     "EmbeddingRegistry": (__spec__.parent, "registry"),
+    "EmbeddingSpace": (__spec__.parent, "persistent_cache"),
     "FastEmbedEmbeddingProvider": (__spec__.parent, "providers.fastembed"),
     "FastEmbedSparseProvider": (__spec__.parent, "providers.fastembed"),
     "GoogleEmbeddingCapabilities": (__spec__.parent, "capabilities.google"),
@@ -252,6 +259,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "NomicAiProvider": (__spec__.parent, "capabilities.nomic_ai"),
     "OpenaiEmbeddingCapabilities": (__spec__.parent, "capabilities.openai"),
     "PartialCapabilities": (__spec__.parent, "capabilities.types"),
+    "PersistentEmbeddingCache": (__spec__.parent, "persistent_cache"),
     "QwenEmbeddingCapabilities": (__spec__.parent, "capabilities.qwen"),
     "QwenProvider": (__spec__.parent, "capabilities.qwen"),
     "SentenceTransformersEmbeddingCapabilities": (
@@ -346,6 +354,7 @@ __all__ = (
     "CohereEmbeddingResponse",
     "CohereRequestHandler",
     "EmbeddingCacheManager",
+    "EmbeddingCacheStats",
     "EmbeddingCapabilitiesDict",
     "EmbeddingCapabilityResolver",
     "EmbeddingCustomDeps",
@@ -354,6 +363,7 @@ __all__ = (
     "EmbeddingModelCapabilities",
     "EmbeddingProvider",
     "EmbeddingRegistry",
+    "EmbeddingSpace",
     "FastEmbedEmbeddingProvider",
     "FastEmbedSparseProvider",
     "GoogleEmbeddingCapabilities",
@@ -381,6 +391,7 @@ __all__ = (
     "OpenAIEmbeddingBase",
     "OpenaiEmbeddingCapabilities",
     "PartialCapabilities",
+    "PersistentEmbeddingCache",
     "QwenEmbeddingCapabilities",
     "QwenProvider",
     "SentenceTransformersEmbeddingCapabilities",
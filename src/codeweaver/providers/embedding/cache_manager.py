@@ -13,6 +13,7 @@ Architecture:
 - Async-safe locking: Uses asyncio.Lock instead of threading.Lock
 - Centralized storage: Replaces ClassVar registries with singleton pattern
 - DI integration: Singleton via FastAPI lifespan and dependency injection
+- Optional persistence: a `PersistentEmbeddingCache` keeps vectors across processes and runs
 
 Example:
     >>> cache_manager = EmbeddingCacheManager(registry=get_embedding_registry())
@@ -23,7 +24,10 @@ Example:
 from __future__ import annotations
 
 import asyncio
+import logging
+import sqlite3
 
+from collections.abc import Mapping, Sequence
 from typing import TYPE_CHECKING, ClassVar
 from uuid import UUID
 
@@ -34,6 +38,11 @@ from codeweaver.core.constants import ONE_MEGABYTE
 from codeweaver.core.stores import BlakeStore, UUIDStore, make_blake_store, make_uuid_store
 from codeweaver.core.types import BasedModel
 from codeweaver.core.utils import get_blake_hash
+from codeweaver.providers.embedding.persistent_cache import (
+    CachedEmbedding,
+    EmbeddingSpace,
+    PersistentEmbeddingCache,
+)
 from codeweaver.providers.embedding.registry import EmbeddingRegistry
 
 
@@ -41,6 +50,9 @@ if TYPE_CHECKING:
     from codeweaver.core.types import AnonymityConversion, EmbeddingBatchInfo, FilteredKeyT
 
 
+logger = logging.getLogger(__name__)
+
+
 class EmbeddingCacheManager(BasedModel):
     """Centralized cache manager with namespace isolation for embedding providers.
 
@@ -56,10 +68,11 @@ class EmbeddingCacheManager(BasedModel):
 
     Attributes:
         registry: Global embedding registry for cross-provider coordination
+        persistent_cache: Optional on-disk cache that outlives the process
         _batch_stores: Namespace-isolated UUID stores for batches
         _hash_stores: Namespace-isolated Blake hash stores for deduplication
         _locks: Async locks per namespace for thread-safe operations
-        _stats: Statistics per namespace (hits, misses, unique chunks)
+        _stats: Statistics per namespace (hits, misses, unique chunks, persistent cache hits)
     """
 
     model_config: ClassVar[ConfigDict] = ConfigDict(
@@ -70,6 +83,9 @@ class EmbeddingCacheManager(BasedModel):
     )
 
     registry: EmbeddingRegistry = Field(..., description="Global embedding registry instance")
+    persistent_cache: PersistentEmbeddingCache | None = Field(
+        None, description="On-disk embedding cache shared across runs, if enabled"
+    )
 
     # Namespace-isolated stores (namespace = "{provider_id}.{embedding_kind}")
     # Use PrivateAttr for internal state that shouldn't be part of the model
@@ -146,7 +162,13 @@ class EmbeddingCacheManager(BasedModel):
     def _init_stats(self, namespace: str) -> None:
         """Initialize statistics for namespace if not exists."""
         if namespace not in self._stats:
-            self._stats[namespace] = {"hits": 0, "misses": 0, "unique_chunks": 0, "total_chunks": 0}
+            self._stats[namespace] = {
+                "hits": 0,
+                "misses": 0,
+                "unique_chunks": 0,
+                "total_chunks": 0,
+                "persistent_hits": 0,
+            }
 
     async def deduplicate(
         self, chunks: list[CodeChunk], namespace: str, batch_id: UUID7
@@ -252,6 +274,49 @@ class EmbeddingCacheManager(BasedModel):
             # Create new ChunkEmbeddings with the chunk, then add the embedding
             self.registry[chunk_id] = ChunkEmbeddings(chunk=chunk).add(embedding_info)
 
+    async def get_persisted(
+        self, space: EmbeddingSpace, hashes: Sequence[str], namespace: str
+    ) -> dict[str, CachedEmbedding]:
+        """Look up previously computed embeddings in the persistent cache.
+
+        Cache failures are logged and treated as misses; the cache is an optimization and must
+        never fail an embedding request.
+
+        Args:
+            space: Embedding space (provider, model, dimension, dtype) of the requesting provider
+            hashes: Blake hashes of the chunks' `serialize_for_embedding()` output
+            namespace: Namespace key, used for statistics
+
+        Returns:
+            Cached embeddings by content hash
+        """
+        if self.persistent_cache is None or not hashes:
+            return {}
+        try:
+            found = await asyncio.to_thread(self.persistent_cache.get, space, hashes)
+        except (sqlite3.Error, OSError, ValueError) as e:
+            logger.warning("Could not read the persistent embedding cache: %s", e)
+            return {}
+        self._init_stats(namespace)
+        self._stats[namespace]["persistent_hits"] += len(found)
+        return found
+
+    async def persist(
+        self, space: EmbeddingSpace, embeddings: Mapping[str, CachedEmbedding]
+    ) -> None:
+        """Write newly computed embeddings to the persistent cache, if one is configured.
+
+        Args:
+            space: Embedding space the vectors belong to
+            embeddings: Embeddings keyed by the Blake hash of their serialized chunk
+        """
+        if self.persistent_cache is None or not embeddings:
+            return
+        try:
+            await asyncio.to_thread(self.persistent_cache.put, space, embeddings)
+        except (sqlite3.Error, OSError, ValueError) as e:
+            logger.warning("Could not write to the persistent embedding cache: %s", e)
+
     def get_batch(self, batch_id: UUID7, namespace: str) -> list[CodeChunk] | None:
         """Get batch by ID from namespace-isolated store.
 
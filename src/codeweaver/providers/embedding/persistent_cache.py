@@ -0,0 +1,374 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Persistent on-disk cache of document embeddings.
+
+The in-memory stores in `EmbeddingCacheManager` only deduplicate within one process. This cache
+outlives the process, so re-indexing a branch (or a fresh clone of one) that was already indexed
+with the same model costs almost no embedding API calls.
+
+Entries are keyed by the blake3 hash of a chunk's `serialize_for_embedding()` output and by the
+*embedding space* that produced the vector -- provider, model, dimension and datatype -- so
+switching models never returns a vector from the wrong space.
+
+The cache is a single SQLite database in WAL mode. Every operation opens its own short-lived
+connection; lookups only read, and writes happen in `BEGIN IMMEDIATE` transactions, so several
+CodeWeaver processes (a watcher, a CLI re-index, a second project) can share the same file without
+lookups queuing behind each other. The total size is bounded; when a write pushes it past the
+limit, the least recently used entries are evicted. Lookups don't write their hits' use times
+themselves: they're batched, and written with the next `put` or `prune` (or `flush`).
+"""
+
+from __future__ import annotations
+
+import logging
+import sqlite3
+import threading
+import time
+
+from collections.abc import Iterable, Iterator, Mapping, Sequence
+from contextlib import closing, contextmanager
+from pathlib import Path
+from typing import TYPE_CHECKING, NamedTuple
+
+from pydantic_core import from_json, to_json
+
+from codeweaver.core.types.embeddings import CodeWeaverSparseEmbedding
+
+
+if TYPE_CHECKING:
+    from datetime import timedelta
+
+    from codeweaver.providers.config.providers import EmbeddingCacheSettings
+
+
+logger = logging.getLogger(__name__)
+
+type CachedEmbedding = list[float] | list[int] | CodeWeaverSparseEmbedding
+
+_SCHEMA = """
+CREATE TABLE IF NOT EXISTS embeddings (
+    content_hash TEXT NOT NULL,
+    provider TEXT NOT NULL,
+    model TEXT NOT NULL,
+    dimension INTEGER NOT NULL,
+    dtype TEXT NOT NULL,
+    vector BLOB NOT NULL,
+    size INTEGER NOT NULL,
+    created_at REAL NOT NULL,
+    last_used REAL NOT NULL,
+    UNIQUE (provider, model, dimension, dtype, content_hash)
+);
+CREATE INDEX IF NOT EXISTS embeddings_last_used ON embeddings (last_used);
+"""
+
+# SQLite's default limit on bound parameters is 999 on older builds
+_MAX_PARAMS_PER_QUERY = 900
+# Evict down to this fraction of the limit so we don't evict on every write near the limit
+_EVICTION_LOW_WATER = 0.9
+# Write batched use times once this many hits are waiting, even without a `put`
+_MAX_PENDING_TOUCHES = 10_000
+
+
+class EmbeddingSpace(NamedTuple):
+    """The provider, model, dimension and datatype a vector was produced with.
+
+    Vectors are only comparable within one space, so every cache entry belongs to exactly one.
+    Sparse embeddings use a dimension of 0.
+    """
+
+    provider: str
+    model: str
+    dimension: int
+    dtype: str
+
+
+class EmbeddingSpaceStats(NamedTuple):
+    """Entry count and size of one embedding space in the cache."""
+
+    space: EmbeddingSpace
+    entries: int
+    size_bytes: int
+    last_used: float
+
+
+class EmbeddingCacheStats(NamedTuple):
+    """A snapshot of the persistent cache's contents."""
+
+    path: Path
+    entries: int
+    size_bytes: int
+    max_size_bytes: int
+    spaces: tuple[EmbeddingSpaceStats, ...]
+
+
+def _encode(embedding: CachedEmbedding) -> bytes:
+    """Serialize a dense or sparse embedding for storage."""
+    if isinstance(embedding, CodeWeaverSparseEmbedding):
+        return to_json({"indices": list(embedding.indices), "values": list(embedding.values)})
+    if isinstance(embedding, Mapping):
+        return to_json({"indices": list(embedding["indices"]), "values": list(embedding["values"])})
+    return to_json(embedding.tolist() if hasattr(embedding, "tolist") else list(embedding))
+
+
+def _decode(blob: bytes) -> CachedEmbedding:
+    """Deserialize a stored embedding."""
+    value = from_json(blob)
+    if isinstance(value, dict):
+        return CodeWeaverSparseEmbedding(indices=value["indices"], values=value["values"])
+    return value
+
+
+def _batched[T](items: Sequence[T], size: int = _MAX_PARAMS_PER_QUERY) -> Iterator[Sequence[T]]:
+    for start in range(0, len(items), size):
+        yield items[start : start + size]
+
+
+class PersistentEmbeddingCache:
+    """A size-bounded, LRU-evicting SQLite cache of embeddings, shared across processes.
+
+    All methods are blocking; async callers should run them in a thread.
+    """
+
+    def __init__(self, path: Path, *, max_size_bytes: int, busy_timeout: float = 30.0) -> None:
+        """Open (and if needed create) the cache database.
+
+        Args:
+            path: Location of the SQLite database file
+            max_size_bytes: Total size of stored vectors above which LRU eviction kicks in
+            busy_timeout: Seconds to wait for another process's write lock before failing
+        """
+        self._path = path
+        self._max_size_bytes = max_size_bytes
+        self._busy_timeout = busy_timeout
+        self._touch_lock = threading.Lock()
+        self._pending_touches: dict[tuple[EmbeddingSpace, str], float] = {}
+        path.parent.mkdir(parents=True, exist_ok=True)
+        with self._connect() as conn:
+            conn.execute("PRAGMA journal_mode=WAL")
+            conn.executescript(_SCHEMA)
+
+    @classmethod
+    def from_settings(cls, settings: EmbeddingCacheSettings) -> PersistentEmbeddingCache:
+        """Create the cache described by the embedding cache settings."""
+        return cls(settings.cache_path, max_size_bytes=settings.max_size_mb * 1024 * 1024)
+
+    @property
+    def path(self) -> Path:
+        """Location of the cache database."""
+        return self._path
+
+    @property
+    def max_size_bytes(self) -> int:
+        """Size limit of the cache."""
+        return self._max_size_bytes
+
+    @contextmanager
+    def _connect(self) -> Iterator[sqlite3.Connection]:
+        """Open a short-lived connection; commits on success and rolls back on error."""
+        with closing(
+            sqlite3.connect(self._path, timeout=self._busy_timeout, isolation_level=None)
+        ) as conn:
+            conn.execute("PRAGMA synchronous=NORMAL")
+            yield conn
+
+    @contextmanager
+    def _write(self) -> Iterator[sqlite3.Connection]:
+        """Open a connection holding the database write lock for the duration of the block."""
+        with self._connect() as conn:
+            conn.execute("BEGIN IMMEDIATE")
+            try:
+                yield conn
+            except BaseException:
+                conn.execute("ROLLBACK")
+                raise
+            conn.execute("COMMIT")
+
+    def get(self, space: EmbeddingSpace, hashes: Sequence[str]) -> dict[str, CachedEmbedding]:
+        """Look up embeddings by content hash and mark the hits as recently used.
+
+        The lookup only reads; the hits' use times are written in a batch later (see `flush`).
+
+        Args:
+            space: The embedding space the vectors must belong to
+            hashes: Blake3 hashes of the chunks' serialized embedding input
+
+        Returns:
+            Cached embeddings by content hash; misses are absent
+        """
+        found: dict[str, CachedEmbedding] = {}
+        if not hashes:
+            return found
+        unique = list(dict.fromkeys(hashes))
+        with self._connect() as conn:
+            # One read transaction, so every batch sees the same snapshot
+            conn.execute("BEGIN DEFERRED")
+            try:
+                for batch in _batched(unique):
+                    marks = ",".join("?" * len(batch))
+                    where = (
+                        "provider = ? AND model = ? AND dimension = ? AND dtype = ? "
+                        f"AND content_hash IN ({marks})"
+                    )
+                    rows = conn.execute(
+                        f"SELECT content_hash, vector FROM embeddings WHERE {where}",  # noqa: S608
+                        (*space, *batch),
+                    ).fetchall()
+                    found.update((content_hash, _decode(blob)) for content_hash, blob in rows)
+            finally:
+                conn.execute("COMMIT")
+        if found and self._touch(space, found) >= _MAX_PENDING_TOUCHES:
+            self.flush()
+        return found
+
+    def _touch(self, space: EmbeddingSpace, hashes: Iterable[str]) -> int:
+        """Queue hits' use times for the next write; returns how many are waiting."""
+        now = time.time()
+        with self._touch_lock:
+            self._pending_touches.update(((space, content_hash), now) for content_hash in hashes)
+            return len(self._pending_touches)
+
+    def _write_touches(self, conn: sqlite3.Connection) -> None:
+        """Write the queued use times, inside a write transaction."""
+        with self._touch_lock:
+            pending, self._pending_touches = self._pending_touches, {}
+        if not pending:
+            return
+        try:
+            conn.executemany(
+                "UPDATE embeddings SET last_used = MAX(last_used, ?) "
+                "WHERE provider = ? AND model = ? AND dimension = ? AND dtype = ? "
+                "AND content_hash = ?",
+                [(used, *space, content_hash) for (space, content_hash), used in pending.items()],
+            )
+        except BaseException:
+            # Keep them for the next write, unless newer hits replaced them meanwhile
+            with self._touch_lock:
+                self._pending_touches = pending | self._pending_touches
+            raise
+
+    def flush(self) -> None:
+        """Write the use times of recent hits, which lookups batch instead of writing."""
+        with self._touch_lock:
+            if not self._pending_touches:
+                return
+        with self._write() as conn:
+            self._write_touches(conn)
+
+    def put(self, space: EmbeddingSpace, embeddings: Mapping[str, CachedEmbedding]) -> None:
+        """Store embeddings by content hash, evicting old entries if the cache grows too large.
+
+        Args:
+            space: The embedding space the vectors belong to
+            embeddings: Embeddings keyed by the blake3 hash of their serialized input
+        """
+        if not embeddings:
+            return
+        now = time.time()
+        rows: list[tuple[object, ...]] = []
+        for content_hash, embedding in embeddings.items():
+            blob = _encode(embedding)
+            rows.append((content_hash, *space, blob, len(blob), now, now))
+        with self._write() as conn:
+            self._write_touches(conn)
+            conn.executemany(
+                "INSERT INTO embeddings "
+                "(content_hash, provider, model, dimension, dtype, vector, size, "
+                "created_at, last_used) "
+                "VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?) "
+                "ON CONFLICT (provider, model, dimension, dtype, content_hash) DO UPDATE SET "
+                "vector = excluded.vector, size = excluded.size, last_used = excluded.last_used",
+                rows,
+            )
+            self._evict(conn, self._max_size_bytes)
+
+    @staticmethod
+    def _evict(conn: sqlite3.Connection, max_size_bytes: int) -> int:
+        """Delete least recently used entries until the cache fits in `max_size_bytes`."""
+        total = conn.execute("SELECT COALESCE(SUM(size), 0) FROM embeddings").fetchone()[0]
+        if total <= max_size_bytes:
+            return 0
+        excess = total - int(max_size_bytes * _EVICTION_LOW_WATER)
+        cursor = conn.execute(
+            "DELETE FROM embeddings WHERE rowid IN ("
+            "SELECT rowid FROM ("
+            "SELECT rowid, SUM(size) OVER (ORDER BY last_used, rowid) - size AS freed_before "
+            "FROM embeddings"
+            ") WHERE freed_before < ?)",
+            (excess,),
+        )
+        logger.debug("Evicted %d embeddings from the persistent embedding cache", cursor.rowcount)
+        return cursor.rowcount
+
+    def stats(self) -> EmbeddingCacheStats:
+        """Summarize the cache's contents per embedding space."""
+        with self._connect() as conn:
+            rows = conn.execute(
+                "SELECT provider, model, dimension, dtype, COUNT(*), SUM(size), MAX(last_used) "
+                "FROM embeddings GROUP BY provider, model, dimension, dtype "
+                "ORDER BY provider, model, dimension, dtype"
+            ).fetchall()
+        spaces = tuple(
+            EmbeddingSpaceStats(EmbeddingSpace(*row[:4]), row[4], row[5], row[6]) for row in rows
+        )
+        return EmbeddingCacheStats(
+            path=self._path,
+            entries=sum(s.entries for s in spaces),
+            size_bytes=sum(s.size_bytes for s in spaces),
+            max_size_bytes=self._max_size_bytes,
+            spaces=spaces,
+        )
+
+    def prune(
+        self, *, max_size_bytes: int | None = None, older_than: timedelta | None = None
+    ) -> int:
+        """Remove entries unused for longer than `older_than`, then evict down to a size limit.
+
+        Args:
+            max_size_bytes: Size to evict down to; defaults to the configured limit
+            older_than: Remove entries that have not been used within this long
+
+        Returns:
+            The number of entries removed
+        """
+        removed = 0
+        with self._write() as conn:
+            self._write_touches(conn)
+            if older_than is not None:
+                cutoff = time.time() - older_than.total_seconds()
+                removed += conn.execute(
+                    "DELETE FROM embeddings WHERE last_used < ?", (cutoff,)
+                ).rowcount
+            removed += self._evict(
+                conn, self._max_size_bytes if max_size_bytes is None else max_size_bytes
+            )
+        return removed
+
+    def clear(self, *, provider: str | None = None, model: str | None = None) -> int:
+        """Remove all entries, or only those of one provider and/or model.
+
+        Returns:
+            The number of entries removed
+        """
+        conditions = {"provider": provider, "model": model}
+        where = " AND ".join(f"{column} = ?" for column, value in conditions.items() if value)
+        params = tuple(value for value in conditions.values() if value)
+        with self._write() as conn:
+            removed = conn.execute(
+                f"DELETE FROM embeddings{f' WHERE {where}' if where else ''}",  # noqa: S608
+                params,
+            ).rowcount
+        if not where:
+            with self._connect() as conn:
+                conn.execute("VACUUM")
+        return removed
+
+
+__all__ = (
+    "CachedEmbedding",
+    "EmbeddingCacheStats",
+    "EmbeddingSpace",
+    "EmbeddingSpaceStats",
+    "PersistentEmbeddingCache",
+)
@@ -78,6 +78,7 @@ from codeweaver.providers.embedding.capabilities.base import (
     EmbeddingModelCapabilities,
     SparseEmbeddingModelCapabilities,
 )
+from codeweaver.providers.embedding.persistent_cache import EmbeddingSpace
 from codeweaver.providers.embedding.registry import EmbeddingRegistry
 from codeweaver.providers.exceptions import CircuitBreakerOpenError
 from codeweaver.providers.types import CircuitBreakerState
@@ -613,9 +614,27 @@ class EmbeddingProvider[EmbeddingClient](BasedModel, ABC):
             },
         )
 
+        # Reuse embeddings computed by earlier runs (or other processes) for identical input
+        space = self._embedding_space()
+        content_hashes = [get_blake_hash(text) for text in self.chunks_to_strings(chunks)]
+        persisted = await self.cache_manager.get_persisted(space, content_hashes, self._namespace)
+        pending_hashes = [h for h in content_hashes if h not in persisted]
+        pending = [
+            chunk
+            for chunk, content_hash in zip(chunks, content_hashes, strict=True)
+            if content_hash not in persisted
+        ]
+        if persisted:
+            logger.debug(
+                "Persistent embedding cache served %d of %d chunks for %s",
+                len(chunks) - len(pending),
+                len(chunks),
+                self._namespace,
+            )
+
         try:
             # Split chunks into token-aware batches to avoid exceeding API limits
-            token_batches = self._split_by_tokens(chunks)
+            token_batches = self._split_by_tokens(pending) if pending else []
 
             all_results: list[
                 Sequence[float] | Sequence[int] | dict[str, list[int] | list[float]]
@@ -644,7 +663,11 @@ class EmbeddingProvider[EmbeddingClient](BasedModel, ABC):
                 # Yield between token batches to keep server responsive
                 await asyncio.sleep(ZERO)
 
-            results = all_results
+            fresh = iter(all_results)
+            results = [
+                persisted[content_hash] if content_hash in persisted else next(fresh)
+                for content_hash in content_hashes
+            ]
         except CircuitBreakerOpenError as e:
             # Circuit breaker open - return error immediately
             await log_to_client_or_fallback(
@@ -703,6 +726,9 @@ class EmbeddingProvider[EmbeddingClient](BasedModel, ABC):
                     batch_id=cast(UUID7, batch_id or cache_key),
                     embeddings=results,  # ty:ignore[invalid-argument-type]
                 )
+            await self.cache_manager.persist(
+                space, dict(zip(pending_hashes, all_results, strict=True))
+            )
 
             await log_to_client_or_fallback(
                 context,
@@ -893,6 +919,16 @@ class EmbeddingProvider[EmbeddingClient](BasedModel, ABC):
                 ],
             )
 
+    def _embedding_space(self) -> EmbeddingSpace:
+        """Identify the vector space this provider's document embeddings belong to."""
+        is_sparse = isinstance(self, SparseEmbeddingProvider)
+        return EmbeddingSpace(
+            provider=type(self)._provider.variable,
+            model=str(self.model_name),
+            dimension=self.get_dimension(sparse=is_sparse),
+            dtype=self.get_datatype(sparse=is_sparse),
+        )
+
     def get_datatype(
         self, *, sparse: bool = False
     ) -> Literal["float32", "float16", "int8", "binary"]:
@@ -36,6 +36,8 @@ if TYPE_CHECKING:
         HybridVectorPayload,
         PayloadFieldDict,
         TransformationRecord,
+        path_prefixes,
+        payload_keyword,
     )
     from codeweaver.providers.types.vectors import VectorConfig, VectorRole, VectorSet
 
@@ -54,6 +56,8 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "HybridVectorPayload": (__spec__.parent, "vector_store"),
     "ModelCapDict": (__spec__.parent, "search"),
     "ModelNameDict": (__spec__.parent, "search"),
+    "path_prefixes": (__spec__.parent, "vector_store"),
+    "payload_keyword": (__spec__.parent, "vector_store"),
     "PayloadFieldDict": (__spec__.parent, "vector_store"),
     "RerankingCapabilityType": (__spec__.parent, "resolvers"),
     "SearchPackage": (__spec__.parent, "search"),
@@ -87,6 +91,8 @@ __all__ = (
     "VectorConfig",
     "VectorRole",
     "VectorSet",
+    "path_prefixes",
+    "payload_keyword",
 )
 
 
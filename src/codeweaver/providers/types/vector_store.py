@@ -10,6 +10,7 @@ import logging
 
 from dataclasses import dataclass
 from datetime import UTC, datetime, timedelta
+from pathlib import Path, PurePosixPath
 from typing import TYPE_CHECKING, Annotated, Any, Literal, TypedDict, cast
 
 from pydantic import Field, computed_field
@@ -98,8 +99,19 @@ class PayloadFieldDict(TypedDict):
     chunked_on: Literal["datetime"]
     hash: Literal["keyword"]  # use keyword to find specific hashes
     provider: Literal["keyword"]
+    generation: Literal["keyword"]
     embedding_complete: Literal["bool"]
     symbol: Literal["keyword"]
+    crate: Literal["keyword"]
+    language: Literal["keyword"]
+    path_prefixes: Literal["keyword"]
+    chunk_kind: Literal["keyword"]
+    semantic_class: Literal["keyword"]
+    is_test: Literal["bool"]
+    modified_at: Literal["datetime"]
+    file_size: Literal["integer"]
+    package: Literal["keyword"]
+    package_version: Literal["keyword"]
 
 
 class HybridVectorPayload(BasedModel):
@@ -130,6 +142,64 @@ class HybridVectorPayload(BasedModel):
             description="Whether the chunk has been fully embedded with both sparse and dense embeddings"
         ),
     ]
+    crate: Annotated[
+        str | None,
+        Field(
+            description="Name of the Cargo crate that owns the chunk's file; indexed for crate filters"
+        ),
+    ] = None
+    generation: Annotated[
+        str | None,
+        Field(
+            description="The index generation the chunk was stored in; a file's older generations are deleted once a new one is stored"
+        ),
+    ] = None
+    language: Annotated[
+        str | None, Field(description="The chunk's language; indexed for language filters")
+    ] = None
+    path_prefixes: Annotated[
+        tuple[str, ...],
+        Field(
+            description="The chunk's file path and every directory above it, relative to the project root; indexed for path filters"
+        ),
+    ] = ()
+    chunk_kind: Annotated[
+        str | None,
+        Field(
+            description="The `ChunkKind` of the chunk's file (code, config, docs...); indexed for kind filters"
+        ),
+    ] = None
+    semantic_class: Annotated[
+        str | None,
+        Field(
+            description="The `SemanticClass` of the chunk's AST node, for semantically chunked code; indexed for class filters"
+        ),
+    ] = None
+    is_test: Annotated[
+        bool | None,
+        Field(description="Whether the chunk is test code; indexed for test filters"),
+    ] = None
+    modified_at: Annotated[
+        datetime | None,
+        Field(
+            description="When the chunk's file was last modified, as of chunking; unset for files not read from disk, like a git revision's. Indexed for modified-since filters"
+        ),
+    ] = None
+    file_size: Annotated[
+        int | None,
+        Field(
+            description="The size of the chunk's file in bytes, as of chunking; unset when unknown. Indexed for size filters"
+        ),
+    ] = None
+    package: Annotated[
+        str | None,
+        Field(
+            description="The third-party package the chunk is from, in the dependency sources' index; indexed for package filters"
+        ),
+    ] = None
+    package_version: Annotated[
+        str | None, Field(description="The version of the package the chunk is from")
+    ] = None
 
     @computed_field
     @property
@@ -184,8 +254,33 @@ class HybridVectorPayload(BasedModel):
             "provider": "keyword",
             "embedding_complete": "bool",
             "symbol": "keyword",
+            "crate": "keyword",
+            "language": "keyword",
+            "path_prefixes": "keyword",
+            "chunk_kind": "keyword",
+            "semantic_class": "keyword",
+            "is_test": "bool",
+            "modified_at": "datetime",
+            "file_size": "integer",
+            "package": "keyword",
+            "package_version": "keyword",
         })
 
+    @staticmethod
+    def filter_fields() -> tuple[str, ...]:
+        """Return the payload fields that search filters are pushed down on."""
+        return (
+            "crate",
+            "language",
+            "path_prefixes",
+            "chunk_kind",
+            "semantic_class",
+            "is_test",
+            "modified_at",
+            "file_size",
+            "package",
+        )
+
 
 class CollectionMetadata(BasedModel):
     """Metadata stored with collections for validation and compatibility checks.
@@ -895,10 +990,31 @@ class CollectionMetadata(BasedModel):
         return {FilteredKey("project_name"): AnonymityConversion.HASH}
 
 
+def payload_keyword(value: object) -> str | None:
+    """How an enum member or a name is stored in keyword payload fields, like `language`."""
+    if value is None:
+        return None
+    if isinstance(value, BaseEnum):
+        return value.variable
+    return str(value).strip().lower() or None
+
+
+def path_prefixes(path: Path | str) -> tuple[str, ...]:
+    """A file's `path_prefixes` payload: each directory above it, then its own path.
+
+    `crates/auth/src/lib.rs` has `crates`, `crates/auth`, `crates/auth/src` and
+    `crates/auth/src/lib.rs`, so a path prefix filter is one exact keyword match.
+    """
+    parts = [part for part in PurePosixPath(Path(path).as_posix()).parts if part not in ("/", ".")]
+    return tuple("/".join(parts[: index + 1]) for index in range(len(parts)))
+
+
 __all__ = (
     "CollectionMetadata",
     "CollectionPolicy",
     "HybridVectorPayload",
     "PayloadFieldDict",
     "TransformationRecord",
+    "path_prefixes",
+    "payload_keyword",
 )
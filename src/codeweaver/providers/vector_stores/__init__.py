@@ -43,9 +43,11 @@ if TYPE_CHECKING:
     )
     from codeweaver.providers.vector_stores.search.filter_factory import (
         ArbitraryFilter,
+        SqliteFilter,
         make_filter,
         make_indexes,
         to_qdrant_filter,
+        to_sqlite_filter,
     )
     from codeweaver.providers.vector_stores.search.geo import (
         GeoBoundingBox,
@@ -75,6 +77,12 @@ if TYPE_CHECKING:
         make_partial_function,
         wrap_filters,
     )
+    from codeweaver.providers.vector_stores.sqlite import SqliteVectorStoreProvider
+    from codeweaver.providers.vector_stores.sqlite_client import (
+        SqliteCollectionInfo,
+        SqlitePoint,
+        SqliteVectorClient,
+    )
 
 _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "AnyVariants": (__spec__.parent, "search.match"),
@@ -114,6 +122,12 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "QdrantVectorStoreService": (__spec__.parent, "qdrant_service"),
     "Range": (__spec__.parent, "search.range"),
     "RangeInterface": (__spec__.parent, "search.range"),
+    "SqliteCollectionInfo": (__spec__.parent, "sqlite_client"),
+    "SqliteFilter": (__spec__.parent, "search.filter_factory"),
+    "SqlitePoint": (__spec__.parent, "sqlite_client"),
+    "SqliteVectorClient": (__spec__.parent, "sqlite_client"),
+    "SqliteVectorStoreProvider": (__spec__.parent, "sqlite"),
+    "to_sqlite_filter": (__spec__.parent, "search.filter_factory"),
     "ValuesCount": (__spec__.parent, "search.condition"),
     "ValueVariants": (__spec__.parent, "search.match"),
     "VectorStoreProvider": (__spec__.parent, "base"),
@@ -165,6 +179,11 @@ __all__ = (
     "QdrantVectorStoreService",
     "Range",
     "RangeInterface",
+    "SqliteCollectionInfo",
+    "SqliteFilter",
+    "SqlitePoint",
+    "SqliteVectorClient",
+    "SqliteVectorStoreProvider",
     "ValueVariants",
     "ValuesCount",
     "VectorStoreProvider",
@@ -173,6 +192,7 @@ __all__ = (
     "make_indexes",
     "make_partial_function",
     "to_qdrant_filter",
+    "to_sqlite_filter",
     "wrap_filters",
 )
 
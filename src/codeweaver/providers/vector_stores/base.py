@@ -11,29 +11,40 @@ import threading
 import time
 
 from abc import ABC, abstractmethod
+from datetime import UTC, datetime
 from pathlib import Path
-from typing import Any, ClassVar, Literal, cast
+from typing import TYPE_CHECKING, Any, ClassVar, Literal, Self, cast
 
 import httpx
 
 from pydantic import UUID7, ConfigDict, PrivateAttr
 from tenacity import retry, retry_if_exception_type, stop_after_attempt, wait_exponential
 
-from codeweaver.core import BasedModel, CodeChunk, Provider, StrategizedQuery
+from codeweaver.core import BasedModel, CodeChunk, Provider, SearchStrategy, StrategizedQuery
 from codeweaver.core.constants import (
     BASE_RETRYABLE_EXCEPTIONS,
     DEFAULT_OPEN_BREAKER_DURATION,
     MAX_RETRY_ATTEMPTS,
     ZERO,
 )
+from codeweaver.core.git_tree import revision_collection_name
 from codeweaver.core.types import ModelNameT, SearchResult
 from codeweaver.providers import EmbeddingModelCapabilities
 from codeweaver.providers.config import VectorStoreProviderSettings
 from codeweaver.providers.exceptions import CircuitBreakerOpenError
 from codeweaver.providers.types import CircuitBreakerState, EmbeddingCapabilityGroup
+from codeweaver.providers.types.vector_store import (
+    HybridVectorPayload,
+    path_prefixes,
+    payload_keyword,
+)
 from codeweaver.providers.vector_stores.search import Filter
 
 
+if TYPE_CHECKING:
+    from codeweaver.providers.embedding.registry import EmbeddingRegistry
+
+
 # Common retryable exceptions for vector store operations
 # Include httpcore and qdrant-specific exceptions that indicate transient network issues
 try:
@@ -56,6 +67,7 @@ except ImportError:
 
 logger = logging.getLogger(__name__)
 
+
 type MixedQueryInput = (
     list[float] | list[int] | dict[Literal["dense", "sparse"], list[float] | list[int] | Any]
 )
@@ -224,6 +236,102 @@ class VectorStoreProvider[VectorStoreClient](BasedModel, ABC):
             else None
         )
 
+    def _normalize_vector_input(
+        self, vector: StrategizedQuery | MixedQueryInput
+    ) -> StrategizedQuery:
+        """Normalize vector input to StrategizedQuery format.
+
+        Args:
+            vector: Input vector in various formats.
+
+        Returns:
+            Normalized StrategizedQuery.
+
+        Raises:
+            ProviderError: Invalid vector input format.
+        """
+        from codeweaver.core import CodeWeaverSparseEmbedding, StrategizedQuery
+
+        if isinstance(vector, StrategizedQuery):
+            return vector
+        sparse, dense = (None, None)
+        if isinstance(vector, dict):
+            if "indices" in vector and "values" in vector:
+                sparse = CodeWeaverSparseEmbedding(
+                    indices=vector["indices"],
+                    values=[float(x) if isinstance(x, int) else x for x in vector["values"]],
+                )
+            elif (
+                "sparse" in vector
+                and isinstance(vector["sparse"], dict)
+                and "indices" in vector["sparse"]
+                and "values" in vector["sparse"]
+            ):
+                sparse = CodeWeaverSparseEmbedding(
+                    indices=vector["sparse"].get("indices", []),
+                    values=[
+                        float(x) if isinstance(x, int) else x
+                        for x in vector["sparse"].get("values", [])
+                    ],
+                )
+            if "dense" in vector:
+                dense = [float(x) if isinstance(x, int) else x for x in vector["dense"]]
+        elif isinstance(vector, list | tuple):
+            dense = [float(x) if isinstance(x, int) else x for x in vector]
+        strategy = (
+            SearchStrategy.HYBRID_SEARCH
+            if dense and sparse
+            else SearchStrategy.DENSE_ONLY
+            if dense
+            else SearchStrategy.SPARSE_ONLY
+        )
+        return StrategizedQuery(query="unavailable", dense=dense, sparse=sparse, strategy=strategy)
+
+    async def _get_registry(self) -> EmbeddingRegistry:
+        """Retrieve the EmbeddingRegistry from the DI container."""
+        from codeweaver.core.di.container import get_container
+        from codeweaver.providers.embedding.registry import EmbeddingRegistry
+
+        container = get_container()
+        return await container.resolve(EmbeddingRegistry)
+
+    def _create_payload(self, chunk: CodeChunk) -> HybridVectorPayload:
+        """Create payload for a code chunk.
+
+        Args:
+            chunk: Code chunk to create payload for.
+
+        Returns:
+            HybridVectorPayload instance.
+        """
+        # The file's size and modification time are recorded when it's chunked (and unknown
+        # for chunks that weren't), since the file on disk may not be the one that was indexed
+        metadata = chunk.metadata or {}
+        mtime = metadata.get("file_mtime")
+        return HybridVectorPayload(
+            chunk=chunk,
+            chunk_id=chunk.chunk_id.hex,
+            chunked_on=datetime.fromtimestamp(chunk.timestamp).astimezone(UTC).isoformat(),
+            file_path=str(chunk.file_path) if chunk.file_path else "",
+            line_start=chunk.line_range.start,
+            line_end=chunk.line_range.end,
+            indexed_at=datetime.now(UTC).isoformat(),
+            hash=chunk.blake_hash,
+            provider=self._provider.variable,
+            embedding_complete=bool(chunk.dense_batch_key and chunk.sparse_batch_key),
+            crate=chunk.crate,
+            generation=chunk.metadata.get("generation") if chunk.metadata else None,
+            language=payload_keyword(chunk.language),
+            path_prefixes=path_prefixes(chunk.file_path) if chunk.file_path else (),
+            chunk_kind=payload_keyword(chunk.ext_category.kind) if chunk.ext_category else None,
+            semantic_class=payload_keyword(chunk.semantic_class),
+            is_test=chunk.is_test,
+            modified_at=datetime.fromtimestamp(mtime, UTC) if mtime is not None else None,
+            file_size=metadata.get("file_size"),
+            package=chunk.package,
+            package_version=chunk.package_version,
+        )
+
     @property
     def _check_circuit_breaker(self) -> None:
         """Check circuit breaker state before making API calls.
@@ -513,12 +621,16 @@ class VectorStoreProvider[VectorStoreClient](BasedModel, ABC):
         """
 
     @abstractmethod
-    async def delete_by_files(self, file_paths: list[Path]) -> None:
+    async def delete_by_files(
+        self, file_paths: list[Path], *, keep_generation: str | None = None
+    ) -> None:
         """Delete all chunks for multiple files in a single operation.
 
         Args:
             file_paths: List of file paths to remove from index.
                 Paths should be relative to project root.
+            keep_generation: Keep the files' chunks stored in this index generation, and
+                delete only older ones. Used to swap in a file's new chunks after storing them.
 
         Raises:
             CollectionNotFoundError: Collection doesn't exist.
@@ -565,6 +677,96 @@ class VectorStoreProvider[VectorStoreClient](BasedModel, ABC):
             - Operation is atomic (all-or-nothing for batch).
         """
 
+    async def update_payloads(self, chunks: list[CodeChunk]) -> list[UUID7]:
+        """Rewrite the payloads of stored chunks, keeping their vectors.
+
+        Used when chunks change in ways that don't need new embeddings: an edit moves them (like
+        lines added above them), their file moves, or a formatter only reformats them.
+
+        Args:
+            chunks: Chunks already in the collection, with their new content, line ranges and
+                paths.
+
+        Returns:
+            The IDs of chunks that aren't in the collection, which have nothing to update and
+            are left out.
+
+        Raises:
+            NotImplementedError: The provider can't update payloads alone; callers should
+                upsert the chunks instead.
+        """
+        raise NotImplementedError(f"{type(self).__name__} can't update payloads alone")
+
+    async def get_chunks(self, ids: list[UUID7]) -> list[CodeChunk]:
+        """Get stored chunks by ID, as their payloads describe them.
+
+        Args:
+            ids: Chunk IDs to get; IDs that aren't stored are skipped.
+
+        Returns:
+            The stored chunks, in no particular order.
+
+        Raises:
+            NotImplementedError: The provider can't read points back by ID.
+        """
+        raise NotImplementedError(f"{type(self).__name__} can't get chunks by ID")
+
+    def with_collection(self, collection_name: str) -> Self:
+        """Get a provider for another collection in the same store, sharing this one's client.
+
+        Args:
+            collection_name: The collection the new provider reads and writes.
+
+        Returns:
+            A provider of the same type and settings, for `collection_name`.
+        """
+        collection = self.config.collection.model_copy(update={"collection_name": collection_name})
+        return type(self)(
+            client=self.client,
+            config=self.config.model_copy(update={"collection": collection}),
+            caps=self.caps,
+        )
+
+    def for_revision(self, commit: str) -> Self:
+        """Get a provider for the index of a git commit, kept next to this one's collection.
+
+        Args:
+            commit: The commit the index is of.
+
+        Returns:
+            A provider for the commit's collection.
+        """
+        collection_name = cast(str, self.config.collection.collection_name)
+        return self.with_collection(revision_collection_name(collection_name, commit))
+
+    def for_dependencies(self) -> Self:
+        """Get a provider for the index of the project's dependency sources, kept next to this one's collection.
+
+        Returns:
+            A provider for the dependency sources' collection.
+        """
+        collection_name = cast(str, self.config.collection.collection_name)
+        return self.with_collection(f"{collection_name}-deps")
+
+    async def copy_files_from(self, collection_name: str, file_paths: list[Path]) -> int:
+        """Copy the chunks of files from another collection in the same store, vectors and all.
+
+        Used to reuse unchanged files between indexes (like the indexes of two git
+        revisions) without chunking or embedding them again.
+
+        Args:
+            collection_name: The collection to copy from.
+            file_paths: Files whose chunks to copy, relative to the project root.
+
+        Returns:
+            The number of chunks copied.
+
+        Raises:
+            NotImplementedError: The provider can't copy between collections; callers should
+                index the files instead.
+        """
+        raise NotImplementedError(f"{type(self).__name__} can't copy between collections")
+
     def _telemetry_keys(self) -> None:
         return None
 
@@ -8,6 +8,7 @@
 
 from __future__ import annotations
 
+import contextlib
 import logging
 
 from abc import ABC, abstractmethod
@@ -16,6 +17,7 @@ from datetime import UTC, datetime
 from pathlib import Path
 from textwrap import dedent
 from typing import TYPE_CHECKING, Any, ClassVar, Literal, NoReturn, cast
+from uuid import UUID
 
 from pydantic import UUID7
 from qdrant_client import AsyncQdrantClient
@@ -23,6 +25,7 @@ from qdrant_client.http.exceptions import UnexpectedResponse
 from qdrant_client.models import (
     CollectionInfo,
     Document,
+    Filter as QdrantFilter,
     PointStruct,
     QueryResponse,
     SparseVectorParams,
@@ -38,7 +41,6 @@ from codeweaver.core import (
     ProviderError,
     ResolvedProjectNameDep,
     SearchResult,
-    SearchStrategy,
     StrategizedQuery,
 )
 from codeweaver.core.constants import DEFAULT_VECTOR_STORE_MAX_RESULTS
@@ -55,9 +57,13 @@ from codeweaver.providers.vector_stores.search import Filter
 logger = logging.getLogger(__name__)
 
 if TYPE_CHECKING:
-    from codeweaver.providers.embedding.registry import EmbeddingRegistry
     from codeweaver.providers.vector_stores.qdrant_service import QdrantVectorStoreService
 
+type PayloadSchemaType = Literal[
+    "keyword", "integer", "float", "geo", "text", "datetime", "bool", "uuid"
+]
+"""The payload field schemas Qdrant can index."""
+
 
 def _project_name(name: ResolvedProjectNameDep = INJECTED) -> str:
     """Return the resolved project name."""
@@ -72,6 +78,14 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
     config: QdrantVectorStoreProviderSettings
     _provider: ClassVar[Literal[Provider.QDRANT, Provider.MEMORY]]
     _service: QdrantVectorStoreService | None = None
+    _payload_indexes: ClassVar[dict[str, PayloadSchemaType]] = {
+        "generation": "keyword",
+        **{
+            field: cast(PayloadSchemaType, HybridVectorPayload.index_field_types()[field])
+            for field in HybridVectorPayload.filter_fields()
+        },
+    }
+    """Payload fields that search and delete filters are pushed down on, and their schemas."""
 
     @property
     def service(self) -> QdrantVectorStoreService:
@@ -233,7 +247,13 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
         collection_name = self.collection_name
         strategized_vector = self._normalize_vector_input(vector)
         try:
-            results = await self._execute_search_query(strategized_vector, collection_name)
+            if strategized_vector.is_hybrid() and not strategized_vector.fusion.server_side:
+                return await self._search_and_fuse(
+                    strategized_vector, collection_name, query_filter
+                )
+            results = await self._execute_search_query(
+                strategized_vector, collection_name, query_filter
+            )
         except Exception as e:
             raise ProviderError(f"Search operation failed: {e}") from e
         else:
@@ -310,6 +330,17 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
             raise ProviderError(
                 "The vector store provider encountered an error when trying to check if the collection existed, or when trying to create it."
             ) from e
+        await self._ensure_payload_indexes(collection_name)
+
+    async def _ensure_payload_indexes(self, collection_name: str) -> None:
+        """Create the payload indexes used by search filters.
+
+        Creating an index that exists is a no-op. A failed index is logged by
+        `create_payload_index`; filters on its field still work, just unindexed.
+        """
+        for field_name, field_schema in self._payload_indexes.items():
+            with contextlib.suppress(ProviderError):
+                await self.create_payload_index(collection_name, field_name, field_schema)
 
     def _raise_dimension_error(
         self,
@@ -522,9 +553,7 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
         self,
         collection_name: str,
         field_name: str,
-        field_schema: Literal[
-            "keyword", "integer", "float", "geo", "text", "datetime", "bool", "uuid"
-        ],
+        field_schema: PayloadSchemaType,
     ) -> UpdateResult:
         """Create a payload index on the specified field.
 
@@ -554,18 +583,19 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
             raise ProviderError(f"Failed to create payload index on '{field_name}': {e}") from e
 
     async def _execute_search_query(
-        self, vector: StrategizedQuery, collection_name: str
+        self, vector: StrategizedQuery, collection_name: str, query_filter: Filter | None = None
     ) -> list[Any] | Any:
         """Execute the appropriate search query based on vector strategy.
 
         Args:
             vector: Strategized query vector.
             collection_name: Target collection name.
+            query_filter: Optional payload filter, applied to every prefetch and the final query.
 
         Returns:
             Raw search results from Qdrant.
         """
-        qdrant_filter = None
+        qdrant_filter = self._to_qdrant_filter(query_filter)
         args = {
             "limit": DEFAULT_VECTOR_STORE_MAX_RESULTS,
             "with_payload": True,
@@ -573,13 +603,13 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
             "with_vectors": False,
         }
         if vector.is_hybrid():
+            # prefetches take the filter as `filter`; the top-level query as `query_filter`
+            args["filter"] = qdrant_filter
             query_params = vector.to_hybrid_query(
                 query_options=args, kwargs={"collection_name": collection_name}
             )
 
-            # BM25/IDF collections require querying with Document (text), not SparseVector.
-            # Qdrant computes BM25 vectors internally from the text at both index and query
-            # time. Sending a pre-computed SparseVector won't match the internal IDF index.
+            # BM25/IDF collections are queried with the text (see `_sparse_query`)
             if self.caps and self.caps.idf is not None:
                 from qdrant_client.http.models import Prefetch as QdrantPrefetch
 
@@ -589,9 +619,7 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
                     if k in ("limit", "score_threshold", "filter", "params") and v is not None
                 }
                 sparse_prefetch = QdrantPrefetch(
-                    query=Document(text=vector.query, model="qdrant/bm25"),
-                    using="sparse",
-                    **prefetch_params,
+                    query=self._sparse_query(vector), using="sparse", **prefetch_params
                 )
                 # Replace the sparse prefetch (index 1) with the Document-based one
                 prefetch = query_params.get("prefetch", [])
@@ -605,56 +633,64 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
             )
         return response.points if hasattr(response, "points") else response
 
-    def _normalize_vector_input(
-        self, vector: StrategizedQuery | MixedQueryInput
-    ) -> StrategizedQuery:
-        """Normalize vector input to StrategizedQuery format.
+    @staticmethod
+    def _to_qdrant_filter(query_filter: Filter | None) -> QdrantFilter | None:
+        """Convert a CodeWeaver filter to Qdrant's filter model."""
+        if not query_filter:
+            return None
+        return QdrantFilter.model_validate(query_filter.model_dump(exclude_none=True, by_alias=True))
+
+    def _sparse_query(self, vector: StrategizedQuery) -> Document | Any:
+        """The query for the sparse vectors.
+
+        BM25/IDF collections require querying with Document (text), not SparseVector. Qdrant
+        computes BM25 vectors internally from the text at both index and query time, so a
+        pre-computed SparseVector won't match the internal IDF index.
+        """
+        from qdrant_client.http.models import SparseVector
 
-        Args:
-            vector: Input vector in various formats.
+        if self.caps and self.caps.idf is not None:
+            return Document(text=vector.query, model="qdrant/bm25")
+        assert vector.sparse is not None  # noqa: S101
+        return SparseVector(indices=list(vector.sparse.indices), values=list(vector.sparse.values))
 
-        Returns:
-            Normalized StrategizedQuery.
+    async def _search_and_fuse(
+        self, vector: StrategizedQuery, collection_name: str, query_filter: Filter | None = None
+    ) -> list[SearchResult]:
+        """Fetch the dense and sparse rankings in one batch and fuse them here.
 
-        Raises:
-            ProviderError: Invalid vector input format.
+        This runs the fusion strategies Qdrant can't run itself: min-max and weighted z-score.
+        The results keep their raw dense and sparse scores.
         """
-        from codeweaver.core import CodeWeaverSparseEmbedding, StrategizedQuery
-
-        if isinstance(vector, StrategizedQuery):
-            return vector
-        sparse, dense = (None, None)
-        if isinstance(vector, dict):
-            if "indices" in vector and "values" in vector:
-                sparse = CodeWeaverSparseEmbedding(
-                    indices=vector["indices"],
-                    values=[float(x) if isinstance(x, int) else x for x in vector["values"]],
-                )
-            elif (
-                "sparse" in vector
-                and isinstance(vector["sparse"], dict)
-                and "indices" in vector["sparse"]
-                and "values" in vector["sparse"]
-            ):
-                sparse = CodeWeaverSparseEmbedding(
-                    indices=vector["sparse"].get("indices", []),
-                    values=[
-                        float(x) if isinstance(x, int) else x
-                        for x in vector["sparse"].get("values", [])
-                    ],
-                )
-            if "dense" in vector:
-                dense = [float(x) if isinstance(x, int) else x for x in vector["dense"]]
-        elif isinstance(vector, list | tuple):
-            dense = [float(x) if isinstance(x, int) else x for x in vector]
-        strategy = (
-            SearchStrategy.HYBRID_SEARCH
-            if dense and sparse
-            else SearchStrategy.DENSE_ONLY
-            if dense
-            else SearchStrategy.SPARSE_ONLY
+        from qdrant_client.http.models import QueryRequest
+
+        assert vector.dense is not None  # noqa: S101
+        qdrant_filter = self._to_qdrant_filter(query_filter)
+        request_params = {
+            "filter": qdrant_filter,
+            "limit": DEFAULT_VECTOR_STORE_MAX_RESULTS,
+            "with_payload": True,
+            "with_vector": False,
+        }
+        dense, sparse = await self.client.query_batch_points(
+            collection_name=collection_name,
+            requests=[
+                QueryRequest(query=list(vector.dense), using="primary", **request_params),
+                QueryRequest(query=self._sparse_query(vector), using="sparse", **request_params),
+            ],
+        )
+        points = {point.id: point for point in (*sparse.points, *dense.points)}
+        fused = vector.fusion.fuse(
+            [(point.id, point.score) for point in dense.points],
+            [(point.id, point.score) for point in sparse.points],
+            limit=DEFAULT_VECTOR_STORE_MAX_RESULTS,
         )
-        return StrategizedQuery(query="unavailable", dense=dense, sparse=sparse, strategy=strategy)
+        results = self._convert_search_results([points[score.key] for score in fused], vector)
+        for result, score in zip(results, fused, strict=True):
+            result.score = score.score
+            result.dense_score = score.dense_score
+            result.sparse_score = score.sparse_score
+        return results
 
     def _convert_search_results(self, results: Any, vector: StrategizedQuery) -> list[SearchResult]:
         """Convert Qdrant results to SearchResult objects.
@@ -681,14 +717,6 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
             search_results.append(search_result)
         return search_results
 
-    async def _get_registry(self) -> EmbeddingRegistry:
-        """Retrieve the EmbeddingRegistry from the DI container."""
-        from codeweaver.core.di.container import get_container
-        from codeweaver.providers.embedding.registry import EmbeddingRegistry
-
-        container = get_container()
-        return await container.resolve(EmbeddingRegistry)
-
     async def _prepare_vectors(self, chunk: CodeChunk) -> dict[str, Any]:
         """Prepare vector dictionary for a code chunk.
 
@@ -770,28 +798,6 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
 
         return vectors
 
-    def _create_payload(self, chunk: CodeChunk) -> HybridVectorPayload:
-        """Create payload for a code chunk.
-
-        Args:
-            chunk: Code chunk to create payload for.
-
-        Returns:
-            HybridVectorPayload instance.
-        """
-        return HybridVectorPayload(
-            chunk=chunk,
-            chunk_id=chunk.chunk_id.hex,
-            chunked_on=datetime.fromtimestamp(chunk.timestamp).astimezone(UTC).isoformat(),
-            file_path=str(chunk.file_path) if chunk.file_path else "",
-            line_start=chunk.line_range.start,
-            line_end=chunk.line_range.end,
-            indexed_at=datetime.now(UTC).isoformat(),
-            hash=chunk.blake_hash,
-            provider=self._provider.variable,
-            embedding_complete=bool(chunk.dense_batch_key and chunk.sparse_batch_key),
-        )
-
     async def delete_collection(self, collection_name: str | None = None) -> bool:
         """Delete a vector store collection.
 
@@ -833,17 +839,20 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
         """
         await self.delete_by_files([file_path])
 
-    async def delete_by_files(self, file_paths: list[Path]) -> None:
+    async def delete_by_files(
+        self, file_paths: list[Path], *, keep_generation: str | None = None
+    ) -> None:
         """Delete all chunks for multiple files in a single operation.
 
         Args:
             file_paths: List of file paths to remove from index.
+            keep_generation: Keep the files' chunks stored in this index generation.
         """
         collection_name = self.collection_name
         if not collection_name:
             raise ProviderError("No collection configured")
         await self._ensure_collection(collection_name)
-        from qdrant_client.models import FieldCondition, MatchAny
+        from qdrant_client.models import FieldCondition, MatchAny, MatchValue
         from qdrant_client.models import Filter as QdrantFilter
 
         _ = await self.client.delete(
@@ -853,11 +862,63 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
                     FieldCondition(
                         key="file_path", match=MatchAny(any=[str(p) for p in file_paths])
                     )
+                ],
+                must_not=[
+                    FieldCondition(key="generation", match=MatchValue(value=keep_generation))
                 ]
+                if keep_generation
+                else None,
             ),
         )
         await self.handle_persistence()
 
+    async def copy_files_from(self, collection_name: str, file_paths: list[Path]) -> int:
+        """Copy the chunks of files from another collection, vectors and all.
+
+        Args:
+            collection_name: The collection to copy from.
+            file_paths: Files whose chunks to copy, relative to the project root.
+
+        Returns:
+            The number of chunks copied.
+        """
+        if not file_paths:
+            return 0
+        await self._ensure_collection(self.collection_name)
+        from qdrant_client.models import FieldCondition, MatchAny
+
+        selector = QdrantFilter(
+            must=[
+                FieldCondition(key="file_path", match=MatchAny(any=[str(p) for p in file_paths]))
+            ]
+        )
+        copied = 0
+        offset = None
+        while True:
+            records, offset = await self.client.scroll(
+                collection_name=collection_name,
+                scroll_filter=selector,
+                limit=256,
+                offset=offset,
+                with_payload=True,
+                with_vectors=True,
+            )
+            if records:
+                await self.client.upsert(
+                    collection_name=self.collection_name,
+                    points=[
+                        PointStruct(
+                            id=record.id, vector=record.vector or {}, payload=record.payload
+                        )
+                        for record in records
+                    ],
+                )
+                copied += len(records)
+            if offset is None:
+                break
+        await self.handle_persistence()
+        return copied
+
     async def apply_quantization(
         self,
         collection_name: str,
@@ -1064,6 +1125,82 @@ class QdrantBaseProvider(VectorStoreProvider[AsyncQdrantClient], ABC):
         _result = await self.client.upsert(collection_name=collection_name, points=points)
         await self.handle_persistence()
 
+    async def update_payloads(self, chunks: list[CodeChunk]) -> list[UUID7]:
+        """Replace the payloads of stored chunks, keeping their vectors.
+
+        Payloads are overwritten, so fields a chunk no longer has are cleared; only the stored
+        `embedding_complete` is kept, since unembedded copies don't carry batch keys.
+
+        Args:
+            chunks: Chunks already in the collection, with their new content, line ranges and
+                paths.
+
+        Returns:
+            The IDs of chunks that aren't in the collection, which are left out.
+        """
+        if not chunks:
+            return []
+        collection_name = self.collection_name
+        if not collection_name:
+            raise ProviderError("No collection configured")
+        await self._ensure_collection(collection_name)
+        from qdrant_client.models import OverwritePayloadOperation, SetPayload
+
+        stored = {
+            UUID(str(record.id)): (record.payload or {}).get("embedding_complete")
+            for record in await self.client.retrieve(
+                collection_name=collection_name,
+                ids=[chunk.chunk_id.hex for chunk in chunks],
+                with_payload=["embedding_complete"],
+                with_vectors=False,
+            )
+        }
+        operations = [
+            OverwritePayloadOperation(
+                overwrite_payload=SetPayload(
+                    payload=self._create_payload(chunk).model_dump(
+                        mode="json",
+                        exclude_none=True,
+                        round_trip=True,
+                        exclude={"embedding_complete"},
+                    )
+                    | {"embedding_complete": stored[chunk.chunk_id]},
+                    points=[chunk.chunk_id.hex],
+                )
+            )
+            for chunk in chunks
+            if chunk.chunk_id in stored
+        ]
+        if operations:
+            _ = await self.client.batch_update_points(
+                collection_name=collection_name, update_operations=operations
+            )
+            await self.handle_persistence()
+        return [chunk.chunk_id for chunk in chunks if chunk.chunk_id not in stored]
+
+    async def get_chunks(self, ids: list[UUID7]) -> list[CodeChunk]:
+        """Get stored chunks by ID, as their payloads describe them.
+
+        Args:
+            ids: Chunk IDs to get; IDs that aren't stored are skipped.
+
+        Returns:
+            The stored chunks, in no particular order.
+        """
+        collection_name = self.collection_name
+        if not ids or not collection_name:
+            return []
+        await self._ensure_collection(collection_name)
+        records = await self.client.retrieve(
+            collection_name=collection_name,
+            ids=[chunk_id.hex for chunk_id in ids],
+            with_payload=True,
+            with_vectors=False,
+        )
+        return [
+            HybridVectorPayload.model_validate(record.payload or {}).chunk for record in records
+        ]
+
     async def migrate_to(
         self,
         dest_client: AsyncQdrantClient,
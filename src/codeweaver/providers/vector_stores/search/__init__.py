@@ -42,9 +42,11 @@ if TYPE_CHECKING:
     )
     from codeweaver.providers.vector_stores.search.filter_factory import (
         ArbitraryFilter,
+        SqliteFilter,
         make_filter,
         make_indexes,
         to_qdrant_filter,
+        to_sqlite_filter,
     )
     from codeweaver.providers.vector_stores.search.geo import (
         GeoBoundingBox,
@@ -108,6 +110,8 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "PayloadSchemaType": (__spec__.parent, "payload"),
     "Range": (__spec__.parent, "range"),
     "RangeInterface": (__spec__.parent, "range"),
+    "SqliteFilter": (__spec__.parent, "filter_factory"),
+    "to_sqlite_filter": (__spec__.parent, "filter_factory"),
     "ValuesCount": (__spec__.parent, "condition"),
     "ValueVariants": (__spec__.parent, "match"),
     "make_filter": (__spec__.parent, "filter_factory"),
@@ -152,12 +156,14 @@ __all__ = (
     "PayloadSchemaType",
     "Range",
     "RangeInterface",
+    "SqliteFilter",
     "ValueVariants",
     "ValuesCount",
     "make_filter",
     "make_indexes",
     "make_partial_function",
     "to_qdrant_filter",
+    "to_sqlite_filter",
     "wrap_filters",
 )
 
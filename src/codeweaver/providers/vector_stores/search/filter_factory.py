@@ -13,18 +13,35 @@ Nearly all of this file and its contents were adapted from Qdrant's example MCP
 
 from __future__ import annotations
 
-from collections.abc import Callable
-from typing import Any
+import itertools
+
+from collections.abc import Callable, Sequence
+from typing import Any, NamedTuple
+
+from pydantic import BaseModel
 
 from codeweaver.core import METADATA_PATH
 from codeweaver.providers.vector_stores.search.condition import (
+    Condition,
     FieldCondition,
     Filter,
     FilterableField,
+    HasIdCondition,
+    IsEmptyCondition,
+    IsNullCondition,
+    NestedCondition,
+    ValuesCount,
+)
+from codeweaver.providers.vector_stores.search.match import (
+    Match,
+    MatchAny,
+    MatchExcept,
+    MatchPhrase,
+    MatchText,
+    MatchValue,
 )
-from codeweaver.providers.vector_stores.search.match import MatchAny, MatchExcept, MatchValue
 from codeweaver.providers.vector_stores.search.payload import PayloadSchemaType
-from codeweaver.providers.vector_stores.search.range import Range
+from codeweaver.providers.vector_stores.search.range import DatetimeRange, Range, RangeInterface
 
 
 ArbitraryFilter = dict[str, Any]
@@ -262,4 +279,220 @@ def to_qdrant_filter(filter_obj: Filter | None) -> Filter | None:
     return None if filter_obj is None else filter_obj
 
 
-__all__ = ("ArbitraryFilter", "make_filter", "make_indexes", "to_qdrant_filter")
+class SqliteFilter(NamedTuple):
+    """A filter compiled to a SQLite `WHERE` clause and its parameters."""
+
+    clause: str
+    params: tuple[Any, ...]
+
+
+_RANGE_OPERATORS = (("gt", ">"), ("gte", ">="), ("lt", "<"), ("lte", "<="))
+
+
+def _json_path(key: str) -> str:
+    """Turn a dotted payload key into a SQLite JSON path, e.g. `chunk.language` -> `$."chunk"."language"`."""
+    return "$" + "".join(f'."{segment}"' for segment in key.split(".") if segment)
+
+
+def _as_conditions(conditions: Sequence[Condition] | Condition | None) -> list[Condition]:
+    if conditions is None:
+        return []
+    if isinstance(conditions, BaseModel):
+        return [conditions]
+    return list(conditions)
+
+
+class _SqliteFilterCompiler:
+    """Compiles filters to SQL over JSON payloads with SQLite's JSON functions.
+
+    Conditions follow Qdrant's semantics: a condition on a field that holds an array matches if
+    any element does, `a[].b` keys reach through arrays, and a nested filter must hold for a
+    single element of the nested array.
+    """
+
+    def __init__(self, id_column: str) -> None:
+        self._id_column = id_column
+        self._aliases = itertools.count()
+        self.params: list[Any] = []
+
+    def _param(self, value: Any) -> str:
+        self.params.append(value)
+        return "?"
+
+    def _param_list(self, values: Sequence[Any]) -> str:
+        return ", ".join(self._param(value) for value in values)
+
+    def _each(self, document: str, key: str) -> tuple[str, str]:
+        """Return a `FROM` list yielding every value at `key`, and the alias holding them."""
+        sources: list[str] = []
+        alias = ""
+        for segment in key.split("[]"):
+            alias = f"j{next(self._aliases)}"
+            sources.append(f"json_each({document}, {self._param(_json_path(segment))}) AS {alias}")
+            document = f"{alias}.value"
+        return ", ".join(sources), alias
+
+    def _exists(self, document: str, key: str, predicate: Callable[[str], str]) -> str:
+        sources, alias = self._each(document, key)
+        return f"EXISTS (SELECT 1 FROM {sources} WHERE {predicate(f'{alias}.value')})"
+
+    def _match(self, document: str, key: str, match: Match) -> str:
+        match match:
+            case MatchValue(value=value):
+                return self._exists(document, key, lambda v: f"{v} = {self._param(value)}")
+            case MatchAny(any=values):
+                return self._exists(document, key, lambda v: f"{v} IN ({self._param_list(values)})")
+            case MatchExcept(except_=values):
+                return self._exists(
+                    document, key, lambda v: f"{v} NOT IN ({self._param_list(values or ())})"
+                )
+            case MatchText(text=text) | MatchPhrase(phrase=text):
+                return self._exists(
+                    document, key, lambda v: f"instr(lower({v}), lower({self._param(text)})) > 0"
+                )
+        raise ValueError(f"Unsupported match condition for {key}: {match!r}")
+
+    def _range(self, document: str, key: str, bounds: RangeInterface) -> str:
+        def predicate(value: str) -> str:
+            comparisons: list[str] = []
+            for name, operator in _RANGE_OPERATORS:
+                if (bound := getattr(bounds, name)) is None:
+                    continue
+                if isinstance(bounds, DatetimeRange):
+                    comparisons.append(
+                        f"julianday({value}) {operator} julianday({self._param(bound.isoformat())})"
+                    )
+                else:
+                    comparisons.append(f"{value} {operator} {self._param(bound)}")
+            if not isinstance(bounds, DatetimeRange):
+                comparisons.insert(0, f"typeof({value}) IN ('integer', 'real')")
+            return " AND ".join(comparisons) or "1"
+
+        return self._exists(document, key, predicate)
+
+    def _values_count(self, document: str, key: str, count: ValuesCount) -> str:
+        def counted() -> str:
+            # Each bound gets its own subquery, so its JSON path parameters come before the bound's
+            sources, _ = self._each(document, key)
+            return f"(SELECT count(*) FROM {sources})"
+
+        return (
+            " AND ".join(
+                f"{counted()} {operator} {self._param(bound)}"
+                for name, operator in _RANGE_OPERATORS
+                if (bound := getattr(count, name)) is not None
+            )
+            or "1"
+        )
+
+    def _is_empty(self, document: str, key: str) -> str:
+        return f"NOT {self._exists(document, key, lambda v: f'{v} IS NOT NULL')}"
+
+    def _is_null(self, document: str, key: str) -> str:
+        return self._exists(document, key, lambda v: f"{v} IS NULL")
+
+    def _field(self, document: str, condition: FieldCondition) -> str:
+        key = condition.key
+        if condition.geo_bounding_box or condition.geo_radius or condition.geo_polygon:
+            raise ValueError(f"Geo conditions aren't supported in SQLite filters ({key})")
+        clauses: list[str] = []
+        if condition.match is not None:
+            clauses.append(self._match(document, key, condition.match))
+        if condition.range is not None:
+            clauses.append(self._range(document, key, condition.range))
+        if condition.values_count is not None:
+            clauses.append(self._values_count(document, key, condition.values_count))
+        if condition.is_empty is not None:
+            empty = self._is_empty(document, key)
+            clauses.append(empty if condition.is_empty else f"NOT ({empty})")
+        if condition.is_null is not None:
+            null = self._is_null(document, key)
+            clauses.append(null if condition.is_null else f"NOT ({null})")
+        return " AND ".join(f"({clause})" for clause in clauses) or "1"
+
+    def _condition(self, document: str, condition: Condition) -> str:
+        match condition:
+            case FieldCondition():
+                return self._field(document, condition)
+            case IsEmptyCondition(is_empty=field):
+                return self._is_empty(document, field.key)
+            case IsNullCondition(is_null=field):
+                return self._is_null(document, field.key)
+            case HasIdCondition(has_id=ids):
+                return f"{self._id_column} IN ({self._param_list([str(id_) for id_ in ids])})"
+            case NestedCondition(nested=nested):
+                sources, alias = self._each(document, nested.key)
+                inner = self.compile(nested.filter, f"{alias}.value")
+                return f"EXISTS (SELECT 1 FROM {sources} WHERE {inner})"
+            case Filter():
+                return self.compile(condition, document)
+        raise ValueError(f"Unsupported condition in SQLite filters: {condition!r}")
+
+    def compile(self, filter_obj: Filter, document: str) -> str:
+        """Compile a filter over the JSON document expression `document`."""
+        clauses: list[str] = []
+        if must := _as_conditions(filter_obj.must):
+            clauses.extend(self._condition(document, condition) for condition in must)
+        if should := _as_conditions(filter_obj.should):
+            clauses.append(
+                " OR ".join(f"({self._condition(document, condition)})" for condition in should)
+            )
+        if filter_obj.min_should is not None:
+            conditions = _as_conditions(filter_obj.min_should.conditions)
+            matched = " + ".join(
+                f"({self._condition(document, condition)})" for condition in conditions
+            )
+            clauses.append(f"({matched or 0}) >= {self._param(filter_obj.min_should.min_count)}")
+        if must_not := _as_conditions(filter_obj.must_not):
+            clauses.extend(
+                f"NOT ({self._condition(document, condition)})" for condition in must_not
+            )
+        return " AND ".join(f"({clause})" for clause in clauses) or "1"
+
+
+def to_sqlite_filter(
+    filter_obj: Filter | None, *, payload_column: str = "payload", id_column: str = "id"
+) -> SqliteFilter | None:
+    """Compile a Filter to a SQLite `WHERE` clause over a column of JSON payloads.
+
+    This is how the embedded SQLite vector store applies the same filters Qdrant does,
+    inside the database rather than on results.
+
+    Args:
+        filter_obj: The filter to compile.
+        payload_column: The SQL expression holding each point's JSON payload.
+        id_column: The SQL expression holding each point's ID, for `has_id` conditions.
+
+    Returns:
+        The clause and its parameters, or None if there is no filter.
+
+    Raises:
+        ValueError: If the filter uses geo or `has_vector` conditions, which SQLite filters
+            don't support.
+
+    Examples:
+        >>> from codeweaver.providers.vector_stores.search import (
+        ...     Filter,
+        ...     FieldCondition,
+        ...     MatchAny,
+        ... )
+        >>> to_sqlite_filter(
+        ...     Filter(must=[FieldCondition(key="crate", match=MatchAny(any=["core"]))])
+        ... )
+        SqliteFilter(clause='((EXISTS (SELECT 1 FROM json_each(payload, ?) AS j0 WHERE j0.value IN (?))))', params=('$."crate"', 'core'))
+    """
+    if filter_obj is None:
+        return None
+    compiler = _SqliteFilterCompiler(id_column)
+    clause = compiler.compile(filter_obj, payload_column)
+    return SqliteFilter(clause, tuple(compiler.params))
+
+
+__all__ = (
+    "ArbitraryFilter",
+    "SqliteFilter",
+    "make_filter",
+    "make_indexes",
+    "to_qdrant_filter",
+    "to_sqlite_filter",
+)
@@ -0,0 +1,447 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Embedded vector store provider backed by a single SQLite file.
+
+For projects where running a Qdrant server is more than the job needs, this provider keeps the
+index in one database file under the user state directory. It supports the same search
+strategies as the Qdrant providers: dense, sparse and hybrid (the two fused with the query's
+fusion strategy). When the sparse side is IDF (BM25), scoring comes from SQLite's FTS5 index over
+the chunk text, which is what Qdrant does server-side for BM25 collections.
+
+Dense search is exact for collections up to `client_options.exact_search_points` chunks (20,000
+by default). Larger collections, like a monorepo's, get an approximate IVF index in the same
+database file, so a query scans a small share of the vectors rather than all of them.
+"""
+
+from __future__ import annotations
+
+import logging
+
+from collections.abc import Iterable, Sequence
+from pathlib import Path
+from typing import ClassVar, Literal, cast
+from uuid import UUID
+
+from pydantic import UUID7
+
+from codeweaver.core import (
+    INJECTED,
+    CodeChunk,
+    CodeWeaverSparseEmbedding,
+    Provider,
+    ProviderError,
+    ResolvedProjectNameDep,
+    SearchResult,
+    StrategizedQuery,
+)
+from codeweaver.core.constants import DEFAULT_VECTOR_STORE_MAX_RESULTS
+from codeweaver.providers.config import SqliteVectorStoreProviderSettings
+from codeweaver.providers.types.embedding import inject_embedding_settings
+from codeweaver.providers.types.vector_store import CollectionMetadata, HybridVectorPayload
+from codeweaver.providers.vector_stores.base import MixedQueryInput, VectorStoreProvider
+from codeweaver.providers.vector_stores.search import Filter
+from codeweaver.providers.vector_stores.search.filter_factory import (
+    SqliteFilter,
+    to_sqlite_filter,
+)
+from codeweaver.providers.vector_stores.sqlite_client import (
+    SQLITE_MEMORY_LOCATION,
+    ScoredPoint,
+    SqliteCollectionInfo,
+    SqlitePoint,
+    SqliteVectorClient,
+)
+
+
+logger = logging.getLogger(__name__)
+
+
+def _project_name(name: ResolvedProjectNameDep = INJECTED) -> str:
+    """Return the resolved project name."""
+    return name
+
+
+def _as_list(values: Iterable[float] | float) -> list:
+    return list(values) if isinstance(values, Iterable) else [values]
+
+
+class SqliteVectorStoreProvider(VectorStoreProvider[SqliteVectorClient]):
+    """Embedded vector store provider that needs no server.
+
+    Stores chunks, their dense and sparse vectors, and their text in one SQLite database.
+    """
+
+    client: SqliteVectorClient
+    config: SqliteVectorStoreProviderSettings
+    _provider: ClassVar[Literal[Provider.SQLITE]] = Provider.SQLITE
+
+    @property
+    def base_url(self) -> str | None:
+        """Get the database file as a `file://` URL (None for an in-memory database)."""
+        if self.client.location == SQLITE_MEMORY_LOCATION:
+            return None
+        return Path(self.client.location).resolve().as_uri()
+
+    @property
+    def collection_name(self) -> str:
+        """Get the collection name."""
+        # we ensure it's set when the config initializes
+        return cast(str, self.config.collection.collection_name)
+
+    @property
+    def collection(self) -> str | None:
+        """Name of the configured collection."""
+        return self.collection_name
+
+    def _telemetry_keys(self) -> None:
+        return None
+
+    def _create_metadata(self) -> CollectionMetadata:
+        """Describe the collection as this provider would create it."""
+        return CollectionMetadata.model_construct(
+            provider=self._provider.variable,
+            project_name=_project_name(),
+            collection_name=self.collection_name,
+            dense_model=self.embedding_capabilities.dense_model,
+            sparse_model=self.embedding_capabilities.sparse_model,
+        )
+
+    async def _initialize(self) -> None:
+        """Create the collection if it doesn't exist yet."""
+        await self._ensure_collection()
+
+    async def _ensure_collection(self) -> None:
+        if self.collection_name in self._known_collections:
+            return
+        metadata = self._create_metadata().model_dump(mode="json", exclude_none=True)
+        if await self.client.create_collection(self.collection_name, metadata):
+            logger.info("Created collection '%s' in %s", self.collection_name, self.client.location)
+        self._known_collections.add(self.collection_name)
+
+    async def collection_info(self) -> CollectionMetadata | None:
+        """Get the collection metadata."""
+        info = await self.get_collection(self.collection_name)
+        return CollectionMetadata.model_validate(info.metadata) if info.metadata else None
+
+    async def get_collection(self, collection_name: str) -> SqliteCollectionInfo:
+        """Get a collection's details (name, points count and metadata).
+
+        Raises:
+            ProviderError: If the collection doesn't exist.
+        """
+        try:
+            return await self.client.get_collection(collection_name)
+        except KeyError as e:
+            raise ProviderError(
+                f"Failed to get collection '{collection_name}': {e}",
+                details={"collection": collection_name, "error": str(e)},
+            ) from e
+
+    async def list_collections(self) -> list[str] | None:
+        """List all collections in the database."""
+        try:
+            return await self.client.get_collections()
+        except Exception as e:
+            raise ProviderError(f"Failed to list collections: {e}") from e
+
+    async def delete_collection(self, collection_name: str | None = None) -> bool:
+        """Delete a collection and everything in it.
+
+        Args:
+            collection_name: Name of collection to delete. If None, uses configured collection.
+
+        Returns:
+            True if the collection was deleted, False if it didn't exist.
+        """
+        target_collection = collection_name or self.collection_name
+        deleted = await self.client.delete_collection(target_collection)
+        self._known_collections.discard(target_collection)
+        if deleted:
+            logger.info("Successfully deleted collection '%s'", target_collection)
+        else:
+            logger.info("Collection '%s' does not exist, nothing to delete", target_collection)
+        return deleted
+
+    async def search(
+        self, vector: StrategizedQuery | MixedQueryInput, query_filter: Filter | None = None
+    ) -> list[SearchResult]:
+        """Search for similar chunks using dense, sparse, or hybrid search.
+
+        Args:
+            vector: Query vector (StrategizedQuery or list of floats/ints or dict for hybrid).
+            query_filter: Optional filter for search results, applied in the database.
+
+        Returns:
+            List of search results sorted by relevance score.
+
+        Raises:
+            ProviderError: The search failed, or the filter uses a condition SQLite can't
+                evaluate (geo and has_vector conditions).
+        """
+        await self._ensure_collection()
+        strategized_vector = self._normalize_vector_input(vector)
+        try:
+            sqlite_filter = to_sqlite_filter(
+                query_filter, payload_column="p.payload", id_column="p.id"
+            )
+            return await self._execute_search_query(strategized_vector, sqlite_filter)
+        except Exception as e:
+            raise ProviderError(f"Search operation failed: {e}") from e
+
+    async def _search_dense(
+        self, vector: StrategizedQuery, query_filter: SqliteFilter | None
+    ) -> list[ScoredPoint]:
+        if not vector.dense:
+            return []
+        return await self.client.search_dense(
+            self.collection_name,
+            "primary",
+            _as_list(vector.dense),
+            metric=self.distance_metric,
+            query_filter=query_filter,
+            limit=DEFAULT_VECTOR_STORE_MAX_RESULTS,
+        )
+
+    async def _search_sparse(
+        self, vector: StrategizedQuery, query_filter: SqliteFilter | None
+    ) -> list[ScoredPoint]:
+        # BM25 collections are scored from the text, like Qdrant does for them
+        if self.caps.idf is not None and self.client.has_text_index:
+            return await self.client.search_text(
+                self.collection_name,
+                vector.query,
+                query_filter=query_filter,
+                limit=DEFAULT_VECTOR_STORE_MAX_RESULTS,
+            )
+        if not isinstance(sparse := vector.sparse, CodeWeaverSparseEmbedding):
+            return []
+        return await self.client.search_sparse(
+            self.collection_name,
+            "sparse",
+            _as_list(sparse.indices),
+            _as_list(sparse.values),
+            query_filter=query_filter,
+            limit=DEFAULT_VECTOR_STORE_MAX_RESULTS,
+        )
+
+    async def _execute_search_query(
+        self, vector: StrategizedQuery, query_filter: SqliteFilter | None
+    ) -> list[SearchResult]:
+        """Run the search the query's strategy calls for."""
+        if vector.is_hybrid():
+            return self._fuse(
+                vector,
+                await self._search_dense(vector, query_filter),
+                await self._search_sparse(vector, query_filter),
+            )
+        if vector.dense:
+            points = await self._search_dense(vector, query_filter)
+        else:
+            points = await self._search_sparse(vector, query_filter)
+        return self._convert_search_results(points, vector)
+
+    def _fuse(
+        self,
+        vector: StrategizedQuery,
+        dense: Sequence[ScoredPoint],
+        sparse: Sequence[ScoredPoint],
+    ) -> list[SearchResult]:
+        """Combine the dense and sparse rankings with the query's fusion strategy."""
+        points = {point.id: point for point in (*sparse, *dense)}
+        fused = vector.fusion.fuse(
+            [(point.id, point.score) for point in dense],
+            [(point.id, point.score) for point in sparse],
+            limit=DEFAULT_VECTOR_STORE_MAX_RESULTS,
+        )
+        results = self._convert_search_results([points[score.key] for score in fused], vector)
+        for result, score in zip(results, fused, strict=True):
+            result.score = score.score
+            result.dense_score = score.dense_score
+            result.sparse_score = score.sparse_score
+        return results
+
+    def _convert_search_results(
+        self, points: Sequence[ScoredPoint], vector: StrategizedQuery
+    ) -> list[SearchResult]:
+        """Convert scored points to SearchResult objects."""
+        search_results: list[SearchResult] = []
+        for point in points:
+            payload = HybridVectorPayload.model_validate(point.payload)
+            search_results.append(
+                SearchResult.model_construct(
+                    content=payload.chunk,
+                    file_path=Path(payload.file_path) if payload.file_path else None,
+                    score=point.score,
+                    metadata={"query": vector.query, "strategy": vector.strategy},
+                )
+            )
+        return search_results
+
+    async def _chunk_to_point(self, chunk: CodeChunk) -> SqlitePoint:
+        """Convert a code chunk, with its registered embeddings, to a point."""
+        chunk_embeddings = (await self._get_registry()).get(chunk.chunk_id)
+        if chunk_embeddings is None:
+            raise ProviderError(
+                f"No embeddings found in registry for chunk {chunk.chunk_id}. "
+                "Embeddings must be registered before upserting chunks."
+            )
+        dense: dict[str, list[float]] = {}
+        sparse: dict[str, tuple[list[int], list[float]]] = {}
+        for intent in chunk.embeddings:
+            embedding_info = chunk_embeddings.embeddings.get(intent)
+            if embedding_info is None:
+                raise ProviderError(
+                    f"No embedding found for intent '{intent}' in chunk {chunk.chunk_id}"
+                )
+            if embedding_info.is_dense:
+                dense[intent] = list(embedding_info.embeddings)
+            elif embedding_info.is_sparse and isinstance(
+                embedding_info.embeddings, CodeWeaverSparseEmbedding
+            ):
+                sparse[intent] = (
+                    _as_list(embedding_info.embeddings.indices),
+                    _as_list(embedding_info.embeddings.values),
+                )
+        payload = self._create_payload(chunk)
+        return SqlitePoint(
+            id=chunk.chunk_id.hex,
+            payload=payload.model_dump(mode="json", exclude_none=True, round_trip=True),
+            dense=dense,
+            sparse=sparse,
+            text=chunk.content,
+            file_path=payload.file_path,
+            chunk_name=chunk.chunk_name,
+        )
+
+    async def upsert(self, chunks: list[CodeChunk]) -> None:
+        """Insert or update code chunks with their embeddings, in one transaction.
+
+        Args:
+            chunks: List of code chunks with embeddings to store.
+        """
+        if not chunks:
+            return
+        await self._ensure_collection()
+        points = [await self._chunk_to_point(chunk) for chunk in chunks]
+        await self.client.upsert(self.collection_name, points, metric=self.distance_metric)
+
+    async def delete_by_file(self, file_path: Path) -> None:
+        """Delete all chunks for a specific file.
+
+        Args:
+            file_path: File path to remove from index.
+        """
+        await self.delete_by_files([file_path])
+
+    async def delete_by_files(
+        self, file_paths: list[Path], *, keep_generation: str | None = None
+    ) -> None:
+        """Delete all chunks for multiple files in a single operation.
+
+        Args:
+            file_paths: List of file paths to remove from index.
+            keep_generation: Keep the files' chunks stored in this index generation.
+        """
+        await self._ensure_collection()
+        await self.client.delete(
+            self.collection_name,
+            file_paths=[str(p) for p in file_paths],
+            keep_generation=keep_generation,
+        )
+
+    async def delete_by_id(self, ids: list[UUID7]) -> None:
+        """Delete chunks by their unique identifiers.
+
+        Args:
+            ids: List of chunk IDs to delete.
+        """
+        if not ids:
+            return
+        await self._ensure_collection()
+        await self.client.delete(self.collection_name, ids=[id_.hex for id_ in ids])
+
+    async def delete_by_name(self, names: list[str]) -> None:
+        """Delete chunks by their unique names.
+
+        Args:
+            names: List of chunk names to delete.
+        """
+        await self._ensure_collection()
+        await self.client.delete(self.collection_name, chunk_names=names)
+
+    async def copy_files_from(self, collection_name: str, file_paths: list[Path]) -> int:
+        """Copy the chunks of files from another collection, vectors and all.
+
+        Args:
+            collection_name: The collection to copy from.
+            file_paths: Files whose chunks to copy, relative to the project root.
+
+        Returns:
+            The number of chunks copied.
+        """
+        await self._ensure_collection()
+        return await self.client.copy(
+            collection_name, self.collection_name, file_paths=[str(p) for p in file_paths]
+        )
+
+    async def update_payloads(self, chunks: list[CodeChunk]) -> list[UUID7]:
+        """Replace the payloads of stored chunks, keeping their vectors.
+
+        Payloads are replaced, so fields a chunk no longer has are cleared; only the stored
+        `embedding_complete` is kept, since unembedded copies don't carry batch keys.
+
+        Args:
+            chunks: Chunks already in the collection, with their new content, line ranges and
+                paths.
+
+        Returns:
+            The IDs of chunks that aren't in the collection, which are left out.
+        """
+        if not chunks:
+            return []
+        await self._ensure_collection()
+        missing = await self.client.update_payloads(
+            self.collection_name,
+            {
+                chunk.chunk_id.hex: self._create_payload(chunk).model_dump(
+                    mode="json", exclude_none=True, round_trip=True, exclude={"embedding_complete"}
+                )
+                for chunk in chunks
+            },
+            keep=("embedding_complete",),
+        )
+        return [UUID(point_id) for point_id in missing]
+
+    async def get_chunks(self, ids: list[UUID7]) -> list[CodeChunk]:
+        """Get stored chunks by ID, as their payloads describe them.
+
+        Args:
+            ids: Chunk IDs to get; IDs that aren't stored are skipped.
+
+        Returns:
+            The stored chunks, in no particular order.
+        """
+        if not ids:
+            return []
+        await self._ensure_collection()
+        points = await self.client.retrieve(
+            self.collection_name, [chunk_id.hex for chunk_id in ids]
+        )
+        return [HybridVectorPayload.model_validate(point.payload).chunk for point in points]
+
+    async def handle_persistence(self) -> None:
+        """Do nothing; every write is committed to the database file as it happens."""
+
+    async def close(self) -> None:
+        """Close the database."""
+        await self.client.close()
+
+
+# Ensure the model is fully defined for Pydantic
+inject_embedding_settings()
+SqliteVectorStoreProvider.model_rebuild()
+
+
+__all__ = ("SqliteVectorStoreProvider",)
@@ -0,0 +1,969 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Storage engine for the embedded SQLite vector store.
+
+`SqliteVectorClient` plays the part `AsyncQdrantClient` plays for the Qdrant providers: it owns
+the database and knows nothing about chunks or embeddings providers. One database file holds
+any number of collections. Each point has a JSON payload, any number of named dense vectors
+(float32 blobs), named sparse vectors (stored as postings, one row per term), and its text in an
+FTS5 table that stands in for BM25 when the collection uses IDF sparse embeddings.
+
+Dense search is an exact scan, streamed in batches so memory stays flat, until a collection
+holds more than the client's `exact_search_points`. Past that, its dense vectors get an inverted
+file (IVF) index: k-means centroids split the vectors into lists, and a query scans only the
+lists whose centroids are closest to it. The index is retrained whenever the collection doubles,
+and new vectors are filed in their nearest list as they're written. Filters are compiled to SQL
+(see `to_sqlite_filter`) and applied in the same query. Every write is one transaction, so a
+batch is all-or-nothing.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import heapq
+import json
+import logging
+import math
+import re
+import sqlite3
+import threading
+
+from collections import defaultdict
+from collections.abc import Callable, Collection, Iterable, Iterator, Mapping, Sequence
+from datetime import UTC, datetime
+from pathlib import Path
+from typing import Any, Literal, NamedTuple
+
+import numpy as np
+
+from codeweaver.core.constants import DEFAULT_SQLITE_EXACT_SEARCH_POINTS
+from codeweaver.providers.vector_stores.search.filter_factory import SqliteFilter
+
+
+logger = logging.getLogger(__name__)
+
+SQLITE_MEMORY_LOCATION = ":memory:"
+
+DENSE_SCAN_BATCH_SIZE = 4096
+"""Dense vectors are scored this many at a time."""
+
+DENSE_INDEX_MAX_LISTS = 2048
+"""The most lists an IVF index splits a collection's vectors into."""
+
+DENSE_INDEX_SAMPLE_PER_LIST = 24
+"""How many vectors per list are sampled to train an IVF index's centroids."""
+
+DENSE_INDEX_TRAINING_ITERATIONS = 8
+"""k-means iterations when training an IVF index."""
+
+DENSE_INDEX_PROBE_RATIO = 16
+"""A query scans one in this many of an IVF index's lists (and at least 8)."""
+
+_SCHEMA = """
+CREATE TABLE IF NOT EXISTS collections (
+    name TEXT PRIMARY KEY,
+    metadata TEXT,
+    created_at TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS points (
+    rowid INTEGER PRIMARY KEY,
+    collection TEXT NOT NULL REFERENCES collections (name) ON DELETE CASCADE,
+    id TEXT NOT NULL,
+    file_path TEXT,
+    chunk_name TEXT,
+    payload TEXT NOT NULL,
+    UNIQUE (collection, id)
+);
+CREATE INDEX IF NOT EXISTS points_file_path ON points (collection, file_path);
+CREATE INDEX IF NOT EXISTS points_chunk_name ON points (collection, chunk_name);
+CREATE TABLE IF NOT EXISTS dense_vectors (
+    point INTEGER NOT NULL REFERENCES points (rowid) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    vector BLOB NOT NULL,
+    list INTEGER,
+    PRIMARY KEY (point, name)
+);
+CREATE INDEX IF NOT EXISTS dense_vectors_list ON dense_vectors (name, list);
+CREATE TABLE IF NOT EXISTS dense_indexes (
+    collection TEXT NOT NULL REFERENCES collections (name) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    metric TEXT NOT NULL,
+    trained_points INTEGER NOT NULL,
+    dimension INTEGER NOT NULL,
+    centroids BLOB NOT NULL,
+    PRIMARY KEY (collection, name)
+);
+CREATE TABLE IF NOT EXISTS sparse_postings (
+    point INTEGER NOT NULL REFERENCES points (rowid) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    term INTEGER NOT NULL,
+    weight REAL NOT NULL
+);
+CREATE INDEX IF NOT EXISTS sparse_postings_term ON sparse_postings (name, term);
+CREATE INDEX IF NOT EXISTS sparse_postings_point ON sparse_postings (point);
+"""
+
+_TEXT_SCHEMA = "CREATE VIRTUAL TABLE IF NOT EXISTS point_texts USING fts5 (content)"
+
+_WORD = re.compile(r"\w+")
+
+type DistanceMetric = Literal["cosine", "dot", "euclidean", "manhattan"]
+
+
+class SqlitePoint(NamedTuple):
+    """A point to store."""
+
+    id: str
+    payload: Mapping[str, Any]
+    dense: Mapping[str, Sequence[float]] = {}
+    """Dense vectors by name."""
+    sparse: Mapping[str, tuple[Sequence[int], Sequence[float]]] = {}
+    """Sparse vectors by name, as (indices, values)."""
+    text: str | None = None
+    """Text for full-text (BM25) search."""
+    file_path: str | None = None
+    chunk_name: str | None = None
+
+
+class SqliteCollectionInfo(NamedTuple):
+    """Details of a collection."""
+
+    name: str
+    points_count: int
+    metadata: dict[str, Any] | None
+    created_at: datetime
+
+
+class DenseIndex(NamedTuple):
+    """An IVF index over a collection's named dense vectors."""
+
+    metric: DistanceMetric
+    trained_points: int
+    """How many points the collection had when the index was trained."""
+    centroids: np.ndarray
+    """One row per list."""
+
+
+class ScoredPoint(NamedTuple):
+    """A point and how well it matched."""
+
+    id: str
+    payload: dict[str, Any]
+    score: float
+
+
+def _as_blob(vector: Sequence[float]) -> bytes:
+    return np.asarray(vector, dtype=np.float32).tobytes()
+
+
+def _fts_query(text: str) -> str | None:
+    """An FTS5 query matching any word of `text`, with every word quoted."""
+    words = dict.fromkeys(word.lower() for word in _WORD.findall(text))
+    return " OR ".join(f'"{word}"' for word in words) or None
+
+
+def _similarities(matrix: np.ndarray, query: np.ndarray, metric: DistanceMetric) -> np.ndarray:
+    """Score rows against a query; higher is more similar for every metric."""
+    match metric:
+        case "cosine":
+            norms = np.linalg.norm(matrix, axis=1) * np.linalg.norm(query)
+            return np.divide(matrix @ query, norms, out=np.zeros(len(matrix)), where=norms > 0)
+        case "dot":
+            return matrix @ query
+        case "euclidean":
+            return 1 / (1 + np.linalg.norm(matrix - query, axis=1))
+        case "manhattan":
+            return 1 / (1 + np.abs(matrix - query).sum(axis=1))
+
+
+def _normalized(matrix: np.ndarray) -> np.ndarray:
+    norms = np.linalg.norm(matrix, axis=1, keepdims=True)
+    return np.divide(matrix, norms, out=np.zeros_like(matrix), where=norms > 0)
+
+
+def _list_scores(matrix: np.ndarray, centroids: np.ndarray, metric: DistanceMetric) -> np.ndarray:
+    """Score rows against every centroid (rows by lists); higher is closer.
+
+    Angular metrics compare directions, so a centroid's norm doesn't pull vectors toward it;
+    the others use squared Euclidean distance (less each row's own norm, which doesn't change
+    the ranking).
+    """
+    if metric in ("cosine", "dot"):
+        return _normalized(matrix) @ _normalized(centroids).T
+    return 2 * (matrix @ centroids.T) - (centroids**2).sum(axis=1)
+
+
+def _nearest_lists(matrix: np.ndarray, centroids: np.ndarray, metric: DistanceMetric) -> np.ndarray:
+    """The list each row belongs in."""
+    batches = (
+        _list_scores(matrix[start : start + DENSE_SCAN_BATCH_SIZE], centroids, metric)
+        for start in range(0, len(matrix), DENSE_SCAN_BATCH_SIZE)
+    )
+    return np.concatenate([scores.argmax(axis=1) for scores in batches])
+
+
+def _train_centroids(sample: np.ndarray, lists: int, metric: DistanceMetric) -> np.ndarray:
+    """Cluster a sample of vectors into `lists` centroids with k-means."""
+    rng = np.random.default_rng(0)
+    centroids = sample[rng.choice(len(sample), lists, replace=False)].copy()
+    for _ in range(DENSE_INDEX_TRAINING_ITERATIONS):
+        assignments = _nearest_lists(sample, centroids, metric)
+        counts = np.bincount(assignments, minlength=lists)
+        starts = np.concatenate(([0], np.cumsum(counts)[:-1]))
+        filled = counts > 0
+        # Sorted by list, each filled list's vectors are one run; empty lists keep their centroid
+        sums = np.add.reduceat(sample[np.argsort(assignments, kind="stable")], starts[filled])
+        centroids[filled] = sums / counts[filled, None]
+    return centroids
+
+
+class SqliteVectorClient:
+    """An embedded vector database in a single SQLite file.
+
+    Methods are async and run their queries in a worker thread; the connection is shared and
+    guarded by a lock, so the client is safe to use from any task.
+    """
+
+    def __init__(
+        self,
+        path: str | Path | None = None,
+        *,
+        timeout: float = 30.0,
+        exact_search_points: int = DEFAULT_SQLITE_EXACT_SEARCH_POINTS,
+        **kwargs: Any,  # noqa: ARG002 - accepts unused client options
+    ) -> None:
+        """Open (or create) the database.
+
+        Args:
+            path: Database file; None or `:memory:` for a throwaway in-memory database
+            timeout: Seconds to wait for another process's write lock
+            exact_search_points: Collections with more points than this get an IVF index over
+                their dense vectors instead of an exact scan
+        """
+        location = str(path) if path is not None else SQLITE_MEMORY_LOCATION
+        self._exact_search_points = exact_search_points
+        if location != SQLITE_MEMORY_LOCATION:
+            Path(location).parent.mkdir(parents=True, exist_ok=True)
+        self._location = location
+        self._lock = threading.Lock()
+        self._connection = sqlite3.connect(location, timeout=timeout, check_same_thread=False)
+        self._connection.execute("PRAGMA foreign_keys = ON")
+        if location != SQLITE_MEMORY_LOCATION:
+            self._connection.execute("PRAGMA journal_mode = WAL")
+        self._connection.executescript(_SCHEMA)
+        try:
+            self._connection.execute(_TEXT_SCHEMA)
+            self._has_text_index = True
+        except sqlite3.OperationalError as e:
+            logger.info("SQLite was built without FTS5; BM25 text search is unavailable: %s", e)
+            self._has_text_index = False
+        self._connection.commit()
+
+    @property
+    def location(self) -> str:
+        """The database file, or `:memory:`."""
+        return self._location
+
+    @property
+    def has_text_index(self) -> bool:
+        """Whether full-text (BM25) search is available."""
+        return self._has_text_index
+
+    async def _run[T](self, operation: Callable[[sqlite3.Connection], T], *, write: bool) -> T:
+        def run() -> T:
+            with self._lock:
+                if not write:
+                    return operation(self._connection)
+                with self._connection:
+                    return operation(self._connection)
+
+        return await asyncio.to_thread(run)
+
+    # ---- collections ----
+
+    async def collection_exists(self, collection_name: str) -> bool:
+        """Check whether a collection exists."""
+        return await self._run(
+            lambda db: db.execute(
+                "SELECT 1 FROM collections WHERE name = ?", (collection_name,)
+            ).fetchone()
+            is not None,
+            write=False,
+        )
+
+    async def get_collections(self) -> list[str]:
+        """List the collections in the database."""
+        return await self._run(
+            lambda db: [
+                name for (name,) in db.execute("SELECT name FROM collections ORDER BY name")
+            ],
+            write=False,
+        )
+
+    async def create_collection(
+        self, collection_name: str, metadata: Mapping[str, Any] | None = None
+    ) -> bool:
+        """Create a collection if it doesn't exist.
+
+        Returns:
+            True if the collection was created, False if it already existed.
+        """
+
+        def create(db: sqlite3.Connection) -> bool:
+            cursor = db.execute(
+                "INSERT OR IGNORE INTO collections (name, metadata, created_at) VALUES (?, ?, ?)",
+                (
+                    collection_name,
+                    json.dumps(metadata) if metadata is not None else None,
+                    datetime.now(UTC).isoformat(),
+                ),
+            )
+            return cursor.rowcount > 0
+
+        return await self._run(create, write=True)
+
+    async def update_collection(self, collection_name: str, metadata: Mapping[str, Any]) -> None:
+        """Replace a collection's metadata."""
+        await self._run(
+            lambda db: db.execute(
+                "UPDATE collections SET metadata = ? WHERE name = ?",
+                (json.dumps(metadata), collection_name),
+            ),
+            write=True,
+        )
+
+    async def get_collection(self, collection_name: str) -> SqliteCollectionInfo:
+        """Get a collection's details.
+
+        Raises:
+            KeyError: If the collection doesn't exist.
+        """
+
+        def get(db: sqlite3.Connection) -> SqliteCollectionInfo:
+            row = db.execute(
+                "SELECT metadata, created_at FROM collections WHERE name = ?", (collection_name,)
+            ).fetchone()
+            if row is None:
+                raise KeyError(f"Collection {collection_name!r} doesn't exist")
+            (count,) = db.execute(
+                "SELECT count(*) FROM points WHERE collection = ?", (collection_name,)
+            ).fetchone()
+            return SqliteCollectionInfo(
+                collection_name,
+                count,
+                json.loads(row[0]) if row[0] else None,
+                datetime.fromisoformat(row[1]),
+            )
+
+        return await self._run(get, write=False)
+
+    async def delete_collection(self, collection_name: str) -> bool:
+        """Delete a collection and its points.
+
+        Returns:
+            True if the collection existed.
+        """
+
+        def delete(db: sqlite3.Connection) -> bool:
+            self._delete_texts(
+                db,
+                "SELECT rowid FROM points WHERE collection = ?",
+                (collection_name,),
+            )
+            return (
+                db.execute("DELETE FROM collections WHERE name = ?", (collection_name,)).rowcount
+                > 0
+            )
+
+        return await self._run(delete, write=True)
+
+    # ---- points ----
+
+    def _delete_texts(self, db: sqlite3.Connection, selection: str, params: Sequence[Any]) -> None:
+        """Delete the full-text rows of the points a `SELECT rowid ...` query selects."""
+        if self._has_text_index:
+            db.execute(f"DELETE FROM point_texts WHERE rowid IN ({selection})", params)  # noqa: S608
+
+    def _delete_where(self, db: sqlite3.Connection, where: str, params: Sequence[Any]) -> int:
+        self._delete_texts(db, f"SELECT rowid FROM points WHERE {where}", params)  # noqa: S608
+        return db.execute(f"DELETE FROM points WHERE {where}", params).rowcount  # noqa: S608
+
+    async def upsert(
+        self,
+        collection_name: str,
+        points: Sequence[SqlitePoint],
+        *,
+        metric: DistanceMetric = "cosine",
+    ) -> None:
+        """Insert points, replacing any with the same IDs, in one transaction.
+
+        Args:
+            collection_name: The points' collection.
+            points: The points to store.
+            metric: The metric the collection's dense vectors are searched with, used to train
+                their IVF index once the collection outgrows an exact scan.
+        """
+
+        def upsert(db: sqlite3.Connection) -> None:
+            for point in points:
+                self._delete_where(db, "collection = ? AND id = ?", (collection_name, point.id))
+                rowid = db.execute(
+                    "INSERT INTO points (collection, id, file_path, chunk_name, payload)"
+                    " VALUES (?, ?, ?, ?, ?)",
+                    (
+                        collection_name,
+                        point.id,
+                        point.file_path,
+                        point.chunk_name,
+                        json.dumps(point.payload),
+                    ),
+                ).lastrowid
+                db.executemany(
+                    "INSERT INTO dense_vectors (point, name, vector) VALUES (?, ?, ?)",
+                    [(rowid, name, _as_blob(vector)) for name, vector in point.dense.items()],
+                )
+                db.executemany(
+                    "INSERT INTO sparse_postings (point, name, term, weight) VALUES (?, ?, ?, ?)",
+                    [
+                        (rowid, name, int(term), float(weight))
+                        for name, (indices, values) in point.sparse.items()
+                        for term, weight in zip(indices, values, strict=True)
+                    ],
+                )
+                if self._has_text_index and point.text:
+                    db.execute(
+                        "INSERT INTO point_texts (rowid, content) VALUES (?, ?)",
+                        (rowid, point.text),
+                    )
+            names = {name for point in points for name in point.dense}
+            self._update_dense_indexes(db, collection_name, names, metric)
+
+        await self._run(upsert, write=True)
+
+    async def delete(
+        self,
+        collection_name: str,
+        *,
+        ids: Iterable[str] = (),
+        file_paths: Iterable[str] = (),
+        chunk_names: Iterable[str] = (),
+        keep_generation: str | None = None,
+    ) -> int:
+        """Delete points by ID, file path or chunk name, in one transaction.
+
+        Args:
+            collection_name: The points' collection.
+            ids: Point IDs to delete.
+            file_paths: Delete the points of these files.
+            chunk_names: Delete the points with these chunk names.
+            keep_generation: Keep points whose payload `generation` is this.
+
+        Returns:
+            The number of points deleted.
+        """
+        selectors = [
+            (column, list(values))
+            for column, values in (
+                ("id", ids),
+                ("file_path", file_paths),
+                ("chunk_name", chunk_names),
+            )
+        ]
+        keep_clause, keep_params = (
+            (" AND json_extract(payload, '$.generation') IS NOT ?", (keep_generation,))
+            if keep_generation
+            else ("", ())
+        )
+
+        def delete(db: sqlite3.Connection) -> int:
+            deleted = 0
+            for column, values in selectors:
+                for start in range(0, len(values), 500):
+                    batch = values[start : start + 500]
+                    placeholders = ", ".join("?" * len(batch))
+                    deleted += self._delete_where(
+                        db,
+                        f"collection = ? AND {column} IN ({placeholders}){keep_clause}",
+                        (collection_name, *batch, *keep_params),
+                    )
+            return deleted
+
+        return await self._run(delete, write=True)
+
+    async def copy(
+        self, source_collection: str, target_collection: str, *, file_paths: Iterable[str]
+    ) -> int:
+        """Copy the points of files, vectors and all, from one collection to another.
+
+        Points already in the target collection with the same IDs are replaced.
+
+        Returns:
+            The number of points copied.
+        """
+        paths = list(file_paths)
+
+        def copy(db: sqlite3.Connection) -> int:
+            copied = 0
+            for start in range(0, len(paths), 500):
+                batch = paths[start : start + 500]
+                placeholders = ", ".join("?" * len(batch))
+                rows = db.execute(
+                    "SELECT rowid, id, file_path, chunk_name, payload FROM points"
+                    f" WHERE collection = ? AND file_path IN ({placeholders})",  # noqa: S608
+                    (source_collection, *batch),
+                ).fetchall()
+                for rowid, point_id, file_path, chunk_name, payload in rows:
+                    self._delete_where(
+                        db, "collection = ? AND id = ?", (target_collection, point_id)
+                    )
+                    new_rowid = db.execute(
+                        "INSERT INTO points (collection, id, file_path, chunk_name, payload)"
+                        " VALUES (?, ?, ?, ?, ?)",
+                        (target_collection, point_id, file_path, chunk_name, payload),
+                    ).lastrowid
+                    db.execute(
+                        "INSERT INTO dense_vectors (point, name, vector)"
+                        " SELECT ?, name, vector FROM dense_vectors WHERE point = ?",
+                        (new_rowid, rowid),
+                    )
+                    db.execute(
+                        "INSERT INTO sparse_postings (point, name, term, weight)"
+                        " SELECT ?, name, term, weight FROM sparse_postings WHERE point = ?",
+                        (new_rowid, rowid),
+                    )
+                    if self._has_text_index:
+                        db.execute(
+                            "INSERT INTO point_texts (rowid, content)"
+                            " SELECT ?, content FROM point_texts WHERE rowid = ?",
+                            (new_rowid, rowid),
+                        )
+                    copied += 1
+            self._update_dense_indexes(db, target_collection, (), None)
+            return copied
+
+        return await self._run(copy, write=True) if paths else 0
+
+    async def update_payloads(
+        self,
+        collection_name: str,
+        payloads: Mapping[str, Mapping[str, Any]],
+        *,
+        keep: Collection[str] = (),
+    ) -> list[str]:
+        """Replace points' payloads, keeping their vectors.
+
+        Args:
+            collection_name: The points' collection.
+            payloads: New payloads by point ID. The `file_path` and `chunk_name` columns, and
+                the full-text index, follow the payload's file path and chunk.
+            keep: Payload keys whose stored values are kept over the new payloads'.
+
+        Returns:
+            The IDs of points that aren't stored, which are left out.
+        """
+
+        def update(db: sqlite3.Connection) -> list[str]:
+            missing: list[str] = []
+            for point_id, new_payload in payloads.items():
+                row = db.execute(
+                    "SELECT rowid, payload FROM points WHERE collection = ? AND id = ?",
+                    (collection_name, point_id),
+                ).fetchone()
+                if row is None:
+                    missing.append(point_id)
+                    continue
+                rowid, stored = row[0], json.loads(row[1])
+                payload = dict(new_payload) | {key: stored[key] for key in keep if key in stored}
+                chunk = payload.get("chunk")
+                db.execute(
+                    "UPDATE points SET payload = ?, file_path = ?, chunk_name = ?"
+                    " WHERE collection = ? AND id = ?",
+                    (
+                        json.dumps(payload),
+                        payload.get("file_path"),
+                        chunk.get("chunk_name") if isinstance(chunk, dict) else None,
+                        collection_name,
+                        point_id,
+                    ),
+                )
+                if self._has_text_index and isinstance(chunk, dict) and chunk.get("content"):
+                    db.execute("DELETE FROM point_texts WHERE rowid = ?", (rowid,))
+                    db.execute(
+                        "INSERT INTO point_texts (rowid, content) VALUES (?, ?)",
+                        (rowid, chunk["content"]),
+                    )
+            return missing
+
+        return await self._run(update, write=True) if payloads else []
+
+    async def retrieve(self, collection_name: str, ids: Sequence[str]) -> list[ScoredPoint]:
+        """Get points by ID (with a score of 0)."""
+
+        def retrieve(db: sqlite3.Connection) -> list[ScoredPoint]:
+            placeholders = ", ".join("?" * len(ids))
+            return [
+                ScoredPoint(point_id, json.loads(payload), 0.0)
+                for point_id, payload in db.execute(
+                    f"SELECT id, payload FROM points WHERE collection = ? AND id IN ({placeholders})",  # noqa: S608
+                    (collection_name, *ids),
+                )
+            ]
+
+        return await self._run(retrieve, write=False) if ids else []
+
+    async def scroll(
+        self, collection_name: str, *, query_filter: SqliteFilter | None = None
+    ) -> list[ScoredPoint]:
+        """Get every point in a collection that matches a filter (with a score of 0)."""
+
+        def scroll(db: sqlite3.Connection) -> list[ScoredPoint]:
+            where, params = self._where(collection_name, query_filter)
+            return [
+                ScoredPoint(point_id, json.loads(payload), 0.0)
+                for point_id, payload in db.execute(
+                    f"SELECT p.id, p.payload FROM points AS p WHERE {where} ORDER BY p.rowid",  # noqa: S608
+                    params,
+                )
+            ]
+
+        return await self._run(scroll, write=False)
+
+    # ---- dense indexes ----
+
+    @staticmethod
+    def _dense_index(
+        db: sqlite3.Connection, collection_name: str, vector_name: str
+    ) -> DenseIndex | None:
+        row = db.execute(
+            "SELECT metric, trained_points, dimension, centroids FROM dense_indexes"
+            " WHERE collection = ? AND name = ?",
+            (collection_name, vector_name),
+        ).fetchone()
+        if row is None:
+            return None
+        metric, trained_points, dimension, centroids = row
+        return DenseIndex(
+            metric,
+            trained_points,
+            np.frombuffer(centroids, dtype=np.float32).reshape(-1, dimension),
+        )
+
+    def _update_dense_indexes(
+        self,
+        db: sqlite3.Connection,
+        collection_name: str,
+        vector_names: Iterable[str],
+        metric: DistanceMetric | None,
+    ) -> None:
+        """Train the IVF indexes of vectors that outgrew their exact scan or their index, and
+        file new vectors in the lists of the rest."""
+        (count,) = db.execute(
+            "SELECT count(*) FROM points WHERE collection = ?", (collection_name,)
+        ).fetchone()
+        indexed = {
+            name
+            for (name,) in db.execute(
+                "SELECT name FROM dense_indexes WHERE collection = ?", (collection_name,)
+            )
+        }
+        for vector_name in sorted(indexed | set(vector_names)):
+            index = self._dense_index(db, collection_name, vector_name)
+            if count > (2 * index.trained_points if index else self._exact_search_points):
+                index_metric = index.metric if index else metric or "cosine"
+                self._train_dense_index(db, collection_name, vector_name, index_metric, count)
+            elif index:
+                self._assign_lists(db, collection_name, vector_name, index, only_new=True)
+
+    def _train_dense_index(
+        self,
+        db: sqlite3.Connection,
+        collection_name: str,
+        vector_name: str,
+        metric: DistanceMetric,
+        count: int,
+    ) -> None:
+        """Train an IVF index on a sample of a collection's vectors and file every vector."""
+        rowids = [
+            rowid
+            for (rowid,) in db.execute(
+                "SELECT d.point FROM dense_vectors AS d JOIN points AS p ON p.rowid = d.point"
+                " WHERE p.collection = ? AND d.name = ?",
+                (collection_name, vector_name),
+            )
+        ]
+        lists = min(DENSE_INDEX_MAX_LISTS, max(1, int(2 * math.sqrt(count))), len(rowids))
+        if lists == 0:
+            return
+        rng = np.random.default_rng(0)
+        sampled = rng.choice(
+            rowids, min(len(rowids), DENSE_INDEX_SAMPLE_PER_LIST * lists), replace=False
+        ).tolist()
+        blobs: list[bytes] = []
+        for start in range(0, len(sampled), 500):
+            batch = sampled[start : start + 500]
+            placeholders = ", ".join("?" * len(batch))
+            blobs.extend(
+                blob
+                for (blob,) in db.execute(
+                    f"SELECT vector FROM dense_vectors WHERE name = ? AND point IN ({placeholders})",  # noqa: S608
+                    (vector_name, *batch),
+                )
+            )
+        sample = np.stack([np.frombuffer(blob, dtype=np.float32) for blob in blobs])
+        index = DenseIndex(metric, count, _train_centroids(sample, lists, metric))
+        db.execute(
+            "INSERT OR REPLACE INTO dense_indexes"
+            " (collection, name, metric, trained_points, dimension, centroids)"
+            " VALUES (?, ?, ?, ?, ?, ?)",
+            (
+                collection_name,
+                vector_name,
+                metric,
+                count,
+                index.centroids.shape[1],
+                index.centroids.astype(np.float32).tobytes(),
+            ),
+        )
+        logger.info(
+            "Trained a %d-list IVF index over %s vectors of %d points in %r",
+            lists,
+            vector_name,
+            count,
+            collection_name,
+        )
+        self._assign_lists(db, collection_name, vector_name, index, only_new=False)
+
+    @staticmethod
+    def _assign_lists(
+        db: sqlite3.Connection,
+        collection_name: str,
+        vector_name: str,
+        index: DenseIndex,
+        *,
+        only_new: bool,
+    ) -> None:
+        """File a collection's vectors (or only those in no list yet) in their nearest lists."""
+        unfiled = " AND d.list IS NULL" if only_new else ""
+        last = 0
+        # Page by rowid rather than iterating one cursor, since the pages' rows are updated.
+        # CROSS JOIN keeps SQLite on the (name, list) index instead of reading every point.
+        while rows := db.execute(
+            "SELECT d.point, d.vector FROM dense_vectors AS d"  # noqa: S608
+            " CROSS JOIN points AS p ON p.rowid = d.point"
+            f" WHERE p.collection = ? AND d.name = ?{unfiled} AND d.point > ?"
+            " ORDER BY d.point LIMIT ?",
+            (collection_name, vector_name, last, DENSE_SCAN_BATCH_SIZE),
+        ).fetchall():
+            matrix = np.stack([np.frombuffer(blob, dtype=np.float32) for _, blob in rows])
+            lists = _nearest_lists(matrix, index.centroids, index.metric).tolist()
+            db.executemany(
+                "UPDATE dense_vectors SET list = ? WHERE point = ? AND name = ?",
+                [
+                    (list_id, rowid, vector_name)
+                    for (rowid, _), list_id in zip(rows, lists, strict=True)
+                ],
+            )
+            last = rows[-1][0]
+
+    async def dense_index(self, collection_name: str, vector_name: str) -> DenseIndex | None:
+        """Get the IVF index over a collection's named dense vectors, if it has one."""
+        return await self._run(
+            lambda db: self._dense_index(db, collection_name, vector_name), write=False
+        )
+
+    # ---- search ----
+
+    @staticmethod
+    def _where(
+        collection_name: str, query_filter: SqliteFilter | None
+    ) -> tuple[str, tuple[Any, ...]]:
+        """A `WHERE` clause over `points AS p` for a collection and optional filter."""
+        if query_filter is None:
+            return "p.collection = ?", (collection_name,)
+        return f"p.collection = ? AND {query_filter.clause}", (
+            collection_name,
+            *query_filter.params,
+        )
+
+    @staticmethod
+    def _with_payloads(
+        db: sqlite3.Connection, scores: Sequence[tuple[int, float]]
+    ) -> list[ScoredPoint]:
+        if not scores:
+            return []
+        placeholders = ", ".join("?" * len(scores))
+        rows = {
+            rowid: (point_id, payload)
+            for rowid, point_id, payload in db.execute(
+                f"SELECT rowid, id, payload FROM points WHERE rowid IN ({placeholders})",  # noqa: S608
+                [rowid for rowid, _ in scores],
+            )
+        }
+        return [
+            ScoredPoint(rows[rowid][0], json.loads(rows[rowid][1]), score)
+            for rowid, score in scores
+            if rowid in rows
+        ]
+
+    async def search_dense(
+        self,
+        collection_name: str,
+        vector_name: str,
+        query: Sequence[float],
+        *,
+        metric: DistanceMetric = "cosine",
+        query_filter: SqliteFilter | None = None,
+        limit: int = 10,
+    ) -> list[ScoredPoint]:
+        """Find the points whose named dense vector is most similar to `query`.
+
+        Collections with an IVF index scan only the lists nearest the query, plus vectors not
+        filed in a list yet. If that finds fewer than `limit` points (a narrow filter can leave
+        the probed lists nearly empty), the rest of the collection is scanned too.
+        """
+        query_vector = np.asarray(query, dtype=np.float32)
+
+        def batches(
+            db: sqlite3.Connection, condition: str, condition_params: Sequence[Any]
+        ) -> Iterator[list[tuple[int, bytes]]]:
+            where, params = self._where(collection_name, query_filter)
+            # CROSS JOIN keeps SQLite on the (name, list) index, so only probed lists are read
+            cursor = db.execute(
+                "SELECT d.point, d.vector FROM dense_vectors AS d"  # noqa: S608
+                " CROSS JOIN points AS p ON p.rowid = d.point"
+                f" WHERE d.name = ? AND {condition} AND {where}",
+                (vector_name, *condition_params, *params),
+            )
+            while rows := cursor.fetchmany(DENSE_SCAN_BATCH_SIZE):
+                yield rows
+
+        def check_dimensions(dimension: int) -> None:
+            if dimension != len(query_vector):
+                raise ValueError(
+                    f"Query has {len(query_vector)} dimensions but {vector_name!r} vectors "
+                    f"in {collection_name!r} have {dimension}"
+                )
+
+        def scan(
+            db: sqlite3.Connection,
+            best: list[tuple[float, int]],
+            condition: str,
+            condition_params: Sequence[Any] = (),
+        ) -> None:
+            for rows in batches(db, condition, condition_params):
+                matrix = np.stack([np.frombuffer(blob, dtype=np.float32) for _, blob in rows])
+                check_dimensions(matrix.shape[1])
+                scores = _similarities(matrix, query_vector, metric)
+                for (rowid, _), score in zip(rows, scores.tolist(), strict=True):
+                    if len(best) < limit:
+                        heapq.heappush(best, (score, rowid))
+                    elif score > best[0][0]:
+                        heapq.heapreplace(best, (score, rowid))
+
+        def search(db: sqlite3.Connection) -> list[ScoredPoint]:
+            best: list[tuple[float, int]] = []
+            if (index := self._dense_index(db, collection_name, vector_name)) is None:
+                scan(db, best, "1")
+            else:
+                check_dimensions(index.centroids.shape[1])
+                lists = len(index.centroids)
+                probes = min(lists, max(8, lists // DENSE_INDEX_PROBE_RATIO))
+                scores = _list_scores(query_vector[None, :], index.centroids, index.metric)[0]
+                probed = np.argsort(-scores)[:probes].tolist()
+                placeholders = ", ".join("?" * len(probed))
+                # Two scans rather than an OR, which would stop SQLite from using the list index
+                scan(db, best, f"d.list IN ({placeholders})", probed)
+                scan(db, best, "d.list IS NULL")
+                if len(best) < limit and probes < lists:
+                    scan(db, best, f"d.list NOT IN ({placeholders})", probed)
+            ranked = sorted(best, reverse=True)
+            return self._with_payloads(db, [(rowid, score) for score, rowid in ranked])
+
+        return await self._run(search, write=False)
+
+    async def search_sparse(
+        self,
+        collection_name: str,
+        vector_name: str,
+        indices: Sequence[int],
+        values: Sequence[float],
+        *,
+        query_filter: SqliteFilter | None = None,
+        limit: int = 10,
+    ) -> list[ScoredPoint]:
+        """Find the points with the highest dot product between their named sparse vector and
+        the query's."""
+        weights = defaultdict(float)
+        for term, weight in zip(indices, values, strict=True):
+            weights[int(term)] += float(weight)
+
+        def search(db: sqlite3.Connection) -> list[ScoredPoint]:
+            if not weights:
+                return []
+            where, params = self._where(collection_name, query_filter)
+            terms = list(weights)
+            scores: dict[int, float] = defaultdict(float)
+            for start in range(0, len(terms), 500):
+                batch = terms[start : start + 500]
+                placeholders = ", ".join("?" * len(batch))
+                for rowid, term, weight in db.execute(
+                    "SELECT s.point, s.term, s.weight FROM sparse_postings AS s"  # noqa: S608
+                    " JOIN points AS p ON p.rowid = s.point"
+                    f" WHERE s.name = ? AND s.term IN ({placeholders}) AND {where}",
+                    (vector_name, *batch, *params),
+                ):
+                    scores[rowid] += weight * weights[term]
+            ranked = heapq.nlargest(limit, scores.items(), key=lambda item: item[1])
+            return self._with_payloads(db, ranked)
+
+        return await self._run(search, write=False)
+
+    async def search_text(
+        self,
+        collection_name: str,
+        text: str,
+        *,
+        query_filter: SqliteFilter | None = None,
+        limit: int = 10,
+    ) -> list[ScoredPoint]:
+        """Rank points by BM25 over their stored text."""
+        if not self._has_text_index or (fts_query := _fts_query(text)) is None:
+            return []
+
+        def search(db: sqlite3.Connection) -> list[ScoredPoint]:
+            where, params = self._where(collection_name, query_filter)
+            # bm25() is lower-is-better, so negate it for a score
+            ranked = [
+                (rowid, -score)
+                for rowid, score in db.execute(
+                    "SELECT t.rowid, bm25(point_texts) FROM point_texts AS t"  # noqa: S608
+                    " JOIN points AS p ON p.rowid = t.rowid"
+                    f" WHERE point_texts MATCH ? AND {where} ORDER BY bm25(point_texts) LIMIT ?",
+                    (fts_query, *params, limit),
+                )
+            ]
+            return self._with_payloads(db, ranked)
+
+        return await self._run(search, write=False)
+
+    async def close(self) -> None:
+        """Close the database."""
+        await self._run(lambda db: db.close(), write=False)
+
+
+__all__ = (
+    "DENSE_INDEX_MAX_LISTS",
+    "DENSE_INDEX_PROBE_RATIO",
+    "DENSE_INDEX_SAMPLE_PER_LIST",
+    "DENSE_INDEX_TRAINING_ITERATIONS",
+    "DENSE_SCAN_BATCH_SIZE",
+    "SQLITE_MEMORY_LOCATION",
+    "DenseIndex",
+    "DistanceMetric",
+    "ScoredPoint",
+    "SqliteCollectionInfo",
+    "SqlitePoint",
+    "SqliteVectorClient",
+)
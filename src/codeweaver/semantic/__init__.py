@@ -88,8 +88,25 @@ if TYPE_CHECKING:
         NodeTypeParser,
         get_things,
     )
+    from codeweaver.semantic.outline import (
+        OUTLINE_LANGUAGES,
+        OutlineItem,
+        build_outline,
+        find_outline_items,
+        render_skeleton,
+    )
     from codeweaver.semantic.registry import ThingRegistry, build_models
     from codeweaver.semantic.scoring import SemanticScorer
+    from codeweaver.semantic.symbols import (
+        SYMBOL_LANGUAGES,
+        SymbolReference,
+        SymbolRefKind,
+        derived_traits,
+        enclosing_items,
+        extract_symbol_references,
+        symbol_name,
+    )
+    from codeweaver.semantic.test_code import find_test_only_ranges, is_test_chunk, is_test_file
     from codeweaver.semantic.token_patterns import (
         IS_ANNOTATION,
         IS_IDENTIFIER,
@@ -126,26 +143,35 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "LANGUAGE_SPECIFIC_TOKEN_EXCEPTIONS": (__spec__.parent, "token_patterns"),
     "NAMED_NODE_COUNTS": (__spec__.parent, "token_patterns"),
     "NOT_SYMBOL": (__spec__.parent, "token_patterns"),
+    "OUTLINE_LANGUAGES": (__spec__.parent, "outline"),
+    "SYMBOL_LANGUAGES": (__spec__.parent, "symbols"),
     "AgentTask": (__spec__.parent, "classifications"),
     "AllThingsDict": (__spec__.parent, "grammar"),
     "AstGrepSearchTypes": (__spec__.parent, "ast_grep"),
     "AstThing": (__spec__.parent, "ast_grep"),
     "BaseAgentTask": (__spec__.parent, "classifications"),
+    "build_outline": (__spec__.parent, "outline"),
     "Category": (__spec__.parent, "grammar"),
     "ClassificationMethod": (__spec__.parent, "classifier"),
     "CompositeThing": (__spec__.parent, "grammar"),
     "Connection": (__spec__.parent, "grammar"),
     "ConnectionClass": (__spec__.parent, "types"),
     "ConnectionConstraint": (__spec__.parent, "types"),
+    "derived_traits": (__spec__.parent, "symbols"),
     "DirectConnection": (__spec__.parent, "grammar"),
+    "enclosing_items": (__spec__.parent, "symbols"),
     "EvidenceKind": (__spec__.parent, "classifier"),
+    "extract_symbol_references": (__spec__.parent, "symbols"),
     "FileThing": (__spec__.parent, "ast_grep"),
+    "find_outline_items": (__spec__.parent, "outline"),
     "Grammar": (__spec__.parent, "grammar"),
     "GrammarBasedClassifier": (__spec__.parent, "classifier"),
     "GrammarClassificationResult": (__spec__.parent, "classifier"),
     "ImportanceRank": (__spec__.parent, "classifications"),
     "ImportanceScores": (__spec__.parent, "classifications"),
     "ImportanceScoresDict": (__spec__.parent, "classifications"),
+    "is_test_chunk": (__spec__.parent, "test_code"),
+    "is_test_file": (__spec__.parent, "test_code"),
     "JavaScriptFamily": (__spec__.parent, "token_patterns"),
     "JavaScriptLangs": (__spec__.parent, "token_patterns"),
     "MetaVar": (__spec__.parent, "ast_grep"),
@@ -153,15 +179,20 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "NodeParserDep": (__spec__.parent, "dependencies"),
     "NodeTypeFileLoader": (__spec__.parent, "node_type_parser"),
     "NodeTypeParser": (__spec__.parent, "node_type_parser"),
+    "OutlineItem": (__spec__.parent, "outline"),
     "Position": (__spec__.parent, "ast_grep"),
     "PositionalConnections": (__spec__.parent, "grammar"),
     "Range": (__spec__.parent, "ast_grep"),
+    "render_skeleton": (__spec__.parent, "outline"),
     "ScoreValidation": (__spec__.parent, "classifications"),
     "SemanticClass": (__spec__.parent, "classifications"),
     "SemanticClassDict": (__spec__.parent, "classifications"),
     "SemanticMetadata": (__spec__.parent, "types"),
     "SemanticScorer": (__spec__.parent, "scoring"),
     "Strictness": (__spec__.parent, "ast_grep"),
+    "symbol_name": (__spec__.parent, "symbols"),
+    "SymbolReference": (__spec__.parent, "symbols"),
+    "SymbolRefKind": (__spec__.parent, "symbols"),
     "Thing": (__spec__.parent, "grammar"),
     "ThingClass": (__spec__.parent, "classifications"),
     "ThingKind": (__spec__.parent, "types"),
@@ -177,6 +208,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "build_models": (__spec__.parent, "registry"),
     "cat_name_normalizer": (__spec__.parent, "grammar"),
     "ChildTypeDTO": (__spec__.parent, "types"),
+    "find_test_only_ranges": (__spec__.parent, "test_code"),
     "get_all_grammars": (__spec__.parent, "grammar"),
     "get_checks": (__spec__.parent, "token_patterns"),
     "get_grammar": (__spec__.parent, "grammar"),
@@ -203,6 +235,8 @@ __all__ = (
     "LANGUAGE_SPECIFIC_TOKEN_EXCEPTIONS",
     "NAMED_NODE_COUNTS",
     "NOT_SYMBOL",
+    "OUTLINE_LANGUAGES",
+    "SYMBOL_LANGUAGES",
     "AgentTask",
     "AllThingsDict",
     "AstGrepSearchTypes",
@@ -232,6 +266,7 @@ __all__ = (
     "NodeTypeDTO",
     "NodeTypeFileLoader",
     "NodeTypeParser",
+    "OutlineItem",
     "Position",
     "PositionalConnections",
     "Range",
@@ -242,6 +277,8 @@ __all__ = (
     "SemanticScorer",
     "SimpleNodeTypeDTO",
     "Strictness",
+    "SymbolRefKind",
+    "SymbolReference",
     "Thing",
     "ThingClass",
     "ThingKind",
@@ -255,17 +292,27 @@ __all__ = (
     "TypeScriptLangs",
     "UsageMetrics",
     "build_models",
+    "build_outline",
     "cat_name_normalizer",
+    "derived_traits",
+    "enclosing_items",
+    "extract_symbol_references",
+    "find_outline_items",
+    "find_test_only_ranges",
     "get_all_grammars",
     "get_checks",
     "get_grammar",
     "get_things",
     "get_token_patterns_sync",
     "is_composite_thing",
+    "is_test_chunk",
+    "is_test_file",
     "is_token",
     "name_normalizer",
     "rebuild_models_for_tests",
+    "render_skeleton",
     "role_name_normalizer",
+    "symbol_name",
     "thing_name_normalizer",
 )
 
@@ -630,6 +630,11 @@ class AstThing[SgNode: (AstGrepNode)](BasedModel):
         """Get the text of the node."""
         return self._node.text()
 
+    @property
+    def sg_node(self) -> AstGrepNode:
+        """The underlying ast-grep node, for helpers that work on the ast-grep API directly."""
+        return self._node
+
     @computed_field
     @property
     def _root(self) -> FileThing[AstGrepRoot]:
@@ -750,6 +755,11 @@ class AstThing[SgNode: (AstGrepNode)](BasedModel):
             else None
         )
 
+    def field(self, name: str) -> AstThing[SgNode] | None:
+        """Get the child in one of the node's named fields (e.g. a function's `body`)."""
+        node = self._node.field(name)
+        return type(self).from_sg_node(node, self.language) if node is not None else None
+
     @computed_field
     @property
     def _ancestor_list(self) -> tuple[AstThing[SgNode], ...]:
@@ -0,0 +1,373 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""File outlines and skeletons: the shape of a file without its bodies.
+
+An outline is the tree of a file's items -- types, traits, classes, impl blocks, modules,
+functions and methods -- each with its signature and the first line of its docs. A skeleton is
+the file's source with function bodies elided, as in
+`fn insert(&mut self, item: T) -> Option<T> { … }`, so it keeps imports, attributes, fields and
+doc comments as written.
+
+Both are read off one walk of a file's `AstThing` tree, and both are a fraction of the file's
+tokens: they show an agent a module's API without making it read the implementation.
+"""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Annotated, NamedTuple
+
+from pydantic import ConfigDict, Field, PositiveInt
+
+from codeweaver.core import BasedModel, SemanticSearchLanguage
+from codeweaver.semantic.symbols import symbol_name
+
+
+if TYPE_CHECKING:
+    from ast_grep_py import SgNode
+
+    from codeweaver.semantic.ast_grep import AstThing
+
+
+_BLOCK_KINDS = frozenset({"block", "statement_block"})
+"""Body kinds that are elided; expression bodies (`x => x + 1`) are left alone."""
+
+_ATTRIBUTE_KINDS = frozenset({"attribute_item", "decorator"})
+
+_BRACED_ELISION = "{ … }"
+
+
+class OutlineItem(BasedModel):
+    """One item in a file's outline, with the items declared in its body."""
+
+    model_config = BasedModel.model_config | ConfigDict(frozen=True)
+
+    name: Annotated[
+        str | None,
+        Field(description="""The item's name; for impl blocks, the implementing type."""),
+    ]
+    kind: Annotated[
+        str,
+        Field(
+            description="""The item's node kind in its language's grammar, like `function_item` or `class_definition`."""
+        ),
+    ]
+    signature: Annotated[
+        str,
+        Field(
+            description="""The item's declaration up to its body (with decorators and `export`), on one line."""
+        ),
+    ]
+    doc: Annotated[
+        str | None,
+        Field(description="""The first line of the item's doc comment or docstring, if any."""),
+    ] = None
+    line: Annotated[PositiveInt, Field(description="""1-based line the item starts on""")]
+    end_line: Annotated[PositiveInt, Field(description="""1-based line the item ends on""")]
+    children: Annotated[
+        tuple[OutlineItem, ...],
+        Field(
+            description="""Items declared in this one's body: an impl block's or class's methods, a module's items."""
+        ),
+    ] = ()
+
+    def _telemetry_keys(self) -> None:
+        return None
+
+
+class _OutlineRules(NamedTuple):
+    """Node kinds that make up one language's outline."""
+
+    items: frozenset[str]
+    """Kinds listed in the outline."""
+    containers: frozenset[str]
+    """Items whose `body` field holds more items (impl blocks, traits, classes, modules)."""
+    callables: frozenset[str]
+    """Kinds whose `body` field is elided in skeletons; the outline doesn't look inside them."""
+    wrappers: frozenset[str]
+    """Kinds wrapping an item that are part of its signature (`export`, decorators)."""
+    doc_prefixes: tuple[str, ...]
+    """Prefixes of the comments that document the item after them; empty if docstrings do."""
+
+
+_RUST = _OutlineRules(
+    items=frozenset({
+        "const_item",
+        "enum_item",
+        "function_item",
+        "function_signature_item",
+        "impl_item",
+        "macro_definition",
+        "mod_item",
+        "static_item",
+        "struct_item",
+        "trait_item",
+        "type_item",
+        "union_item",
+    }),
+    containers=frozenset({"impl_item", "mod_item", "trait_item"}),
+    callables=frozenset({"function_item"}),
+    wrappers=frozenset(),
+    doc_prefixes=("///", "/**"),
+)
+
+_PYTHON = _OutlineRules(
+    items=frozenset({"class_definition", "function_definition"}),
+    containers=frozenset({"class_definition"}),
+    callables=frozenset({"function_definition"}),
+    wrappers=frozenset({"decorated_definition"}),
+    doc_prefixes=(),
+)
+
+_ECMASCRIPT = _OutlineRules(
+    items=frozenset({
+        "abstract_class_declaration",
+        "abstract_method_signature",
+        "class_declaration",
+        "enum_declaration",
+        "function_declaration",
+        "function_signature",
+        "generator_function_declaration",
+        "interface_declaration",
+        "internal_module",
+        "method_definition",
+        "method_signature",
+        "type_alias_declaration",
+    }),
+    containers=frozenset({
+        "abstract_class_declaration",
+        "class_declaration",
+        "interface_declaration",
+        "internal_module",
+    }),
+    callables=frozenset({
+        "arrow_function",
+        "function_declaration",
+        "function_expression",
+        "generator_function_declaration",
+        "method_definition",
+    }),
+    wrappers=frozenset({"export_statement"}),
+    doc_prefixes=("/**",),
+)
+
+_GO = _OutlineRules(
+    items=frozenset({"function_declaration", "method_declaration", "type_spec"}),
+    containers=frozenset(),
+    callables=frozenset({"func_literal", "function_declaration", "method_declaration"}),
+    wrappers=frozenset({"type_declaration"}),
+    doc_prefixes=("//",),
+)
+
+_RULES: dict[SemanticSearchLanguage, _OutlineRules] = {
+    SemanticSearchLanguage.RUST: _RUST,
+    SemanticSearchLanguage.PYTHON: _PYTHON,
+    SemanticSearchLanguage.JAVASCRIPT: _ECMASCRIPT,
+    SemanticSearchLanguage.JSX: _ECMASCRIPT,
+    SemanticSearchLanguage.TYPESCRIPT: _ECMASCRIPT,
+    SemanticSearchLanguage.TSX: _ECMASCRIPT,
+    SemanticSearchLanguage.GO: _GO,
+}
+
+OUTLINE_LANGUAGES: frozenset[SemanticSearchLanguage] = frozenset(_RULES)
+"""Languages that outlines and skeletons are built for."""
+
+
+# ---------------------------------------------------------------------------
+# Outlines
+# ---------------------------------------------------------------------------
+
+
+def _anchor(item: AstThing[SgNode], rules: _OutlineRules) -> AstThing[SgNode]:
+    """The wrapper an item is the only item of (`export class`, a decorated def), or the item."""
+    parent = item.parent
+    if parent is None or parent.name not in rules.wrappers:
+        return item
+    siblings = [child for child in parent.positional_connections if child.name in rules.items]
+    return parent if len(siblings) == 1 else item
+
+
+def _signature(item: AstThing[SgNode], anchor: AstThing[SgNode], rules: _OutlineRules) -> str:
+    text = item.text
+    if (body := item.field("body")) is not None and item.name in (
+        rules.callables | rules.containers
+    ):
+        head = text[: text.rfind(body.text)] if body.text else text
+    else:
+        head = text.split("\n", 1)[0].removesuffix("{")
+    anchor_text = anchor.text
+    prefix = anchor_text[: max(anchor_text.rfind(text), 0)] if anchor is not item else ""
+    return " ".join(f"{prefix}{head}".split())
+
+
+def _docstring_statement(statement: AstThing[SgNode] | None) -> AstThing[SgNode] | None:
+    """The string of a statement that is only a string (a Python docstring), if it is one."""
+    if statement is None or statement.name != "expression_statement":
+        return None
+    string = statement.child(0)
+    return string if string is not None and string.name == "string" else None
+
+
+def _first_statement(body: AstThing[SgNode]) -> AstThing[SgNode] | None:
+    return next((child for child in body.positional_connections if child.has_explicit_rule), None)
+
+
+def _docstring(item: AstThing[SgNode]) -> str | None:
+    """The docstring opening a Python definition's body."""
+    if (body := item.field("body")) is None or (
+        string := _docstring_statement(_first_statement(body))
+    ) is None:
+        return None
+    return string.text.lstrip("rRuUbB").strip("\"'")
+
+
+def _doc_comment(anchor: AstThing[SgNode], rules: _OutlineRules) -> str | None:
+    """The doc comments directly above an item, skipping its attributes."""
+    comments: list[str] = []
+    next_line = anchor.range.start.line
+    previous = anchor.prev()
+    while previous is not None and previous.range.end.line + 1 >= next_line:
+        if "comment" in previous.name:
+            comments.append(previous.text)
+        elif previous.name not in _ATTRIBUTE_KINDS:
+            break
+        next_line = previous.range.start.line
+        previous = previous.prev()
+    docs = [comment for comment in reversed(comments) if comment.startswith(rules.doc_prefixes)]
+    return "\n".join(docs) or None
+
+
+def _first_doc_line(doc: str | None) -> str | None:
+    for line in (doc or "").splitlines():
+        if text := line.strip().lstrip("/*!").removesuffix("*/").strip():
+            return text
+    return None
+
+
+def _outline_item(item: AstThing[SgNode], rules: _OutlineRules) -> OutlineItem:
+    anchor = _anchor(item, rules)
+    name_node = item.field("name") or item.field("type")
+    body = item.field("body")
+    item_range = item.range
+    return OutlineItem(
+        name=name_node.text if name_node is not None else None,
+        kind=str(item.name),
+        signature=_signature(item, anchor, rules),
+        doc=_first_doc_line(
+            _doc_comment(anchor, rules) if rules.doc_prefixes else _docstring(item)
+        ),
+        line=item_range.start.line + 1,
+        end_line=item_range.end.line + 1,
+        children=_outline_items(body, rules)
+        if body is not None and item.name in rules.containers
+        else (),
+    )
+
+
+def _outline_items(node: AstThing[SgNode], rules: _OutlineRules) -> tuple[OutlineItem, ...]:
+    items: list[OutlineItem] = []
+    for child in node.positional_connections:
+        if child.name in rules.items:
+            items.append(_outline_item(child, rules))
+        elif child.name not in rules.callables:
+            items.extend(_outline_items(child, rules))
+    return tuple(items)
+
+
+def build_outline(root: AstThing[SgNode]) -> tuple[OutlineItem, ...] | None:
+    """Build the outline of a parsed file.
+
+    Items inside function bodies (closures, nested helpers) aren't part of the outline.
+
+    Args:
+        root: The root node of a parsed file
+
+    Returns:
+        The file's top-level items, with the items they declare as children, or None for
+        languages without outline rules
+    """
+    if (rules := _RULES.get(root.language)) is None:
+        return None
+    return _outline_items(root, rules)
+
+
+def find_outline_items(items: tuple[OutlineItem, ...], symbol: str) -> tuple[OutlineItem, ...]:
+    """Find the items named like a symbol at any depth of an outline.
+
+    A type's impl blocks are named by the type, so `Cache` finds `struct Cache` and every
+    `impl ... for Cache` in the outline.
+    """
+    name = symbol_name(symbol)
+    found: list[OutlineItem] = []
+    for item in items:
+        if item.name is not None and symbol_name(item.name) == name:
+            found.append(item)
+        else:
+            found.extend(find_outline_items(item.children, symbol))
+    return tuple(found)
+
+
+# ---------------------------------------------------------------------------
+# Skeletons
+# ---------------------------------------------------------------------------
+
+
+def _elidable_body(node: AstThing[SgNode], rules: _OutlineRules) -> AstThing[SgNode] | None:
+    """The body of a callable, if it's a block that ends the node's text."""
+    if node.name not in rules.callables or (body := node.field("body")) is None:
+        return None
+    return body if body.name in _BLOCK_KINDS and node.text.endswith(body.text) else None
+
+
+def _elided(body: AstThing[SgNode], language: SemanticSearchLanguage) -> str:
+    """What a body is replaced with: braces, or `...` after any Python docstring."""
+    if language != SemanticSearchLanguage.PYTHON:
+        return _BRACED_ELISION
+    first = _first_statement(body)
+    if first is not None and _docstring_statement(first) is not None:
+        return f"{first.text}\n{' ' * body.range.start.column}..."
+    return "..."
+
+
+def _skeleton(node: AstThing[SgNode], rules: _OutlineRules) -> tuple[str, int]:
+    """Render a node's text with the callable bodies in it elided, and count the elisions."""
+    text = node.text
+    if (body := _elidable_body(node, rules)) is not None:
+        return f"{text[: len(text) - len(body.text)]}{_elided(body, node.language)}", 1
+    parts: list[str] = []
+    cursor = elided = 0
+    for child in node.positional_connections:
+        child_text = child.text
+        if not child_text or (start := text.find(child_text, cursor)) < 0:
+            continue
+        rendered, count = _skeleton(child, rules)
+        parts.extend((text[cursor:start], rendered))
+        cursor, elided = start + len(child_text), elided + count
+    parts.append(text[cursor:])
+    return "".join(parts), elided
+
+
+def render_skeleton(root: AstThing[SgNode]) -> str | None:
+    """Render a parsed file with its function and method bodies elided.
+
+    Args:
+        root: The root node of a parsed file
+
+    Returns:
+        The skeleton, or None for languages without outline rules and for files with no
+        bodies to elide (where the skeleton would be the file itself)
+    """
+    if (rules := _RULES.get(root.language)) is None:
+        return None
+    skeleton, elided = _skeleton(root, rules)
+    return skeleton if elided else None
+
+
+__all__ = (
+    "OUTLINE_LANGUAGES",
+    "OutlineItem",
+    "build_outline",
+    "find_outline_items",
+    "render_skeleton",
+)
@@ -0,0 +1,515 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Symbol reference extraction from ast-grep syntax trees.
+
+Walks a parsed file and records, per language, where symbols are *defined*, where they are
+*called*, which types *implement* (or extend) which traits, interfaces and base classes, and
+which symbols are *imported*. The engine's symbol index stores these per file so that
+`find_code` and the `find_references` tool can answer "who calls X?" or "what implements Y?"
+without a vector search.
+
+Extraction is syntactic: names are matched by their last path segment (`cache::Cache::get`
+and `self.get` both reference `get`), so results can include same-named symbols from other
+types. That's the trade-off for being language-agnostic and not needing a type checker.
+
+Rust macros are symbols too: `macro_rules!` definitions are definitions, invocations are calls
+(with the macro path as written), and `#[derive(...)]` attributes are implementations of the
+derived traits, since the impls they generate aren't in the source.
+"""
+
+from __future__ import annotations
+
+import re
+
+from collections.abc import Callable, Iterator
+from typing import TYPE_CHECKING, Annotated, NamedTuple
+
+from pydantic import ConfigDict, Field, PositiveInt
+
+from codeweaver.core import BasedModel, BaseEnum, SemanticSearchLanguage
+
+
+if TYPE_CHECKING:
+    from ast_grep_py import SgNode
+
+
+_IDENTIFIER = re.compile(r"[A-Za-z_$][\w$]*")
+_GENERICS = re.compile(r"<[^<>]*(?:<[^<>]*>[^<>]*)*>")
+_TYPE_NAME_KINDS = frozenset({"identifier", "type_identifier"})
+_DERIVE = re.compile(r"\bderive\s*\(([^()]*)\)")
+_RUST_MACRO_INVOCATION = "macro_invocation"
+_RUST_DERIVING_KINDS = frozenset({"enum_item", "struct_item", "union_item"})
+
+
+class SymbolRefKind(BaseEnum):
+    """How a symbol occurs at a location."""
+
+    DEFINITION = "definition"
+    CALL = "call"
+    IMPL = "impl"
+    IMPORT = "import"
+
+
+class SymbolReference(BasedModel):
+    """One occurrence of a symbol in a file."""
+
+    model_config = BasedModel.model_config | ConfigDict(frozen=True)
+
+    name: Annotated[
+        str,
+        Field(
+            description="""The symbol's simple name. For implementations, the implemented trait, interface or base class."""
+        ),
+    ]
+    kind: Annotated[SymbolRefKind, Field(description="""How the symbol occurs here""")]
+    line: Annotated[PositiveInt, Field(description="""1-based line the occurrence starts on""")]
+    end_line: Annotated[PositiveInt, Field(description="""1-based line the occurrence ends on""")]
+    container: Annotated[
+        str | None,
+        Field(
+            description="""For definitions, the enclosing type, impl or class; for calls, the enclosing function or type (the caller)."""
+        ),
+    ] = None
+    target: Annotated[
+        str | None,
+        Field(
+            description="""For implementations, the implementing type; for imports, the imported path; for macro invocations, the macro path as written."""
+        ),
+    ] = None
+
+    def _telemetry_keys(self) -> None:
+        return None
+
+
+class _LanguageRules(NamedTuple):
+    """Node kinds that carry symbol references in one language's grammar."""
+
+    definitions: frozenset[str]
+    """Named definitions; the name is the node's `name` field."""
+    scopes: dict[str, str]
+    """Container kinds mapped to the field holding their name (e.g. `impl_item` -> `type`)."""
+    calls: dict[str, str]
+    """Call kinds mapped to the field holding the called expression."""
+    impls: Callable[[SgNode], Iterator[tuple[str, str, SgNode]]] | None
+    """Yields (implemented name, implementing type, node) tuples."""
+    imports: Callable[[SgNode], Iterator[tuple[str, str, SgNode]]] | None
+    """Yields (imported name, imported path, node) tuples."""
+
+
+def _last_name(text: str) -> str | None:
+    """Reduce a path, member access or generic type to its last identifier."""
+    names = _IDENTIFIER.findall(_GENERICS.sub("", text))
+    return names[-1] if names else None
+
+
+def _field_name(node: SgNode, field: str) -> str | None:
+    return _last_name(child.text()) if (child := node.field(field)) is not None else None
+
+
+def _callee(node: SgNode) -> str | None:
+    """Reduce a call's target expression to the called name."""
+    if node.kind() in ("generic_function", "generic_type") and (
+        inner := node.field("function") or node.field("type")
+    ):
+        node = inner
+    # `foo(a)(b)` or `make()()` -- the callee is itself a call; use its own target
+    while node.kind() in ("call_expression", "call") and (inner := node.field("function")):
+        node = inner
+    return _last_name(node.text())
+
+
+def _type_names(node: SgNode) -> Iterator[SgNode]:
+    """Yield the type names directly referenced by a heritage clause, skipping type arguments."""
+    for candidate in node.find_all(any=[{"kind": kind} for kind in _TYPE_NAME_KINDS]):
+        if not any(
+            ancestor.kind() in ("type_arguments", "arguments", "call_expression")
+            for ancestor in _ancestors_within(candidate, node)
+        ):
+            yield candidate
+
+
+def _ancestors_within(node: SgNode, stop: SgNode) -> Iterator[SgNode]:
+    for ancestor in node.ancestors():
+        if ancestor == stop:
+            return
+        yield ancestor
+
+
+# ---------------------------------------------------------------------------
+# Implementations
+# ---------------------------------------------------------------------------
+
+
+def derived_traits(item: SgNode) -> tuple[str, ...]:
+    """The traits a Rust item derives, from the `#[derive(...)]` attributes before it.
+
+    Derives inside `#[cfg_attr(...)]` count too. Traits are returned as written
+    (`serde::Serialize`), in order.
+    """
+    attributes: list[str] = []
+    previous = item.prev()
+    while previous is not None and previous.kind() in (
+        "attribute_item",
+        "line_comment",
+        "block_comment",
+    ):
+        if previous.kind() == "attribute_item":
+            attributes.append(previous.text())
+        previous = previous.prev()
+    return tuple(
+        trait
+        for attribute in reversed(attributes)
+        for derive in _DERIVE.finditer(attribute)
+        if (traits := derive.group(1))
+        for trait in (name.strip() for name in traits.split(","))
+        if trait
+    )
+
+
+def _rust_impls(root: SgNode) -> Iterator[tuple[str, str, SgNode]]:
+    for impl in root.find_all(kind="impl_item"):
+        if (trait := _field_name(impl, "trait")) and (self_type := _field_name(impl, "type")):
+            yield trait, self_type, impl
+    for item in root.find_all(any=[{"kind": kind} for kind in _RUST_DERIVING_KINDS]):
+        if name := _field_name(item, "name"):
+            for trait in derived_traits(item):
+                if trait_name := _last_name(trait):
+                    yield trait_name, name, item
+
+
+def _python_impls(root: SgNode) -> Iterator[tuple[str, str, SgNode]]:
+    for cls in root.find_all(kind="class_definition"):
+        if not (name := _field_name(cls, "name")) or not (bases := cls.field("superclasses")):
+            continue
+        for base in bases.children():
+            if base.kind() in ("identifier", "attribute") and (
+                base_name := _last_name(base.text())
+            ):
+                yield base_name, name, cls
+
+
+def _heritage_impls(
+    class_kinds: tuple[str, ...], heritage_kinds: frozenset[str]
+) -> Callable[[SgNode], Iterator[tuple[str, str, SgNode]]]:
+    """Implementations declared in `extends`/`implements` clauses (JavaScript, TypeScript, Java)."""
+
+    def impls(root: SgNode) -> Iterator[tuple[str, str, SgNode]]:
+        for cls in root.find_all(any=[{"kind": kind} for kind in class_kinds]):
+            if not (name := _field_name(cls, "name")):
+                continue
+            for clause in cls.children():
+                if clause.kind() not in heritage_kinds:
+                    continue
+                for base in _type_names(clause):
+                    yield base.text(), name, cls
+
+    return impls
+
+
+# ---------------------------------------------------------------------------
+# Imports
+# ---------------------------------------------------------------------------
+
+
+def _rust_use_names(node: SgNode) -> Iterator[str]:
+    match node.kind():
+        case "identifier" | "type_identifier":
+            yield node.text()
+        case "scoped_identifier":
+            if name := _field_name(node, "name"):
+                yield name
+        case "use_as_clause":
+            if path := node.field("path"):
+                yield from _rust_use_names(path)
+        case "scoped_use_list":
+            if use_list := node.field("list"):
+                yield from _rust_use_names(use_list)
+        case "use_list":
+            for child in node.children():
+                yield from _rust_use_names(child)
+        case _:
+            return
+
+
+def _rust_imports(root: SgNode) -> Iterator[tuple[str, str, SgNode]]:
+    for use in root.find_all(kind="use_declaration"):
+        if argument := use.field("argument"):
+            for name in _rust_use_names(argument):
+                yield name, argument.text(), use
+
+
+def _python_imports(root: SgNode) -> Iterator[tuple[str, str, SgNode]]:
+    for statement in root.find_all(kind="import_statement"):
+        for child in statement.children():
+            module = child.field("name") if child.kind() == "aliased_import" else child
+            if module is not None and module.kind() == "dotted_name":
+                yield _last_name(module.text()) or module.text(), module.text(), statement
+    for statement in root.find_all(kind="import_from_statement"):
+        module = statement.field("module_name")
+        after_import = False
+        for child in statement.children():
+            if child.kind() == "import":
+                after_import = True
+                continue
+            name = child.field("name") if child.kind() == "aliased_import" else child
+            if after_import and name is not None and name.kind() == "dotted_name":
+                path = f"{module.text()}.{name.text()}" if module else name.text()
+                yield name.text(), path, statement
+
+
+def _js_imports(root: SgNode) -> Iterator[tuple[str, str, SgNode]]:
+    for statement in root.find_all(kind="import_statement"):
+        source = (statement.field("source").text() if statement.field("source") else "").strip(
+            "'\"`"
+        )
+        for specifier in statement.find_all(kind="import_specifier"):
+            if name := _field_name(specifier, "name"):
+                yield name, source, statement
+        for clause in statement.find_all(kind="import_clause"):
+            for child in clause.children():
+                if child.kind() == "identifier":  # default import
+                    yield child.text(), source, statement
+
+
+def _go_imports(root: SgNode) -> Iterator[tuple[str, str, SgNode]]:
+    for spec in root.find_all(kind="import_spec"):
+        if path := spec.field("path"):
+            import_path = path.text().strip("\"`")
+            yield import_path.rsplit("/", 1)[-1], import_path, spec
+
+
+def _java_imports(root: SgNode) -> Iterator[tuple[str, str, SgNode]]:
+    for declaration in root.find_all(kind="import_declaration"):
+        for child in declaration.children():
+            if child.kind() in ("scoped_identifier", "identifier"):
+                yield _last_name(child.text()) or child.text(), child.text(), declaration
+
+
+# ---------------------------------------------------------------------------
+# Language rules
+# ---------------------------------------------------------------------------
+
+_RUST = _LanguageRules(
+    definitions=frozenset({
+        "const_item",
+        "enum_item",
+        "function_item",
+        "function_signature_item",
+        "macro_definition",
+        "mod_item",
+        "static_item",
+        "struct_item",
+        "trait_item",
+        "type_item",
+        "union_item",
+    }),
+    scopes={"impl_item": "type", "trait_item": "name", "mod_item": "name"},
+    calls={"call_expression": "function", _RUST_MACRO_INVOCATION: "macro"},
+    impls=_rust_impls,
+    imports=_rust_imports,
+)
+
+_PYTHON = _LanguageRules(
+    definitions=frozenset({"class_definition", "function_definition"}),
+    scopes={"class_definition": "name"},
+    calls={"call": "function"},
+    impls=_python_impls,
+    imports=_python_imports,
+)
+
+_JS_CLASS_KINDS = ("class_declaration", "abstract_class_declaration", "class")
+_ECMASCRIPT = _LanguageRules(
+    definitions=frozenset({
+        "abstract_class_declaration",
+        "class_declaration",
+        "enum_declaration",
+        "function_declaration",
+        "generator_function_declaration",
+        "interface_declaration",
+        "method_definition",
+        "type_alias_declaration",
+    }),
+    scopes={kind: "name" for kind in (*_JS_CLASS_KINDS, "interface_declaration")},
+    calls={"call_expression": "function", "new_expression": "constructor"},
+    impls=_heritage_impls(
+        (*_JS_CLASS_KINDS, "interface_declaration"),
+        frozenset({"class_heritage", "extends_type_clause"}),
+    ),
+    imports=_js_imports,
+)
+
+_GO = _LanguageRules(
+    definitions=frozenset({"function_declaration", "method_declaration", "type_spec"}),
+    scopes={},
+    calls={"call_expression": "function"},
+    # Go interfaces are satisfied implicitly; there's nothing to read from the syntax
+    impls=None,
+    imports=_go_imports,
+)
+
+_JAVA = _LanguageRules(
+    definitions=frozenset({
+        "annotation_type_declaration",
+        "class_declaration",
+        "constructor_declaration",
+        "enum_declaration",
+        "interface_declaration",
+        "method_declaration",
+        "record_declaration",
+    }),
+    scopes={
+        kind: "name"
+        for kind in (
+            "class_declaration",
+            "enum_declaration",
+            "interface_declaration",
+            "record_declaration",
+        )
+    },
+    calls={"method_invocation": "name", "object_creation_expression": "type"},
+    impls=_heritage_impls(
+        ("class_declaration", "enum_declaration", "interface_declaration", "record_declaration"),
+        frozenset({"superclass", "super_interfaces", "extends_interfaces"}),
+    ),
+    imports=_java_imports,
+)
+
+_RULES: dict[SemanticSearchLanguage, _LanguageRules] = {
+    SemanticSearchLanguage.RUST: _RUST,
+    SemanticSearchLanguage.PYTHON: _PYTHON,
+    SemanticSearchLanguage.JAVASCRIPT: _ECMASCRIPT,
+    SemanticSearchLanguage.JSX: _ECMASCRIPT,
+    SemanticSearchLanguage.TYPESCRIPT: _ECMASCRIPT,
+    SemanticSearchLanguage.TSX: _ECMASCRIPT,
+    SemanticSearchLanguage.GO: _GO,
+    SemanticSearchLanguage.JAVA: _JAVA,
+}
+
+SYMBOL_LANGUAGES: frozenset[SemanticSearchLanguage] = frozenset(_RULES)
+"""Languages that symbol references are extracted for."""
+
+
+def _scope_name(node: SgNode, rules: _LanguageRules) -> str | None:
+    """Name of the innermost type, impl or class enclosing `node`."""
+    for ancestor in node.ancestors():
+        if (field := rules.scopes.get(ancestor.kind())) and (name := _field_name(ancestor, field)):
+            return name
+    return None
+
+
+def _caller_name(node: SgNode, rules: _LanguageRules) -> str | None:
+    """Name of the innermost definition enclosing `node`."""
+    for ancestor in node.ancestors():
+        if ancestor.kind() in rules.definitions and (name := _field_name(ancestor, "name")):
+            return name
+    return None
+
+
+def _reference(
+    name: str, kind: SymbolRefKind, node: SgNode, **extra: str | None
+) -> SymbolReference:
+    node_range = node.range()
+    return SymbolReference(
+        name=name,
+        kind=kind,
+        line=node_range.start.line + 1,
+        end_line=node_range.end.line + 1,
+        **extra,
+    )
+
+
+def extract_symbol_references(
+    root: SgNode, language: SemanticSearchLanguage
+) -> tuple[SymbolReference, ...]:
+    """Extract the symbol definitions, calls, implementations and imports in a syntax tree.
+
+    Args:
+        root: The root node of a parsed file
+        language: The file's language
+
+    Returns:
+        The file's symbol references in source order, or an empty tuple for unsupported
+        languages
+    """
+    if (rules := _RULES.get(language)) is None:
+        return ()
+    references: list[SymbolReference] = []
+    for node in root.find_all(any=[{"kind": kind} for kind in rules.definitions]):
+        if name := _field_name(node, "name"):
+            references.append(
+                _reference(
+                    name,
+                    SymbolRefKind.DEFINITION,
+                    node,
+                    # Go methods name their type in a receiver rather than an enclosing block
+                    container=_field_name(node, "receiver") or _scope_name(node, rules),
+                )
+            )
+    for kind, field in rules.calls.items():
+        for node in root.find_all(kind=kind):
+            if (callee := node.field(field)) is not None and (name := _callee(callee)):
+                references.append(
+                    _reference(
+                        name,
+                        SymbolRefKind.CALL,
+                        node,
+                        container=_caller_name(node, rules) or _scope_name(node, rules),
+                        target=callee.text() if kind == _RUST_MACRO_INVOCATION else None,
+                    )
+                )
+    for extractor, ref_kind in (
+        (rules.impls, SymbolRefKind.IMPL),
+        (rules.imports, SymbolRefKind.IMPORT),
+    ):
+        if extractor is not None:
+            references.extend(
+                _reference(name, ref_kind, node, target=target)
+                for name, target, node in extractor(root)
+            )
+    references.sort(key=lambda ref: (ref.line, ref.name))
+    return tuple(references)
+
+
+def enclosing_items(
+    root: SgNode, language: SemanticSearchLanguage, start: int, end: int
+) -> tuple[tuple[int, int], ...]:
+    """Line ranges of the definitions and scopes (functions, impls, classes) enclosing a range.
+
+    Args:
+        root: The root node of a parsed file
+        language: The file's language
+        start: 1-based first line of the range
+        end: 1-based last line of the range
+
+    Returns:
+        1-based `(start, end)` ranges strictly larger than the given range, innermost first,
+        or an empty tuple for unsupported languages
+    """
+    if (rules := _RULES.get(language)) is None:
+        return ()
+    kinds = rules.definitions | rules.scopes.keys()
+    ranges: set[tuple[int, int]] = set()
+    for node in root.find_all(any=[{"kind": kind} for kind in kinds]):
+        node_range = node.range()
+        item = (node_range.start.line + 1, node_range.end.line + 1)
+        if item[0] <= start and item[1] >= end and item != (start, end):
+            ranges.add(item)
+    return tuple(sorted(ranges, key=lambda item: (item[1] - item[0], item[0])))
+
+
+def symbol_name(query: str) -> str:
+    """Normalize a user-supplied symbol (`Cache::get`, `cache.get()`, `Cache<T>`) to its name."""
+    return _last_name(query.strip().removesuffix("()")) or query.strip()
+
+
+__all__ = (
+    "SYMBOL_LANGUAGES",
+    "SymbolRefKind",
+    "SymbolReference",
+    "derived_traits",
+    "enclosing_items",
+    "extract_symbol_references",
+    "symbol_name",
+)
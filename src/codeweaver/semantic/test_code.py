@@ -0,0 +1,330 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Telling test code from production code.
+
+Test detection works per chunk rather than per file. A chunk is treated as test code when:
+- its file is a test file under the language's `RepoConventions` (`test_dirs`/`test_patterns`),
+- the chunker classified it as `SemanticClass.DEFINITION_TEST`, or
+- (Rust) it carries, or sits inside an item that carries, a test attribute such as
+  `#[cfg(test)]`, `#[test]` or `#[tokio::test]`.
+
+Chunks are tagged with the result when they're chunked, from their file's content, so the
+vector store can filter on it and search results read it back. Chunks without the tag are checked
+by every rule but the last, which needs the file's content.
+"""
+
+from __future__ import annotations
+
+import contextlib
+import logging
+import re
+
+from fnmatch import fnmatch
+from functools import cache
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from codeweaver.core import CodeChunk, SemanticSearchLanguage
+from codeweaver.semantic.classifications import SemanticClass
+
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+
+logger = logging.getLogger(__name__)
+
+_GENERIC_TEST_DIRS = frozenset({"test", "tests", "__tests__"})
+"""Test directories for any language; `spec` only counts where a language's conventions say."""
+_GENERIC_TEST_SUFFIXES = ("_test.py", "_tests.py", "_spec.py")
+_NAME_SEPARATORS = ("_", "-", ".")
+"""Characters that separate the parts of a file name, for bare-suffix test patterns."""
+
+_RUST_EXTRA_TEST_DIRS = frozenset({"tests", "benches"})
+"""Cargo treats `tests/` as integration test crates and `benches/` as benchmark crates."""
+
+_RUST_ATTRIBUTE = re.compile(r"^\#!?\[\s*(?P<body>.*?)\s*\]$", re.DOTALL)
+_RUST_TEST_MACRO = re.compile(r"(?:\w+::)*test(?:\s*\(.*\))?", re.DOTALL)
+"""`test`, `tokio::test`, `tokio::test(flavor = ...)`."""
+_RUST_CFG = re.compile(r"cfg\s*\((?P<predicate>.*)\)", re.DOTALL)
+_CFG_TOKEN = re.compile(r'\s*(?:(?P<string>"(?:[^"\\]|\\.)*")|(?P<word>[\w:]+)|(?P<punct>[(),=]))')
+
+
+def _cfg_tokens(predicate: str) -> list[str] | None:
+    """Split a cfg predicate into identifiers, strings and punctuation; None if it's malformed."""
+    tokens: list[str] = []
+    position = 0
+    while position < len(predicate.rstrip()):
+        if not (token := _CFG_TOKEN.match(predicate, position)):
+            return None
+        tokens.append(token.group().strip())
+        position = token.end()
+    return tokens
+
+
+def _cfg_requires_test(tokens: list[str], position: int = 0) -> tuple[bool, int]:
+    """Check whether a cfg predicate only holds when compiling tests.
+
+    That's a `test` atom, an `all(...)` with any such predicate, or an `any(...)` with only
+    such predicates. `not(...)` never requires `test`, and `feature = "test-utils"` isn't the
+    `test` atom.
+
+    Returns:
+        Whether the predicate at `position` requires `test`, and the position after it
+
+    Raises:
+        IndexError: If the predicate is truncated
+    """
+    word = tokens[position]
+    position += 1
+    if position < len(tokens) and tokens[position] == "=":
+        return False, position + 2  # key = "value"
+    if position >= len(tokens) or tokens[position] != "(":
+        return word == "test", position
+    position += 1
+    requirements: list[bool] = []
+    while tokens[position] != ")":
+        requires, position = _cfg_requires_test(tokens, position)
+        requirements.append(requires)
+        if tokens[position] == ",":
+            position += 1
+    position += 1
+    match word:
+        case "all":
+            return any(requirements), position
+        case "any":
+            return bool(requirements) and all(requirements), position
+        case _:
+            return False, position
+
+
+def _is_rust_test_attribute(text: str) -> bool:
+    """Check whether a Rust outer or inner attribute marks an item (or module) as test-only."""
+    if not (attribute := _RUST_ATTRIBUTE.match(text)):
+        return False
+    body = attribute["body"]
+    if _RUST_TEST_MACRO.fullmatch(body):
+        return True
+    if not (cfg := _RUST_CFG.fullmatch(body)) or not (tokens := _cfg_tokens(cfg["predicate"])):
+        return False
+    try:
+        requires, position = _cfg_requires_test(tokens)
+    except IndexError:
+        return False
+    return requires and position == len(tokens)
+
+
+def _language_for(file_path: Path, chunk: CodeChunk | None = None) -> SemanticSearchLanguage | None:
+    """Resolve the semantic language for a chunk, falling back to the file extension."""
+    if chunk is not None and isinstance(chunk.language, SemanticSearchLanguage):
+        return chunk.language
+    if chunk is not None and chunk.language:
+        with contextlib.suppress(ValueError, KeyError, AttributeError):
+            return SemanticSearchLanguage.from_string(str(chunk.language))
+    return SemanticSearchLanguage.from_extension(file_path.suffix) if file_path.suffix else None
+
+
+@cache
+def _test_conventions(
+    language: SemanticSearchLanguage,
+) -> tuple[frozenset[str], tuple[str, ...]]:
+    """Get the test directories and test file patterns for a language."""
+    conventions = language.repo_conventions
+    test_dirs = frozenset(
+        str(d).lower() for d in conventions.get("test_dirs", ()) if "/" not in str(d)
+    )
+    if language == SemanticSearchLanguage.RUST:
+        test_dirs |= _RUST_EXTRA_TEST_DIRS
+    patterns = tuple(str(p) for p in conventions.get("test_patterns", ()) if "/" not in str(p))
+    return test_dirs, patterns
+
+
+def _ends_with_part(name: str, pattern: str) -> bool:
+    """Check that a name ends with a pattern that is a whole part of it.
+
+    The pattern must be the whole name, start with or follow a separator (`_`, `-`, `.`), or
+    be capitalized (`Test.cs`), starting a new word of a CamelCase name.
+    """
+    if not name.endswith(pattern):
+        return False
+    prefix = name[: -len(pattern)]
+    return (
+        not prefix
+        or pattern.startswith(_NAME_SEPARATORS)
+        or prefix.endswith(_NAME_SEPARATORS)
+        or pattern[:1].isupper()
+    )
+
+
+def _matches_test_pattern(name: str, pattern: str) -> bool:
+    """Check a file name against a `RepoConventions` test pattern.
+
+    Conventions mix true globs (`test_*`, `*Test.scala`) with bare suffixes (`_test.rs`,
+    `Test.cs`, `test.nix`), so patterns without a wildcard are matched as the last part of the
+    name or stem: `test.nix` matches `test.nix` and `vm-test.nix`, but not `latest.nix`.
+    """
+    if "*" in pattern or "?" in pattern:
+        return fnmatch(name, pattern) or fnmatch(name, pattern.lstrip("."))
+    return _ends_with_part(name, pattern) or _ends_with_part(Path(name).stem, pattern)
+
+
+def is_test_file(file_path: Path, language: SemanticSearchLanguage | None = None) -> bool:
+    """Check if a file is a test file using filename and directory name heuristics.
+
+    Uses the language's `RepoConventions` when the language is known, in addition to
+    generic heuristics (`test`, `tests` and `__tests__` directories). For absolute paths,
+    checks only the immediate parent directory name to avoid false positives when the
+    project itself is located under a path containing the word "test" (e.g., pytest temp
+    directories like /tmp/pytest-of-user/pytest-123/test_my_project/test_codebase/auth.py).
+    Project-relative paths (what the index stores) are checked in every directory
+    component, so nested layouts like `crates/core/tests/common/mod.rs` are recognized.
+
+    Args:
+        file_path: Path to check
+        language: Optional language of the file; inferred from the extension if omitted
+
+    Returns:
+        True if the file appears to be a test file
+    """
+    name = file_path.name.lower()
+    # Filename heuristics: test_*.py, *_test.py, *_spec.py, *_tests.py
+    if name.startswith("test_") or name.endswith(_GENERIC_TEST_SUFFIXES):
+        return True
+    language = language or _language_for(file_path)
+    test_dirs, patterns = _test_conventions(language) if language else (frozenset(), ())
+    if any(_matches_test_pattern(file_path.name, pattern) for pattern in patterns):
+        return True
+    dir_names = (
+        {file_path.parent.name.lower()}
+        if file_path.is_absolute()
+        else {part.lower() for part in file_path.parent.parts}
+    )
+    return bool(dir_names & (_GENERIC_TEST_DIRS | test_dirs))
+
+
+def _is_test_classification(chunk: CodeChunk) -> bool:
+    """Check if the chunker classified the chunk as a test definition."""
+    if not chunk.metadata or not (context := chunk.metadata.get("context")):
+        return False
+    classification = context.get("classification")
+    if classification is None:
+        return False
+    if not isinstance(classification, SemanticClass):
+        try:
+            classification = SemanticClass.from_string(str(classification))
+        except (ValueError, KeyError, AttributeError):
+            return False
+    return classification == SemanticClass.DEFINITION_TEST
+
+
+def _leading_rust_attributes(content: str) -> list[str]:
+    """The attributes that lead a Rust chunk, before the item itself, each as one string.
+
+    An attribute can span lines (`#[tokio::test(\n    flavor = "multi_thread")]`), so each one
+    runs until its brackets balance.
+    """
+    attributes: list[str] = []
+    attribute: list[str] = []
+    depth = 0
+    for raw_line in content.lstrip().splitlines():
+        line = raw_line.strip()
+        if depth:
+            attribute.append(line)
+        elif not line or line.startswith("//"):
+            continue
+        elif line.startswith("#"):
+            attribute = [line]
+        else:
+            break
+        depth = max(depth + line.count("[") - line.count("]"), 0)
+        if not depth:
+            attributes.append(" ".join(attribute))
+    return attributes
+
+
+def _has_leading_rust_test_attribute(content: str) -> bool:
+    """Check the attributes that lead a Rust chunk (before the item itself) for test markers."""
+    return any(_is_rust_test_attribute(text) for text in _leading_rust_attributes(content))
+
+
+def _rust_test_ranges(source: str) -> tuple[tuple[int, int], ...]:
+    """Find the 1-based, inclusive line ranges of test-only items in Rust source.
+
+    An item is test-only when one of its outer attributes is a test attribute. An inner
+    `#![cfg(test)]` at the file level makes the whole file test-only.
+    """
+    from ast_grep_py import SgRoot
+
+    root = SgRoot(source, "rust").root()
+    ranges: list[tuple[int, int]] = []
+    for inner in root.find_all(kind="inner_attribute_item"):
+        if inner.parent() == root and _is_rust_test_attribute(inner.text().strip()):
+            return ((1, source.count("\n") + 1),)
+    for attribute in root.find_all(kind="attribute_item"):
+        if not _is_rust_test_attribute(attribute.text().strip()):
+            continue
+        item = attribute.next()
+        while item is not None and item.kind() in (
+            "attribute_item",
+            "line_comment",
+            "block_comment",
+        ):
+            item = item.next()
+        if item is not None:
+            ranges.append((attribute.range().start.line + 1, item.range().end.line + 1))
+    return tuple(ranges)
+
+
+def find_test_only_ranges(
+    file_path: Path, source: str | None = None
+) -> tuple[tuple[int, int], ...]:
+    """Find the 1-based, inclusive line ranges of a file's test-only code, from its content.
+
+    Only Rust marks test-only code inside production files; other files have none. Without
+    `source`, the content is read from `file_path`.
+    """
+    if _language_for(file_path) != SemanticSearchLanguage.RUST:
+        return ()
+    try:
+        if source is None:
+            source = file_path.read_text(encoding="utf-8", errors="replace")
+        return _rust_test_ranges(source)
+    except Exception:
+        logger.debug("Could not parse %s for Rust test ranges", file_path, exc_info=True)
+        return ()
+
+
+def is_test_chunk(
+    chunk: CodeChunk, file_path: Path | None, *, test_ranges: Sequence[tuple[int, int]] = ()
+) -> bool:
+    """Check if a chunk is test code.
+
+    A chunk that was tagged when it was chunked is whatever its tag says.
+
+    Args:
+        chunk: The chunk to check
+        file_path: The chunk's file path, if known
+        test_ranges: The line ranges of test-only code in the chunk's file (`find_test_only_ranges`)
+
+    Returns:
+        True if the chunk is a test file chunk, a test definition, or lies within test-only code
+    """
+    if chunk.is_test is not None:
+        return chunk.is_test
+    file_path = file_path or chunk.file_path
+    language = _language_for(file_path, chunk) if file_path else None
+    if file_path and is_test_file(file_path, language):
+        return True
+    if _is_test_classification(chunk):
+        return True
+    if language != SemanticSearchLanguage.RUST:
+        return False
+    if _has_leading_rust_test_attribute(chunk.content):
+        return True
+    start, end = chunk.line_start, chunk.line_end
+    return any(test_start <= start and end <= test_end for test_start, test_end in test_ranges)
+
+
+__all__ = ("find_test_only_ranges", "is_test_chunk", "is_test_file")
@@ -16,7 +16,14 @@ import re
 from enum import Flag, auto
 from typing import Annotated, Any, Literal, NamedTuple, TypedDict, cast
 
-from pydantic import ConfigDict, Field, PrivateAttr, SkipValidation, computed_field
+from pydantic import (
+    ConfigDict,
+    Field,
+    NonNegativeFloat,
+    PrivateAttr,
+    SkipValidation,
+    computed_field,
+)
 
 from codeweaver.core.language import SemanticSearchLanguage
 from codeweaver.core.types import (
@@ -244,6 +251,26 @@ def _set_symbol(data: Any) -> str | None:
     return None
 
 
+def _classification_fields(thing: Any) -> dict[str, Any]:
+    """Get an AST node's classification as SemanticMetadata fields.
+
+    The node itself can't be stored with a chunk, so its classification is copied out to
+    survive the round-trip through the vector store.
+    """
+    try:
+        if (classification := getattr(thing, "classification", None)) is None:
+            return {}
+        return {
+            "semantic_class": classification.name.value,
+            "importance_scores": classification.importance_scores.as_dict(),
+            "classification_confidence": getattr(
+                getattr(thing, "thing", None), "classification_confidence", None
+            ),
+        }
+    except Exception:
+        return {}
+
+
 class SemanticMetadata(BasedModel):
     """Metadata associated with the semantics of a code chunk."""
 
@@ -273,6 +300,58 @@ class SemanticMetadata(BasedModel):
             description="""Whether the node is a partial node. Partial nodes are created when the node is too large for the context window."""
         ),
     ] = False
+    impl_type: Annotated[
+        str | None,
+        Field(
+            description="""For members of an implementation block (e.g. a Rust `impl`), the self type being implemented, such as `Cache<T>`."""
+        ),
+    ] = None
+    impl_trait: Annotated[
+        str | None,
+        Field(
+            description="""For members of a trait implementation, the implemented trait, such as `Cacheable`."""
+        ),
+    ] = None
+    impl_generics: Annotated[
+        str | None,
+        Field(
+            description="""For members of an implementation block, its generic parameters, such as `<T: Cacheable>`."""
+        ),
+    ] = None
+    derived_traits: Annotated[
+        tuple[str, ...] | None,
+        Field(
+            description="""For types with derive attributes (e.g. Rust's `#[derive(Serialize)]`), the traits implemented by the derived code, as written."""
+        ),
+    ] = None
+    macro_arms: Annotated[
+        tuple[str, ...] | None,
+        Field(
+            description="""For macro definitions (e.g. Rust's `macro_rules!`), the matcher of each arm, such as `($id:expr, $value:expr)`."""
+        ),
+    ] = None
+    macro_invocations: Annotated[
+        dict[str, str | None] | None,
+        Field(
+            description="""Macros invoked in the chunk, by their path as written, mapped to the path of the definition they resolve to when it's local (or None)."""
+        ),
+    ] = None
+    semantic_class: Annotated[
+        str | None,
+        Field(
+            description="""The `SemanticClass` of the node, such as `definition_callable`."""
+        ),
+    ] = None
+    importance_scores: Annotated[
+        dict[str, NonNegativeFloat] | None,
+        Field(
+            description="""The node's importance for each kind of agent task (discovery, comprehension, modification, debugging, documentation), from its semantic class."""
+        ),
+    ] = None
+    classification_confidence: Annotated[
+        NonNegativeFloat | None,
+        Field(description="""How confident the classification of the node is, from 0 to 1."""),
+    ] = None
 
     def __init__(self, **data: Any) -> None:
         """Initialize SemanticMetadata with optional symbol setting."""
@@ -322,7 +401,7 @@ class SemanticMetadata(BasedModel):
             positional_connections=tuple(getattr(child, "positional_connections", ())),
             thing_id=getattr(child, "thing_id", uuid7()),
             parent_thing_id=parent_meta.thing_id,
-            **overrides,
+            **(_classification_fields(child) | overrides),
         )
 
     @classmethod
@@ -345,6 +424,7 @@ class SemanticMetadata(BasedModel):
             symbol=getattr(thing, "symbol", None),
             parent_thing_id=getattr(thing, "parent_thing_id", None),
             is_partial_node=False,
+            **_classification_fields(thing),
         )
 
 
@@ -21,7 +21,12 @@ if TYPE_CHECKING:
     from codeweaver.server._assets import CODEWEAVER_SVG_ICON
     from codeweaver.server._logging import setup_logger
     from codeweaver.server.agent_api import get_user_agent
-    from codeweaver.server.agent_api.search import CodeWeaverSettingsType, MatchedSection, find_code
+    from codeweaver.server.agent_api.search import (
+        CodeWeaverSettingsType,
+        MatchedSection,
+        find_code,
+        find_references,
+    )
     from codeweaver.server.agent_api.search.intent import (
         IntentResult,
         IntentType,
@@ -29,10 +34,15 @@ if TYPE_CHECKING:
         QueryIntent,
     )
     from codeweaver.server.agent_api.search.types import (
+        CodeKind,
         CodeMatch,
         CodeMatchType,
+        ElidedMatch,
         FindCodeResponseSummary,
         FindCodeSubmission,
+        SearchFilters,
+        SearchMode,
+        SearchScope,
     )
     from codeweaver.server.background_services import run_background_indexing, start_watcher
     from codeweaver.server.config.helpers import get_settings, get_settings_map, update_settings
@@ -56,6 +66,7 @@ if TYPE_CHECKING:
         DefaultEndpointSettings,
         DefaultFastMcpHttpRunArgs,
         DefaultFastMcpServerSettings,
+        DefaultSearchSettings,
         DefaultUvicornSettings,
         DefaultUvicornSettingsForMcp,
     )
@@ -72,6 +83,8 @@ if TYPE_CHECKING:
         FastMcpHttpRunArgs,
         FastMcpServerSettingsDict,
         MCPConfigDict,
+        SearchSettings,
+        SearchSettingsDict,
         StdioCodeWeaverConfigDict,
         UvicornServerSettings,
         UvicornServerSettingsDict,
@@ -132,13 +145,14 @@ if TYPE_CHECKING:
         register_tool,
     )
     from codeweaver.server.mcp.types import ToolAnnotationsDict, ToolRegistrationDict
-    from codeweaver.server.mcp.user_agent import find_code_tool
+    from codeweaver.server.mcp.user_agent import find_code_tool, find_references_tool
     from codeweaver.server.server import BRACKET_PATTERN, CodeWeaverState, lifespan
 
 _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "BRACKET_PATTERN": (__spec__.parent, "server"),
     "CODEWEAVER_SVG_ICON": (__spec__.parent, "_assets"),
     "BaseFastMcpServerSettings": (__spec__.parent, "config.settings"),
+    "CodeKind": (__spec__.parent, "agent_api.search.types"),
     "CodeMatch": (__spec__.parent, "agent_api.search.types"),
     "CodeMatchType": (__spec__.parent, "agent_api.search.types"),
     "CodeWeaverSettings": (__spec__.parent, "config.settings"),
@@ -152,9 +166,11 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "DefaultFastMcpHttpRunArgs": (__spec__.parent, "config.server_defaults"),
     "DefaultFastMcpServerSettings": (__spec__.parent, "config.server_defaults"),
     "DefaultMiddlewareSettings": (__spec__.parent, "config.middleware"),
+    "DefaultSearchSettings": (__spec__.parent, "config.server_defaults"),
     "DefaultUvicornSettings": (__spec__.parent, "config.server_defaults"),
     "DefaultUvicornSettingsForMcp": (__spec__.parent, "config.server_defaults"),
     "DetailedTimingMiddleware": (__spec__.parent, "mcp.middleware.fastmcp"),
+    "ElidedMatch": (__spec__.parent, "agent_api.search.types"),
     "EmbeddingProviderServiceInfo": (__spec__.parent, "health.models"),
     "EndpointSettingsDict": (__spec__.parent, "config.types"),
     "ErrorHandlingMiddleware": (__spec__.parent, "mcp.middleware.fastmcp"),
@@ -164,6 +180,8 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "FastMcpHttpServerSettings": (__spec__.parent, "config.settings"),
     "FastMcpServerSettingsDict": (__spec__.parent, "config.types"),
     "FastMcpStdioServerSettings": (__spec__.parent, "config.settings"),
+    "find_references": (__spec__.parent, "agent_api.search"),
+    "find_references_tool": (__spec__.parent, "mcp.user_agent"),
     "FindCodeResponseSummary": (__spec__.parent, "agent_api.search.types"),
     "FindCodeSubmission": (__spec__.parent, "agent_api.search.types"),
     "HealthResponse": (__spec__.parent, "health.models"),
@@ -190,6 +208,11 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "ResponseCachingMiddlewareSettings": (__spec__.parent, "config.middleware"),
     "RetryMiddleware": (__spec__.parent, "mcp.middleware.fastmcp"),
     "RetryMiddlewareSettings": (__spec__.parent, "config.middleware"),
+    "SearchFilters": (__spec__.parent, "agent_api.search.types"),
+    "SearchMode": (__spec__.parent, "agent_api.search.types"),
+    "SearchScope": (__spec__.parent, "agent_api.search.types"),
+    "SearchSettings": (__spec__.parent, "config.types"),
+    "SearchSettingsDict": (__spec__.parent, "config.types"),
     "ServicesInfo": (__spec__.parent, "health.models"),
     "SparseEmbeddingServiceInfo": (__spec__.parent, "health.models"),
     "StatisticsInfo": (__spec__.parent, "health.models"),
@@ -244,6 +267,7 @@ __all__ = (
     "BRACKET_PATTERN",
     "CODEWEAVER_SVG_ICON",
     "BaseFastMcpServerSettings",
+    "CodeKind",
     "CodeMatch",
     "CodeMatchType",
     "CodeWeaverMCPConfig",
@@ -259,9 +283,11 @@ __all__ = (
     "DefaultFastMcpHttpRunArgs",
     "DefaultFastMcpServerSettings",
     "DefaultMiddlewareSettings",
+    "DefaultSearchSettings",
     "DefaultUvicornSettings",
     "DefaultUvicornSettingsForMcp",
     "DetailedTimingMiddleware",
+    "ElidedMatch",
     "EmbeddingProviderServiceInfo",
     "EndpointSettingsDict",
     "ErrorHandlingMiddleware",
@@ -301,6 +327,11 @@ __all__ = (
     "ResponseCachingMiddlewareSettings",
     "RetryMiddleware",
     "RetryMiddlewareSettings",
+    "SearchFilters",
+    "SearchMode",
+    "SearchScope",
+    "SearchSettings",
+    "SearchSettingsDict",
     "ServicesInfo",
     "SparseEmbeddingServiceInfo",
     "StatisticsInfo",
@@ -321,6 +352,8 @@ __all__ = (
     "favicon",
     "find_code",
     "find_code_tool",
+    "find_references",
+    "find_references_tool",
     "get_bulk_tool",
     "get_settings",
     "get_settings_map",
@@ -27,49 +27,83 @@ from lateimport import create_late_getattr
 
 
 if TYPE_CHECKING:
-    from codeweaver.server.agent_api.search import CodeWeaverSettingsType, MatchedSection, find_code
+    from codeweaver.server.agent_api.search import (
+        CodeWeaverSettingsType,
+        MatchedSection,
+        find_code,
+        find_references,
+        get_outline,
+        read_code,
+    )
     from codeweaver.server.agent_api.search.intent import (
         IntentResult,
         IntentType,
         QueryComplexity,
         QueryIntent,
     )
+    from codeweaver.server.agent_api.search.outline import OutlineResponse
+    from codeweaver.server.agent_api.search.reading import ReadCodeResponse
     from codeweaver.server.agent_api.search.types import (
+        CodeKind,
         CodeMatch,
         CodeMatchType,
+        ElidedMatch,
         FindCodeResponseSummary,
         FindCodeSubmission,
+        SearchFilters,
+        SearchMode,
+        SearchScope,
     )
 
 _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
+    "CodeKind": (__spec__.parent, "search.types"),
     "CodeMatch": (__spec__.parent, "search.types"),
     "CodeMatchType": (__spec__.parent, "search.types"),
     "CodeWeaverSettingsType": (__spec__.parent, "search"),
+    "ElidedMatch": (__spec__.parent, "search.types"),
+    "find_references": (__spec__.parent, "search"),
     "FindCodeResponseSummary": (__spec__.parent, "search.types"),
     "FindCodeSubmission": (__spec__.parent, "search.types"),
+    "get_outline": (__spec__.parent, "search"),
     "IntentResult": (__spec__.parent, "search.intent"),
     "IntentType": (__spec__.parent, "search.intent"),
     "MatchedSection": (__spec__.parent, "search"),
+    "OutlineResponse": (__spec__.parent, "search.outline"),
     "QueryComplexity": (__spec__.parent, "search.intent"),
     "QueryIntent": (__spec__.parent, "search.intent"),
     "find_code": (__spec__.parent, "search"),
+    "read_code": (__spec__.parent, "search"),
+    "ReadCodeResponse": (__spec__.parent, "search.reading"),
+    "SearchFilters": (__spec__.parent, "search.types"),
+    "SearchMode": (__spec__.parent, "search.types"),
+    "SearchScope": (__spec__.parent, "search.types"),
 })
 
 __getattr__ = create_late_getattr(_dynamic_imports, globals(), __name__)
 
 __all__ = (
+    "CodeKind",
     "CodeMatch",
     "CodeMatchType",
     "CodeWeaverSettingsType",
+    "ElidedMatch",
     "FindCodeResponseSummary",
     "FindCodeSubmission",
     "IntentResult",
     "IntentType",
     "MatchedSection",
+    "OutlineResponse",
     "QueryComplexity",
     "QueryIntent",
+    "ReadCodeResponse",
+    "SearchFilters",
+    "SearchMode",
+    "SearchScope",
     "find_code",
+    "find_references",
+    "get_outline",
     "get_user_agent",
+    "read_code",
 )
 
 
@@ -26,10 +26,19 @@ For example, your agent might say:
 
 The find_code package is organized into focused modules:
 
+- **assembly.py**: Expands, merges and packs matches into the token budget
+- **context_agent.py**: The opt-in agent that plans, re-runs and prunes `find_code` searches
 - **conversion.py**: Converts SearchResult objects to CodeMatch responses
-- **filters.py**: Post-search filtering (test files, language focus)
-- **pipeline.py**: Query embedding and vector search orchestration
+- **dependency_matches.py**: Answers dependency questions from the project's dependency graph
+- **exact_matches.py**: Exact modes -- ast-grep patterns, keywords, regexes and path globs
+- **filters.py**: Post-search filtering (test files, language focus, paths, kinds, symbols)
+- **operators.py**: Inline filter operators in queries, like `lang:rust kind:fn`
+- **outline.py**: File and symbol outlines for the `get_outline` tool
+- **pagination.py**: Continuation cursors and the cache of ranked candidates they page through
+- **pipeline.py**: Query embedding, vector store filters and vector search orchestration
+- **reading.py**: Files, line ranges and symbol definitions for the `read_code` tool
 - **scoring.py**: Score calculation, reranking, and semantic weighting
+- **symbol_matches.py**: Callers, callees and implementors from the symbol reference index
 
 This modular structure makes it easy to:
 - Add new filtering strategies
@@ -44,51 +53,108 @@ The design of `find_code` is heavily influenced by the concept of *Agent User Ex
 
 from __future__ import annotations
 
+import asyncio
 import logging
 import time
 
+from collections.abc import Sequence
 from pathlib import Path
-from typing import NamedTuple
+from typing import TYPE_CHECKING, NamedTuple, cast
 
+from codeweaver_tokenizers import Tokenizer
 from fastmcp.server.context import Context
 from pydantic import NonNegativeInt, PositiveInt
 
 from codeweaver.core import (
     INJECTED,
+    GitTree,
+    IndexingError,
+    QueryError,
     SearchStrategy,
     SettingsDep,
     Span,
     TelemetryServiceDep,
     TelemetrySettingsDep,
     capture_search_event,
+    get_project_path,
     log_to_client_or_fallback,
 )
 from codeweaver.core.config.settings_type import CodeWeaverSettingsType
 from codeweaver.core.constants import DEFAULT_MAX_RESULTS, DEFAULT_MAX_TOKENS
 from codeweaver.engine import IndexingServiceDep
 from codeweaver.engine.services.indexing_service import IndexingService
+from codeweaver.engine.services.symbol_index_service import SymbolIndex
 from codeweaver.providers import SearchPackageDep, VectorStoreProvider
 from codeweaver.providers.types import SearchPackage
 from codeweaver.semantic import AgentTask
+from codeweaver.semantic.ast_grep import Strictness
+from codeweaver.server.agent_api.search.assembly import assemble_context
 from codeweaver.server.agent_api.search.conversion import convert_search_result_to_code_match
-from codeweaver.server.agent_api.search.filters import apply_filters
+from codeweaver.server.agent_api.search.dependency_matches import find_dependency_matches
+from codeweaver.server.agent_api.search.exact_matches import find_exact_matches
+from codeweaver.server.agent_api.search.filters import (
+    apply_filters,
+    filter_live_chunks,
+    filter_matches,
+)
 from codeweaver.server.agent_api.search.intent import (
     INTENT_TO_AGENT_TASK,
     IntentType,
     detect_intent,
 )
+from codeweaver.server.agent_api.search.outline import (
+    OutlineResponse,
+    outline_path,
+    outline_symbol,
+    resolve_project_file,
+)
+from codeweaver.server.agent_api.search.pagination import (
+    RANKED_RESULTS,
+    RankedResults,
+    merged_page,
+    next_cursor,
+    query_fingerprint,
+    read_cursor,
+)
 from codeweaver.server.agent_api.search.pipeline import (
+    build_query_filter,
     build_query_vector,
     embed_query,
     execute_vector_search,
     rerank_results,
 )
+from codeweaver.server.agent_api.search.reading import (
+    ReadCodeResponse,
+    parse_expansion,
+    read_path,
+    read_symbol,
+)
 from codeweaver.server.agent_api.search.response import build_error_response, build_success_response
 from codeweaver.server.agent_api.search.scoring import (
     process_reranked_results,
     process_unranked_results,
 )
-from codeweaver.server.agent_api.search.types import CodeMatch, FindCodeResponseSummary
+from codeweaver.server.agent_api.search.symbol_matches import (
+    SymbolRelation,
+    enrich_related_symbols,
+    find_symbol_matches,
+)
+from codeweaver.server.agent_api.search.types import (
+    CodeKind,
+    CodeMatch,
+    FindCodeResponseSummary,
+    FindCodeSubmission,
+    SearchFilters,
+    SearchMode,
+    SearchScope,
+)
+from codeweaver.server.config.types import SearchSettings
+
+
+if TYPE_CHECKING:
+    from pydantic_ai.models import Model
+
+    from codeweaver.engine import IndexFileManifest
 
 
 logger = logging.getLogger(__name__)
@@ -182,6 +248,86 @@ async def _resolve_indexer_from_container() -> IndexingService | None:
         return None
 
 
+async def _resolve_dependency_matches(
+    query: str, crates: tuple[str, ...] | None
+) -> list[CodeMatch]:
+    """Answer dependency questions ("what depends on serde?") from the dependency graph."""
+    try:
+        from codeweaver.core.di.container import get_container
+        from codeweaver.engine import DependencyService
+
+        service = await get_container().resolve(DependencyService)
+        graph = await asyncio.to_thread(service.graph)
+        return await find_dependency_matches(query, graph, service.project_path, crates=crates)
+    except Exception as e:
+        logger.debug("Dependency graph lookup failed: %s", e, exc_info=True)
+        return []
+
+
+async def _resolve_symbol_index() -> tuple[SymbolIndex, Path] | None:
+    """Get the project's symbol reference index and root from the DI container."""
+    try:
+        from codeweaver.core.di.container import get_container
+        from codeweaver.engine import SymbolIndexService
+
+        service = await get_container().resolve(SymbolIndexService)
+        return await service.get_index(), service.project_path
+    except Exception as e:
+        logger.debug("Symbol index lookup failed: %s", e, exc_info=True)
+        return None
+
+
+async def _resolve_exact_matches(
+    query: str,
+    mode: SearchMode,
+    *,
+    strictness: Strictness | None,
+    focus_languages: tuple[str, ...] | None,
+    crates: tuple[str, ...] | None,
+    max_results: int,
+) -> list[CodeMatch]:
+    """Run an exact (pattern, keyword, regex or path) search over the project's files."""
+    from codeweaver.core.di.container import get_container
+    from codeweaver.engine import ExactSearchService
+
+    service = await get_container().resolve(ExactSearchService)
+    return await find_exact_matches(
+        query,
+        mode,
+        service,
+        strictness=strictness,
+        focus_languages=focus_languages,
+        crates=crates,
+        max_results=max_results,
+    )
+
+
+async def _resolve_search_settings() -> SearchSettings:
+    """Get the search settings, falling back to the defaults when none are configured."""
+    try:
+        from codeweaver.core.di.container import get_container
+
+        settings = await get_container().resolve(CodeWeaverSettingsType)
+    except Exception as e:
+        logger.debug("Using default search settings: %s", e)
+        return SearchSettings()
+    search = getattr(settings, "search", None)
+    return search if isinstance(search, SearchSettings) else SearchSettings()
+
+
+async def _resolve_tokenizer() -> Tokenizer:
+    """Get the configured tokenizer, falling back to `o200k_base` if it can't be resolved."""
+    from codeweaver_tokenizers import get_tokenizer
+
+    try:
+        from codeweaver.core.di.container import get_container
+
+        return await get_container().resolve(Tokenizer)
+    except Exception as e:
+        logger.debug("Tokenizer lookup failed, using o200k_base: %s", e, exc_info=True)
+        return get_tokenizer("tiktoken", "o200k_base")
+
+
 async def _ensure_index_ready(
     context: Context | None = None,
     vector_store: VectorStoreProvider | None = None,
@@ -204,6 +350,48 @@ async def _ensure_index_ready(
             logger.warning("Auto-indexing failed: %s", e, exc_info=True)
 
 
+async def _resolve_revision(
+    rev: str, vector_store: VectorStoreProvider | None
+) -> tuple[GitTree, VectorStoreProvider]:
+    """Resolve a git revision and get the vector store holding its index.
+
+    Raises:
+        IndexingError: If `rev` doesn't name a commit or the commit hasn't been indexed
+    """
+    tree = await asyncio.to_thread(GitTree.resolve, get_project_path(), rev)
+    store = vector_store.for_revision(tree.commit) if vector_store else None
+    index_exists, chunk_count = await _check_index_status(None, vector_store=store)
+    if store is None or not index_exists or chunk_count == 0:
+        raise IndexingError(
+            f"{rev} ({tree.short_commit}) hasn't been indexed",
+            details={"rev": rev, "commit": tree.commit},
+            suggestions=[f"Index it first with `cw index --rev {rev}`"],
+        )
+    return tree, store
+
+
+async def _resolve_dependency_store(
+    vector_store: VectorStoreProvider | None,
+) -> VectorStoreProvider:
+    """Get the vector store holding the dependency sources' index.
+
+    Raises:
+        IndexingError: If the dependency sources haven't been indexed
+    """
+    store = vector_store.for_dependencies() if vector_store else None
+    index_exists, chunk_count = await _check_index_status(None, vector_store=store)
+    if store is None or not index_exists or chunk_count == 0:
+        raise IndexingError(
+            "The project's dependency sources haven't been indexed",
+            details={"scope": SearchScope.DEPS.variable},
+            suggestions=[
+                "Index them first with `cw index --deps`, or set the indexer's "
+                "`index_dependency_sources` setting"
+            ],
+        )
+    return store
+
+
 async def _build_search_package(package: SearchPackageDep) -> SearchPackage:
     """Build a search package from the given dependency."""
     return package
@@ -239,20 +427,23 @@ async def _handle_intent_detection(
 async def _process_and_score_candidates(
     query: str,
     candidates: list,
-    intent_type: IntentType,
     agent_task: AgentTask,
     context: Context | None,
     reranking_provider,
+    boost_factor: float,
 ) -> tuple[list, list[SearchStrategy]]:
     """Apply reranking and semantic scoring to search candidates.
 
+    Chunks are boosted by how important their semantic class is for `agent_task`, up to
+    `boost_factor` (the configured `search.semantic_boost`).
+
     Args:
         query: Search query
         candidates: Initial search candidates
-        intent_type: Detected query intent
         agent_task: Mapped agent task
         context: Optional MCP context
         reranking_provider: Optional reranking provider
+        boost_factor: Maximum semantic boost
 
     Returns:
         Tuple of (scored_candidates, strategies_used)
@@ -269,10 +460,12 @@ async def _process_and_score_candidates(
     # Apply semantic weights
     if reranked_results:
         scored_candidates = process_reranked_results(
-            reranked_results, candidates, intent_type, agent_task
+            reranked_results, candidates, agent_task, boost_factor=boost_factor
         )
     else:
-        scored_candidates = process_unranked_results(candidates, intent_type, agent_task)
+        scored_candidates = process_unranked_results(
+            candidates, agent_task, boost_factor=boost_factor
+        )
 
     return scored_candidates, strategies_used
 
@@ -287,11 +480,15 @@ async def _finalize_response(
     strategies_used: list[SearchStrategy],
     telemetry_settings,
     telemetry,
+    *,
+    expand: int = 0,
+    tree: GitTree | None = None,
+    cursor: str | None = None,
 ) -> FindCodeResponseSummary:
-    """Build final response and capture telemetry.
+    """Assemble matches into the token budget, build the final response and capture telemetry.
 
     Args:
-        code_matches: Converted search results
+        code_matches: Converted search results, in rank order
         query: Original search query
         intent_type: Detected intent
         total_candidates: Total number of candidates
@@ -300,18 +497,33 @@ async def _finalize_response(
         strategies_used: List of search strategies used
         telemetry_settings: Telemetry configuration
         telemetry: Telemetry service
+        expand: How many enclosing items to grow each match to
+        tree: The git revision the matches are from, if they aren't from the working tree
+        cursor: Cursor for the next page of results, if there is one
 
     Returns:
         Final response summary
     """
+    # The symbol index describes the working tree, so a revision's matches go without signatures
+    assembled = assemble_context(
+        code_matches,
+        token_limit=token_limit,
+        tokenizer=await _resolve_tokenizer(),
+        expand=expand,
+        symbols=None if tree else await _resolve_symbol_index(),
+        read_file=tree.read_text if tree else None,
+    )
     response = build_success_response(
-        code_matches=code_matches,
+        code_matches=assembled.matches,
         query=query,
         intent_type=intent_type,
         total_candidates=total_candidates,
         token_limit=token_limit,
         execution_time_ms=execution_time_ms,
         strategies_used=strategies_used,
+        token_count=assembled.token_count,
+        elided=assembled.elided,
+        cursor=cursor,
     )
 
     if getattr(telemetry_settings, "tools_over_privacy", False):
@@ -335,19 +547,321 @@ async def _finalize_response(
     return response
 
 
+def _merge_filters(
+    filters: SearchFilters | None,
+    focus_languages: tuple[str, ...] | None,
+    crates: tuple[str, ...] | None,
+) -> SearchFilters:
+    """Add `find_code`'s `focus_languages` and `crates` arguments to its filters."""
+    filters = filters or SearchFilters()
+    return filters.model_copy(
+        update={
+            "languages": tuple(dict.fromkeys((*filters.languages, *(focus_languages or ())))),
+            "crates": tuple(dict.fromkeys((*filters.crates, *(crates or ())))),
+        }
+    )
+
+
+async def _resolve_index_generation(tree: GitTree | None, scope: SearchScope) -> str | None:
+    """The generation of the index a search runs against, which its cursors are tied to.
+
+    A revision's index doesn't change once it's built, so its generation is its commit. None
+    when it can't be told, so no cursors are issued.
+    """
+    if tree is not None:
+        return tree.commit
+    if (indexer := await _resolve_indexer_from_container()) is None:
+        return None
+    if scope == SearchScope.DEPS:
+        return await indexer.dependency_index_generation()
+    return await indexer.index_generation()
+
+
+async def _resolve_live_manifest(
+    tree: GitTree | None, scope: SearchScope
+) -> IndexFileManifest | None:
+    """The manifest of the working tree's index, which searches keep to the live chunks of.
+
+    A revision's index doesn't change once it's built, and dependency sources are only ever
+    added, so their searches see every chunk.
+    """
+    if tree is not None or scope == SearchScope.DEPS:
+        return None
+    if (indexer := await _resolve_indexer_from_container()) is None:
+        return None
+    return await indexer.live_manifest()
+
+
+async def _resolve_page(
+    cursor: str | None,
+    query: str,
+    *,
+    filters: SearchFilters,
+    intent: IntentType | None,
+    mode: SearchMode,
+    strictness: Strictness | None,
+    rev: str | None,
+    tree: GitTree | None,
+    scope: SearchScope = SearchScope.PROJECT,
+) -> tuple[str, str | None, int]:
+    """Fingerprint a query and find where its page starts.
+
+    Returns:
+        The query's fingerprint, the current index generation (None if it's unknown), and the
+        page's offset
+
+    Raises:
+        QueryError: If the cursor is invalid, is for another query, or is stale
+    """
+    generation = await _resolve_index_generation(tree, scope)
+    fingerprint = query_fingerprint(
+        query,
+        filters=filters,
+        intent=intent,
+        mode=mode,
+        strictness=strictness,
+        rev=rev,
+        scope=scope,
+    )
+    offset = read_cursor(cursor, fingerprint, generation) if cursor else 0
+    return fingerprint, generation, offset
+
+
+def _filters_for_intent(filters: SearchFilters, intent_type: IntentType) -> SearchFilters:
+    """The filters with test code left out unless the caller, the intent or `kind:test` wants it."""
+    if filters.include_tests is not None:
+        return filters
+    wants_tests = (
+        intent_type in {IntentType.DEBUG, IntentType.TEST} or CodeKind.TEST in filters.kinds
+    )
+    return filters.model_copy(update={"include_tests": wants_tests})
+
+
+def _filter_code_matches(matches: list[CodeMatch], filters: SearchFilters) -> list[CodeMatch]:
+    """Matches found outside the vector store, through the same post-search filters."""
+    return filter_matches(
+        matches,
+        include_tests=filters.include_tests,
+        focus_languages=filters.languages or None,
+        paths=filters.paths or None,
+        exclude_paths=filters.exclude_paths or None,
+        kinds=filters.kinds or None,
+        symbol_prefix=filters.symbol_prefix,
+    )
+
+
+async def _rank_candidates(
+    query: str,
+    filters: SearchFilters,
+    intent_type: IntentType,
+    agent_task: AgentTask,
+    *,
+    context: Context | None,
+    vector_store: VectorStoreProvider | None,
+    search_package: SearchPackage,
+    manifest: IndexFileManifest | None = None,
+) -> tuple[list, list[SearchStrategy]]:
+    """Embed a query, search the vector store and rank every candidate that passes the filters.
+
+    With the index's `manifest`, only the chunks it has live are ranked (see
+    `filters.filter_live_chunks`).
+
+    Returns:
+        Tuple of (candidates in rank order, strategies_used)
+    """
+    strategies_used: list[SearchStrategy] = []
+    search_settings = await _resolve_search_settings()
+
+    # Embed query (dense + sparse)
+    embeddings = await embed_query(
+        query,
+        context=context,
+        dense_provider=search_package.embedding,
+        sparse_provider=search_package.sparse_embedding,
+    )
+
+    # Build query vector and determine strategy
+    query_vector = build_query_vector(
+        embeddings, query, fusion=search_settings.fusion_strategy(intent_type.value)
+    )
+    strategies_used.append(query_vector.strategy)
+
+    # Execute vector search (filters are applied by the vector store)
+    filters = _filters_for_intent(filters, intent_type)
+    candidates = await execute_vector_search(
+        query_vector,
+        context=context,
+        vector_store=vector_store,
+        query_filter=build_query_filter(filters),
+    )
+    if manifest is not None:
+        candidates = filter_live_chunks(candidates, manifest)
+
+    # Post-search filtering, for path globs and points indexed without the fields
+    candidates = apply_filters(
+        candidates,
+        include_tests=filters.include_tests,
+        focus_languages=filters.languages or None,
+        paths=filters.paths or None,
+        exclude_paths=filters.exclude_paths or None,
+        kinds=filters.kinds or None,
+        symbol_prefix=filters.symbol_prefix,
+    )
+
+    logger.info("Vector search returned %d candidates after filtering", len(candidates))
+
+    # Rerank and score
+    scored_candidates, rerank_strategies = await _process_and_score_candidates(
+        query,
+        candidates,
+        agent_task,
+        context,
+        search_package.reranking,
+        search_settings.semantic_boost,
+    )
+    strategies_used.extend(rerank_strategies)
+
+    # Sort
+    scored_candidates.sort(
+        key=lambda x: x.relevance_score if x.relevance_score is not None else x.score,
+        reverse=True,
+    )
+    return scored_candidates, strategies_used
+
+
+async def _resolve_context_agent_model() -> Model | str | None:
+    """The configured agent provider's model for the context agent, or None if there isn't one."""
+    try:
+        from pydantic_ai import Agent
+
+        from codeweaver.core.di.container import get_container
+
+        agent = await get_container().resolve(Agent)
+    except Exception as e:
+        logger.debug("No agent provider for the context agent: %s", e)
+        return None
+    return (agent.model or None) if isinstance(agent, Agent) else None
+
+
+def _context_agent_search(filters: SearchFilters, scope: SearchScope) -> ContextSearch:
+    """Search through the context agent's toolkit, with the `find_code` call's filters and scope."""
+    from codeweaver.server.mcp.tools import CONTEXT_AGENT_TOOLKIT
+
+    search_tool = CONTEXT_AGENT_TOOLKIT["search_tool"]
+
+    async def search(query: str, intent: IntentType) -> Sequence[CodeMatch]:
+        response = await search_tool.fn(  # ty:ignore[unresolved-attribute]
+            query, intent, filters=filters, scope=scope
+        )
+        return response.matches
+
+    return search
+
+
+def _record_context_agent_tokens(used: int, saved: int) -> None:
+    """Record the context agent's token spend, and the tokens it kept out of a response."""
+    from codeweaver.core.di import get_container
+    from codeweaver.core.statistics import SessionStatistics
+
+    try:
+        statistics = get_container()[SessionStatistics]
+    except KeyError:
+        return
+    statistics.add_token_usage(context_agent_used=used, saved_by_context_agent=saved)
+
+
+async def _run_context_agent(
+    query: str,
+    code_matches: list[CodeMatch],
+    filters: SearchFilters,
+    intent: IntentType | None,
+    scope: SearchScope = SearchScope.PROJECT,
+) -> tuple[ContextAgentResult | None, str | None]:
+    """Review a page of matches with the context agent.
+
+    Returns:
+        The agent's result, or None and a warning for the response if it couldn't run
+    """
+    from codeweaver.server.agent_api.search.context_agent import run_context_agent
+
+    if (model := await _resolve_context_agent_model()) is None:
+        return None, "No agent provider is configured, so the context agent didn't run"
+    try:
+        result = await run_context_agent(
+            query, code_matches, _context_agent_search(filters, scope), model, intent=intent
+        )
+    except Exception as e:
+        logger.warning("Context agent failed: %s", e, exc_info=True)
+        return None, f"The context agent failed ({type(e).__name__}), so results weren't reviewed"
+    tokenizer = await _resolve_tokenizer()
+    saved = sum(tokenizer.estimate(match.content.content) for match in result.pruned)
+    _record_context_agent_tokens(result.tokens, saved)
+    logger.info(
+        "Context agent ran %d searches and kept %d of %d matches",
+        len(result.searches),
+        len(result.matches),
+        len(result.matches) + len(result.pruned),
+    )
+    return result, None
+
+
 async def find_code(
     query: str,
     *,
     intent: IntentType | None = None,
     token_limit: int = DEFAULT_MAX_TOKENS,
     focus_languages: tuple[str, ...] | None = None,
+    crates: tuple[str, ...] | None = None,
+    filters: SearchFilters | None = None,
     max_results: int = DEFAULT_MAX_RESULTS,
+    mode: SearchMode = SearchMode.SEMANTIC,
+    strictness: Strictness | None = None,
+    expand: NonNegativeInt = 0,
+    rev: str | None = None,
+    scope: SearchScope = SearchScope.PROJECT,
+    agentic: bool = False,
+    cursor: str | None = None,
     context: Context | None = None,
     search_package: SearchPackageDep = INJECTED,
     telemetry_settings: TelemetrySettingsDep = INJECTED,
     telemetry: TelemetryServiceDep = INJECTED,
 ) -> FindCodeResponseSummary:
-    """Find relevant code based on semantic search with intent-driven ranking."""
+    """Find relevant code based on semantic search with intent-driven ranking.
+
+    With a `mode` other than `SearchMode.SEMANTIC`, the query is also matched exactly against
+    the project's files as an ast-grep pattern (with `strictness`), a keyword, a regex or a path
+    glob. The exact hits come first, followed by what the index ranks for the same query; exact
+    modes don't need an index, and without one return only the exact hits.
+
+    `filters` (with `focus_languages` and `crates` added to it) is pushed down to the vector
+    store, so it ranks only the chunks that pass; exact hits go through the same filters. In
+    semantic mode, filter operators in the query like `lang:rust path:crates/auth/** kind:fn`
+    are taken out of it and added to `filters` (see `operators`).
+
+    Matches are then merged, optionally grown by `expand` enclosing items (function, then impl
+    block or class, then file), and packed into `token_limit`; what didn't fit is listed in the
+    response's `elided`.
+
+    With `rev` (a commit, branch or tag indexed with `cw index --rev`), the search runs against
+    that revision's index and reads its files from git, so past releases and other branches can
+    be searched without checking them out. Only semantic search supports `rev`.
+
+    With `scope=SearchScope.DEPS`, the search runs against the sources of the project's
+    third-party dependencies instead, indexed with `cw index --deps` (see
+    `engine.services.dependency_sources`); `filters.packages` narrows it to some of them. Matches
+    point into Cargo's registry, `node_modules` or `site-packages`. Only semantic search supports
+    it, for the working tree.
+
+    With `agentic`, the context agent reviews the matches before they're assembled: it checks the
+    intent, runs rewritten or split-up queries, and drops matches that don't answer the query
+    (see `context_agent`). It needs a configured agent provider; without one, the matches are
+    returned as ranked, with a warning.
+
+    Each response holds a page of `max_results` matches. If there are more, its `cursor` gets the
+    next page when passed back with the same arguments; pages are slices of one ranking, cached
+    per query (see `pagination`). A cursor from before a re-index fails as stale; while the index
+    generation is unknown (the project was never indexed), no cursor is issued.
+    """
     # Resolve dependencies if not provided (supports direct calls in tests)
     from codeweaver.core.di import get_container
     from codeweaver.core.di.dependency import is_depends_marker
@@ -370,12 +884,52 @@ async def find_code(
     strategies_used: list[SearchStrategy] = []
 
     try:
-        # Step 0: Auto-index if needed
-        index_exists, chunk_count = await _check_index_status(
-            context, vector_store=search_package.vector_store
+        filters = _merge_filters(filters, focus_languages, crates)
+        if mode == SearchMode.SEMANTIC:
+            submission = FindCodeSubmission(
+                query=query, intent=intent, filters=filters
+            ).with_query_operators()
+            query, filters = submission.query, submission.filters or filters
+        if agentic and mode != SearchMode.SEMANTIC:
+            raise QueryError(
+                f"The context agent only works in semantic mode, not {mode.variable}",
+                details={"mode": mode.variable},
+                suggestions=["Drop `agentic`, or use semantic mode"],
+            )
+        tree: GitTree | None = None
+        if rev is not None:
+            if mode != SearchMode.SEMANTIC:
+                raise QueryError(
+                    f"Searching a git revision only works in semantic mode, not {mode.variable}",
+                    details={"rev": rev, "mode": mode.variable},
+                    suggestions=["Drop `rev` to search the working tree, or use semantic mode"],
+                )
+            tree, vector_store = await _resolve_revision(rev, search_package.vector_store)
+        else:
+            vector_store = search_package.vector_store
+        deps = scope == SearchScope.DEPS
+        if deps:
+            if mode != SearchMode.SEMANTIC or tree is not None:
+                raise QueryError(
+                    "Searching dependency sources only works in semantic mode, without `rev`",
+                    details={"scope": scope.variable, "mode": mode.variable, "rev": rev},
+                    suggestions=["Use semantic mode without `rev`, or search the project scope"],
+                )
+            vector_store = await _resolve_dependency_store(search_package.vector_store)
+
+        # Exact modes match the files directly; their hits come first, ahead of what the index
+        # ranks for the same query
+        exact = mode != SearchMode.SEMANTIC
+
+        # Step 0: Auto-index if needed (a revision's or the dependencies' index was checked
+        # when it was resolved). Exact modes don't need the index, so they don't wait for one.
+        index_exists, chunk_count = (
+            (True, 1)
+            if tree or deps
+            else await _check_index_status(context, vector_store=vector_store)
         )
-
-        if not index_exists or chunk_count == 0:
+        index_ready = index_exists and chunk_count > 0
+        if not index_ready and not exact:
             # Full indexing needed - BLOCK and wait
             await log_to_client_or_fallback(
                 context,
@@ -385,72 +939,141 @@ async def find_code(
                 },
             )
             await _ensure_index_ready(context, vector_store=search_package.vector_store)
+            index_ready = True
 
-        # Step 1: Intent detection
+        # Step 1: Intent detection, and where the page starts
         intent_type, agent_task = await _handle_intent_detection(query, intent)
-
-        # Step 2: Embed query (dense + sparse)
-        embeddings = await embed_query(
+        fingerprint, generation, offset = await _resolve_page(
+            cursor,
             query,
-            context=context,
-            dense_provider=search_package.embedding,
-            sparse_provider=search_package.sparse_embedding,
-        )
-
-        # Step 3: Build query vector and determine strategy
-        query_vector = build_query_vector(embeddings, query)
-        strategies_used.append(query_vector.strategy)
-
-        # Step 4: Execute vector search
-        candidates = await execute_vector_search(
-            query_vector, context=context, vector_store=search_package.vector_store
-        )
-
-        # Step 5: Post-search filtering
-        candidates = apply_filters(
-            candidates,
-            include_tests=intent_type in {IntentType.DEBUG, IntentType.TEST},
-            focus_languages=focus_languages,
+            filters=filters,
+            intent=intent,
+            mode=mode,
+            strictness=strictness,
+            rev=rev,
+            tree=tree,
+            scope=scope,
         )
 
-        logger.info("Vector search returned %d candidates after filtering", len(candidates))
-
-        # Step 6: Rerank and score
-        scored_candidates, rerank_strategies = await _process_and_score_candidates(
-            query, candidates, intent_type, agent_task, context, search_package.reranking
-        )
-        strategies_used.extend(rerank_strategies)
+        # Step 1a: Exact hits, through the same filters as the ranked results. One more than the
+        # page holds tells whether the exact hits alone fill a next page.
+        exact_matches: list[CodeMatch] = []
+        if exact:
+            exact_matches = _filter_code_matches(
+                await _resolve_exact_matches(
+                    query,
+                    mode,
+                    strictness=strictness,
+                    focus_languages=filters.languages or None,
+                    crates=filters.crates or None,
+                    max_results=offset + max_results + 1,
+                ),
+                _filters_for_intent(filters, intent_type),
+            )
+            strategies_used.append(mode.strategy)
+
+        # Step 1b: Dependency questions are answered precisely, ahead of the semantic matches (for
+        # the working tree only; the dependency graph describes it, not the revision). Like exact
+        # hits, they lead the ranked results, so pages and cursors count them.
+        dependency_matches: list[CodeMatch] = []
+        if not exact and tree is None and not deps:
+            dependency_matches = _filter_code_matches(
+                await _resolve_dependency_matches(query, filters.crates or None),
+                _filters_for_intent(filters, intent_type),
+            )
 
-        # Step 7: Sort and limit
-        scored_candidates.sort(
-            key=lambda x: x.relevance_score if x.relevance_score is not None else x.score,
-            reverse=True,
-        )
-        search_results = scored_candidates[:max_results]
+        # Steps 2-7: Embed, search, filter, rerank and sort -- unless an earlier page did. Exact
+        # hits stand on their own when there's no index to rank with.
+        # A ranking of an unknown generation isn't cached, since it couldn't be told apart from
+        # one of the index after it changes.
+        ranked = RankedResults((), ())
+        if (
+            index_ready
+            and generation is not None
+            and (cached := RANKED_RESULTS.get(fingerprint, generation)) is not None
+        ):
+            ranked = cached
+        elif index_ready:
+            try:
+                candidates, strategies = await _rank_candidates(
+                    query,
+                    filters,
+                    intent_type,
+                    agent_task,
+                    context=context,
+                    vector_store=vector_store,
+                    search_package=search_package,
+                    manifest=await _resolve_live_manifest(tree, scope),
+                )
+                ranked = (
+                    RankedResults(tuple(candidates), tuple(strategies))
+                    if generation is None
+                    else RANKED_RESULTS.put(fingerprint, generation, candidates, strategies)
+                )
+            except Exception as e:
+                if not exact:
+                    raise
+                logger.warning("Returning exact matches only; ranking failed: %s", e)
+        strategies_used.extend(ranked.strategies)
+        # Only one of the two kinds of leading matches is ever found
+        leading = exact_matches or dependency_matches
+        leading_page, search_results = merged_page(leading, ranked.candidates, offset, max_results)
+        total_candidates = len(leading) + len(ranked.candidates)
+        dependency_page = leading_page if dependency_matches else ()
 
         # Step 8: Convert to CodeMatch objects for response
-        code_matches: list[CodeMatch] = []
+        code_matches: list[CodeMatch] = [] if dependency_page else list(leading_page)
         for result in search_results:
             try:
-                match: CodeMatch = await convert_search_result_to_code_match(result)
+                match: CodeMatch = await convert_search_result_to_code_match(result, tree=tree)
                 code_matches.append(match)
             except Exception as e:
                 logger.warning("Failed to convert search result to code match: %s", e)
                 continue
 
-        # Step 9: Build response and capture telemetry
+        # Step 8a: With `agentic`, the context agent checks the intent, searches again and prunes
+        agent_warning: str | None = None
+        if agentic:
+            reviewed, agent_warning = await _run_context_agent(
+                query, code_matches, filters, intent, scope
+            )
+            if reviewed is not None:
+                code_matches, intent_type = list(reviewed.matches), reviewed.intent
+                strategies_used.append(SearchStrategy.CONTEXT_AGENT)
+
+        # Step 8b: List real callers, callees and implementors as related symbols (the symbol
+        # index covers the project's working tree only)
+        if (
+            code_matches
+            and tree is None
+            and not deps
+            and (symbols := await _resolve_symbol_index())
+        ):
+            code_matches = enrich_related_symbols(code_matches, symbols[0])
+
+        # Step 8c: The page's dependency matches go ahead of its semantic matches
+        if dependency_page:
+            strategies_used.append(SearchStrategy.TEXT_SEARCH)
+            code_matches = [*dependency_page, *code_matches]
+
+        # Step 9: Assemble into the token budget, build response and capture telemetry
         execution_time_ms = (time.monotonic() - start_time) * 1000
         response = await _finalize_response(
             code_matches,
             query,
             intent_type,
-            len(scored_candidates),
+            total_candidates,
             token_limit,
             execution_time_ms,
             strategies_used,
             telemetry_settings,
             telemetry,
+            expand=expand,
+            tree=tree,
+            cursor=next_cursor(fingerprint, generation, offset + max_results, total_candidates),
         )
+        if agent_warning:
+            response = response.model_copy(update={"warnings": [*response.warnings, agent_warning]})
 
     except Exception as e:
         logger.warning("find_code failed: %s", e, exc_info=True)
@@ -483,6 +1106,134 @@ async def find_code(
         return response
 
 
+async def find_references(
+    symbol: str,
+    relation: SymbolRelation = "callers",
+    *,
+    token_limit: int = DEFAULT_MAX_TOKENS,
+    max_results: int = DEFAULT_MAX_RESULTS,
+) -> FindCodeResponseSummary:
+    """Find a symbol's callers, callees, implementors, definitions or importers.
+
+    Answered from the symbol reference index built during indexing, without a vector search.
+
+    Args:
+        symbol: The symbol, as a bare name or a path like `Cache::get` or `cache.get`
+        relation: Which occurrences to return
+        token_limit: Maximum tokens to return
+        max_results: Maximum number of matches to return
+
+    Returns:
+        A response whose matches are the occurrences, in file and line order
+    """
+    start_time = time.monotonic()
+    code_matches: list[CodeMatch] = []
+    if symbols := await _resolve_symbol_index():
+        index, project_path = symbols
+        code_matches = await find_symbol_matches(
+            symbol, relation, index, project_path, max_results=max_results
+        )
+    return build_success_response(
+        code_matches=code_matches,
+        query=f"{relation} of {symbol}",
+        intent_type=IntentType.UNDERSTAND,
+        total_candidates=len(code_matches),
+        token_limit=token_limit,
+        execution_time_ms=(time.monotonic() - start_time) * 1000,
+        strategies_used=[SearchStrategy.SYMBOL_SEARCH],
+    )
+
+
+async def get_outline(target: str) -> OutlineResponse:
+    """Outline a file, or the items named like a symbol, with signatures and docs but no bodies.
+
+    Args:
+        target: A file path (relative to the project root, or absolute within it), or a symbol
+            as a bare name or a path like `Cache::get`
+
+    Returns:
+        A response with the outline of the file, or of the symbol's items in each file that
+        defines it
+    """
+    project_path = get_project_path()
+    if (path := resolve_project_file(target, project_path)) is not None:
+        return await outline_path(target, path, project_path)
+    if not (symbols := await _resolve_symbol_index()):
+        return OutlineResponse(
+            target=target,
+            summary=f"No file named {target}, and the symbol index isn't available",
+        )
+    index, project_path = symbols
+    return await outline_symbol(target, index, project_path)
+
+
+async def _passes_ignore_rules(relative: Path, project_path: Path) -> bool:
+    """Whether a file passes the indexer's ignore rules (or, without them, the ignore files)."""
+    from codeweaver.engine import ExactSearchService
+
+    try:
+        from codeweaver.core.di.container import get_container
+
+        service = await get_container().resolve(ExactSearchService)
+    except Exception as e:
+        logger.debug("Indexer walk settings unavailable, using ignore files only: %s", e)
+        service = ExactSearchService(project_path)
+    return await asyncio.to_thread(service.includes, relative)
+
+
+async def read_code(
+    path: str | None = None,
+    span: tuple[PositiveInt, PositiveInt] | None = None,
+    *,
+    symbol: str | None = None,
+    expand: str | None = None,
+) -> ReadCodeResponse:
+    """Read a file, a line range of it, or a symbol's definitions.
+
+    Args:
+        path: A file path, relative to the project root or absolute within it
+        span: The 1-based, inclusive `(start, end)` lines to read from `path`
+        symbol: A symbol whose definitions to read, as a bare name or a path like `Cache::get`;
+            with `path`, only definitions in that file are read
+        expand: `"item"` to widen the lines to the item they're in, or `"lines:N"` to add N
+            lines on each side; symbols are widened to their items unless this says otherwise
+
+    Returns:
+        A response with the lines read, each section with its `Span`
+
+    Raises:
+        ValueError: If neither a path nor a symbol is given, or `span` or `expand` is invalid
+    """
+    expansion = parse_expansion(expand)
+    if path is None and symbol is None:
+        raise ValueError("read_code needs a path, a symbol, or both")
+    if span is not None and span[0] > span[1]:
+        raise ValueError(f"span must be (start, end) with start <= end, not {span}")
+    project_path = get_project_path()
+    file: Path | None = None
+    if path is not None:
+        if (file := resolve_project_file(path, project_path)) is None:
+            return ReadCodeResponse(target=path, summary=f"{path} isn't a file in the project")
+        relative = file.relative_to(project_path.resolve())
+        if not await _passes_ignore_rules(relative, project_path):
+            return ReadCodeResponse(
+                target=path, summary=f"{path} is excluded by the project's ignore rules"
+            )
+        if symbol is None:
+            return await read_path(path, file, project_path, span, expansion)
+    symbol = cast(str, symbol)
+    if not (symbols := await _resolve_symbol_index()):
+        return ReadCodeResponse(target=symbol, summary="The symbol index isn't available")
+    index, project_path = symbols
+    return await read_symbol(
+        symbol,
+        index,
+        project_path,
+        "item" if expand is None else expansion,
+        in_file=file.relative_to(project_path.resolve()) if file is not None else None,
+    )
+
+
 # === MANAGED EXPORTS ===
 
 # Exportify manages this section. It contains lazy-loading infrastructure
@@ -496,14 +1247,39 @@ from lateimport import create_late_getattr
 
 
 if TYPE_CHECKING:
-    from codeweaver.server.agent_api.search.filters import filter_by_languages, filter_test_files
+    from codeweaver.server.agent_api.search.assembly import AssembledContext
+    from codeweaver.server.agent_api.search.context_agent import (
+        ContextAgentDeps,
+        ContextAgentResult,
+        ContextSearch,
+        QueryPlan,
+        ResultReview,
+        run_context_agent,
+        search_code,
+    )
+    from codeweaver.server.agent_api.search.dependency_matches import dependency_query_targets
+    from codeweaver.server.agent_api.search.filters import (
+        filter_by_excluded_paths,
+        filter_by_kinds,
+        filter_by_languages,
+        filter_by_paths,
+        filter_by_symbol_prefix,
+        filter_test_files,
+    )
     from codeweaver.server.agent_api.search.intent import (
         INTENT_KEYWORDS,
         IntentResult,
         QueryComplexity,
         QueryIntent,
     )
+    from codeweaver.server.agent_api.search.operators import parse_query_operators
+    from codeweaver.server.agent_api.search.outline import FileOutline
+    from codeweaver.server.agent_api.search.pagination import (
+        PageCursor,
+        RankedResultsCache,
+    )
     from codeweaver.server.agent_api.search.pipeline import raise_value_error
+    from codeweaver.server.agent_api.search.reading import CodeSection
     from codeweaver.server.agent_api.search.response import (
         calculate_token_count,
         extract_languages,
@@ -514,13 +1290,26 @@ if TYPE_CHECKING:
         apply_hybrid_weights,
         apply_semantic_weighting,
     )
-    from codeweaver.server.agent_api.search.types import CodeMatchType, FindCodeSubmission
+    from codeweaver.server.agent_api.search.types import CodeMatchType, ElidedMatch
 
 _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "INTENT_KEYWORDS": (__spec__.parent, "intent"),
+    "AssembledContext": (__spec__.parent, "assembly"),
     "CodeMatchType": (__spec__.parent, "types"),
-    "FindCodeSubmission": (__spec__.parent, "types"),
+    "CodeSection": (__spec__.parent, "reading"),
+    "ContextAgentDeps": (__spec__.parent, "context_agent"),
+    "ContextAgentResult": (__spec__.parent, "context_agent"),
+    "ContextSearch": (__spec__.parent, "context_agent"),
+    "dependency_query_targets": (__spec__.parent, "dependency_matches"),
+    "ElidedMatch": (__spec__.parent, "types"),
+    "FileOutline": (__spec__.parent, "outline"),
+    "filter_by_excluded_paths": (__spec__.parent, "filters"),
+    "filter_by_kinds": (__spec__.parent, "filters"),
+    "filter_by_paths": (__spec__.parent, "filters"),
+    "filter_by_symbol_prefix": (__spec__.parent, "filters"),
     "IntentResult": (__spec__.parent, "intent"),
+    "PageCursor": (__spec__.parent, "pagination"),
+    "parse_query_operators": (__spec__.parent, "operators"),
     "QueryComplexity": (__spec__.parent, "intent"),
     "QueryIntent": (__spec__.parent, "intent"),
     "apply_hybrid_weights": (__spec__.parent, "scoring"),
@@ -531,7 +1320,12 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "filter_test_files": (__spec__.parent, "filters"),
     "generate_summary": (__spec__.parent, "response"),
     "get_indexer_state_info": (__spec__.parent, "response"),
+    "QueryPlan": (__spec__.parent, "context_agent"),
     "raise_value_error": (__spec__.parent, "pipeline"),
+    "RankedResultsCache": (__spec__.parent, "pagination"),
+    "ResultReview": (__spec__.parent, "context_agent"),
+    "run_context_agent": (__spec__.parent, "context_agent"),
+    "search_code": (__spec__.parent, "context_agent"),
 })
 
 __getattr__ = create_late_getattr(_dynamic_imports, globals(), __name__)
@@ -539,37 +1333,80 @@ __getattr__ = create_late_getattr(_dynamic_imports, globals(), __name__)
 __all__ = (
     "INTENT_KEYWORDS",
     "INTENT_TO_AGENT_TASK",
+    "RANKED_RESULTS",
+    "AssembledContext",
+    "CodeKind",
     "CodeMatch",
     "CodeMatchType",
+    "CodeSection",
     "CodeWeaverSettingsType",
+    "ContextAgentDeps",
+    "ContextAgentResult",
+    "ContextSearch",
+    "ElidedMatch",
+    "FileOutline",
     "FindCodeResponseSummary",
     "FindCodeSubmission",
     "IntentResult",
     "IntentType",
     "MatchedSection",
+    "OutlineResponse",
+    "PageCursor",
     "QueryComplexity",
     "QueryIntent",
+    "QueryPlan",
+    "RankedResults",
+    "RankedResultsCache",
+    "ReadCodeResponse",
+    "ResultReview",
+    "SearchFilters",
+    "SearchMode",
+    "SearchScope",
+    "SymbolRelation",
     "apply_filters",
     "apply_hybrid_weights",
     "apply_semantic_weighting",
+    "assemble_context",
     "build_error_response",
+    "build_query_filter",
     "build_query_vector",
     "build_success_response",
     "calculate_token_count",
     "convert_search_result_to_code_match",
+    "dependency_query_targets",
     "detect_intent",
     "embed_query",
+    "enrich_related_symbols",
     "execute_vector_search",
     "extract_languages",
+    "filter_by_excluded_paths",
+    "filter_by_kinds",
     "filter_by_languages",
+    "filter_by_paths",
+    "filter_by_symbol_prefix",
+    "filter_live_chunks",
+    "filter_matches",
     "filter_test_files",
     "find_code",
+    "find_dependency_matches",
+    "find_exact_matches",
+    "find_references",
+    "find_symbol_matches",
     "generate_summary",
     "get_indexer_state_info",
+    "get_outline",
+    "merged_page",
+    "next_cursor",
+    "parse_query_operators",
     "process_reranked_results",
     "process_unranked_results",
+    "query_fingerprint",
     "raise_value_error",
+    "read_code",
+    "read_cursor",
     "rerank_results",
+    "run_context_agent",
+    "search_code",
 )
 
 
@@ -0,0 +1,312 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Token-budgeted context assembly for `find_code` responses.
+
+Ranked matches are turned into the context an agent actually reads, in four steps:
+1. **Expand** (optional): grow each match to its enclosing items -- a function to its impl
+   block or class, then to the whole file.
+2. **Merge**: overlapping or adjacent matches in the same file become one match, at the rank of
+   the best of them.
+   File skeletons (bodies elided) span their whole file but aren't its text, so they're
+   neither expanded nor merged, and keep their span when truncated.
+3. **Signatures**: when the symbol index knows where the types a match uses are defined, their
+   signature lines are attached so the match can be read on its own.
+4. **Pack**: matches are added greedily in rank order while they fit the token budget, counted
+   with the configured tokenizer. A match that doesn't fit is cut to the lines that do, or left
+   out, and reported in the response's `elided` list.
+"""
+
+from __future__ import annotations
+
+import logging
+import re
+
+from collections.abc import Callable, Sequence
+from pathlib import Path
+from typing import TYPE_CHECKING, NamedTuple
+
+from codeweaver.core import ChunkSource, SemanticSearchLanguage, Span
+from codeweaver.semantic.symbols import enclosing_items
+from codeweaver.server.agent_api.search.types import CodeMatch, ElidedMatch
+
+
+if TYPE_CHECKING:
+    from codeweaver_tokenizers import Tokenizer
+
+    from codeweaver.engine.services.symbol_index_service import SymbolIndex
+
+
+logger = logging.getLogger(__name__)
+
+# Capitalized identifiers are taken as type references; that's the convention in every
+# language the symbol index covers
+_TYPE_REFERENCE = re.compile(r"\b[A-Z][A-Za-z0-9_]*\b")
+
+MAX_SIGNATURES_PER_MATCH = 5
+"""At most this many referenced type signatures are attached to one match."""
+
+MIN_TRUNCATED_TOKENS = 64
+"""A match is only truncated to fit if at least this many tokens of it still fit."""
+
+
+class AssembledContext(NamedTuple):
+    """Matches packed into a token budget."""
+
+    matches: list[CodeMatch]
+    token_count: int
+    elided: tuple[ElidedMatch, ...]
+
+
+class _FileLines:
+    """Reads each matched file at most once."""
+
+    def __init__(self, read: Callable[[Path], str] | None = None) -> None:
+        self._read = read or (lambda path: path.read_text("utf-8", "ignore"))
+        self._lines: dict[Path, list[str] | None] = {}
+
+    def get(self, path: Path) -> list[str] | None:
+        if path not in self._lines:
+            try:
+                self._lines[path] = self._read(path).splitlines()
+            except OSError:
+                self._lines[path] = None
+        return self._lines[path]
+
+
+def _absolute_path(match: CodeMatch) -> Path:
+    return match.file.absolute_path
+
+
+def _is_skeleton(match: CodeMatch) -> bool:
+    return match.content.source == ChunkSource.SKELETON
+
+
+def _with_span(match: CodeMatch, span: Span, lines: list[str]) -> CodeMatch:
+    """Point a match at a new span, with that span's text as its content."""
+    content = "\n".join(lines[span.start - 1 : span.end])
+    return match.model_copy(
+        update={
+            "span": span,
+            "content": match.content.model_copy(update={"content": content, "line_range": span}),
+        }
+    )
+
+
+def expand_matches(
+    matches: Sequence[CodeMatch], levels: int, files: _FileLines | None = None
+) -> list[CodeMatch]:
+    """Grow each match to its enclosing items, `levels` levels up.
+
+    The first levels are the enclosing definitions and scopes (function, impl block, class);
+    past the outermost of those, the match grows to the whole file. Languages without
+    symbol rules go straight to the whole file.
+    """
+    if levels <= 0:
+        return list(matches)
+    from ast_grep_py import SgRoot
+
+    files = files or _FileLines()
+    expanded: list[CodeMatch] = []
+    for match in matches:
+        path = _absolute_path(match)
+        if _is_skeleton(match) or (lines := files.get(path)) is None:
+            expanded.append(match)
+            continue
+        ranges: tuple[tuple[int, int], ...] = ()
+        if language := SemanticSearchLanguage.from_extension(path.suffix or path.name):
+            try:
+                root = SgRoot("\n".join(lines), language.variable).root()
+                ranges = enclosing_items(root, language, match.span.start, match.span.end)
+            except Exception as e:
+                logger.debug("Could not find the items enclosing %s: %s", path, e)
+        ranges = (*ranges, (1, max(len(lines), match.span.end)))
+        start, end = ranges[min(levels, len(ranges)) - 1]
+        span = Span(min(start, match.span.start), max(end, match.span.end), match.file.source_id)
+        expanded.append(_with_span(match, span, lines))
+    return expanded
+
+
+def merge_matches(matches: Sequence[CodeMatch], files: _FileLines | None = None) -> list[CodeMatch]:
+    """Merge overlapping and adjacent matches in the same file.
+
+    A merged match takes the place and type of its best-ranked part, the highest relevance
+    score and the related symbols of all of its parts. File skeletons are kept as they are.
+    """
+    files = files or _FileLines()
+    merged: list[CodeMatch] = []
+    for match in matches:
+        span = Span(match.span.start, match.span.end, match.file.source_id)
+        for position, kept in enumerate(merged):
+            if kept.file.path != match.file.path or _is_skeleton(kept) or _is_skeleton(match):
+                continue
+            kept_span = Span(kept.span.start, kept.span.end, kept.file.source_id)
+            if not (kept_span & span or kept_span.is_adjacent(span)):
+                continue
+            if (lines := files.get(_absolute_path(kept))) is None:
+                continue
+            combined = _with_span(kept, kept_span | span, lines)
+            merged[position] = combined.model_copy(
+                update={
+                    "relevance_score": max(kept.relevance_score, match.relevance_score),
+                    "related_symbols": tuple(
+                        dict.fromkeys((*kept.related_symbols, *match.related_symbols))
+                    ),
+                }
+            )
+            break
+        else:
+            merged.append(match)
+    # A merge can close the gap between two matches that didn't touch before
+    return merge_matches(merged, files) if len(merged) < len(matches) else merged
+
+
+def attach_signatures(
+    matches: Sequence[CodeMatch],
+    index: SymbolIndex,
+    project_path: Path,
+    files: _FileLines | None = None,
+) -> list[CodeMatch]:
+    """Attach the signature lines of the types each match references but doesn't define."""
+    files = files or _FileLines()
+    attached: list[CodeMatch] = []
+    for match in matches:
+        defined_here = {
+            reference.name
+            for reference in index.definitions_in(match.file.path, match.span.start, match.span.end)
+        }
+        signatures: list[str] = []
+        for name in dict.fromkeys(_TYPE_REFERENCE.findall(match.content.content)):
+            if name in defined_here or len(signatures) >= MAX_SIGNATURES_PER_MATCH:
+                continue
+            definition = min(
+                index.definitions(name),
+                key=lambda occurrence: (str(occurrence.path), occurrence.reference.line),
+                default=None,
+            )
+            if definition is None:
+                continue
+            lines = files.get(project_path / definition.path)
+            if lines and definition.reference.line <= len(lines):
+                signature = lines[definition.reference.line - 1].strip()
+                signatures.append(f"{definition.path}:{definition.reference.line}: {signature}")
+        attached.append(
+            match.model_copy(update={"referenced_signatures": tuple(signatures)})
+            if signatures
+            else match
+        )
+    return attached
+
+
+def _match_tokens(match: CodeMatch, tokenizer: Tokenizer) -> int:
+    return tokenizer.estimate(match.content.content) + sum(
+        tokenizer.estimate(signature) for signature in match.referenced_signatures
+    )
+
+
+def _truncate(match: CodeMatch, budget: int, tokenizer: Tokenizer) -> tuple[CodeMatch, int] | None:
+    """Cut a match to the leading lines that fit a budget, signatures first to go."""
+    lines = match.content.content.splitlines()
+    kept: list[str] = []
+    used = 0
+    for line in lines:
+        cost = tokenizer.estimate(f"{line}\n")
+        if used + cost > budget:
+            break
+        kept.append(line)
+        used += cost
+    if used < MIN_TRUNCATED_TOKENS:
+        return None
+    # A skeleton's lines aren't the file's, so it keeps its whole-file span
+    span = (
+        match.span
+        if _is_skeleton(match)
+        else Span(match.span.start, match.span.start + len(kept) - 1, match.span.source_id)
+    )
+    truncated = match.model_copy(
+        update={
+            "span": span,
+            "content": match.content.model_copy(
+                update={"content": "\n".join(kept), "line_range": span}
+            ),
+            "referenced_signatures": (),
+        }
+    )
+    return truncated, used
+
+
+def pack_matches(
+    matches: Sequence[CodeMatch], token_limit: int, tokenizer: Tokenizer
+) -> AssembledContext:
+    """Greedily fill the token budget with matches in rank order.
+
+    Matches that don't fit are truncated to their leading lines when enough of them fits,
+    and omitted otherwise; later, smaller matches still get a chance to fill the budget.
+    """
+    packed: list[CodeMatch] = []
+    elided: list[ElidedMatch] = []
+    used = 0
+    for match in matches:
+        cost = _match_tokens(match, tokenizer)
+        remaining = token_limit - used
+        if cost <= remaining:
+            packed.append(match)
+            used += cost
+            continue
+        reason = "omitted"
+        if remaining > 0 and (truncated := _truncate(match, remaining, tokenizer)) is not None:
+            packed.append(truncated[0])
+            used += truncated[1]
+            reason = "truncated"
+        elided.append(
+            ElidedMatch(
+                file_path=match.file.path,
+                span=(match.span.start, match.span.end),
+                reason=reason,
+                tokens=cost,
+            )
+        )
+    return AssembledContext(packed, used, tuple(elided))
+
+
+def assemble_context(
+    matches: Sequence[CodeMatch],
+    *,
+    token_limit: int,
+    tokenizer: Tokenizer,
+    expand: int = 0,
+    symbols: tuple[SymbolIndex, Path] | None = None,
+    read_file: Callable[[Path], str] | None = None,
+) -> AssembledContext:
+    """Expand, merge, annotate and pack ranked matches into a token budget.
+
+    Args:
+        matches: Matches in rank order
+        token_limit: Maximum tokens of content (including attached signatures)
+        tokenizer: Tokenizer to count with
+        expand: How many enclosing items to grow each match to (0 to keep matches as found)
+        symbols: The symbol index and project root, for referenced type signatures
+        read_file: Reads a matched file's text, when the matches aren't from the working tree
+
+    Returns:
+        The packed matches, the tokens they use, and the matches that were truncated or
+        omitted
+    """
+    files = _FileLines(read_file)
+    assembled = merge_matches(expand_matches(matches, expand, files), files)
+    if symbols is not None:
+        assembled = attach_signatures(assembled, *symbols, files)
+    return pack_matches(assembled, token_limit, tokenizer)
+
+
+__all__ = (
+    "MAX_SIGNATURES_PER_MATCH",
+    "MIN_TRUNCATED_TOKENS",
+    "AssembledContext",
+    "assemble_context",
+    "attach_signatures",
+    "expand_matches",
+    "merge_matches",
+    "pack_matches",
+)
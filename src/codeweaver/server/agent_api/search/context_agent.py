@@ -0,0 +1,239 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""The context agent: an opt-in agentic pass over `find_code`'s results.
+
+With `agentic=True`, `find_code` hands its query and first results to CodeWeaver's own agent,
+which works in two runs:
+1. **Plan**: it checks the intent `detect_intent` guessed, and rewrites the query or splits it
+   into a few focused searches (with `CONTEXT_AGENT_INTENT_AND_TASK_VALIDATION_CONFIG`)
+2. **Review**: it runs those searches, and any others it needs, through the search tool of the
+   `ContextAgentToolkit`, then keeps only the matches that help answer the query, best first
+   (with `CONTEXT_AGENT_RESULT_REVIEW_CONFIG`)
+
+The agent runs on the configured agent provider's model. It's given as a parameter, so tests
+can run it offline with pydantic-ai's `TestModel` or `FunctionModel`.
+"""
+
+from __future__ import annotations
+
+import logging
+
+from collections.abc import Awaitable, Callable, Sequence
+from dataclasses import dataclass, field
+from typing import TYPE_CHECKING, Annotated, NamedTuple, cast
+
+from pydantic import Field
+from pydantic_ai import Agent, RunContext
+from pydantic_ai.settings import ModelSettings
+
+from codeweaver.core import BasedModel, ChunkSource
+from codeweaver.core.constants import (
+    CONTEXT_AGENT_INTENT_AND_TASK_VALIDATION_CONFIG,
+    CONTEXT_AGENT_PLAN_INSTRUCTIONS,
+    CONTEXT_AGENT_RESULT_REVIEW_CONFIG,
+    CONTEXT_AGENT_REVIEW_INSTRUCTIONS,
+    DEFAULT_CONTEXT_AGENT_MAX_SEARCHES,
+)
+from codeweaver.server.agent_api.search.intent import IntentType, detect_intent
+
+
+if TYPE_CHECKING:
+    from pydantic_ai.models import Model
+
+    from codeweaver.server.agent_api.search.types import CodeMatch
+
+
+logger = logging.getLogger(__name__)
+
+PREVIEW_LINES = 12
+"""Lines of each match the agent sees while reviewing."""
+
+type ContextSearch = Callable[[str, IntentType], Awaitable[Sequence[CodeMatch]]]
+"""Runs one search for the agent: a query and its intent to matches."""
+
+
+class QueryPlan(BasedModel):
+    """The context agent's checked intent and the searches it plans."""
+
+    intent: Annotated[IntentType, Field(description="""The intent behind the query""")]
+    queries: Annotated[
+        list[str],
+        Field(
+            min_length=1,
+            max_length=DEFAULT_CONTEXT_AGENT_MAX_SEARCHES,
+            description="""Focused natural language searches that find what the query needs""",
+        ),
+    ]
+
+    def _telemetry_keys(self) -> None:
+        return None
+
+
+class ResultReview(BasedModel):
+    """The matches the context agent keeps."""
+
+    keep: Annotated[
+        list[int],
+        Field(description="""IDs of the matches that help answer the query, most useful first"""),
+    ]
+
+    def _telemetry_keys(self) -> None:
+        return None
+
+
+@dataclass
+class ContextAgentDeps:
+    """What the review run's search tool works with."""
+
+    search: ContextSearch
+    intent: IntentType
+    matches: list[CodeMatch]
+    max_searches: int = DEFAULT_CONTEXT_AGENT_MAX_SEARCHES
+    searches: list[str] = field(default_factory=list)
+
+
+class ContextAgentResult(NamedTuple):
+    """What the context agent made of a query."""
+
+    matches: tuple[CodeMatch, ...]
+    """The matches it kept, most useful first."""
+    pruned: tuple[CodeMatch, ...]
+    """The matches it found or was given and left out."""
+    intent: IntentType
+    """The intent it settled on."""
+    searches: tuple[str, ...]
+    """The searches it ran."""
+    tokens: int
+    """Tokens its model spent on both runs."""
+
+
+def _match_key(match: CodeMatch) -> tuple[str, int, int, str | None]:
+    # A file's skeleton parts all span the whole file, like a whole-file match can; their names
+    # tell them apart
+    skeleton = match.content.chunk_name if match.content.source == ChunkSource.SKELETON else None
+    return str(match.file.path), match.span.start, match.span.end, skeleton
+
+
+def _describe(match_id: int, match: CodeMatch) -> str:
+    """A match as the agent sees it: its ID, location and first lines."""
+    lines = match.content.content.splitlines()
+    preview = "\n".join(lines[:PREVIEW_LINES])
+    more = f"\n... ({len(lines) - PREVIEW_LINES} more lines)" if len(lines) > PREVIEW_LINES else ""
+    return f"[{match_id}] {match.file.path}:{match.span.start}-{match.span.end}\n{preview}{more}"
+
+
+async def search_code(ctx: RunContext[ContextAgentDeps], query: str) -> str:
+    """Search the codebase and list the new matches with their IDs.
+
+    Args:
+        ctx: The run's context
+        query: What to look for, in plain language
+    """
+    deps = ctx.deps
+    if len(deps.searches) >= deps.max_searches:
+        return "No searches left; choose from the matches found so far."
+    deps.searches.append(query)
+    known = {_match_key(match) for match in deps.matches}
+    found: list[str] = []
+    for match in await deps.search(query, deps.intent):
+        if (key := _match_key(match)) in known:
+            continue
+        known.add(key)
+        deps.matches.append(match)
+        found.append(_describe(len(deps.matches) - 1, match))
+    return "\n\n".join(found) if found else "No new matches."
+
+
+def _plan_prompt(query: str, intent: IntentType | None) -> str:
+    if intent is not None:
+        return f"Query: {query}\nIntent (set by the caller): {intent.variable}"
+    guess = detect_intent(query)
+    return (
+        f"Query: {query}\nGuessed intent: {guess.intent_type.variable} "
+        f"(confidence {guess.confidence:.2f}; {guess.reasoning})"
+    )
+
+
+def _review_prompt(query: str, plan: QueryPlan, matches: Sequence[CodeMatch]) -> str:
+    searches = "\n".join(f"- {planned}" for planned in plan.queries)
+    found = "\n\n".join(_describe(match_id, match) for match_id, match in enumerate(matches))
+    return (
+        f"Query: {query}\nIntent: {plan.intent.variable}\n\nPlanned searches:\n{searches}\n\n"
+        f"Matches so far:\n\n{found or 'None yet.'}"
+    )
+
+
+async def run_context_agent(
+    query: str,
+    matches: Sequence[CodeMatch],
+    search: ContextSearch,
+    model: Model | str,
+    *,
+    intent: IntentType | None = None,
+    max_searches: int = DEFAULT_CONTEXT_AGENT_MAX_SEARCHES,
+) -> ContextAgentResult:
+    """Plan a query's searches, run them and keep the matches that answer it.
+
+    Args:
+        query: The query, without filter operators
+        matches: The matches `find_code` found for the query, in rank order
+        search: Runs one search through the context agent's toolkit
+        model: The model the agent runs on
+        intent: The intent the caller set, which the agent keeps; if None it checks the
+            heuristic one
+        max_searches: Most searches the agent can run
+
+    Returns:
+        The kept matches, the rest, the settled intent, the searches run and the tokens spent
+    """
+    planner = Agent(
+        model,
+        output_type=QueryPlan,
+        instructions=CONTEXT_AGENT_PLAN_INSTRUCTIONS,
+        model_settings=cast(ModelSettings, CONTEXT_AGENT_INTENT_AND_TASK_VALIDATION_CONFIG),
+        name="codeweaver-context-plan",
+    )
+    planned = await planner.run(_plan_prompt(query, intent))
+    plan = planned.output
+    if intent is not None and plan.intent != intent:
+        plan = plan.model_copy(update={"intent": intent})
+    logger.debug("Context agent planned %s for %r", plan.queries, query)
+
+    reviewer = Agent(
+        model,
+        deps_type=ContextAgentDeps,
+        output_type=ResultReview,
+        instructions=CONTEXT_AGENT_REVIEW_INSTRUCTIONS,
+        model_settings=cast(ModelSettings, CONTEXT_AGENT_RESULT_REVIEW_CONFIG),
+        tools=[search_code],
+        name="codeweaver-context-review",
+    )
+    deps = ContextAgentDeps(
+        search=search, intent=plan.intent, matches=list(matches), max_searches=max_searches
+    )
+    reviewed = await reviewer.run(_review_prompt(query, plan, deps.matches), deps=deps)
+    kept = tuple(
+        dict.fromkeys(
+            match_id for match_id in reviewed.output.keep if 0 <= match_id < len(deps.matches)
+        )
+    )
+    return ContextAgentResult(
+        matches=tuple(deps.matches[match_id] for match_id in kept),
+        pruned=tuple(match for match_id, match in enumerate(deps.matches) if match_id not in kept),
+        intent=plan.intent,
+        searches=tuple(deps.searches),
+        tokens=planned.usage().total_tokens + reviewed.usage().total_tokens,
+    )
+
+
+__all__ = (
+    "ContextAgentDeps",
+    "ContextAgentResult",
+    "ContextSearch",
+    "QueryPlan",
+    "ResultReview",
+    "run_context_agent",
+    "search_code",
+)
@@ -15,17 +15,25 @@ from __future__ import annotations
 import asyncio
 
 from pathlib import Path
+from typing import TYPE_CHECKING
 
 from codeweaver.core import DiscoveredFile, SearchResult, Span
 from codeweaver.core.constants import POSIX_NEWLINE
 from codeweaver.server.agent_api.search.types import CodeMatch, CodeMatchType
 
 
-async def convert_search_result_to_code_match(result: SearchResult) -> CodeMatch:
+if TYPE_CHECKING:
+    from codeweaver.core import GitTree
+
+
+async def convert_search_result_to_code_match(
+    result: SearchResult, *, tree: GitTree | None = None
+) -> CodeMatch:
     """Convert SearchResult from vector store to CodeMatch for response.
 
     Args:
         result: SearchResult from vector store search
+        tree: The git revision the result was indexed from, if it isn't from the working tree
 
     Returns:
         CodeMatch with all required fields populated
@@ -35,7 +43,12 @@ async def convert_search_result_to_code_match(result: SearchResult) -> CodeMatch
 
     # Get file info (prefer from chunk, fallback to result.file_path, then create fallback)
     file: DiscoveredFile | None = None
-    if hasattr(chunk, "file_path") and chunk.file_path:
+    if tree is not None and (file_path := getattr(chunk, "file_path", None) or result.file_path):
+        # The file may differ or be gone in the working tree, so don't look it up there
+        file = DiscoveredFile(
+            path=tree.root / file_path, git_branch=tree.rev, project_path=tree.root
+        )
+    elif hasattr(chunk, "file_path") and chunk.file_path:
         file = await asyncio.to_thread(DiscoveredFile.from_path, chunk.file_path)
     elif result.file_path:
         file = await asyncio.to_thread(DiscoveredFile.from_path, result.file_path)
@@ -0,0 +1,183 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Precise answers to dependency questions from the project's dependency graph.
+
+Semantic search is a poor fit for "which crates depend on serde_json" or "what uses our `auth`
+crate" -- the answer is a set of manifest declarations, not similar-looking code. When a query
+asks about dependencies in so many words and names a package the graph knows, this module
+returns the matching manifest declarations as keyword matches, ahead of the semantic results.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+import re
+
+from typing import TYPE_CHECKING, Literal
+
+from codeweaver.core import ChunkSource, CodeChunk, DiscoveredFile, Span
+from codeweaver.engine.services.dependency_service import normalize_package_name
+from codeweaver.server.agent_api.search.types import CodeMatch, CodeMatchType
+
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from codeweaver.engine.services.dependency_service import DependencyEdge, DependencyGraph
+
+
+logger = logging.getLogger(__name__)
+
+_DEPENDENCY_WORDS = re.compile(
+    r"\b(?:depend\w*|deps|dependents?|relies|rely|uses?|using|imports?|requires?)\b",
+    re.IGNORECASE,
+)
+"""A cheap check before looking for the phrasings below."""
+_NAME_TOKEN = re.compile(r"@?[A-Za-z0-9][A-Za-z0-9._/@-]*")
+_ARTICLE = r"(?:(?:the|our|a)\s+)?`?"
+_PACKAGE_NOUN = r"`?\s+(?:crate|package|library|dependency|module)\b"
+
+_DEPENDENTS_PHRASES = (
+    # "which crates depend on serde_json", "what relies on the auth crate"
+    rf"(?:depends?|depending|relies|rely)\s+on\s+{_ARTICLE}{{name}}`?(?!\w)",
+    # "dependents of auth"
+    rf"dependents\s+of\s+{_ARTICLE}{{name}}`?(?!\w)",
+    # "what uses our auth crate"; a bare "uses serde_json" is an everyday code question
+    rf"(?:uses?|using|imports?|requires?)\s+{_ARTICLE}{{name}}{_PACKAGE_NOUN}",
+)
+"""Phrasings that ask for a package's dependents; `{name}` is the escaped package name."""
+_DEPENDENCIES_PHRASES = (
+    # "dependencies of server", "deps for `server`"
+    rf"(?:dependencies|deps)\s+(?:of|for|in)\s+{_ARTICLE}{{name}}`?(?!\w)",
+    # "server's dependencies", "server depends on"
+    rf"(?<!\w){{name}}`?(?:'s)?\s+(?:depends\s+on|dependencies|deps)\b",
+    # "what does server depend on"
+    rf"does\s+{_ARTICLE}{{name}}`?\s+(?:(?:crate|package)\s+)?depend\s+on\b",
+)
+"""Phrasings that ask for one of the project's own packages' dependencies."""
+
+DependencyDirection = Literal["dependents", "dependencies"]
+
+
+def _asks(phrases: tuple[str, ...], token: str, query: str) -> bool:
+    escaped = re.escape(token)
+    return any(re.search(phrase.format(name=escaped), query, re.IGNORECASE) for phrase in phrases)
+
+
+def dependency_query_targets(
+    query: str, graph: DependencyGraph
+) -> list[tuple[str, DependencyDirection]]:
+    """Find the packages a dependency question is about, and which way it asks.
+
+    Only explicit dependency phrasings count, so everyday questions that happen to name a
+    package ("how does auth use serde_json") stay semantic searches. "What depends on X",
+    "dependents of X" and "what uses the X crate" ask for X's *dependents*; "dependencies of X"
+    and "what does X depend on" ask for X's own *dependencies* (only meaningful for the
+    project's own packages).
+
+    Args:
+        query: The search query
+        graph: The project's dependency graph
+
+    Returns:
+        `(package name, direction)` pairs, empty if the query isn't a dependency question
+    """
+    if not _DEPENDENCY_WORDS.search(query):
+        return []
+    targets: list[tuple[str, DependencyDirection]] = []
+    for token in dict.fromkeys(t.strip(".") for t in _NAME_TOKEN.findall(query)):
+        if not token or (name := graph.find(token)) is None:
+            continue
+        if graph.is_internal(name) and _asks(_DEPENDENCIES_PHRASES, token, query):
+            targets.append((name, "dependencies"))
+        elif _asks(_DEPENDENTS_PHRASES, token, query):
+            targets.append((name, "dependents"))
+    return targets
+
+
+def _declaration_text(lines: list[str], line: int) -> tuple[str, int]:
+    """Get a declaration's text and last line, following a `[dependencies.name]` table body."""
+    end = line
+    if lines[line - 1].lstrip().startswith("["):
+        while end < len(lines) and lines[end].strip() and not lines[end].lstrip().startswith("["):
+            end += 1
+    return "\n".join(lines[line - 1 : end]), end
+
+
+def _edge_to_match(edge: DependencyEdge, project_path: Path) -> CodeMatch | None:
+    """Build a keyword match pointing at a dependency's declaration in its manifest."""
+    manifest = project_path / edge.manifest
+    if not edge.line or (file := DiscoveredFile.from_path(manifest)) is None:
+        return None
+    lines = manifest.read_text(encoding="utf-8").splitlines()
+    if edge.line > len(lines):
+        return None
+    content, end = _declaration_text(lines, edge.line)
+    span = Span(edge.line, end, file.source_id)
+    version = f" {edge.version}" if edge.version else ""
+    chunk = CodeChunk.model_validate({
+        "content": content,
+        "line_range": span,
+        "file_path": manifest,
+        "language": file.ext_category.language if file.ext_category else None,
+        "source": ChunkSource.FILE,
+        "parent_id": file.source_id,
+        "chunk_name": f"{edge.package} -> {edge.name}{version} ({edge.kind.variable})",
+        "crate": file.crate,
+    })
+    return CodeMatch(
+        file=file,
+        content=chunk,
+        span=span,
+        relevance_score=1.0,
+        match_type=CodeMatchType.KEYWORD,
+        related_symbols=(edge.package, edge.name),
+    )
+
+
+async def find_dependency_matches(
+    query: str,
+    graph: DependencyGraph,
+    project_path: Path,
+    *,
+    crates: tuple[str, ...] | None = None,
+) -> list[CodeMatch]:
+    """Answer a dependency question with the manifest declarations that answer it.
+
+    Args:
+        query: The search query
+        graph: The project's dependency graph
+        project_path: The project root the graph's manifest paths are relative to
+        crates: Optional crate filter; only declarations made by these packages are returned
+
+    Returns:
+        Keyword matches for the relevant declarations, or an empty list if the query isn't a
+        dependency question about a known package
+    """
+    allowed = {normalize_package_name(c) for c in crates} if crates else None
+    edges: dict[tuple[str, str, str], DependencyEdge] = {}
+    for name, direction in dependency_query_targets(query, graph):
+        found = (
+            graph.dependents_of(name)
+            if direction == "dependents"
+            else graph.dependencies_of(name)
+        )
+        for edge in found:
+            if allowed is None or normalize_package_name(edge.package) in allowed:
+                edges.setdefault((edge.package, edge.name, edge.kind.variable), edge)
+    matches: list[CodeMatch] = []
+    for edge in edges.values():
+        try:
+            match = await asyncio.to_thread(_edge_to_match, edge, project_path)
+        except Exception as e:
+            logger.debug("Could not build a match for %s -> %s: %s", edge.package, edge.name, e)
+            continue
+        if match is not None:
+            matches.append(match)
+    return matches
+
+
+__all__ = ("dependency_query_targets", "find_dependency_matches")
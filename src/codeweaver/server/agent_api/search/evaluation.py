@@ -0,0 +1,366 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Retrieval quality benchmarks for `find_code`, behind `cw eval`.
+
+A query file lists queries with the files a good search should return for them (the schema of
+`tests/fixtures/reference_queries.yml`):
+
+```yaml
+queries:
+  - query: how does semantic chunking work
+    intent: UNDERSTAND
+    precision_target: 3  # optional; the default cutoff (k) for this query
+    expected_files:
+      - src/codeweaver/engine/chunker/semantic.py
+    description: optional
+```
+
+Each query is searched, the results are collapsed to a ranked list of files, and scored against
+the expected files with precision@k, recall@k, reciprocal rank and nDCG@k. Reports average them
+per intent and overall, and can be compared to a baseline report -- from an earlier run or a
+run with another configuration -- to show whether a change (a new embedding model, reranker or
+fusion strategy) helps or hurts.
+"""
+
+from __future__ import annotations
+
+import math
+
+from collections.abc import Awaitable, Callable, Iterable, Mapping, Sequence
+from pathlib import Path, PurePosixPath
+from typing import Any, NamedTuple
+
+from codeweaver.core import ConfigurationError
+from codeweaver.server.agent_api.search.intent import IntentType
+
+
+DEFAULT_EVAL_K = 5
+"""Results scored per query, for queries without a `precision_target`."""
+
+METRICS = ("precision", "recall", "mrr", "ndcg")
+"""Reported metrics, in report order."""
+
+
+class EvalQuery(NamedTuple):
+    """A benchmark query and the files a good search returns for it."""
+
+    query: str
+    intent: IntentType
+    expected_files: tuple[str, ...]
+    precision_target: int = DEFAULT_EVAL_K
+    description: str = ""
+
+
+def load_eval_queries(path: Path) -> list[EvalQuery]:
+    """Load benchmark queries from a YAML query file.
+
+    Raises:
+        ConfigurationError: If the file can't be read or doesn't follow the schema
+    """
+    import yaml
+
+    try:
+        data = yaml.safe_load(path.read_text(encoding="utf-8"))
+        queries = [
+            EvalQuery(
+                query=str(item["query"]),
+                intent=IntentType(str(item["intent"]).lower()),
+                expected_files=tuple(str(file) for file in item["expected_files"]),
+                precision_target=int(item.get("precision_target", DEFAULT_EVAL_K)),
+                description=str(item.get("description", "")),
+            )
+            for item in data["queries"]
+        ]
+    except (OSError, yaml.YAMLError, KeyError, TypeError, ValueError) as e:
+        raise ConfigurationError(
+            f"Couldn't load benchmark queries from {path}: {e}",
+            details={"path": str(path)},
+            suggestions=[
+                "Each entry under `queries` needs `query`, `intent` and `expected_files`",
+                f"Intents are one of: {', '.join(intent.value for intent in IntentType)}",
+            ],
+        ) from e
+    if not queries:
+        raise ConfigurationError(f"{path} has no queries", details={"path": str(path)})
+    return queries
+
+
+# ===========================================================================
+# *                               Metrics
+# ===========================================================================
+
+
+def _is_expected(result: str, expected: str) -> bool:
+    """Whether a result path is an expected file; expected paths may be partial (`lib.rs`)."""
+    return result == expected or result.endswith(f"/{expected}")
+
+
+def rank_files(results: Iterable[str], expected_files: Iterable[str]) -> list[bool]:
+    """Collapse results to distinct files, in rank order, and mark the expected ones.
+
+    A file matching several results counts once, at its best rank; an expected file matched by
+    two results isn't a hit twice.
+    """
+    expected = [PurePosixPath(file).as_posix() for file in expected_files]
+    found: set[str] = set()
+    relevance: list[bool] = []
+    for result in dict.fromkeys(PurePosixPath(path).as_posix() for path in results):
+        match = next((file for file in expected if _is_expected(result, file)), None)
+        relevance.append(match is not None and match not in found)
+        if match is not None:
+            found.add(match)
+    return relevance
+
+
+class QueryScore(NamedTuple):
+    """How well a search did for one benchmark query."""
+
+    query: EvalQuery
+    results: tuple[str, ...]
+    """The files returned, best first."""
+    k: int
+    precision: float
+    recall: float
+    mrr: float
+    """Reciprocal rank of the first expected file (0 if none was returned)."""
+    ndcg: float
+
+    @classmethod
+    def score(cls, query: EvalQuery, results: Sequence[str], k: int) -> QueryScore:
+        """Score a search's results (file paths, best first) at cutoff `k`."""
+        relevance = rank_files(results, query.expected_files)
+        top = relevance[:k]
+        hits = sum(top)
+        first_hit = next((rank for rank, relevant in enumerate(relevance, 1) if relevant), None)
+        ideal = sum(1 / math.log2(rank + 2) for rank in range(min(len(query.expected_files), k)))
+        dcg = sum(1 / math.log2(rank + 2) for rank, relevant in enumerate(top) if relevant)
+        return cls(
+            query=query,
+            results=tuple(dict.fromkeys(PurePosixPath(path).as_posix() for path in results)),
+            k=k,
+            precision=hits / k,
+            recall=hits / len(query.expected_files) if query.expected_files else 0.0,
+            mrr=1 / first_hit if first_hit else 0.0,
+            ndcg=dcg / ideal if ideal else 0.0,
+        )
+
+    @property
+    def missed(self) -> tuple[str, ...]:
+        """Expected files that weren't in the top k."""
+        top = self.results[: self.k]
+        return tuple(
+            file
+            for file in self.query.expected_files
+            if not any(_is_expected(result, file) for result in top)
+        )
+
+
+class MetricSummary(NamedTuple):
+    """Metrics averaged over a set of queries."""
+
+    queries: int
+    precision: float
+    recall: float
+    mrr: float
+    ndcg: float
+
+    @classmethod
+    def of(cls, scores: Sequence[QueryScore]) -> MetricSummary:
+        """Average the scores of some queries."""
+        if not scores:
+            return cls(0, 0.0, 0.0, 0.0, 0.0)
+        averages = (
+            math.fsum(getattr(score, metric) for score in scores) / len(scores)
+            for metric in METRICS
+        )
+        return cls(len(scores), *averages)
+
+
+class EvalReport(NamedTuple):
+    """The scores of one benchmark run."""
+
+    label: str
+    """What was run, like the config file or profile."""
+    scores: tuple[QueryScore, ...]
+
+    @property
+    def overall(self) -> MetricSummary:
+        """Metrics over all queries."""
+        return MetricSummary.of(self.scores)
+
+    @property
+    def by_intent(self) -> dict[str, MetricSummary]:
+        """Metrics per query intent."""
+        intents = sorted({score.query.intent.value for score in self.scores})
+        return {
+            intent: MetricSummary.of([s for s in self.scores if s.query.intent.value == intent])
+            for intent in intents
+        }
+
+    def as_dict(self) -> dict[str, Any]:
+        """Serialize the report (for JSON output and later comparison)."""
+        return {
+            "label": self.label,
+            "overall": self.overall._asdict(),
+            "by_intent": {intent: summary._asdict() for intent, summary in self.by_intent.items()},
+            "queries": [
+                {
+                    "query": score.query.query,
+                    "intent": score.query.intent.value,
+                    "expected_files": list(score.query.expected_files),
+                    "precision_target": score.query.precision_target,
+                    "description": score.query.description,
+                    "results": list(score.results),
+                    "k": score.k,
+                    **{metric: getattr(score, metric) for metric in METRICS},
+                }
+                for score in self.scores
+            ],
+        }
+
+    @classmethod
+    def from_dict(cls, data: Mapping[str, Any]) -> EvalReport:
+        """Load a report saved with `as_dict`."""
+        return cls(
+            label=data["label"],
+            scores=tuple(
+                QueryScore(
+                    query=EvalQuery(
+                        query=item["query"],
+                        intent=IntentType(item["intent"]),
+                        expected_files=tuple(item["expected_files"]),
+                        precision_target=item.get("precision_target", DEFAULT_EVAL_K),
+                        description=item.get("description", ""),
+                    ),
+                    results=tuple(item["results"]),
+                    k=item["k"],
+                    **{metric: item[metric] for metric in METRICS},
+                )
+                for item in data["queries"]
+            ),
+        )
+
+
+async def evaluate(
+    queries: Sequence[EvalQuery],
+    search: Callable[[EvalQuery], Awaitable[Sequence[str]]],
+    *,
+    label: str,
+    k: int | None = None,
+) -> EvalReport:
+    """Run and score benchmark queries.
+
+    Args:
+        queries: The benchmark queries
+        search: Searches for a query and returns the matched file paths, best first
+        label: What's being evaluated, for the report
+        k: Results scored per query (default: each query's `precision_target`)
+    """
+    scores = [
+        QueryScore.score(query, await search(query), k or query.precision_target)
+        for query in queries
+    ]
+    return EvalReport(label=label, scores=tuple(scores))
+
+
+async def find_code_files(query: EvalQuery, *, max_results: int = 20) -> list[str]:
+    """Search with `find_code` and return the matched files, best first.
+
+    The token budget is lifted so results aren't dropped for size: the benchmark measures
+    ranking, not packing.
+
+    Raises:
+        ConfigurationError: If the search fails (like when no providers are configured)
+    """
+    from codeweaver.server.agent_api.search import find_code
+
+    response = await find_code(
+        query.query, intent=query.intent, max_results=max_results, token_limit=1_000_000
+    )
+    if response.status == "error":
+        raise ConfigurationError(
+            f"Search failed for '{query.query}': {response.summary}",
+            suggestions=["Check the configuration with `cw doctor`"],
+        )
+    return [match.file.path.as_posix() for match in response.matches]
+
+
+# ===========================================================================
+# *                               Reports
+# ===========================================================================
+
+
+def metric_deltas(report: EvalReport, baseline: EvalReport) -> dict[str, dict[str, float]]:
+    """Change in each metric from the baseline, overall and per intent."""
+    groups = {"overall": (report.overall, baseline.overall)} | {
+        intent: (summary, baseline.by_intent.get(intent, MetricSummary.of([])))
+        for intent, summary in report.by_intent.items()
+    }
+    return {
+        group: {metric: getattr(now, metric) - getattr(before, metric) for metric in METRICS}
+        for group, (now, before) in groups.items()
+    }
+
+
+def query_changes(report: EvalReport, baseline: EvalReport) -> list[tuple[str, float, float]]:
+    """Queries whose nDCG changed from the baseline: `(query, baseline nDCG, nDCG)`, worst first."""
+    before = {score.query.query: score.ndcg for score in baseline.scores}
+    changes = [
+        (score.query.query, before[score.query.query], score.ndcg)
+        for score in report.scores
+        if score.query.query in before and not math.isclose(before[score.query.query], score.ndcg)
+    ]
+    return sorted(changes, key=lambda change: change[2] - change[1])
+
+
+def _summary_row(name: str, summary: MetricSummary, deltas: Mapping[str, float] | None) -> str:
+    cells = [
+        f"{getattr(summary, metric):.3f}"
+        + (f" ({deltas[metric]:+.3f})" if deltas is not None else "")
+        for metric in METRICS
+    ]
+    return f"| {name} | {summary.queries} | {' | '.join(cells)} |"
+
+
+def render_markdown(report: EvalReport, baseline: EvalReport | None = None) -> str:
+    """Render a report as Markdown, with changes from `baseline` in parentheses."""
+    deltas = metric_deltas(report, baseline) if baseline else None
+    lines = [f"# Retrieval benchmark: {report.label}", ""]
+    if baseline:
+        lines += [f"Compared to **{baseline.label}**; changes are in parentheses.", ""]
+    lines += [
+        "| Intent | Queries | Precision@k | Recall@k | MRR | nDCG@k |",
+        "| --- | ---: | ---: | ---: | ---: | ---: |",
+        _summary_row("**overall**", report.overall, deltas["overall"] if deltas else None),
+    ]
+    lines += [
+        _summary_row(intent, summary, deltas[intent] if deltas else None)
+        for intent, summary in report.by_intent.items()
+    ]
+    if baseline and (changes := query_changes(report, baseline)):
+        lines += ["", "## Changed queries", "", "| Query | nDCG before | nDCG after |"]
+        lines += ["| --- | ---: | ---: |"]
+        lines += [f"| {query} | {before:.3f} | {after:.3f} |" for query, before, after in changes]
+    if misses := [score for score in report.scores if score.missed]:
+        lines += ["", "## Missed files", ""]
+        lines += [f"- {score.query.query}: {', '.join(score.missed)}" for score in misses]
+    return "\n".join(lines) + "\n"
+
+
+__all__ = (
+    "DEFAULT_EVAL_K",
+    "METRICS",
+    "EvalQuery",
+    "EvalReport",
+    "MetricSummary",
+    "QueryScore",
+    "evaluate",
+    "find_code_files",
+    "load_eval_queries",
+    "metric_deltas",
+    "query_changes",
+    "rank_files",
+    "render_markdown",
+)
@@ -0,0 +1,140 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Exact `find_code` modes: ast-grep patterns, keywords, regexes and path globs.
+
+These modes skip embedding entirely. The query is handed to the engine's
+`ExactSearchService`, and each hit becomes a `CodeMatch` whose `match_type` says how it was
+found: `SYNTACTIC` for patterns, `KEYWORD` for keywords and regexes, and `FILE_PATTERN` for
+paths. Hits are exact, so every match gets a relevance score of 1.0 and keeps walk order.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+
+from typing import TYPE_CHECKING
+
+from codeweaver.core import ChunkSource, CodeChunk, DiscoveredFile, Span
+from codeweaver.semantic.ast_grep import Strictness
+from codeweaver.server.agent_api.search.types import CodeMatch, SearchMode
+
+
+if TYPE_CHECKING:
+    from codeweaver.engine.services.exact_search_service import ExactHit, ExactSearchService
+
+
+logger = logging.getLogger(__name__)
+
+
+def _hit_to_match(hit: ExactHit, mode: SearchMode, service: ExactSearchService) -> CodeMatch | None:
+    """Build a match pointing at an exact hit."""
+    path = service.project_path / hit.path
+    if (file := DiscoveredFile.from_path(path, project_path=service.project_path)) is None:
+        return None
+    span = Span(hit.start_line, hit.end_line, file.source_id)
+    chunk = CodeChunk.model_validate({
+        "content": hit.content,
+        "line_range": span,
+        "file_path": path,
+        "language": file.ext_category.language if file.ext_category else None,
+        "source": ChunkSource.FILE,
+        "parent_id": file.source_id,
+        "crate": file.crate,
+    })
+    return CodeMatch(
+        file=file,
+        content=chunk,
+        span=span,
+        relevance_score=1.0,
+        match_type=mode.match_type,
+    )
+
+
+def _search(
+    query: str,
+    mode: SearchMode,
+    service: ExactSearchService,
+    *,
+    strictness: Strictness,
+    focus_languages: tuple[str, ...] | None,
+    crates: tuple[str, ...] | None,
+    max_results: int,
+) -> list[ExactHit]:
+    match mode:
+        case SearchMode.PATTERN:
+            return service.find_pattern(
+                query,
+                strictness=strictness,
+                languages=focus_languages,
+                crates=crates,
+                limit=max_results,
+            )
+        case SearchMode.KEYWORD | SearchMode.REGEX:
+            return service.find_text(
+                query,
+                regex=mode == SearchMode.REGEX,
+                languages=focus_languages,
+                crates=crates,
+                limit=max_results,
+            )
+        case SearchMode.PATH:
+            return service.find_paths(
+                query, languages=focus_languages, crates=crates, limit=max_results
+            )
+        case _:
+            raise ValueError(f"{mode} is not an exact search mode")
+
+
+async def find_exact_matches(
+    query: str,
+    mode: SearchMode,
+    service: ExactSearchService,
+    *,
+    strictness: Strictness | None = None,
+    focus_languages: tuple[str, ...] | None = None,
+    crates: tuple[str, ...] | None = None,
+    max_results: int = 50,
+) -> list[CodeMatch]:
+    """Find exact matches for a pattern, keyword, regex or path glob.
+
+    Args:
+        query: The ast-grep pattern, text, regular expression or glob
+        mode: How to interpret the query; must not be `SearchMode.SEMANTIC`
+        service: The exact search service
+        strictness: How closely pattern matches must match (patterns only; default `smart`)
+        focus_languages: Only search files in these languages
+        crates: Only search files in these Cargo workspace crates
+        max_results: Maximum number of matches
+
+    Returns:
+        Matches in walk order
+
+    Raises:
+        ValueError: If the mode is semantic or a regex is invalid
+    """
+    hits = await asyncio.to_thread(
+        _search,
+        query,
+        mode,
+        service,
+        strictness=strictness or Strictness.SMART,
+        focus_languages=focus_languages,
+        crates=crates,
+        max_results=max_results,
+    )
+    matches: list[CodeMatch] = []
+    for hit in hits:
+        try:
+            match = await asyncio.to_thread(_hit_to_match, hit, mode, service)
+        except Exception as e:
+            logger.debug("Could not build a match for %s: %s", hit.path, e)
+            continue
+        if match is not None:
+            matches.append(match)
+    return matches
+
+
+__all__ = ("find_exact_matches",)
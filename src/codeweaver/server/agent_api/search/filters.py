@@ -6,58 +6,128 @@
 """Post-search filtering utilities.
 
 This module provides functions for filtering search results based on
-various criteria such as test file inclusion and language focus.
+various criteria such as test code inclusion, language focus, paths, kinds and symbols.
+
+Most filters are also pushed down to the vector store (see `pipeline.build_query_filter`);
+these run afterwards for what the store can't check exactly, like path globs and symbol
+prefixes, and for points indexed before their payload had the filtered fields.
+
+Test detection works per chunk rather than per file; see `codeweaver.semantic.test_code` for
+the rules.
+
+Matches found outside the vector store -- exact-mode matches and dependency declarations -- go
+through the same filters with `filter_matches`.
 """
 
 from __future__ import annotations
 
-from pathlib import Path
+import fnmatch
+import logging
 
-from codeweaver.core import CodeChunk, SearchResult
+from pathlib import Path, PurePosixPath
+from typing import TYPE_CHECKING, NamedTuple, Protocol
 
+from codeweaver.core import CodeChunk
+from codeweaver.semantic.symbols import symbol_name
+from codeweaver.semantic.test_code import is_test_chunk, is_test_file
+from codeweaver.server.agent_api.search.types import CodeKind, CodeMatch
 
-def _is_test_file(file_path: Path) -> bool:
-    """Check if a file is a test file using filename and directory name heuristics.
 
-    Checks only the filename and immediate parent directory name to avoid
-    false positives when the project itself is located under a path containing
-    the word "test" (e.g., pytest temp directories like
-    /tmp/pytest-of-user/pytest-123/test_my_project/test_codebase/auth.py).
+if TYPE_CHECKING:
+    from codeweaver.engine import IndexFileManifest
 
-    Args:
-        file_path: Path to check
 
-    Returns:
-        True if the file appears to be a test file
+logger = logging.getLogger(__name__)
+
+_GLOB_CHARS = frozenset("*?[")
+
+
+class FilterCandidate(Protocol):
+    """What the filters look at: a `SearchResult`, or a `CodeMatch` (see `filter_matches`)."""
+
+    @property
+    def content(self) -> CodeChunk: ...
+
+    @property
+    def file_path(self) -> Path | None: ...
+
+
+class _MatchCandidate(NamedTuple):
+    """A code match, seen as a filter candidate."""
+
+    match: CodeMatch
+
+    @property
+    def content(self) -> CodeChunk:
+        return self.match.content
+
+    @property
+    def file_path(self) -> Path | None:
+        return self.match.file.path
+
+
+def _normalize_path_filter(path: str) -> str:
+    """A path filter as a project-relative posix path, without `./` or trailing slashes."""
+    parts = [part for part in PurePosixPath(path.strip().replace("\\", "/")).parts if part != "."]
+    return "/".join(parts).lstrip("/")
+
+
+def is_path_glob(path: str) -> bool:
+    """Whether a path filter is a glob rather than a directory or file path."""
+    return not _GLOB_CHARS.isdisjoint(path)
+
+
+def path_literal_prefix(path: str) -> str | None:
+    """The leading directories of a path filter that have no glob characters.
+
+    `crates/*/src/**/*.rs` has `crates`; `crates/auth` is its own prefix; `**/*.rs` has none.
     """
-    name = file_path.name.lower()
-    # Filename heuristics: test_*.py, *_test.py, *_spec.py, *_tests.py
-    if name.startswith("test_") or name.endswith(("_test.py", "_tests.py", "_spec.py")):
-        return True
-    # Check if immediately inside a test directory
-    parent_name = file_path.parent.name.lower()
-    return parent_name in ("test", "tests", "spec", "specs", "__tests__")
+    literal: list[str] = []
+    for part in _normalize_path_filter(path).split("/"):
+        if is_path_glob(part):
+            break
+        literal.append(part)
+    return "/".join(literal) or None
+
 
+def matches_path(file_path: Path, path: str) -> bool:
+    """Whether a project-relative file path is under a path filter or matches its glob.
+
+    In globs, `*` also matches across directories.
+    """
+    relative = file_path.as_posix().removeprefix("./")
+    pattern = _normalize_path_filter(path)
+    if is_path_glob(pattern):
+        return fnmatch.fnmatchcase(relative, pattern)
+    return relative == pattern or relative.startswith(f"{pattern}/")
 
-def filter_test_files(candidates: list[SearchResult]) -> list[SearchResult]:
-    """Filter out test files if include_tests is False.
+
+def filter_test_files[C: FilterCandidate](candidates: list[C]) -> list[C]:
+    """Filter out test code if include_tests is False.
+
+    Filtering is per chunk: a chunk from a non-test file is still removed when it is a test
+    definition or sits inside test-only code (like a Rust `#[cfg(test)] mod tests`).
 
     Args:
         candidates: List of search results to filter
 
     Returns:
         Filtered list of search results
-
-    Note:
-        This is a basic implementation using path name heuristics.
-        Future versions will use repo metadata to properly tag test files.
     """
-    return [c for c in candidates if not (c.file_path and _is_test_file(c.file_path))]
+    return [
+        c
+        for c in candidates
+        if not (
+            is_test_chunk(c.content, c.file_path)
+            if isinstance(c.content, CodeChunk)
+            else c.file_path and is_test_file(c.file_path)
+        )
+    ]
 
 
-def filter_by_languages(
-    candidates: list[SearchResult], focus_languages: tuple[str, ...]
-) -> list[SearchResult]:
+def filter_by_languages[C: FilterCandidate](
+    candidates: list[C], focus_languages: tuple[str, ...]
+) -> list[C]:
     """Filter search results to only include specified languages.
 
     Args:
@@ -75,39 +145,219 @@ def filter_by_languages(
             c
             and isinstance(c.content, CodeChunk)
             and c.content.language
-            and str(c.content.language) in langs
+            and not langs.isdisjoint({
+                str(c.content.language),
+                getattr(c.content.language, "variable", str(c.content.language)),
+            })
         )
     ]
 
 
-def apply_filters(
-    candidates: list[SearchResult],
+def filter_by_paths[C: FilterCandidate](candidates: list[C], paths: tuple[str, ...]) -> list[C]:
+    """Filter search results to files under, or matching, any of the given path filters.
+
+    Args:
+        candidates: List of search results to filter
+        paths: Project-relative directory or file paths, or globs
+
+    Returns:
+        Filtered list of search results
+    """
+    return [
+        c
+        for c in candidates
+        if c.file_path is not None and any(matches_path(c.file_path, path) for path in paths)
+    ]
+
+
+def filter_by_excluded_paths[C: FilterCandidate](
+    candidates: list[C], exclude_paths: tuple[str, ...]
+) -> list[C]:
+    """Filter out search results in files under, or matching, any of the given path filters.
+
+    Args:
+        candidates: List of search results to filter
+        exclude_paths: Project-relative directory or file paths, or globs
+
+    Returns:
+        Filtered list of search results
+    """
+    return [
+        c
+        for c in candidates
+        if c.file_path is None or not any(matches_path(c.file_path, path) for path in exclude_paths)
+    ]
+
+
+def is_kind(chunk: CodeChunk, kind: CodeKind) -> bool:
+    """Whether a chunk is of a kind, by its semantic class, its file's kind or its test-ness."""
+    if chunk.semantic_class in kind.semantic_classes:
+        return True
+    if chunk.ext_category is not None and chunk.ext_category.kind in kind.chunk_kinds:
+        return True
+    return kind == CodeKind.TEST and is_test_chunk(chunk, chunk.file_path)
+
+
+def filter_by_kinds[C: FilterCandidate](
+    candidates: list[C], kinds: tuple[CodeKind, ...]
+) -> list[C]:
+    """Filter search results to chunks of any of the given kinds.
+
+    Args:
+        candidates: List of search results to filter
+        kinds: Kinds of code to include
+
+    Returns:
+        Filtered list of search results
+    """
+    return [
+        c
+        for c in candidates
+        if isinstance(c.content, CodeChunk) and any(is_kind(c.content, kind) for kind in kinds)
+    ]
+
+
+def _chunk_symbol(chunk: CodeChunk) -> str | None:
+    if not chunk.metadata or not (meta := chunk.metadata.get("semantic_meta")):
+        return None
+    return meta.get("symbol") if isinstance(meta, dict) else getattr(meta, "symbol", None)
+
+
+def filter_by_symbol_prefix[C: FilterCandidate](candidates: list[C], prefix: str) -> list[C]:
+    """Filter search results to chunks whose symbol, or its last segment, starts with a prefix.
+
+    Args:
+        candidates: List of search results to filter
+        prefix: The start of the symbol, like `parse` or `Config::`
+
+    Returns:
+        Filtered list of search results
+    """
+    return [
+        c
+        for c in candidates
+        if isinstance(c.content, CodeChunk)
+        and (symbol := _chunk_symbol(c.content))
+        and (symbol.startswith(prefix) or symbol_name(symbol).startswith(prefix))
+    ]
+
+
+def filter_live_chunks[C: FilterCandidate](
+    candidates: list[C], manifest: IndexFileManifest
+) -> list[C]:
+    """Keep only the chunks the index manifest has live for their files.
+
+    A re-indexed file's new chunks are stored before its old ones are removed, and the manifest
+    takes up the new ones last. Until then, or for good if indexing stops in between, searches
+    see the file as it was indexed before, rather than both versions at once. Chunks of files
+    the manifest doesn't list are all kept.
+
+    Args:
+        candidates: List of search results to filter
+        manifest: The manifest of the index that was searched
+
+    Returns:
+        Filtered list of search results
+    """
+    live: dict[Path, frozenset[str] | None] = {}
+    kept: list[C] = []
+    for c in candidates:
+        if (path := c.file_path) is not None and path not in live:
+            entry = manifest.get_file(path)
+            live[path] = frozenset(entry["chunk_ids"]) if entry else None
+        if path is None or (ids := live[path]) is None or str(c.content.chunk_id) in ids:
+            kept.append(c)
+    return kept
+
+
+def apply_filters[C: FilterCandidate](
+    candidates: list[C],
     *,
     include_tests: bool = False,
     focus_languages: tuple[str, ...] | None = None,
-) -> list[SearchResult]:
+    paths: tuple[str, ...] | None = None,
+    exclude_paths: tuple[str, ...] | None = None,
+    kinds: tuple[CodeKind, ...] | None = None,
+    symbol_prefix: str | None = None,
+) -> list[C]:
     """Apply all configured filters to search results.
 
     Args:
         candidates: List of search results to filter
-        include_tests: Whether to include test files
+        include_tests: Whether to include test code
         focus_languages: Optional tuple of language names to include
+        paths: Optional tuple of path filters to include
+        exclude_paths: Optional tuple of path filters to leave out
+        kinds: Optional tuple of kinds of code to include
+        symbol_prefix: Optional start of the symbols to include
 
     Returns:
         Filtered list of search results
     """
-    # we don't need to filter anything
-    if not focus_languages and include_tests:
-        return candidates
-    # if include_tests is True, only filter by languages (if any)
-    if include_tests:
-        if not focus_languages:
-            return candidates
-        return filter_by_languages(candidates, focus_languages=focus_languages)
-    # include_tests is False: always filter test files; optionally filter by language
-    if not focus_languages:
-        return filter_test_files(candidates)
-    return filter_test_files(filter_by_languages(candidates, focus_languages=focus_languages))
-
-
-__all__ = ("apply_filters", "filter_by_languages", "filter_test_files")
+    if focus_languages:
+        candidates = filter_by_languages(candidates, focus_languages=focus_languages)
+    if paths:
+        candidates = filter_by_paths(candidates, paths)
+    if exclude_paths:
+        candidates = filter_by_excluded_paths(candidates, exclude_paths)
+    if kinds:
+        candidates = filter_by_kinds(candidates, kinds)
+    if symbol_prefix:
+        candidates = filter_by_symbol_prefix(candidates, symbol_prefix)
+    if not include_tests:
+        candidates = filter_test_files(candidates)
+    return candidates
+
+
+def filter_matches(
+    matches: list[CodeMatch],
+    *,
+    include_tests: bool = False,
+    focus_languages: tuple[str, ...] | None = None,
+    paths: tuple[str, ...] | None = None,
+    exclude_paths: tuple[str, ...] | None = None,
+    kinds: tuple[CodeKind, ...] | None = None,
+    symbol_prefix: str | None = None,
+) -> list[CodeMatch]:
+    """Apply all configured filters to code matches, like `apply_filters` does to search results.
+
+    Args:
+        matches: List of code matches to filter
+        include_tests: Whether to include test code
+        focus_languages: Optional tuple of language names to include
+        paths: Optional tuple of path filters to include
+        exclude_paths: Optional tuple of path filters to leave out
+        kinds: Optional tuple of kinds of code to include
+        symbol_prefix: Optional start of the symbols to include
+
+    Returns:
+        Filtered list of code matches, in their original order
+    """
+    candidates = apply_filters(
+        [_MatchCandidate(match) for match in matches],
+        include_tests=include_tests,
+        focus_languages=focus_languages,
+        paths=paths,
+        exclude_paths=exclude_paths,
+        kinds=kinds,
+        symbol_prefix=symbol_prefix,
+    )
+    return [candidate.match for candidate in candidates]
+
+
+__all__ = (
+    "FilterCandidate",
+    "apply_filters",
+    "filter_by_excluded_paths",
+    "filter_by_kinds",
+    "filter_by_languages",
+    "filter_by_paths",
+    "filter_by_symbol_prefix",
+    "filter_live_chunks",
+    "filter_matches",
+    "filter_test_files",
+    "is_kind",
+    "is_path_glob",
+    "matches_path",
+    "path_literal_prefix",
+)
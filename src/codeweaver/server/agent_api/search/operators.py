@@ -0,0 +1,110 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Inline filter operators in `find_code` queries.
+
+Agents and people can narrow a natural-language query without a structured `filters` argument
+by writing `key:value` operators in it; they're taken out of the query before it's embedded:
+- `lang:rust` (or `language:`): only these languages
+- `path:crates/auth/**`: only under this path or matching this glob; `-path:` leaves it out
+- `kind:fn`: only functions; also `type`, `test`, `config` and `docs`, with their aliases
+- `symbol:parse` (or `sym:`): only chunks whose symbol starts with this
+- `size:<10kb`: file size in bytes, with `<`, `<=`, `>` or `>=` and a `b`, `kb` or `mb` unit
+- `crate:store-core`: only this Cargo crate
+- `package:serde` (or `pkg:`): only this dependency, when searching with `scope="deps"`
+
+Values can be comma separated (`lang:rust,python`), and operators can repeat. Anything that
+isn't a known operator, like `std::io` or a URL, stays in the query.
+"""
+
+from __future__ import annotations
+
+import re
+
+from typing import Any
+
+from codeweaver.server.agent_api.search.types import SearchFilters
+
+
+_OPERATOR = re.compile(
+    r"(?<!\S)(?P<negated>-?)"
+    r"(?P<key>lang|language|path|kind|symbol|sym|size|crate|package|pkg):(?P<value>\S+)",
+    re.IGNORECASE,
+)
+_SIZE = re.compile(r"(?P<op><=|>=|<|>)?(?P<number>\d+(?:\.\d+)?)(?P<unit>b|kb|k|mb|m)?", re.I)
+_SIZE_UNITS = {"b": 1, "k": 1024, "kb": 1024, "m": 1024 * 1024, "mb": 1024 * 1024}
+_FIELDS = {
+    "lang": "languages",
+    "language": "languages",
+    "path": "paths",
+    "kind": "kinds",
+    "crate": "crates",
+    "package": "packages",
+    "pkg": "packages",
+}
+
+
+def _parse_size(value: str) -> dict[str, int]:
+    """Parse a `size:` value into `min_file_size` and/or `max_file_size`."""
+    if not (parsed := _SIZE.fullmatch(value)):
+        raise ValueError(f"size:{value} isn't a file size like `size:<10kb` or `size:>=200`")
+    size = int(float(parsed["number"]) * _SIZE_UNITS[(parsed["unit"] or "b").lower()])
+    match parsed["op"]:
+        case "<":
+            return {"max_file_size": max(size - 1, 0)}
+        case "<=":
+            return {"max_file_size": size}
+        case ">":
+            return {"min_file_size": size + 1}
+        case ">=":
+            return {"min_file_size": size}
+        case _:
+            return {"min_file_size": size, "max_file_size": size}
+
+
+def parse_query_operators(
+    query: str, filters: SearchFilters | None = None
+) -> tuple[str, SearchFilters]:
+    """Take inline filter operators out of a query and add them to its filters.
+
+    Operators add to list filters like `paths`; for single-valued filters like `symbol_prefix`
+    they take precedence over `filters`.
+
+    Args:
+        query: The query, possibly with operators like `lang:rust`
+        filters: Structured filters given with the query
+
+    Returns:
+        The query without its operators, and the combined filters
+
+    Raises:
+        ValueError: If an operator's value is invalid, like an unknown language or kind, or if
+            the query is nothing but operators
+    """
+    filters = filters or SearchFilters()
+    values: dict[str, Any] = {}
+    for match in _OPERATOR.finditer(query):
+        key, value = match["key"].lower(), match["value"]
+        if match["negated"] and key != "path":
+            raise ValueError(f"Only path: operators can be negated, not -{key}:")
+        if key in ("symbol", "sym"):
+            values["symbol_prefix"] = value
+        elif key == "size":
+            values |= _parse_size(value)
+        else:
+            field = "exclude_paths" if match["negated"] else _FIELDS[key]
+            values.setdefault(field, [*getattr(filters, field)])
+            values[field].extend(part for part in value.split(",") if part)
+    if not values:
+        return query, filters
+    remaining = " ".join(_OPERATOR.sub(" ", query).split())
+    if not remaining:
+        raise ValueError("The query has only filter operators; add what you're looking for")
+    combined = SearchFilters.model_validate(
+        filters.model_dump(exclude_unset=True, exclude_defaults=True) | values
+    )
+    return remaining, combined
+
+
+__all__ = ("parse_query_operators",)
@@ -0,0 +1,181 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""File outlines for the `get_outline` tool.
+
+`get_outline` takes a file path or a symbol. A path gets the file's whole outline. A symbol gets
+the outline items named like it, in each file the symbol index says defines it -- for a Rust
+type, the type and its impl blocks with their methods.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+
+from pathlib import Path
+from typing import TYPE_CHECKING, Annotated, Any
+
+from pydantic import Field, NonNegativeInt
+
+from codeweaver.core import BasedModel, SemanticSearchLanguage
+from codeweaver.semantic.outline import (
+    OUTLINE_LANGUAGES,
+    OutlineItem,
+    build_outline,
+    find_outline_items,
+)
+from codeweaver.semantic.symbols import symbol_name
+
+
+if TYPE_CHECKING:
+    from codeweaver.core import AnonymityConversion, FilteredKeyT
+    from codeweaver.engine.services.symbol_index_service import SymbolIndex
+
+
+logger = logging.getLogger(__name__)
+
+
+class FileOutline(BasedModel):
+    """The outline of one file."""
+
+    path: Annotated[Path, Field(description="""The file's path, relative to the project root""")]
+    language: Annotated[SemanticSearchLanguage, Field(description="""The file's language""")]
+    line_count: Annotated[NonNegativeInt, Field(description="""Number of lines in the file""")]
+    items: Annotated[
+        tuple[OutlineItem, ...],
+        Field(
+            description="""The file's top-level items with their nested items, or only the items named like the requested symbol."""
+        ),
+    ] = ()
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        from codeweaver.core import AnonymityConversion, FilteredKey
+
+        return {
+            FilteredKey("path"): AnonymityConversion.HASH,
+            FilteredKey("items"): AnonymityConversion.COUNT,
+        }
+
+
+class OutlineResponse(BasedModel):
+    """Response of the `get_outline` tool."""
+
+    target: Annotated[str, Field(description="""The file path or symbol that was outlined""")]
+    outlines: Annotated[
+        tuple[FileOutline, ...],
+        Field(description="""One outline per file, in path order"""),
+    ] = ()
+    summary: Annotated[
+        str, Field(description="""What was outlined, or why nothing was""", max_length=1000)
+    ]
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        from codeweaver.core import AnonymityConversion, FilteredKey
+
+        return {
+            FilteredKey("target"): AnonymityConversion.HASH,
+            FilteredKey("summary"): AnonymityConversion.TEXT_COUNT,
+        }
+
+    @classmethod
+    def get_schema(cls) -> dict[str, Any]:
+        """Get the JSON schema for the model as a Python dictionary."""
+        return cls.model_json_schema(mode="serialization")
+
+
+def _item_count(items: tuple[OutlineItem, ...]) -> int:
+    return sum(1 + _item_count(item.children) for item in items)
+
+
+def resolve_project_file(target: str, project_path: Path) -> Path | None:
+    """Resolve a target to a file inside the project, if it names one."""
+    candidate = Path(target).expanduser()
+    if not candidate.is_absolute():
+        candidate = project_path / candidate
+    try:
+        resolved = candidate.resolve()
+        _ = resolved.relative_to(project_path.resolve())
+    except (OSError, ValueError):
+        return None
+    return resolved if resolved.is_file() else None
+
+
+def outline_file(path: Path, project_path: Path) -> FileOutline | None:
+    """Build the outline of a file, or None if its language has no outline rules."""
+    from ast_grep_py import SgRoot
+
+    from codeweaver.semantic.ast_grep import AstThing
+
+    language = SemanticSearchLanguage.from_extension(path.suffix or path.name)
+    if language not in OUTLINE_LANGUAGES:
+        return None
+    content = path.read_text("utf-8", "ignore")
+    root = AstThing.from_sg_node(SgRoot(content, language.variable).root(), language)
+    try:
+        relative = path.resolve().relative_to(project_path.resolve())
+    except ValueError:
+        relative = path
+    return FileOutline(
+        path=relative,
+        language=language,
+        line_count=len(content.splitlines()),
+        items=build_outline(root) or (),
+    )
+
+
+async def outline_path(target: str, path: Path, project_path: Path) -> OutlineResponse:
+    """Outline a whole file."""
+    outline = await asyncio.to_thread(outline_file, path, project_path)
+    if outline is None:
+        return OutlineResponse(
+            target=target,
+            summary=(
+                f"{target} isn't in a language with outlines "
+                "(Rust, Python, JavaScript, TypeScript or Go)"
+            ),
+        )
+    return OutlineResponse(
+        target=target,
+        outlines=(outline,),
+        summary=(
+            f"{_item_count(outline.items)} items in {outline.path} "
+            f"({outline.line_count} lines)"
+        ),
+    )
+
+
+async def outline_symbol(symbol: str, index: SymbolIndex, project_path: Path) -> OutlineResponse:
+    """Outline the items named like a symbol, in the files that define it."""
+    paths = sorted({occurrence.path for occurrence in index.definitions(symbol)}, key=str)
+    outlines: list[FileOutline] = []
+    for path in paths:
+        try:
+            outline = await asyncio.to_thread(outline_file, project_path / path, project_path)
+        except Exception as e:
+            logger.debug("Could not outline %s: %s", path, e)
+            continue
+        if outline is not None and (items := find_outline_items(outline.items, symbol)):
+            outlines.append(outline.model_copy(update={"items": items}))
+    if not outlines:
+        return OutlineResponse(
+            target=symbol,
+            summary=f"No file or symbol named {symbol_name(symbol)} was found in the project",
+        )
+    count = sum(_item_count(outline.items) for outline in outlines)
+    return OutlineResponse(
+        target=symbol,
+        outlines=tuple(outlines),
+        summary=f"{count} items for {symbol_name(symbol)} in {len(outlines)} files",
+    )
+
+
+__all__ = (
+    "FileOutline",
+    "OutlineResponse",
+    "outline_file",
+    "outline_path",
+    "outline_symbol",
+    "resolve_project_file",
+)
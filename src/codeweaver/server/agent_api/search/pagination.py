@@ -0,0 +1,261 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Continuation cursors for paging through `find_code` results.
+
+`find_code` ranks every candidate for a query but returns only `max_results` of them. When
+there are more, its response carries a cursor: an opaque string encoding the query's
+fingerprint, the index generation the ranking came from, and the offset of the next result.
+Ranked candidates are cached by fingerprint and generation, so later pages are slices of the
+same ranking instead of a new search that could overlap the pages before it.
+
+A cursor only works for the query that issued it, and only until the index changes; once the
+codebase is re-indexed, it's stale and the query has to start again from the first page. When
+the index generation can't be told (the project was never indexed, or the indexer isn't
+available), no cursors are issued, and none are accepted.
+"""
+
+from __future__ import annotations
+
+import base64
+import binascii
+import json
+import time
+
+from collections import OrderedDict
+from collections.abc import Sequence
+from typing import TYPE_CHECKING, Any, NamedTuple
+
+from codeweaver.core import QueryError
+from codeweaver.core.constants import DEFAULT_RANKED_QUERIES, DEFAULT_RANKED_RESULTS_TTL
+from codeweaver.core.utils import get_blake_hash
+
+
+if TYPE_CHECKING:
+    from codeweaver.core import SearchStrategy
+    from codeweaver.semantic.ast_grep import Strictness
+    from codeweaver.server.agent_api.search.intent import IntentType
+    from codeweaver.server.agent_api.search.types import SearchFilters, SearchMode, SearchScope
+
+
+_CURSOR_VERSION = 1
+
+
+class PageCursor(NamedTuple):
+    """Where the next page of a query's results starts."""
+
+    fingerprint: str
+    """The query's fingerprint, from `query_fingerprint`."""
+    generation: str
+    """The index generation the query's results were ranked in."""
+    offset: int
+    """The rank of the next page's first result."""
+
+    def encode(self) -> str:
+        """Encode the cursor as the opaque string agents pass back."""
+        payload = json.dumps([_CURSOR_VERSION, *self], separators=(",", ":"))
+        return base64.urlsafe_b64encode(payload.encode("utf-8")).decode("ascii").rstrip("=")
+
+    @classmethod
+    def decode(cls, cursor: str) -> PageCursor:
+        """Decode a cursor from `encode`.
+
+        Raises:
+            QueryError: If the string isn't a `find_code` cursor
+        """
+        try:
+            padded = cursor.strip() + "=" * (-len(cursor.strip()) % 4)
+            version, fingerprint, generation, offset = json.loads(
+                base64.urlsafe_b64decode(padded.encode("ascii"))
+            )
+        except (binascii.Error, UnicodeError, ValueError, TypeError) as e:
+            raise _invalid_cursor(cursor) from e
+        if (
+            version != _CURSOR_VERSION
+            or not isinstance(fingerprint, str)
+            or not isinstance(generation, str)
+            or not isinstance(offset, int)
+            or offset < 0
+        ):
+            raise _invalid_cursor(cursor)
+        return cls(fingerprint, generation, offset)
+
+
+def _invalid_cursor(cursor: str) -> QueryError:
+    return QueryError(
+        "The cursor isn't one `find_code` returned",
+        details={"cursor": cursor[:100]},
+        suggestions=["Pass the `cursor` of a previous response unchanged, or leave it out"],
+    )
+
+
+def query_fingerprint(
+    query: str,
+    *,
+    filters: SearchFilters,
+    intent: IntentType | None,
+    mode: SearchMode,
+    strictness: Strictness | None = None,
+    rev: str | None = None,
+    scope: SearchScope | None = None,
+) -> str:
+    """Fingerprint everything that decides a query's ranking, so pages come from one ranking."""
+    key = {
+        "query": " ".join(query.split()),
+        "filters": filters.model_dump(mode="json", exclude_defaults=True),
+        "intent": intent.variable if intent else None,
+        "mode": mode.variable,
+        "strictness": strictness.variable if strictness else None,
+        "rev": rev,
+        "scope": scope.variable if scope else None,
+    }
+    return get_blake_hash(json.dumps(key, sort_keys=True))[:32]
+
+
+def read_cursor(cursor: str, fingerprint: str, generation: str | None) -> int:
+    """Check a cursor against the query it's used with and the current index generation.
+
+    Returns:
+        The offset the page starts at
+
+    Raises:
+        QueryError: If the cursor is invalid, belongs to another query, or is stale because the
+            index changed since it was issued (or its generation is unknown)
+    """
+    page = PageCursor.decode(cursor)
+    if generation is None:
+        raise QueryError(
+            "Can't check the cursor: the current index generation is unknown",
+            details={"cursor": cursor[:100], "offset": page.offset},
+            suggestions=[
+                "Run the query again without `cursor`, once the codebase is indexed",
+                "Check `cw status` for the index",
+            ],
+        )
+    if page.fingerprint != fingerprint:
+        raise QueryError(
+            "The cursor belongs to a different query",
+            details={"cursor": cursor[:100]},
+            suggestions=[
+                "Pass the cursor with the same query and arguments as the search that returned it"
+            ],
+        )
+    if page.generation != generation:
+        raise QueryError(
+            "Stale cursor: the codebase was re-indexed after the cursor was issued",
+            details={"cursor": cursor[:100], "offset": page.offset},
+            suggestions=["Run the query again without `cursor` to start from the first page"],
+        )
+    return page.offset
+
+
+def next_cursor(fingerprint: str, generation: str | None, offset: int, total: int) -> str | None:
+    """The cursor for the page starting at `offset`.
+
+    None if there's nothing left, or if the index generation is unknown, since a cursor that
+    can't go stale could page through a ranking of an index that has since changed.
+    """
+    if generation is None or offset >= total:
+        return None
+    return PageCursor(fingerprint, generation, offset).encode()
+
+
+def merged_page[T, U](
+    leading: Sequence[T], ranked: Sequence[U], offset: int, size: int
+) -> tuple[Sequence[T], Sequence[U]]:
+    """The page at `offset` of `leading` followed by `ranked`, as a slice of each.
+
+    Exact hits lead the ranked candidates this way, so pages run through the exact hits first.
+    """
+    head = leading[offset : offset + size]
+    start = max(offset - len(leading), 0)
+    return head, ranked[start : start + size - len(head)]
+
+
+class RankedResults(NamedTuple):
+    """A query's candidates in rank order, with the strategies that ranked them."""
+
+    candidates: tuple[Any, ...]
+    strategies: tuple[SearchStrategy, ...]
+
+
+class RankedResultsCache:
+    """Least recently used cache of ranked candidates, by query fingerprint and generation.
+
+    Entries expire after `ttl` seconds. Entries from an older generation are never returned,
+    because their cursors are stale.
+    """
+
+    def __init__(
+        self, max_queries: int = DEFAULT_RANKED_QUERIES, ttl: float = DEFAULT_RANKED_RESULTS_TTL
+    ) -> None:
+        """Initialize the cache.
+
+        Args:
+            max_queries: Most queries to keep; the least recently used are dropped first
+            ttl: Seconds to keep a query's candidates
+        """
+        self._entries: OrderedDict[tuple[str, str], tuple[float, RankedResults]] = OrderedDict()
+        self.configure(max_queries, ttl)
+
+    def configure(self, max_queries: int | None = None, ttl: float | None = None) -> None:
+        """Change the cache's size or time to live; what's cached is kept if it still fits."""
+        if max_queries is not None:
+            self._max_queries = max(max_queries, 0)
+        if ttl is not None:
+            self._ttl = ttl
+        while len(self._entries) > self._max_queries:
+            self._entries.popitem(last=False)
+
+    def get(self, fingerprint: str, generation: str) -> RankedResults | None:
+        """Get a query's ranked candidates, if they're cached and fresh."""
+        key = (fingerprint, generation)
+        if (entry := self._entries.get(key)) is None:
+            return None
+        stored_at, results = entry
+        if time.monotonic() - stored_at > self._ttl:
+            del self._entries[key]
+            return None
+        self._entries.move_to_end(key)
+        return results
+
+    def put(
+        self,
+        fingerprint: str,
+        generation: str,
+        candidates: Sequence[Any],
+        strategies: Sequence[SearchStrategy] = (),
+    ) -> RankedResults:
+        """Cache a query's ranked candidates, replacing those of older generations."""
+        for key in [key for key in self._entries if key[0] == fingerprint]:
+            del self._entries[key]
+        results = RankedResults(tuple(candidates), tuple(strategies))
+        if self._max_queries:
+            self._entries[fingerprint, generation] = (time.monotonic(), results)
+            self.configure()
+        return results
+
+    def clear(self) -> None:
+        """Drop everything cached."""
+        self._entries.clear()
+
+    def __len__(self) -> int:
+        """The number of queries cached."""
+        return len(self._entries)
+
+
+RANKED_RESULTS = RankedResultsCache()
+"""The ranked candidates `find_code` pages through; sized by the MCP response caching settings."""
+
+
+__all__ = (
+    "RANKED_RESULTS",
+    "PageCursor",
+    "RankedResults",
+    "RankedResultsCache",
+    "merged_page",
+    "next_cursor",
+    "query_fingerprint",
+    "read_cursor",
+)
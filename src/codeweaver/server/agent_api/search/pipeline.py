@@ -19,26 +19,45 @@ from typing import TYPE_CHECKING, Any, NoReturn
 
 from codeweaver.core import (
     CodeWeaverSparseEmbedding,
+    ConfigLanguage,
     ConfigurationError,
+    FusionStrategy,
     QueryError,
     QueryResult,
     RawEmbeddingVectors,
     SearchResult,
     SearchStrategy,
+    SemanticSearchLanguage,
     StrategizedQuery,
 )
 from codeweaver.core.constants import ZERO
 from codeweaver.core.di import INJECTED
-from codeweaver.providers import SearchPackageDep
+from codeweaver.providers import (
+    DatetimeRange,
+    FieldCondition,
+    Filter,
+    IsEmptyCondition,
+    MatchAny,
+    MatchValue,
+    PayloadField,
+    Range,
+    SearchPackageDep,
+)
+from codeweaver.server.agent_api.search.filters import is_path_glob, path_literal_prefix
+from codeweaver.server.agent_api.search.types import CodeKind
 
 
 if TYPE_CHECKING:
+    from collections.abc import Iterable
+
     from codeweaver.providers import (
+        Condition,
         EmbeddingProvider,
         RerankingProvider,
         SparseEmbeddingProvider,
         VectorStoreProvider,
     )
+    from codeweaver.server.agent_api.search.types import SearchFilters
 
 
 def _get_package(search_package: SearchPackageDep = INJECTED) -> SearchPackageDep:
@@ -357,12 +376,15 @@ async def embed_query(
     return QueryResult(vectors=vectors)
 
 
-def build_query_vector(query_result: QueryResult, query: str) -> StrategizedQuery:
+def build_query_vector(
+    query_result: QueryResult, query: str, fusion: FusionStrategy | None = None
+) -> StrategizedQuery:
     """Build query vector for search from embeddings.
 
     Args:
         query_result: QueryResult containing embeddings keyed by intent
         query: Natural language query string
+        fusion: How a hybrid search fuses its dense and sparse rankings (default: RRF)
 
     Returns:
         A StrategizedQuery containing sparse and/or dense vectors and the chosen strategy
@@ -392,6 +414,7 @@ def build_query_vector(query_result: QueryResult, query: str) -> StrategizedQuer
                 dense=dense_vector,
                 sparse=sparse_embedding,
                 strategy=SearchStrategy.HYBRID_SEARCH,
+                fusion=fusion or FusionStrategy(),
             )
         logger.warning("Using dense-only search (sparse embeddings unavailable)")
         return StrategizedQuery(
@@ -413,10 +436,121 @@ def build_query_vector(query_result: QueryResult, query: str) -> StrategizedQuer
     )
 
 
+def _package_names(packages: Iterable[str]) -> list[str]:
+    """Crate and package names in both spellings; Cargo and PyPI treat `-` and `_` alike."""
+    return sorted({
+        variant
+        for package in packages
+        for variant in (package, package.replace("-", "_"), package.replace("_", "-"))
+    })
+
+
+def _language_names(languages: Iterable[str]) -> list[str]:
+    """Language names as given and as stored in the `language` payload field."""
+    names: set[str] = set()
+    for language in languages:
+        names.add(language.strip().lower())
+        for language_type in (SemanticSearchLanguage, ConfigLanguage):
+            try:
+                names.add(language_type.from_string(language).variable)
+                break
+            except ValueError:
+                continue
+    return sorted(names)
+
+
+def _match_any(key: str, values: Iterable[str]) -> FieldCondition:
+    return FieldCondition(key=key, match=MatchAny(any=sorted(set(values))))
+
+
+def _or_unset(key: str, *conditions: Condition) -> Filter:
+    """Match any of the conditions, or a point whose payload doesn't have the field yet.
+
+    Points indexed before a field was added to the payload still match until they're
+    re-indexed; the post-search filters in `filters` handle them.
+    """
+    return Filter(should=[*conditions, IsEmptyCondition(is_empty=PayloadField(key=key))])
+
+
+def _subtree(path: str) -> str | None:
+    """The directory or file a path filter covers entirely, like `build` for `build/**`."""
+    base = path.strip().rstrip("/").removesuffix("/**")
+    return None if is_path_glob(base) else path_literal_prefix(base)
+
+
+def _kind_conditions(kinds: Iterable[CodeKind]) -> list[Condition]:
+    """Conditions matching chunks of any of the kinds."""
+    kinds = tuple(kinds)
+    conditions: list[Condition] = []
+    if classes := {c.variable for kind in kinds for c in kind.semantic_classes}:
+        conditions.append(_match_any("semantic_class", classes))
+    if chunk_kinds := {k.variable for kind in kinds for k in kind.chunk_kinds}:
+        conditions.append(_match_any("chunk_kind", chunk_kinds))
+    if CodeKind.TEST in kinds:
+        conditions.append(FieldCondition(key="is_test", match=MatchValue(value=True)))
+    return conditions
+
+
+def build_query_filter(filters: SearchFilters | None = None) -> Filter | None:
+    """Build the payload filter pushed down to the vector store.
+
+    Crates, languages, paths, kinds, chunk kinds and semantic classes become keyword matches,
+    test code is excluded with the `is_test` flag, and `modified_since` and file sizes are
+    ranges. Path globs are pushed down as their leading literal directories and matched exactly
+    afterwards (see `filters.filter_by_paths`); if a glob has none, paths aren't pushed down at
+    all. Excluded paths are pushed down only when they cover whole directories or files, and
+    symbol prefixes not at all: keyword indexes can't match prefixes.
+
+    Args:
+        filters: What to restrict results to
+
+    Returns:
+        A filter, or None if there is nothing to filter on
+    """
+    if filters is None:
+        return None
+    must: list[Condition] = []
+    if filters.crates:
+        must.append(_match_any("crate", _package_names(filters.crates)))
+    if filters.packages:
+        must.append(_match_any("package", _package_names(filters.packages)))
+    if filters.languages:
+        languages = _language_names(filters.languages)
+        must.append(_or_unset("language", _match_any("language", languages)))
+    prefixes = [path_literal_prefix(path) for path in filters.paths]
+    if prefixes and None not in prefixes:
+        must.append(
+            _or_unset("path_prefixes", _match_any("path_prefixes", (p for p in prefixes if p)))
+        )
+    if filters.kinds:
+        must.append(_or_unset("chunk_kind", *_kind_conditions(filters.kinds)))
+    if filters.chunk_kinds:
+        chunk_kinds = (k.variable for k in filters.chunk_kinds)
+        must.append(_or_unset("chunk_kind", _match_any("chunk_kind", chunk_kinds)))
+    if filters.semantic_classes:
+        classes = (c.variable for c in filters.semantic_classes)
+        must.append(_or_unset("semantic_class", _match_any("semantic_class", classes)))
+    if filters.modified_since is not None:
+        since = DatetimeRange(gte=filters.modified_since)
+        must.append(_or_unset("modified_at", FieldCondition(key="modified_at", range=since)))
+    if filters.min_file_size is not None or filters.max_file_size is not None:
+        size = Range(gte=filters.min_file_size, lte=filters.max_file_size)
+        must.append(_or_unset("file_size", FieldCondition(key="file_size", range=size)))
+    must_not: list[Condition] = []
+    if filters.include_tests is False:
+        must_not.append(FieldCondition(key="is_test", match=MatchValue(value=True)))
+    if excluded := [prefix for path in filters.exclude_paths if (prefix := _subtree(path))]:
+        must_not.append(_match_any("path_prefixes", excluded))
+    if not must and not must_not:
+        return None
+    return Filter(must=must or None, must_not=must_not or None)
+
+
 async def execute_vector_search(
     query_vector: StrategizedQuery,
     context: Any = None,
     vector_store: VectorStoreProvider | None = None,
+    query_filter: Filter | None = None,
 ) -> list[SearchResult]:
     """Execute vector search against configured vector store.
 
@@ -424,6 +558,7 @@ async def execute_vector_search(
         query_vector: Query vector (dense, sparse, or hybrid)
         context: Optional FastMCP context for structured logging
         vector_store: Injected vector store instance
+        query_filter: Optional payload filter applied by the vector store
 
     Returns:
         List of search results from vector store
@@ -445,12 +580,13 @@ async def execute_vector_search(
                 "search_strategy": query_vector.strategy.variable,
                 "has_dense": query_vector.dense is not None,
                 "has_sparse": query_vector.sparse is not None,
+                "filtered": query_filter is not None,
             },
         },
     )
     if vector_store is None:
         raise ConfigurationError("No vector store provider configured")
-    results = await vector_store.search(vector=query_vector, query_filter=None)
+    results = await vector_store.search(vector=query_vector, query_filter=query_filter)
     await log_to_client_or_fallback(
         context,
         "info",
@@ -688,6 +824,7 @@ async def rerank_results(
 
 
 __all__ = (
+    "build_query_filter",
     "build_query_vector",
     "embed_query",
     "execute_vector_search",
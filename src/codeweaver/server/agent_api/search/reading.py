@@ -0,0 +1,257 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Reading code for the `read_code` tool.
+
+`read_code` opens a file, a line range in it, or the definitions of a symbol. A range can be
+widened to the item it sits in (`expand="item"`, using the file's outline) or by a number of
+lines on each side (`expand="lines:N"`). Symbols are read as whole items by default.
+
+Files must be inside the project and must pass the indexer's ignore rules, so `read_code` never
+shows an agent what indexing leaves out. Long sections are cut to `MAX_READ_LINES`; the span
+still covers what was asked for.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+
+from pathlib import Path
+from typing import TYPE_CHECKING, Annotated, Any, Literal
+
+from pydantic import Field
+
+from codeweaver.core import BasedModel, DiscoveredFile, SemanticSearchLanguage, Span
+from codeweaver.semantic.symbols import symbol_name
+from codeweaver.server.agent_api.search.outline import outline_file
+
+
+if TYPE_CHECKING:
+    from codeweaver.core import AnonymityConversion, FilteredKeyT
+    from codeweaver.engine.services.symbol_index_service import SymbolIndex
+    from codeweaver.semantic.outline import OutlineItem
+
+
+logger = logging.getLogger(__name__)
+
+MAX_READ_LINES = 400
+"""Most lines returned for one section."""
+
+type Expansion = Literal["item"] | int
+"""`"item"` to widen to the enclosing item, or the number of lines to add on each side."""
+
+
+class CodeSection(BasedModel):
+    """A range of lines read from one file."""
+
+    path: Annotated[Path, Field(description="""The file's path, relative to the project root""")]
+    language: Annotated[
+        SemanticSearchLanguage | None,
+        Field(description="""The file's language, if it has semantic support"""),
+    ] = None
+    span: Annotated[Span, Field(description="""The lines read, 1-based and inclusive""")]
+    content: Annotated[str, Field(description="""The lines' text""")]
+    item: Annotated[
+        str | None,
+        Field(description="""Signature of the item the section was widened to, if it was"""),
+    ] = None
+    truncated: Annotated[
+        bool,
+        Field(
+            description="""Whether the content stops before the span's end, because the section was longer than the most lines `read_code` returns"""
+        ),
+    ] = False
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        from codeweaver.core import AnonymityConversion, FilteredKey
+
+        return {
+            FilteredKey("path"): AnonymityConversion.HASH,
+            FilteredKey("content"): AnonymityConversion.TEXT_COUNT,
+            FilteredKey("item"): AnonymityConversion.BOOLEAN,
+        }
+
+
+class ReadCodeResponse(BasedModel):
+    """Response of the `read_code` tool."""
+
+    target: Annotated[str, Field(description="""The file path or symbol that was read""")]
+    sections: Annotated[
+        tuple[CodeSection, ...],
+        Field(description="""The sections read; one per definition for a symbol"""),
+    ] = ()
+    summary: Annotated[
+        str, Field(description="""What was read, or why nothing was""", max_length=1000)
+    ]
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        from codeweaver.core import AnonymityConversion, FilteredKey
+
+        return {
+            FilteredKey("target"): AnonymityConversion.HASH,
+            FilteredKey("summary"): AnonymityConversion.TEXT_COUNT,
+        }
+
+    @classmethod
+    def get_schema(cls) -> dict[str, Any]:
+        """Get the JSON schema for the model as a Python dictionary."""
+        return cls.model_json_schema(mode="serialization")
+
+
+def parse_expansion(expand: str | None) -> Expansion | None:
+    """Parse an `expand` argument: `"item"` or `"lines:N"`.
+
+    Raises:
+        ValueError: If the argument is neither
+    """
+    if expand is None or not (expand := expand.strip().lower()):
+        return None
+    if expand == "item":
+        return "item"
+    prefix, _, count = expand.partition(":")
+    if prefix == "lines" and count.isdigit():
+        return int(count)
+    raise ValueError(f'expand must be "item" or "lines:N", not {expand!r}')
+
+
+def _enclosing_item(items: tuple[OutlineItem, ...], start: int, end: int) -> OutlineItem | None:
+    """The innermost outline item containing a line range."""
+    for item in items:
+        if item.line <= start and end <= item.end_line:
+            return _enclosing_item(item.children, start, end) or item
+    return None
+
+
+def read_section(
+    path: Path,
+    project_path: Path,
+    lines: tuple[int, int] | None = None,
+    expand: Expansion | None = None,
+) -> CodeSection | None:
+    """Read a line range of a file, widened as asked.
+
+    Args:
+        path: The file to read
+        project_path: The project root, which the section's path is relative to
+        lines: The 1-based, inclusive range to read; the whole file if None
+        expand: How to widen the range
+
+    Returns:
+        The section, or None if the file can't be read
+    """
+    if (file := DiscoveredFile.from_path(path, project_path=project_path)) is None:
+        return None
+    try:
+        file_lines = path.read_text("utf-8").splitlines()
+    except (OSError, UnicodeDecodeError) as e:
+        logger.debug("Could not read %s: %s", path, e)
+        return None
+    last = max(len(file_lines), 1)
+    start, end = lines or (1, last)
+    start, end = min(max(start, 1), last), min(max(end, start), last)
+    item: OutlineItem | None = None
+    if expand == "item":
+        outline = outline_file(path, project_path)
+        if outline is not None and (item := _enclosing_item(outline.items, start, end)):
+            start, end = item.line, item.end_line
+    elif isinstance(expand, int):
+        start, end = max(start - expand, 1), min(end + expand, last)
+    shown = file_lines[start - 1 : min(end, start + MAX_READ_LINES - 1)]
+    language = file.ext_category.language if file.ext_category else None
+    return CodeSection(
+        path=file.path,
+        language=language if isinstance(language, SemanticSearchLanguage) else None,
+        span=Span(start, end, file.source_id),
+        content="\n".join(shown),
+        item=item.signature if item is not None else None,
+        truncated=len(shown) < end - start + 1,
+    )
+
+
+def _describe(section: CodeSection) -> str:
+    lines = f"{section.path}:{section.span.start}-{section.span.end}"
+    return f"{lines} (cut to {MAX_READ_LINES} lines)" if section.truncated else lines
+
+
+async def read_path(
+    target: str,
+    path: Path,
+    project_path: Path,
+    lines: tuple[int, int] | None = None,
+    expand: Expansion | None = None,
+) -> ReadCodeResponse:
+    """Read a file, or a line range of it."""
+    section = await asyncio.to_thread(read_section, path, project_path, lines, expand)
+    if section is None:
+        return ReadCodeResponse(target=target, summary=f"{target} isn't a readable text file")
+    return ReadCodeResponse(
+        target=target, sections=(section,), summary=f"Read {_describe(section)}"
+    )
+
+
+async def read_symbol(
+    symbol: str,
+    index: SymbolIndex,
+    project_path: Path,
+    expand: Expansion | None = "item",
+    *,
+    in_file: Path | None = None,
+    max_results: int = 10,
+) -> ReadCodeResponse:
+    """Read the definitions of a symbol, each widened to its item by default.
+
+    Args:
+        symbol: The symbol, as a bare name or a path like `Cache::get`
+        index: The project's symbol reference index
+        project_path: The project root
+        expand: How to widen each definition's line
+        in_file: Only read definitions in this file, relative to the project root
+        max_results: Most definitions to read
+    """
+    locations = sorted(
+        {
+            (occurrence.path, occurrence.reference.line)
+            for occurrence in index.definitions(symbol)
+            if in_file is None or occurrence.path == in_file
+        },
+        key=lambda location: (str(location[0]), location[1]),
+    )
+    sections: list[CodeSection] = []
+    for path, line in locations:
+        section = await asyncio.to_thread(
+            read_section, project_path / path, project_path, (line, line), expand
+        )
+        if section is not None and section not in sections:
+            sections.append(section)
+        if len(sections) >= max_results:
+            break
+    if not sections:
+        return ReadCodeResponse(
+            target=symbol,
+            summary=(
+                f"No definition of {symbol_name(symbol)} was found in "
+                f"{in_file or 'the project'}"
+            ),
+        )
+    return ReadCodeResponse(
+        target=symbol,
+        sections=tuple(sections),
+        summary=(
+            f"Read {len(sections)} definitions of {symbol_name(symbol)}: "
+            f"{', '.join(_describe(section) for section in sections)}"
+        )[:1000],
+    )
+
+
+__all__ = (
+    "MAX_READ_LINES",
+    "CodeSection",
+    "Expansion",
+    "ReadCodeResponse",
+    "parse_expansion",
+    "read_path",
+    "read_section",
+    "read_symbol",
+)
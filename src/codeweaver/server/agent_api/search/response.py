@@ -12,6 +12,7 @@ from search results, including summary generation and metadata calculation.
 
 from __future__ import annotations
 
+from collections.abc import Sequence
 from typing import Literal
 
 from codeweaver.core import ConfigLanguage, LanguageName, SearchStrategy, SemanticSearchLanguage
@@ -21,7 +22,11 @@ from codeweaver.providers.embedding.providers.base import EmbeddingProvider, Spa
 from codeweaver.providers.reranking.providers.base import RerankingProvider
 from codeweaver.providers.vector_stores.base import VectorStoreProvider
 from codeweaver.server.agent_api.search.intent import IntentType
-from codeweaver.server.agent_api.search.types import CodeMatch, FindCodeResponseSummary
+from codeweaver.server.agent_api.search.types import (
+    CodeMatch,
+    ElidedMatch,
+    FindCodeResponseSummary,
+)
 from codeweaver.server.dependencies import CodeWeaverStateDep
 
 
@@ -129,6 +134,10 @@ def build_success_response(
     token_limit: int,
     execution_time_ms: float,
     strategies_used: list[SearchStrategy],
+    *,
+    token_count: int | None = None,
+    elided: Sequence[ElidedMatch] = (),
+    cursor: str | None = None,
 ) -> FindCodeResponseSummary:
     """Build a successful FindCodeResponseSummary.
 
@@ -140,6 +149,9 @@ def build_success_response(
         token_limit: Maximum token limit
         execution_time_ms: Execution time in milliseconds
         strategies_used: List of search strategies used
+        token_count: Tokens counted while assembling the matches; estimated if not given
+        elided: Matches truncated or omitted to fit the token limit
+        cursor: Cursor for the next page of results, if there is one
 
     Returns:
         FindCodeResponseSummary with all fields populated
@@ -155,6 +167,12 @@ def build_success_response(
         or SearchStrategy.KEYWORD_FALLBACK in strategies_used
     ):
         search_mode = "sparse_only"
+    elif {
+        SearchStrategy.STRUCTURAL_SEARCH,
+        SearchStrategy.TEXT_SEARCH,
+        SearchStrategy.FILE_DISCOVERY,
+    }.intersection(strategies_used):
+        search_mode = "exact"
 
     # Get indexing state from global application state
     indexing_state, index_coverage = get_indexer_state_info()
@@ -168,6 +186,10 @@ def build_success_response(
         status = "partial"
     elif search_mode == "dense_only":
         warnings.append("Sparse embeddings unavailable - using dense search only")
+    if elided:
+        warnings.append(
+            f"{len(elided)} match(es) truncated or omitted to fit the token limit; see `elided`"
+        )
 
     return FindCodeResponseSummary(
         matches=code_matches,
@@ -175,7 +197,9 @@ def build_success_response(
         query_intent=intent_type,
         total_matches=total_candidates,
         total_results=len(code_matches),
-        token_count=calculate_token_count(code_matches, token_limit),
+        token_count=calculate_token_count(code_matches, token_limit)
+        if token_count is None
+        else token_count,
         execution_time_ms=execution_time_ms,
         search_strategy=tuple(strategies_used),
         languages_found=extract_languages(code_matches),
@@ -184,6 +208,8 @@ def build_success_response(
         indexing_state=indexing_state,
         index_coverage=index_coverage,
         search_mode=search_mode,
+        elided=tuple(elided),
+        cursor=cursor,
         metadata={},
     )
 
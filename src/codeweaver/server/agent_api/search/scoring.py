@@ -6,103 +6,112 @@
 """Scoring and reranking utilities for search results.
 
 This module handles the scoring pipeline including:
-- Hybrid search score combination (dense + sparse)
+- Hybrid search score fusion (dense + sparse), for results the vector store didn't fuse
 - Semantic reranking
-- Intent-based semantic weighting
+- Task-aware semantic weighting from each chunk's semantic classification
 """
 
 from __future__ import annotations
 
 import logging
 
-from codeweaver.core import CodeChunk, SearchResult
-from codeweaver.core.constants import DEFAULT_DENSE_WEIGHT, DEFAULT_SPARSE_WEIGHT, ZERO_POINT_ZERO
+from codeweaver.core import CodeChunk, FusionStrategy, SearchResult
+from codeweaver.core.constants import DEFAULT_SEMANTIC_BOOST, ONE_POINT_ZERO
 from codeweaver.providers import RerankingResult
 from codeweaver.semantic import AgentTask
-from codeweaver.server.agent_api.search.intent import IntentType
 
 
 logger = logging.getLogger(__name__)
 
 
 def apply_hybrid_weights(
-    candidates: list[SearchResult],
-    dense_weight: float = DEFAULT_DENSE_WEIGHT,
-    sparse_weight: float = DEFAULT_SPARSE_WEIGHT,
+    candidates: list[SearchResult], strategy: FusionStrategy | None = None
 ) -> None:
-    """Apply static weights to hybrid search scores (in-place).
+    """Fuse candidates' dense and sparse scores into their score (in-place).
+
+    Vector stores fuse hybrid results themselves; this is for candidates that come back with
+    separate `dense_score` and `sparse_score` values instead. Candidates without either keep
+    their score.
 
     Args:
-        candidates: List of search results with dense and sparse scores
-        dense_weight: Weight for dense embeddings (default: 0.65)
-        sparse_weight: Weight for sparse embeddings (default: 0.35)
+        candidates: List of search results with dense and/or sparse scores
+        strategy: How to fuse the scores (default: reciprocal rank fusion, 0.65 dense weight)
+    """
+    strategy = strategy or FusionStrategy()
+    by_id = {id(candidate): candidate for candidate in candidates}
+
+    def ranking(channel: str) -> list[tuple[int, float]]:
+        scored = [
+            (key, score)
+            for key, candidate in by_id.items()
+            if (score := getattr(candidate, channel, None)) is not None
+        ]
+        return sorted(scored, key=lambda pair: pair[1], reverse=True)
+
+    for fused in strategy.fuse(ranking("dense_score"), ranking("sparse_score")):
+        by_id[fused.key].score = fused.score
 
-    Note:
-        Modifies candidates in place by updating the score attribute.
+
+def task_importance(chunk: CodeChunk, agent_task: AgentTask) -> float | None:
+    """How important a chunk's kind of code is for an agent task, from 0 to 1.
+
+    The chunk's importance scores (from its semantic class) are averaged, weighted by the
+    task's profile: debugging weighs the debugging dimension most, documenting the
+    documentation dimension, and so on. The result is scaled by the confidence of the chunk's
+    classification.
+
+    Returns:
+        The task importance, or None for chunks without a semantic classification
     """
-    for candidate in candidates:
-        # Static weights for v0.1: dense=0.65, sparse=0.35
-        candidate.score = (
-            getattr(candidate, "dense_score", candidate.score) * dense_weight
-            + getattr(candidate, "sparse_score", ZERO_POINT_ZERO) * sparse_weight
-        )
+    if (importance := chunk.importance_scores) is None:
+        return None
+    profile = agent_task.profile
+    if not (total_weight := sum(profile.values())):
+        return None
+    scores = importance.as_dict()
+    weighted = sum(scores[dimension] * weight for dimension, weight in profile.items())
+    confidence = chunk.classification_confidence
+    return weighted / total_weight * (ONE_POINT_ZERO if confidence is None else min(confidence, 1))
 
 
 def apply_semantic_weighting(
     base_score: float,
     chunk: CodeChunk | None,
-    intent_type: IntentType,
     agent_task: AgentTask,
-    boost_factor: float = 0.2,
+    boost_factor: float = DEFAULT_SEMANTIC_BOOST,
 ) -> float:
-    """Apply semantic importance weighting based on intent.
+    """Raise a score by how important the chunk's kind of code is for the agent's task.
 
     Args:
         base_score: Base relevance score
-        chunk: CodeChunk with potential semantic metadata
-        intent_type: Detected query intent
-        agent_task: Corresponding agent task
-        boost_factor: Maximum boost percentage (default: 0.2 = 20%)
+        chunk: The matched chunk, with its semantic classification if it has one
+        agent_task: The agent's task, from the query's intent
+        boost_factor: Maximum boost (default: 0.2 = 20%); 0 leaves scores alone
 
     Returns:
         Final score with semantic weighting applied
     """
-    if chunk is None:
+    if chunk is None or not boost_factor:
         return base_score
-
-    semantic_class = getattr(chunk, "semantic_class", None)
-    if not semantic_class or not hasattr(semantic_class, "importance_scores"):
+    if (importance := task_importance(chunk, agent_task)) is None:
         return base_score
-
-    importance = semantic_class.importance_scores.for_task(agent_task)
-
-    # Use appropriate importance dimension based on intent
-    if intent_type == IntentType.DEBUG:
-        semantic_boost = importance.debugging
-    elif intent_type == IntentType.IMPLEMENT:
-        semantic_boost = (importance.discovery + importance.modification) / 2
-    elif intent_type == IntentType.UNDERSTAND:
-        semantic_boost = importance.comprehension
-    else:
-        semantic_boost = importance.discovery
-
-    # Apply semantic boost (configurable % adjustment)
-    return base_score * (1 + semantic_boost * boost_factor)
+    return base_score * (1 + importance * boost_factor)
 
 
 def process_reranked_results(
     reranked_results: list[RerankingResult],
     original_candidates: list[SearchResult],
-    intent_type: IntentType,
     agent_task: AgentTask,
+    *,
+    boost_factor: float = DEFAULT_SEMANTIC_BOOST,
 ) -> list[SearchResult]:
     """Process reranked results and apply semantic weighting.
 
     Args:
         reranked_results: Results from reranking provider
         original_candidates: Original search results (for mapping back)
-        intent_type: Detected query intent
-        agent_task: Corresponding agent task
+        agent_task: The agent's task, from the query's intent
+        boost_factor: Maximum semantic boost
 
     Returns:
         List of SearchResult objects with updated scores
@@ -116,7 +125,7 @@ def process_reranked_results(
 
         # Apply semantic weighting
         final_score = apply_semantic_weighting(
-            base_score, rerank_result.chunk, intent_type, agent_task
+            base_score, rerank_result.chunk, agent_task, boost_factor
         )
 
         # Create updated SearchResult with new scores
@@ -129,14 +138,17 @@ def process_reranked_results(
 
 
 def process_unranked_results(
-    candidates: list[SearchResult], intent_type: IntentType, agent_task: AgentTask
+    candidates: list[SearchResult],
+    agent_task: AgentTask,
+    *,
+    boost_factor: float = DEFAULT_SEMANTIC_BOOST,
 ) -> list[SearchResult]:
     """Process results without reranking, applying semantic weighting to base scores.
 
     Args:
         candidates: Original search results
-        intent_type: Detected query intent
-        agent_task: Corresponding agent task
+        agent_task: The agent's task, from the query's intent
+        boost_factor: Maximum semantic boost
 
     Returns:
         List of SearchResult objects with updated relevance scores
@@ -148,7 +160,7 @@ def process_unranked_results(
 
         # Apply semantic weighting if semantic class available
         chunk_obj = candidate.content if isinstance(candidate.content, CodeChunk) else None
-        final_score = apply_semantic_weighting(base_score, chunk_obj, intent_type, agent_task)
+        final_score = apply_semantic_weighting(base_score, chunk_obj, agent_task, boost_factor)
 
         # Create updated SearchResult with relevance score
         scored_candidate = candidate.model_copy(update={"relevance_score": final_score})
@@ -162,4 +174,5 @@ __all__ = (
     "apply_semantic_weighting",
     "process_reranked_results",
     "process_unranked_results",
+    "task_importance",
 )
@@ -0,0 +1,143 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Structural answers from the project's symbol reference index.
+
+Two uses:
+- `enrich_related_symbols` fills each `find_code` match's `related_symbols` with the callers,
+  callees and implementors of the symbols it defines.
+- `find_symbol_matches` answers "who calls X", "what does X call" and "what implements Y"
+  directly, for the `find_references` tool, with no vector search involved.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import logging
+
+from typing import TYPE_CHECKING, Literal
+
+from codeweaver.core import ChunkSource, CodeChunk, DiscoveredFile, Span
+from codeweaver.semantic.symbols import SymbolRefKind
+from codeweaver.server.agent_api.search.types import CodeMatch, CodeMatchType
+
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+    from codeweaver.engine.services.symbol_index_service import SymbolIndex, SymbolOccurrence
+
+
+logger = logging.getLogger(__name__)
+
+SymbolRelation = Literal["definitions", "callers", "callees", "implementors", "importers"]
+
+# Long definitions and impl blocks are cut off; the span still points at the whole thing
+_MAX_MATCH_LINES = 40
+
+
+def enrich_related_symbols(matches: list[CodeMatch], index: SymbolIndex) -> list[CodeMatch]:
+    """Replace matches' related symbols with the callers, callees and implementors the index knows.
+
+    Matches for which the index has nothing keep their existing related symbols.
+    """
+    enriched: list[CodeMatch] = []
+    for match in matches:
+        related = index.related_symbols(match.file.path, match.span.start, match.span.end)
+        enriched.append(match.model_copy(update={"related_symbols": related}) if related else match)
+    return enriched
+
+
+def _occurrence_label(symbol: str, occurrence: SymbolOccurrence) -> tuple[str, tuple[str, ...]]:
+    """A chunk name and related symbols describing an occurrence."""
+    reference = occurrence.reference
+    match reference.kind:
+        case SymbolRefKind.CALL:
+            caller = reference.container or occurrence.path.name
+            return f"{caller} calls {reference.name}", (caller, reference.name)
+        case SymbolRefKind.IMPL:
+            implementor = reference.target or "?"
+            return f"{implementor} implements {reference.name}", (implementor, reference.name)
+        case SymbolRefKind.IMPORT:
+            return f"imports {reference.target or reference.name}", (reference.name,)
+        case _:
+            owner = f"{reference.container}::" if reference.container else ""
+            return f"defines {owner}{reference.name}", (symbol,)
+
+
+def _occurrence_to_match(
+    symbol: str, occurrence: SymbolOccurrence, project_path: Path
+) -> CodeMatch | None:
+    """Build a syntactic match pointing at a symbol occurrence."""
+    path = project_path / occurrence.path
+    if (file := DiscoveredFile.from_path(path)) is None:
+        return None
+    lines = path.read_text(encoding="utf-8", errors="ignore").splitlines()
+    reference = occurrence.reference
+    if reference.line > len(lines):
+        return None
+    end = min(reference.end_line, len(lines), reference.line + _MAX_MATCH_LINES - 1)
+    span = Span(reference.line, end, file.source_id)
+    chunk_name, related = _occurrence_label(symbol, occurrence)
+    chunk = CodeChunk.model_validate({
+        "content": "\n".join(lines[reference.line - 1 : end]),
+        "line_range": span,
+        "file_path": path,
+        "language": file.ext_category.language if file.ext_category else None,
+        "source": ChunkSource.FILE,
+        "parent_id": file.source_id,
+        "chunk_name": chunk_name,
+        "crate": file.crate,
+    })
+    return CodeMatch(
+        file=file,
+        content=chunk,
+        span=span,
+        relevance_score=1.0,
+        match_type=CodeMatchType.SYNTACTIC,
+        related_symbols=related,
+    )
+
+
+async def find_symbol_matches(
+    symbol: str,
+    relation: SymbolRelation,
+    index: SymbolIndex,
+    project_path: Path,
+    *,
+    max_results: int = 50,
+) -> list[CodeMatch]:
+    """Find a symbol's definitions, callers, callees, implementors or importers.
+
+    Args:
+        symbol: The symbol, as a bare name or a path like `Cache::get` or `cache.get`
+        relation: Which occurrences to return
+        index: The project's symbol index
+        project_path: The project root the index's paths are relative to
+        max_results: Maximum number of matches to return
+
+    Returns:
+        Syntactic matches in file and line order
+    """
+    lookup = {
+        "definitions": index.definitions,
+        "callers": index.callers,
+        "callees": index.callees,
+        "implementors": index.implementors,
+        "importers": index.importers,
+    }[relation]
+    occurrences = sorted(lookup(symbol), key=lambda o: (str(o.path), o.reference.line))
+    matches: list[CodeMatch] = []
+    for occurrence in occurrences[:max_results]:
+        try:
+            match = await asyncio.to_thread(_occurrence_to_match, symbol, occurrence, project_path)
+        except Exception as e:
+            logger.debug("Could not build a match for %s in %s: %s", symbol, occurrence.path, e)
+            continue
+        if match is not None:
+            matches.append(match)
+    return matches
+
+
+__all__ = ("SymbolRelation", "enrich_related_symbols", "find_symbol_matches")
@@ -8,15 +8,27 @@ Types and models for the find_code agent API.
 
 from __future__ import annotations
 
+from datetime import datetime
+from pathlib import Path
 from typing import TYPE_CHECKING, Annotated, Any, Literal
 
-from pydantic import ConfigDict, Field, NonNegativeFloat, NonNegativeInt, model_validator
+from pydantic import (
+    ConfigDict,
+    Field,
+    NonNegativeFloat,
+    NonNegativeInt,
+    PositiveInt,
+    field_validator,
+    model_validator,
+)
 
 from codeweaver.core import (
     BASEDMODEL_CONFIG,
     BasedModel,
     BaseEnum,
+    ChunkKind,
     CodeChunk,
+    ConfigLanguage,
     DiscoveredFile,
     LanguageName,
     SearchStrategy,
@@ -25,6 +37,7 @@ from codeweaver.core import (
     ValidationError,
 )
 from codeweaver.core.constants import ONE_POINT_ZERO, ZERO
+from codeweaver.semantic.classifications import SemanticClass
 from codeweaver.server.agent_api.search.intent import IntentType
 
 
@@ -43,6 +56,101 @@ class CodeMatchType(BaseEnum):
     FILE_PATTERN = "file_pattern"
 
 
+class SearchMode(BaseEnum):
+    """How `find_code` interprets its query."""
+
+    SEMANTIC = "semantic"
+    """Natural-language search over the embedded index (the default)."""
+    PATTERN = "pattern"
+    """An ast-grep structural pattern, like `impl $T for $U { $$$ }` or `$X.unwrap()`."""
+    KEYWORD = "keyword"
+    """A literal string."""
+    REGEX = "regex"
+    """A regular expression."""
+    PATH = "path"
+    """A glob over project-relative file paths, like `crates/*/src/**/*.rs`."""
+
+    @property
+    def match_type(self) -> CodeMatchType:
+        """The match type of results found in this mode."""
+        match self:
+            case SearchMode.PATTERN:
+                return CodeMatchType.SYNTACTIC
+            case SearchMode.KEYWORD | SearchMode.REGEX:
+                return CodeMatchType.KEYWORD
+            case SearchMode.PATH:
+                return CodeMatchType.FILE_PATTERN
+            case _:
+                return CodeMatchType.SEMANTIC
+
+    @property
+    def strategy(self) -> SearchStrategy:
+        """The search strategy reported for this mode."""
+        match self:
+            case SearchMode.PATTERN:
+                return SearchStrategy.STRUCTURAL_SEARCH
+            case SearchMode.KEYWORD | SearchMode.REGEX:
+                return SearchStrategy.TEXT_SEARCH
+            case SearchMode.PATH:
+                return SearchStrategy.FILE_DISCOVERY
+            case _:
+                return SearchStrategy.HYBRID_SEARCH
+
+
+class SearchScope(BaseEnum):
+    """Which index `find_code` searches."""
+
+    PROJECT = "project"
+    """The project's own code (the default)."""
+    DEPS = "deps"
+    """The sources of the project's third-party dependencies, indexed with `cw index --deps`."""
+
+
+class CodeKind(BaseEnum):
+    """What a chunk of code is, for `kind` filters."""
+
+    FUNCTION = "function"
+    """Function and method definitions."""
+    TYPE = "type"
+    """Class, struct, interface, trait and type alias definitions."""
+    TEST = "test"
+    """Test definitions, and anything in test files or test-only code."""
+    CONFIG = "config"
+    """Chunks of config files."""
+    DOCS = "docs"
+    """Chunks of documentation files, and structured documentation like docstrings."""
+
+    @property
+    def alias(self) -> tuple[str, ...]:
+        """Short and common names for each kind, as used in `kind:` query operators."""
+        return {
+            CodeKind.FUNCTION: ("fn", "func", "method", "def"),
+            CodeKind.TYPE: ("class", "struct", "interface", "trait", "enum"),
+            CodeKind.TEST: ("tests",),
+            CodeKind.CONFIG: ("conf", "settings"),
+            CodeKind.DOCS: ("doc", "documentation"),
+        }[self]
+
+    @property
+    def semantic_classes(self) -> tuple[SemanticClass, ...]:
+        """The semantic classes of chunks of this kind."""
+        return {
+            CodeKind.FUNCTION: (SemanticClass.DEFINITION_CALLABLE,),
+            CodeKind.TYPE: (SemanticClass.DEFINITION_TYPE,),
+            CodeKind.TEST: (SemanticClass.DEFINITION_TEST,),
+            CodeKind.CONFIG: (),
+            CodeKind.DOCS: (SemanticClass.DOCUMENTATION_STRUCTURED,),
+        }[self]
+
+    @property
+    def chunk_kinds(self) -> tuple[ChunkKind, ...]:
+        """The kinds of files whose chunks are all of this kind."""
+        return {
+            CodeKind.CONFIG: (ChunkKind.CONFIG,),
+            CodeKind.DOCS: (ChunkKind.DOCS,),
+        }.get(self, ())
+
+
 class CodeMatch(BasedModel):
     """Individual code match with context and metadata."""
 
@@ -87,10 +195,21 @@ class CodeMatch(BasedModel):
         Field(default_factory=tuple, description="""Related functions, classes, or symbols"""),
     ]
 
+    referenced_signatures: Annotated[
+        tuple[str, ...],
+        Field(
+            default_factory=tuple,
+            description="""Signature lines of types the match uses but doesn't define, as `path:line: signature`, so the match can be read without looking them up.""",
+        ),
+    ]
+
     def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
         from codeweaver.core import AnonymityConversion, FilteredKey
 
-        return {FilteredKey("related_symbols"): AnonymityConversion.COUNT}
+        return {
+            FilteredKey("related_symbols"): AnonymityConversion.COUNT,
+            FilteredKey("referenced_signatures"): AnonymityConversion.COUNT,
+        }
 
     @model_validator(mode="after")
     def validate_span(self) -> CodeMatch:
@@ -138,12 +257,155 @@ class CodeMatch(BasedModel):
         }
 
 
+class ElidedMatch(BasedModel):
+    """A match that was cut short or left out to fit the response's token budget."""
+
+    file_path: Annotated[Path, Field(description="""The match's file, relative to the project""")]
+    span: Annotated[
+        tuple[PositiveInt, PositiveInt],
+        Field(description="""1-based first and last line of the match as found"""),
+    ]
+    reason: Annotated[
+        Literal["truncated", "omitted"],
+        Field(
+            description="""`truncated` if only the match's first lines were returned, `omitted` if it was left out entirely"""
+        ),
+    ]
+    tokens: Annotated[
+        NonNegativeInt,
+        Field(description="""Tokens the full match would have needed"""),
+    ]
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        from codeweaver.core import AnonymityConversion, FilteredKey
+
+        return {FilteredKey("file_path"): AnonymityConversion.HASH}
+
+
+class SearchFilters(BasedModel):
+    """Restrictions on which chunks a search may return.
+
+    Each set field is pushed down to the vector store as a payload filter where the store can
+    check it (see `pipeline.build_query_filter`), so the store ranks only matching chunks; the
+    rest, like symbol prefixes, are checked after the search. Empty fields don't restrict
+    anything.
+    """
+
+    languages: Annotated[
+        tuple[str, ...],
+        Field(
+            description="""Only chunks in these languages, by name, e.g. `rust`. Each must be a language CodeWeaver supports semantically or as a config language."""
+        ),
+    ] = ()
+    crates: Annotated[
+        tuple[str, ...], Field(description="""Only chunks in these Cargo workspace crates""")
+    ] = ()
+    packages: Annotated[
+        tuple[str, ...],
+        Field(
+            description="""Only dependency chunks from these packages, by name, e.g. `serde`; for searches with `scope="deps"`"""
+        ),
+    ] = ()
+    paths: Annotated[
+        tuple[str, ...],
+        Field(
+            description="""Only chunks under these project-relative paths. Each is a directory or file path like `crates/auth`, or a glob like `crates/*/src/**/*.rs` where `*` also matches across directories."""
+        ),
+    ] = ()
+    exclude_paths: Annotated[
+        tuple[str, ...],
+        Field(
+            description="""Leave out chunks under these project-relative paths or matching these globs, like `**/generated/**`"""
+        ),
+    ] = ()
+    kinds: Annotated[
+        tuple[CodeKind, ...],
+        Field(
+            description="""Only chunks of any of these kinds: `function`, `type`, `test`, `config` or `docs`""",
+            examples=[kind.variable for kind in CodeKind],
+        ),
+    ] = ()
+    chunk_kinds: Annotated[
+        tuple[ChunkKind, ...], Field(description="""Only chunks of these kinds, e.g. `config`""")
+    ] = ()
+    semantic_classes: Annotated[
+        tuple[SemanticClass, ...],
+        Field(description="""Only chunks with these semantic classes"""),
+    ] = ()
+    include_tests: Annotated[
+        bool | None,
+        Field(
+            description="""Whether test code (files and test-only items) may be returned. By default, only when the intent is debugging or testing, or `kind:test` asks for it"""
+        ),
+    ] = None
+    modified_since: Annotated[
+        datetime | None, Field(description="""Only chunks from files modified at or after this""")
+    ] = None
+    symbol_prefix: Annotated[
+        str | None,
+        Field(
+            description="""Only chunks whose symbol, or its last segment, starts with this, like `parse` for `Config::parse_args`"""
+        ),
+    ] = None
+    min_file_size: Annotated[
+        NonNegativeInt | None, Field(description="""Only chunks from files of at least this many bytes""")
+    ] = None
+    max_file_size: Annotated[
+        NonNegativeInt | None, Field(description="""Only chunks from files of at most this many bytes""")
+    ] = None
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        from codeweaver.core import AnonymityConversion, FilteredKey
+
+        return {
+            FilteredKey("crates"): AnonymityConversion.COUNT,
+            FilteredKey("packages"): AnonymityConversion.COUNT,
+            FilteredKey("paths"): AnonymityConversion.COUNT,
+            FilteredKey("exclude_paths"): AnonymityConversion.COUNT,
+            FilteredKey("modified_since"): AnonymityConversion.BOOLEAN,
+            FilteredKey("symbol_prefix"): AnonymityConversion.BOOLEAN,
+        }
+
+    @field_validator("languages", mode="after")
+    @classmethod
+    def _validate_languages(cls, languages: tuple[str, ...]) -> tuple[str, ...]:
+        """Resolve language names to supported languages, as their variable names."""
+        resolved: list[str] = []
+        for name in languages:
+            for language_type in (SemanticSearchLanguage, ConfigLanguage):
+                try:
+                    resolved.append(language_type.from_string(name.strip()).variable)
+                    break
+                except ValueError:
+                    continue
+            else:
+                raise ValueError(
+                    f"{name!r} isn't a supported language; use a name like `rust` or `yaml`"
+                )
+        return tuple(dict.fromkeys(resolved))
+
+    @model_validator(mode="after")
+    def _validate_file_sizes(self) -> SearchFilters:
+        if (
+            self.min_file_size is not None
+            and self.max_file_size is not None
+            and self.min_file_size > self.max_file_size
+        ):
+            raise ValueError("min_file_size can't be larger than max_file_size")
+        return self
+
+
 class FindCodeSubmission(BasedModel):
     """Structured submission for find_code tool."""
 
     model_config = BASEDMODEL_CONFIG
 
-    query: Annotated[str, Field(description="""Your code search query in natural language.""")]
+    query: Annotated[
+        str,
+        Field(
+            description="""Your code search query in natural language. It can include filter operators like `lang:rust path:crates/auth/** kind:fn`."""
+        ),
+    ]
 
     intent: Annotated[
         IntentType | None,
@@ -151,7 +413,30 @@ class FindCodeSubmission(BasedModel):
             description="""Optional intent to guide search and ranking""",
             examples=[i.variable for i in IntentType],
         ),
-    ]
+    ] = None
+
+    filters: Annotated[
+        SearchFilters | None,
+        Field(description="""Optional filters on languages, paths, kinds, symbols and file sizes"""),
+    ] = None
+
+    def _telemetry_keys(self) -> dict[FilteredKeyT, AnonymityConversion]:
+        from codeweaver.core import AnonymityConversion, FilteredKey
+
+        return {FilteredKey("query"): AnonymityConversion.TEXT_COUNT}
+
+    def with_query_operators(self) -> FindCodeSubmission:
+        """Move the query's inline filter operators into `filters`.
+
+        Raises:
+            ValueError: If an operator is invalid; see `operators.parse_query_operators`
+        """
+        from codeweaver.server.agent_api.search.operators import parse_query_operators
+
+        query, filters = parse_query_operators(self.query, self.filters)
+        if query == self.query:
+            return self
+        return self.model_copy(update={"query": query, "filters": filters})
 
 
 class FindCodeResponseSummary(BasedModel):
@@ -219,10 +504,10 @@ class FindCodeResponseSummary(BasedModel):
     ]
 
     search_mode: Annotated[
-        Literal["hybrid", "dense_only", "sparse_only", "unknown"] | None,
+        Literal["hybrid", "dense_only", "sparse_only", "exact", "unknown"] | None,
         Field(
             default=None,
-            description="""Actual search mode used: hybrid (dense+sparse embeddings), dense_only (semantic only), sparse_only (Splade/keyword-aware), unknown (unable to determine, likely because of a critical error)""",
+            description="""Actual search mode used: hybrid (dense+sparse embeddings), dense_only (semantic only), sparse_only (Splade/keyword-aware), exact (ast-grep pattern, keyword, regex or path search with no embeddings), unknown (unable to determine, likely because of a critical error)""",
         ),
     ]
 
@@ -239,6 +524,22 @@ class FindCodeResponseSummary(BasedModel):
         ),
     ]
 
+    elided: Annotated[
+        tuple[ElidedMatch, ...],
+        Field(
+            default_factory=tuple,
+            description="""Matches that were truncated or omitted to fit the token limit, in rank order. Raise `token_limit` or narrow the query to get them.""",
+        ),
+    ]
+
+    cursor: Annotated[
+        str | None,
+        Field(
+            default=None,
+            description="""Opaque cursor for the next page of results; pass it back as `cursor` with the same query and arguments. None on the last page. It goes stale when the codebase is re-indexed.""",
+        ),
+    ]
+
     metadata: Annotated[
         dict[str, Any] | None,
         Field(
@@ -316,6 +617,12 @@ class FindCodeResponseSummary(BasedModel):
         )
         table.add_row("Execution Time", f"{self.execution_time_ms:.2f} ms")
         table.add_row("Token Count", str(self.token_count))
+        if self.elided:
+            truncated = sum(match.reason == "truncated" for match in self.elided)
+            table.add_row(
+                "Elided (token limit)",
+                f"{truncated} truncated, {len(self.elided) - truncated} omitted",
+            )
         table.add_row("Summary", self.summary)
         return table
 
@@ -327,4 +634,14 @@ if not FindCodeResponseSummary.__pydantic_complete__:
     _ = FindCodeResponseSummary.model_rebuild()
 
 
-__all__ = ("CodeMatch", "CodeMatchType", "FindCodeResponseSummary", "FindCodeSubmission")
+__all__ = (
+    "CodeKind",
+    "CodeMatch",
+    "CodeMatchType",
+    "ElidedMatch",
+    "FindCodeResponseSummary",
+    "FindCodeSubmission",
+    "SearchFilters",
+    "SearchMode",
+    "SearchScope",
+)
@@ -64,6 +64,8 @@ def _display_indexing_summary(progress_reporter: ProgressReporter, stats: Any) -
     progress_reporter.report_status(f"  Files processed: {stats.files_processed}")
     progress_reporter.report_status(f"  Chunks created: {stats.chunks_created}")
     progress_reporter.report_status(f"  Chunks indexed: {stats.chunks_indexed}")
+    if stats.chunks_reused:
+        progress_reporter.report_status(f"  Chunks reused without embedding: {stats.chunks_reused}")
     progress_reporter.report_status(f"  Processing rate: {stats.processing_rate():.2f} files/sec")
 
     # Format elapsed time in human-readable format
@@ -48,6 +48,7 @@ if TYPE_CHECKING:
         DefaultEndpointSettings,
         DefaultFastMcpHttpRunArgs,
         DefaultFastMcpServerSettings,
+        DefaultSearchSettings,
         DefaultUvicornSettings,
         DefaultUvicornSettingsForMcp,
     )
@@ -66,6 +67,8 @@ if TYPE_CHECKING:
         FastMcpHttpRunArgs,
         FastMcpServerSettingsDict,
         MCPConfigDict,
+        SearchSettings,
+        SearchSettingsDict,
         StdioCodeWeaverConfigDict,
         UvicornServerSettings,
         UvicornServerSettingsDict,
@@ -82,6 +85,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "DefaultFastMcpHttpRunArgs": (__spec__.parent, "server_defaults"),
     "DefaultFastMcpServerSettings": (__spec__.parent, "server_defaults"),
     "DefaultMiddlewareSettings": (__spec__.parent, "middleware"),
+    "DefaultSearchSettings": (__spec__.parent, "server_defaults"),
     "DefaultUvicornSettings": (__spec__.parent, "server_defaults"),
     "DefaultUvicornSettingsForMcp": (__spec__.parent, "server_defaults"),
     "EndpointSettingsDict": (__spec__.parent, "types"),
@@ -95,6 +99,8 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
     "RateLimitingMiddlewareSettings": (__spec__.parent, "middleware"),
     "ResponseCachingMiddlewareSettings": (__spec__.parent, "middleware"),
     "RetryMiddlewareSettings": (__spec__.parent, "middleware"),
+    "SearchSettings": (__spec__.parent, "types"),
+    "SearchSettingsDict": (__spec__.parent, "types"),
     "StdioCodeWeaverConfig": (__spec__.parent, "mcp"),
     "StdioCodeWeaverConfigDict": (__spec__.parent, "types"),
     "UvicornServerSettings": (__spec__.parent, "types"),
@@ -126,6 +132,7 @@ __all__ = (
     "DefaultFastMcpHttpRunArgs",
     "DefaultFastMcpServerSettings",
     "DefaultMiddlewareSettings",
+    "DefaultSearchSettings",
     "DefaultUvicornSettings",
     "DefaultUvicornSettingsForMcp",
     "EndpointSettingsDict",
@@ -142,6 +149,8 @@ __all__ = (
     "RateLimitingMiddlewareSettings",
     "ResponseCachingMiddlewareSettings",
     "RetryMiddlewareSettings",
+    "SearchSettings",
+    "SearchSettingsDict",
     "StdioCodeWeaverConfig",
     "StdioCodeWeaverConfigDict",
     "UvicornServerSettings",
@@ -79,6 +79,10 @@ class ResponseCachingMiddlewareSettings(TypedDict, total=False):
     get_prompt_settings: NotRequired[GetPromptSettings | None]
     call_tool_settings: NotRequired[CallToolSettings | None]
     max_item_size: NotRequired[int]
+    # CodeWeaver's own: how many queries' ranked `find_code` candidates to keep for paging with
+    # cursors, and for how many seconds. These aren't passed to FastMCP's middleware.
+    ranked_queries: NotRequired[PositiveInt]
+    ranked_results_ttl: NotRequired[PositiveInt]
 
 
 # ===========================
@@ -8,11 +8,21 @@ from __future__ import annotations
 
 import logging
 
-from codeweaver.core.constants import DEFAULT_MCP_PORT, LOCALHOST, MCP_ENDPOINT
+from codeweaver.core import FusionMethod
+from codeweaver.core.constants import (
+    DEFAULT_DENSE_WEIGHT,
+    DEFAULT_INTENT_DENSE_WEIGHTS,
+    DEFAULT_MCP_PORT,
+    DEFAULT_RRF_K,
+    DEFAULT_SEMANTIC_BOOST,
+    LOCALHOST,
+    MCP_ENDPOINT,
+)
 from codeweaver.server.config.types import (
     EndpointSettingsDict,
     FastMcpHttpRunArgs,
     FastMcpServerSettingsDict,
+    SearchSettingsDict,
     UvicornServerSettings,
 )
 
@@ -32,6 +42,14 @@ DefaultEndpointSettings = EndpointSettingsDict(
     enable_settings=True, enable_version=True, enable_state=True
 )
 
+DefaultSearchSettings = SearchSettingsDict(
+    semantic_boost=DEFAULT_SEMANTIC_BOOST,
+    fusion=FusionMethod.RRF,
+    rrf_k=DEFAULT_RRF_K,
+    dense_weight=DEFAULT_DENSE_WEIGHT,
+    intent_dense_weights=dict(DEFAULT_INTENT_DENSE_WEIGHTS),
+)
+
 DefaultUvicornSettings = UvicornServerSettings.codeweaver_management_defaults()
 
 DefaultUvicornSettingsForMcp = UvicornServerSettings.codeweaver_server_mcp()
@@ -51,6 +69,7 @@ __all__ = (
     "DefaultEndpointSettings",
     "DefaultFastMcpHttpRunArgs",
     "DefaultFastMcpServerSettings",
+    "DefaultSearchSettings",
     "DefaultUvicornSettings",
     "DefaultUvicornSettingsForMcp",
 )
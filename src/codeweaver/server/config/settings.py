@@ -37,12 +37,15 @@ from codeweaver.server.config.middleware import DefaultMiddlewareSettings, Middl
 from codeweaver.server.config.server_defaults import (
     DefaultEndpointSettings,
     DefaultFastMcpHttpRunArgs,
+    DefaultSearchSettings,
     DefaultUvicornSettings,
 )
 from codeweaver.server.config.types import (
     EndpointSettingsDict,
     FastMcpHttpRunArgs,
     FastMcpServerSettingsDict,
+    SearchSettings,
+    SearchSettingsDict,
     StdioCodeWeaverConfigDict,
     UvicornServerSettings,
     UvicornServerSettingsDict,
@@ -218,6 +221,13 @@ class CodeWeaverSettings(CodeWeaverEngineSettings):
             validate_default=False,
         ),
     ] = UNSET
+    search: Annotated[
+        SearchSettings | Unset,
+        Field(
+            description="""Settings for how `find_code` ranks results.""",
+            validate_default=False,
+        ),
+    ] = UNSET
     mcp_server: Annotated[
         FastMcpHttpServerSettings | Unset,
         Field(
@@ -287,6 +297,7 @@ class CodeWeaverSettings(CodeWeaverEngineSettings):
         self,
         token_limit: PositiveInt | Unset = UNSET,
         max_results: PositiveInt | Unset = UNSET,
+        search: SearchSettings | Unset = UNSET,
         mcp_server: FastMcpHttpServerSettings | Unset = UNSET,
         stdio_server: FastMcpStdioServerSettings | Unset = UNSET,
         middleware: MiddlewareOptions | Unset = UNSET,
@@ -301,6 +312,7 @@ class CodeWeaverSettings(CodeWeaverEngineSettings):
         self._set_unset_fields(
             token_limit=token_limit,
             max_results=max_results,
+            search=search,
             mcp_server=mcp_server,
             stdio_server=stdio_server,
             middleware=middleware,
@@ -320,6 +332,11 @@ class CodeWeaverSettings(CodeWeaverEngineSettings):
             if max_results is not UNSET and max_results is not None
             else DEFAULT_MAX_RESULTS
         )
+        data["search"] = (
+            search
+            if search is not UNSET and search is not None
+            else SearchSettings.model_construct(**DefaultSearchSettings)
+        )
         data["mcp_server"] = (
             mcp_server
             if mcp_server is not UNSET and mcp_server is not None
@@ -368,6 +385,7 @@ class CodeWeaverSettings(CodeWeaverEngineSettings):
         fields = (
             ("token_limit", DEFAULT_MAX_TOKENS, int),
             ("max_results", DEFAULT_MAX_RESULTS, int),
+            ("search", DefaultSearchSettings, SearchSettings),
             ("mcp_server", DefaultFastMcpServerSettings, FastMcpHttpServerSettings),
             ("stdio_server", mcp_stdio_default, FastMcpStdioServerSettings),
             ("middleware", DefaultMiddlewareSettings, MiddlewareOptions),
@@ -422,6 +440,7 @@ class CodeWeaverSettings(CodeWeaverEngineSettings):
         return {
             "token_limit": DEFAULT_MAX_TOKENS,
             "max_results": DEFAULT_MAX_RESULTS,
+            "search": DefaultSearchSettings,
             "mcp_server": FastMcpHttpServerSettings().as_settings(),
             "stdio_server": FastMcpStdioServerSettings().as_settings(),
             "middleware": DefaultMiddlewareSettings,
@@ -438,6 +457,7 @@ class CodeWeaverSettingsDict(TypedDict, total=False):
 
     token_limit: PositiveInt | None
     max_results: PositiveInt | None
+    search: SearchSettingsDict | None
     mcp_server: FastMcpServerSettingsDict | None
     stdio_server: FastMcpServerSettingsDict | None
     middleware: MiddlewareOptions | None
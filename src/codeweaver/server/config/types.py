@@ -28,7 +28,7 @@ from fastmcp.server.server import DuplicateBehavior
 from fastmcp.tools import Tool
 from mcp.server.auth.settings import AuthSettings
 from mcp.server.lowlevel.server import LifespanResultT
-from pydantic import Field, PositiveFloat, PositiveInt, SecretStr
+from pydantic import Field, NonNegativeFloat, PositiveFloat, PositiveInt, SecretStr
 from starlette.middleware import Middleware as ASGIMiddleware
 from uvicorn.config import (
     SSL_PROTOCOL_VERSION,
@@ -42,10 +42,20 @@ from codeweaver.core import (
     BASEDMODEL_CONFIG,
     AnonymityConversion,
     BasedModel,
+    FusionMethod,
+    FusionStrategy,
     LoggingConfigDict,
     Unset,
 )
-from codeweaver.core.constants import DEFAULT_MANAGEMENT_PORT, LOCALHOST, ONE_MEGABYTE
+from codeweaver.core.constants import (
+    DEFAULT_DENSE_WEIGHT,
+    DEFAULT_INTENT_DENSE_WEIGHTS,
+    DEFAULT_MANAGEMENT_PORT,
+    DEFAULT_RRF_K,
+    DEFAULT_SEMANTIC_BOOST,
+    LOCALHOST,
+    ONE_MEGABYTE,
+)
 
 
 if TYPE_CHECKING:
@@ -279,6 +289,72 @@ class UvicornServerSettings(BasedModel):
         })
 
 
+# ===========================================================================
+# *                            Search Settings
+# ===========================================================================
+
+
+class SearchSettingsDict(TypedDict, total=False):
+    """TypedDict for SearchSettings serialization."""
+
+    semantic_boost: NotRequired[NonNegativeFloat]
+    fusion: NotRequired[FusionMethod]
+    rrf_k: NotRequired[PositiveInt]
+    dense_weight: NotRequired[NonNegativeFloat]
+    intent_dense_weights: NotRequired[dict[str, NonNegativeFloat]]
+
+
+class SearchSettings(BasedModel):
+    """Settings for how `find_code` ranks what it finds."""
+
+    model_config = BASEDMODEL_CONFIG
+
+    semantic_boost: Annotated[
+        NonNegativeFloat,
+        Field(
+            le=1.0,
+            description="""How much a result's importance for the agent's task (debugging, documenting, implementing...) can raise its score. At 0.2, the most important kinds of code for the task (like function definitions when debugging) score up to 20% higher. Set to 0 to rank without regard to the task.""",
+        ),
+    ] = DEFAULT_SEMANTIC_BOOST
+    fusion: Annotated[
+        FusionMethod,
+        Field(
+            description="""How hybrid search fuses its dense (semantic) and sparse (keyword) rankings. `rrf` (reciprocal rank fusion) scores results by their rank in each; `min_max` and `z_score` scale each ranking's scores to 0-1 by their range or distribution and blend them. Qdrant runs `rrf` and unweighted `z_score` server-side; the others are fused after fetching both rankings."""
+        ),
+    ] = FusionMethod.RRF
+    rrf_k: Annotated[
+        PositiveInt,
+        Field(
+            description="""Reciprocal rank fusion's rank constant. Small values favor results at the top of either ranking; large ones (60 is common) favor results ranked well in both."""
+        ),
+    ] = DEFAULT_RRF_K
+    dense_weight: Annotated[
+        NonNegativeFloat,
+        Field(
+            le=1.0,
+            description="""Share of a hybrid result's score from the dense ranking, for query intents without an entry in `intent_dense_weights`. The sparse ranking gets the rest.""",
+        ),
+    ] = DEFAULT_DENSE_WEIGHT
+    intent_dense_weights: Annotated[
+        dict[str, Annotated[NonNegativeFloat, Field(le=1.0)]],
+        Field(
+            description="""Dense weight by query intent (understand, implement, debug, optimize, test, configure, document). By default debugging and configuration queries, which tend to quote exact names and messages, lean on sparse (keyword) matching, and conceptual ones on dense (semantic) similarity.""",
+            default_factory=lambda: dict(DEFAULT_INTENT_DENSE_WEIGHTS),
+        ),
+    ]
+
+    def fusion_strategy(self, intent: str | None = None) -> FusionStrategy:
+        """The fusion strategy for a query with the given intent."""
+        return FusionStrategy(
+            method=self.fusion,
+            dense_weight=self.intent_dense_weights.get(intent or "", self.dense_weight),
+            rrf_k=self.rrf_k,
+        )
+
+    def _telemetry_keys(self) -> None:
+        return None
+
+
 # ===========================================================================
 # *                            Mcp Configuration Typed Dicts
 # ===========================================================================
@@ -325,6 +401,8 @@ __all__ = (
     "FastMcpHttpRunArgs",
     "FastMcpServerSettingsDict",
     "MCPConfigDict",
+    "SearchSettings",
+    "SearchSettingsDict",
     "StdioCodeWeaverConfigDict",
     "UvicornServerSettings",
     "UvicornServerSettingsDict",
@@ -10,6 +10,10 @@ There are *three* symbols named "find_code" in CodeWeaver, two in this package:
 - `find_code_tool`: The actual implementation function of the tool. This version is really a wrapper around the real `find_code` function defined in `codeweaver.agent_api`. `find_code_tool` is defined here in `codeweaver.server.mcp.user_agent` because it's the part exposed as an MCP tool for user's agents to call.
 - `find_code_tool_definition`: The MCP `Tool` definition for the `find_code` tool. This is defined in `codeweaver.server.mcp.tools` as part of the `TOOL_DEFINITIONS` dictionary. This is what gets registered with the MCP server.
 - `find_code`: The actual implementation function of the `find_code` logic, defined in `codeweaver.agent_api`. This is the core logic that does the code searching. If a user uses the `search` command in CodeWeaver's CLI, this `find_code` function is what gets called under the hood.
+
+The `find_references`, `get_outline` and `read_code` tools follow the same pattern: `find_references_tool`, `get_outline_tool` and `read_code_tool` wrap `find_references`, `get_outline` and `read_code` from `codeweaver.agent_api`, and `find_references_tool_definition`, `get_outline_tool_definition` and `read_code_tool_definition` are their `Tool` definitions.
+
+CodeWeaver's own context agent (see `codeweaver.server.agent_api.search.context_agent`) searches through `CONTEXT_AGENT_TOOLKIT`, whose `search_tool` wraps `search_code_tool` from `codeweaver.server.mcp.context_agent`. Those tools aren't registered with the server.
 """
 
 from __future__ import annotations
@@ -26,6 +30,7 @@ from lateimport import create_late_getattr
 
 
 if TYPE_CHECKING:
+    from codeweaver.server.mcp.context_agent import search_code_tool
     from codeweaver.server.mcp.middleware import default_middleware_for_transport
     from codeweaver.server.mcp.middleware.fastmcp import (
         DetailedTimingMiddleware,
@@ -52,6 +57,7 @@ if TYPE_CHECKING:
     )
     from codeweaver.server.mcp.state import CwMcpHttpState, FastMCPServerSettings
     from codeweaver.server.mcp.tools import (
+        CONTEXT_AGENT_TOOLKIT,
         TOOL_DEFINITIONS,
         ContextAgentToolkit,
         ToolCollectionDict,
@@ -59,20 +65,30 @@ if TYPE_CHECKING:
         register_tool,
     )
     from codeweaver.server.mcp.types import ToolAnnotationsDict, ToolRegistrationDict
-    from codeweaver.server.mcp.user_agent import find_code_tool
+    from codeweaver.server.mcp.user_agent import (
+        find_code_tool,
+        find_references_tool,
+        get_outline_tool,
+        read_code_tool,
+    )
 
 _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
+    "CONTEXT_AGENT_TOOLKIT": (__spec__.parent, "tools"),
     "TOOL_DEFINITIONS": (__spec__.parent, "tools"),
     "TOOLS_TO_REGISTER": (__spec__.parent, "server"),
     "ContextAgentToolkit": (__spec__.parent, "tools"),
     "CwMcpHttpState": (__spec__.parent, "state"),
     "DetailedTimingMiddleware": (__spec__.parent, "middleware.fastmcp"),
     "ErrorHandlingMiddleware": (__spec__.parent, "middleware.fastmcp"),
+    "find_references_tool": (__spec__.parent, "user_agent"),
+    "get_outline_tool": (__spec__.parent, "user_agent"),
     "LoggingMiddleware": (__spec__.parent, "middleware.fastmcp"),
     "McpMiddleware": (__spec__.parent, "middleware.fastmcp"),
     "RateLimitingMiddleware": (__spec__.parent, "middleware.fastmcp"),
+    "read_code_tool": (__spec__.parent, "user_agent"),
     "ResponseCachingMiddleware": (__spec__.parent, "middleware.fastmcp"),
     "RetryMiddleware": (__spec__.parent, "middleware.fastmcp"),
+    "search_code_tool": (__spec__.parent, "context_agent"),
     "StatisticsMiddleware": (__spec__.parent, "middleware.statistics"),
     "StdioClientLifespan": (__spec__.parent, "server"),
     "StructuredLoggingMiddleware": (__spec__.parent, "middleware.fastmcp"),
@@ -97,6 +113,7 @@ _dynamic_imports: MappingProxyType[str, tuple[str, str]] = MappingProxyType({
 __getattr__ = create_late_getattr(_dynamic_imports, globals(), __name__)
 
 __all__ = (
+    "CONTEXT_AGENT_TOOLKIT",
     "TOOLS_TO_REGISTER",
     "TOOL_DEFINITIONS",
     "ContextAgentToolkit",
@@ -120,11 +137,15 @@ __all__ = (
     "create_stdio_server",
     "default_middleware_for_transport",
     "find_code_tool",
+    "find_references_tool",
     "get_bulk_tool",
+    "get_outline_tool",
     "get_statistics_middleware",
+    "read_code_tool",
     "register_middleware",
     "register_tool",
     "register_tools",
+    "search_code_tool",
     "setup_middleware",
     "setup_runargs",
 )
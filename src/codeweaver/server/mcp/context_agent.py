@@ -0,0 +1,49 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Tools for CodeWeaver's own context agent, as opposed to the user's agent: `search_code` runs the internal search the agent calls while reviewing `find_code` results."""
+
+from __future__ import annotations
+
+from codeweaver.core.constants import DEFAULT_MAX_RESULTS
+from codeweaver.server.agent_api import (
+    FindCodeResponseSummary,
+    IntentType,
+    SearchFilters,
+    SearchScope,
+    find_code,
+)
+
+
+async def search_code_tool(
+    query: str,
+    intent: IntentType | None = None,
+    *,
+    filters: SearchFilters | None = None,
+    scope: SearchScope = SearchScope.PROJECT,
+    max_results: int = DEFAULT_MAX_RESULTS,
+) -> FindCodeResponseSummary:
+    """Search the codebase for the context agent: `find_code` in semantic mode, without another agentic pass.
+
+    Args:
+        query: Natural language search query
+        intent: The intent to rank the results for
+        filters: The filters of the `find_code` call the agent is working on
+        scope: The index that call searches
+        max_results: Maximum number of matches to return
+
+    Returns:
+        FindCodeResponseSummary with ranked matches
+    """
+    return await find_code(
+        query,
+        intent=intent,
+        filters=filters,
+        scope=scope,
+        max_results=max_results,
+        agentic=False,
+    )
+
+
+__all__ = ("search_code_tool",)
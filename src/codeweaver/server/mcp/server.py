@@ -44,7 +44,7 @@ if TYPE_CHECKING:
     from codeweaver.server.mcp.state import CwMcpHttpState
 
 
-TOOLS_TO_REGISTER = ("find_code",)
+TOOLS_TO_REGISTER = ("find_code", "find_references", "get_outline", "read_code")
 
 type StdioClientLifespan = AsyncIterator[Any]
 
@@ -205,7 +205,13 @@ def setup_middleware(
                     | {"logger": logging.getLogger("codeweaver.middleware._logging")}  # ty:ignore[unsupported-operator, invalid-argument-type]
                 )
             case "ResponseCachingMiddleware":
-                instance = mw(**middleware_settings.get("caching", {}))  # ty:ignore[invalid-argument-type]
+                from codeweaver.server.agent_api.search.pagination import RANKED_RESULTS
+
+                caching = dict(middleware_settings.get("caching") or {})
+                RANKED_RESULTS.configure(
+                    caching.pop("ranked_queries", None), caching.pop("ranked_results_ttl", None)
+                )
+                instance = mw(**caching)  # ty:ignore[invalid-argument-type]
             case _:
                 if any_settings := middleware_settings.get(mw_name.lower()):
                     instance = mw(**any_settings)
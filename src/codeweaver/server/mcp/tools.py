@@ -18,14 +18,32 @@ from mcp.types import ToolAnnotations
 
 from codeweaver.core import DictView
 from codeweaver.core.constants import (
+    CONTEXT_AGENT_SEARCH_DESCRIPTION,
+    CONTEXT_AGENT_SEARCH_TITLE,
     CONTEXT_AGENT_TAGS,
     FIND_CODE_DESCRIPTION,
     FIND_CODE_TITLE,
+    FIND_REFERENCES_DESCRIPTION,
+    FIND_REFERENCES_TITLE,
+    GET_OUTLINE_DESCRIPTION,
+    GET_OUTLINE_TITLE,
+    READ_CODE_DESCRIPTION,
+    READ_CODE_TITLE,
     USER_AGENT_TAGS,
 )
-from codeweaver.server.agent_api import FindCodeResponseSummary
+from codeweaver.server.agent_api import (
+    FindCodeResponseSummary,
+    OutlineResponse,
+    ReadCodeResponse,
+)
+from codeweaver.server.mcp.context_agent import search_code_tool
 from codeweaver.server.mcp.types import ToolRegistrationDict
-from codeweaver.server.mcp.user_agent import find_code_tool
+from codeweaver.server.mcp.user_agent import (
+    find_code_tool,
+    find_references_tool,
+    get_outline_tool,
+    read_code_tool,
+)
 
 
 class ContextAgentToolkit(TypedDict):
@@ -39,6 +57,9 @@ class ToolCollectionDict(TypedDict):
     """Collection of CodeWeaver MCP tool definitions."""
 
     find_code: Tool
+    find_references: Tool
+    get_outline: Tool
+    read_code: Tool
     # Bulk tool caller is being tested and isn't used in release versions yet. We will probably change the signature for find_code to allow multiple queries at once instead.
     call_tool_bulk: Callable[[FastMCP[Any]], Tool]
 
@@ -85,11 +106,89 @@ TOOL_DEFINITIONS: DictView[ToolCollectionDict] = DictView(
                 serializer=FindCodeResponseSummary.model_dump_json,
             )
         ),
+        find_references=Tool.from_function(
+            **ToolRegistrationDict(
+                fn=find_references_tool,
+                name="find_references",
+                description=FIND_REFERENCES_DESCRIPTION,
+                tags=USER_AGENT_TAGS | {"find_references"},
+                annotations=ToolAnnotations(
+                    title=FIND_REFERENCES_TITLE,
+                    readOnlyHint=True,
+                    destructiveHint=False,
+                    idempotentHint=True,
+                    openWorldHint=False,
+                ),
+                output_schema=FindCodeResponseSummary.get_schema(),
+                serializer=FindCodeResponseSummary.model_dump_json,
+            )
+        ),
+        get_outline=Tool.from_function(
+            **ToolRegistrationDict(
+                fn=get_outline_tool,
+                name="get_outline",
+                description=GET_OUTLINE_DESCRIPTION,
+                tags=USER_AGENT_TAGS | {"get_outline"},
+                annotations=ToolAnnotations(
+                    title=GET_OUTLINE_TITLE,
+                    readOnlyHint=True,
+                    destructiveHint=False,
+                    idempotentHint=True,
+                    openWorldHint=False,
+                ),
+                output_schema=OutlineResponse.get_schema(),
+                serializer=OutlineResponse.model_dump_json,
+            )
+        ),
+        read_code=Tool.from_function(
+            **ToolRegistrationDict(
+                fn=read_code_tool,
+                name="read_code",
+                description=READ_CODE_DESCRIPTION,
+                tags=USER_AGENT_TAGS | {"read_code"},
+                annotations=ToolAnnotations(
+                    title=READ_CODE_TITLE,
+                    readOnlyHint=True,
+                    destructiveHint=False,
+                    idempotentHint=True,
+                    openWorldHint=False,
+                ),
+                output_schema=ReadCodeResponse.get_schema(),
+                serializer=ReadCodeResponse.model_dump_json,
+            )
+        ),
         call_tool_bulk=lambda server: get_bulk_tool(server),
     )
 )
 
+CONTEXT_AGENT_TOOLKIT: DictView[ContextAgentToolkit] = DictView(
+    ContextAgentToolkit(
+        search_tool=Tool.from_function(
+            **ToolRegistrationDict(
+                fn=search_code_tool,
+                name="search_code",
+                description=CONTEXT_AGENT_SEARCH_DESCRIPTION,
+                tags=CONTEXT_AGENT_TAGS | {"search"},
+                annotations=ToolAnnotations(
+                    title=CONTEXT_AGENT_SEARCH_TITLE,
+                    readOnlyHint=True,
+                    destructiveHint=False,
+                    idempotentHint=True,
+                    openWorldHint=False,
+                ),
+                output_schema=FindCodeResponseSummary.get_schema(),
+                serializer=FindCodeResponseSummary.model_dump_json,
+            )
+        ),
+        call_tool_bulk=get_bulk_tool,
+    )
+)
+"""The tools CodeWeaver's context agent searches with; they aren't registered with the server."""
+
 find_code_tool_definition: Tool = TOOL_DEFINITIONS["find_code"]
+find_references_tool_definition: Tool = TOOL_DEFINITIONS["find_references"]
+get_outline_tool_definition: Tool = TOOL_DEFINITIONS["get_outline"]
+read_code_tool_definition: Tool = TOOL_DEFINITIONS["read_code"]
 
 
 def register_tool(app: FastMCP[Any], tool: Tool) -> FastMCP[Any]:
@@ -99,6 +198,7 @@ def register_tool(app: FastMCP[Any], tool: Tool) -> FastMCP[Any]:
 
 
 __all__ = (
+    "CONTEXT_AGENT_TOOLKIT",
     "TOOL_DEFINITIONS",
     "ContextAgentToolkit",
     "ToolCollectionDict",
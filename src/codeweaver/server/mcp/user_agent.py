@@ -4,7 +4,7 @@
 # SPDX-License-Identifier: MIT OR Apache-2.0
 
 # sourcery skip: no-complex-if-expressions
-"""Tools, Resources, and Prompts exposed to users and Users' Agents. Currently there's only one: `find_code` and we don't plan to change that soon."""
+"""Tools, Resources, and Prompts exposed to users and Users' Agents: `find_code`, `find_references` for structural questions about a known symbol, `get_outline` for the shape of a file or symbol, and `read_code` for opening the code itself."""
 
 from __future__ import annotations
 
@@ -16,13 +16,28 @@ from typing import Any, cast
 from fastmcp.server.context import Context
 from mcp.server.session import ServerSession
 from mcp.shared.context import RequestContext
+from pydantic import PositiveInt
 from starlette.requests import Request
 
 from codeweaver.core import SemanticSearchLanguage, StatisticsDep
 from codeweaver.core.constants import DEFAULT_MAX_RESULTS, DEFAULT_MAX_TOKENS
 from codeweaver.core.di import get_container
 from codeweaver.core.di.dependency import INJECTED, is_depends_marker
-from codeweaver.server.agent_api import FindCodeResponseSummary, IntentType, find_code
+from codeweaver.semantic.ast_grep import Strictness
+from codeweaver.server.agent_api import (
+    FindCodeResponseSummary,
+    IntentType,
+    OutlineResponse,
+    ReadCodeResponse,
+    SearchFilters,
+    SearchMode,
+    SearchScope,
+    find_code,
+    find_references,
+    get_outline,
+    read_code,
+)
+from codeweaver.server.agent_api.search.symbol_matches import SymbolRelation
 from codeweaver.server.dependencies import CodeWeaverStateDep
 
 
@@ -76,6 +91,15 @@ async def find_code_tool(
     *,
     token_limit: int = DEFAULT_MAX_TOKENS,
     focus_languages: tuple[SemanticSearchLanguage | str, ...] | None = None,
+    crates: tuple[str, ...] | None = None,
+    filters: SearchFilters | None = None,
+    mode: SearchMode = SearchMode.SEMANTIC,
+    strictness: Strictness | None = None,
+    expand: int = 0,
+    rev: str | None = None,
+    scope: SearchScope = SearchScope.PROJECT,
+    agentic: bool = False,
+    cursor: str | None = None,
     context: Context | None = None,
 ) -> FindCodeResponseSummary:
     """CodeWeaver's `find_code` tool is an advanced code search function that leverages context and task-aware semantic search to identify and retrieve relevant code snippets from a codebase using natural language queries. `find_code` uses advanced sparse and dense embedding models, and reranking models to provide the best possible results. It is purpose-built for AI coding agents to assist with code understanding, implementation, debugging, optimization, testing, configuration, and documentation tasks.
@@ -83,10 +107,19 @@ async def find_code_tool(
     To use it, provide a natural language query describing what you are looking for. You can optionally specify an intent to help narrow down the search results. You can also set a token limit to control the size of the response, and filter results by programming language.
 
     Args:
-        query: Natural language search query
+        query: Natural language search query; may include filter operators like `lang:rust path:crates/auth/** kind:fn`
         intent: Optional search intent. One of `understand`, `implement`, `debug`, `optimize`, `test`, `configure`, `document`
         token_limit: Maximum tokens to return (default: 30000)
         focus_languages: Optional language filter
+        crates: Optional Cargo crate filter; only returns code from these workspace crates
+        filters: Optional structured filters on languages, paths, kinds, symbol prefix, file size and modification time
+        mode: How to read the query: `semantic` (default), or exactly as an ast-grep `pattern`, a `keyword`, a `regex` or a `path` glob
+        strictness: How closely `pattern` matches must match the pattern (default: `smart`)
+        expand: How many enclosing items (function, class or impl, file) to grow each match to
+        rev: Optional git commit, branch or tag to search instead of the working tree; it must have been indexed with `cw index --rev`
+        scope: `project` (default) to search the project's code, or `deps` to search the sources of its third-party dependencies, indexed with `cw index --deps`
+        agentic: Have CodeWeaver's context agent check the intent, run follow-up searches and drop matches that don't answer the query; slower, and needs a configured agent provider
+        cursor: The `cursor` of a previous response, to get the next page of its results; pass it with the same arguments
         context: MCP context for request tracking if available
 
     Returns:
@@ -117,7 +150,16 @@ async def find_code_tool(
             intent=intent,
             token_limit=token_limit,
             focus_languages=cast(tuple[str, ...], focus_langs),
+            crates=crates or None,
+            filters=filters,
             max_results=DEFAULT_MAX_RESULTS,  # Default from find_code signature
+            mode=mode,
+            strictness=strictness,
+            expand=max(expand, 0),
+            rev=rev or None,
+            scope=scope,
+            agentic=agentic,
+            cursor=cursor or None,
         )
 
         with contextlib.suppress(RuntimeError):
@@ -168,4 +210,143 @@ async def find_code_tool(
         return response
 
 
-__all__ = ("find_code_tool",)
+# -------------------------
+# * `find_references` tool definition
+#
+# * Answers "who calls X / what does X call / what implements Y" from the symbol index, without a vector search.
+# -------------------------
+async def find_references_tool(
+    symbol: str,
+    relation: SymbolRelation = "callers",
+    *,
+    token_limit: int = DEFAULT_MAX_TOKENS,
+    context: Context | None = None,
+) -> FindCodeResponseSummary:
+    """CodeWeaver's `find_references` tool finds the callers, callees, implementors, definitions or importers of a symbol, using the symbol index built while indexing the codebase.
+
+    Args:
+        symbol: The symbol name; paths like `Cache::get` or `cache.get` are accepted
+        relation: One of `callers`, `callees`, `implementors`, `definitions`, `importers`
+        token_limit: Maximum tokens to return (default: 30000)
+        context: MCP context for request tracking if available
+
+    Returns:
+        FindCodeResponseSummary with one match per occurrence
+
+    Raises:
+        QueryError: If the lookup fails unexpectedly
+    """
+    statistics = _get_statistics()
+    try:
+        response = await find_references(
+            symbol, relation, token_limit=token_limit, max_results=DEFAULT_MAX_RESULTS
+        )
+        if statistics is not None and context:
+            statistics.log_request_from_context(context, successful=True)
+    except Exception as e:
+        if context and statistics is not None:
+            statistics.log_request_from_context(context, successful=False)
+        _logger.exception("find_references failed")
+
+        from codeweaver.core import QueryError
+
+        raise QueryError(
+            f"Unexpected error in find_references: {e!s}",
+            suggestions=["Check the symbol name", "Check server logs for details"],
+        ) from e
+    else:
+        return response
+
+
+# -------------------------
+# * `get_outline` tool definition
+#
+# * Signatures and docs of a file's or symbol's items, without their bodies.
+# -------------------------
+async def get_outline_tool(target: str, *, context: Context | None = None) -> OutlineResponse:
+    """CodeWeaver's `get_outline` tool returns the item tree of a file, or of the items named like a symbol, with signatures and docs but no bodies.
+
+    Args:
+        target: A file path relative to the project root, or a symbol name like `Cache::insert`
+        context: MCP context for request tracking if available
+
+    Returns:
+        OutlineResponse with one outline per file
+
+    Raises:
+        QueryError: If outlining fails unexpectedly
+    """
+    statistics = _get_statistics()
+    try:
+        response = await get_outline(target)
+        if statistics is not None and context:
+            statistics.log_request_from_context(context, successful=True)
+    except Exception as e:
+        if context and statistics is not None:
+            statistics.log_request_from_context(context, successful=False)
+        _logger.exception("get_outline failed")
+
+        from codeweaver.core import QueryError
+
+        raise QueryError(
+            f"Unexpected error in get_outline: {e!s}",
+            suggestions=["Check the file path or symbol name", "Check server logs for details"],
+        ) from e
+    else:
+        return response
+
+
+# -------------------------
+# * `read_code` tool definition
+#
+# * Opens a file, a line range, or a symbol's definition, within the project's ignore rules.
+# -------------------------
+async def read_code_tool(
+    path: str | None = None,
+    span: tuple[PositiveInt, PositiveInt] | None = None,
+    symbol: str | None = None,
+    expand: str | None = None,
+    *,
+    context: Context | None = None,
+) -> ReadCodeResponse:
+    """CodeWeaver's `read_code` tool reads a file, a line range of it, or a symbol's definitions, within the project and its ignore rules.
+
+    Args:
+        path: A file path relative to the project root
+        span: The `(start, end)` lines to read from `path`, 1-based and inclusive
+        symbol: A symbol name like `Cache::insert` whose definitions to read
+        expand: `"item"` to widen to the enclosing item, or `"lines:N"` to add N lines each side
+        context: MCP context for request tracking if available
+
+    Returns:
+        ReadCodeResponse with one section per file or definition read
+
+    Raises:
+        QueryError: If the arguments are invalid or reading fails unexpectedly
+    """
+    statistics = _get_statistics()
+    try:
+        response = await read_code(path, span, symbol=symbol, expand=expand)
+        if statistics is not None and context:
+            statistics.log_request_from_context(context, successful=True)
+    except Exception as e:
+        if context and statistics is not None:
+            statistics.log_request_from_context(context, successful=False)
+        _logger.exception("read_code failed")
+
+        from codeweaver.core import QueryError
+
+        problem = "Invalid arguments to" if isinstance(e, ValueError) else "Unexpected error in"
+        raise QueryError(
+            f"{problem} read_code: {e!s}",
+            suggestions=[
+                "Give a path, a symbol, or both",
+                'Use expand="item" or expand="lines:N"',
+                "Check server logs for details",
+            ],
+        ) from e
+    else:
+        return response
+
+
+__all__ = ("find_code_tool", "find_references_tool", "get_outline_tool", "read_code_tool")
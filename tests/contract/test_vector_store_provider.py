@@ -5,21 +5,67 @@
 """Contract tests for VectorStoreProvider abstract interface.
 
 These tests verify that the VectorStoreProvider abstract base class defines
-all required methods and properties according to the contract specification.
+all required methods and properties according to the contract specification,
+and that the embedded providers (in-memory Qdrant and SQLite) behave the same
+way through it.
 """
 
 import inspect
 
+from pathlib import Path
 from typing import get_type_hints
+from uuid import uuid4
 
+import numpy as np
 import pytest
 
-from codeweaver.core import SearchResult
-from codeweaver.providers import VectorStoreProvider
-
+from qdrant_client import AsyncQdrantClient
+
+from codeweaver.core import (
+    CodeChunk,
+    SearchResult,
+    SemanticSearchLanguage,
+    Span,
+    uuid7,
+)
+from codeweaver.core.constants import DEFAULT_SQLITE_EXACT_SEARCH_POINTS
+from codeweaver.core.types import (
+    CodeWeaverSparseEmbedding,
+    Provider,
+    SearchStrategy,
+    StrategizedQuery,
+)
+from codeweaver.providers import (
+    ConfiguredCapability,
+    EmbeddingCapabilityGroup,
+    EmbeddingModelCapabilities,
+    MemoryVectorStoreProvider,
+    MemoryVectorStoreProviderSettings,
+    SqliteClientOptions,
+    SqliteVectorClient,
+    SqliteVectorStoreProvider,
+    SqliteVectorStoreProviderSettings,
+    VectorStoreProvider,
+)
+from codeweaver.providers.vector_stores.search import (
+    FieldCondition,
+    Filter,
+    MatchAny,
+    MatchValue,
+    Range,
+    ValuesCount,
+)
+from codeweaver.providers.vector_stores.search.filter_factory import to_sqlite_filter
+from codeweaver.providers.vector_stores.sqlite_client import SqlitePoint
+
+
+MemoryVectorStoreProvider.model_rebuild()
+SqliteVectorStoreProvider.model_rebuild()
 
 pytestmark = [pytest.mark.unit, pytest.mark.validation]
 
+DIMENSION = 768
+
 
 class TestVectorStoreProviderContract:
     """Test VectorStoreProvider abstract interface contract compliance."""
@@ -159,3 +205,447 @@ class TestVectorStoreProviderContract:
 
         # The class should have type parameters
         # Note: Detailed type parameter checking is complex, so we just verify the structure exists
+
+
+def dense_query(vector: list[float], query: str = "test search") -> StrategizedQuery:
+    return StrategizedQuery(
+        query=query, dense=vector, sparse=None, strategy=SearchStrategy.DENSE_ONLY
+    )
+
+
+def unit_vector(position: int) -> list[float]:
+    vector = [0.0] * DIMENSION
+    vector[position] = 1.0
+    return vector
+
+
+@pytest.fixture
+def database_path(tmp_path: Path) -> Path:
+    return tmp_path / "vectors" / "index.sqlite3"
+
+
+@pytest.fixture
+def sqlite_config(database_path: Path) -> SqliteVectorStoreProviderSettings:
+    """Provide test SQLite configuration."""
+    from codeweaver.providers.config import CollectionConfig
+
+    return SqliteVectorStoreProviderSettings(
+        provider=Provider.SQLITE,
+        client_options=SqliteClientOptions(path=str(database_path)),
+        collection=CollectionConfig(collection_name=f"test_sqlite_{uuid4().hex[:8]}"),
+    )
+
+
+@pytest.fixture
+def memory_config(tmp_path: Path) -> MemoryVectorStoreProviderSettings:
+    """Provide test in-memory Qdrant configuration."""
+    from codeweaver.providers.config import CollectionConfig
+
+    return MemoryVectorStoreProviderSettings(
+        provider=Provider.MEMORY,
+        collection=CollectionConfig(collection_name=f"test_memory_{uuid4().hex[:8]}"),
+        in_memory_config={
+            "persist_path": str(tmp_path / "vector_store"),
+            "auto_persist": False,
+            "persist_interval": None,
+        },
+    )
+
+
+@pytest.fixture
+def test_embedding_caps() -> EmbeddingCapabilityGroup:
+    """Provide test embedding capabilities with 768 dimensions."""
+    from codeweaver.providers.config import EmbeddingProviderSettings, FastEmbedEmbeddingConfig
+
+    dense_caps = EmbeddingModelCapabilities(
+        name="test-dense-model",
+        default_dimension=DIMENSION,
+        default_dtype="float32",
+        preferred_metrics=("cosine", "dot"),
+    )
+    embedding_config = FastEmbedEmbeddingConfig(
+        tag="fastembed",
+        provider=Provider.FASTEMBED,
+        model_name="test-dense-model",
+        embedding={"dimensions": DIMENSION},
+    )
+    settings = EmbeddingProviderSettings(
+        provider=Provider.FASTEMBED,
+        model_name="test-dense-model",
+        embedding_config=embedding_config,
+    )
+    return EmbeddingCapabilityGroup(
+        dense=ConfiguredCapability(capability=dense_caps, config=settings), sparse=None
+    )
+
+
+@pytest.fixture
+async def sqlite_provider(sqlite_config, test_embedding_caps, database_path):
+    """Create a SqliteVectorStoreProvider instance for testing."""
+    provider = SqliteVectorStoreProvider(
+        client=SqliteVectorClient(database_path), config=sqlite_config, caps=test_embedding_caps
+    )
+    await provider._initialize()
+    yield provider
+    await provider.close()
+
+
+@pytest.fixture(params=["memory", "sqlite"])
+async def provider(request, memory_config, sqlite_config, test_embedding_caps, database_path):
+    """Each embedded provider, for the behavior every provider shares."""
+    if request.param == "sqlite":
+        provider = SqliteVectorStoreProvider(
+            client=SqliteVectorClient(database_path),
+            config=sqlite_config,
+            caps=test_embedding_caps,
+        )
+    else:
+        provider = MemoryVectorStoreProvider(
+            client=AsyncQdrantClient(location=":memory:"),
+            config=memory_config,
+            caps=test_embedding_caps,
+        )
+    await provider._initialize()
+    yield provider
+    await provider.close()
+
+
+@pytest.fixture
+def make_chunk(clean_container):
+    """Build chunks with dense (and optionally sparse) embeddings registered in the DI container."""
+    from codeweaver.core import BatchKeys, ChunkKind, ExtCategory
+    from codeweaver.core.types import ChunkEmbeddings, EmbeddingBatchInfo
+    from codeweaver.providers.embedding.registry import EmbeddingRegistry
+
+    async def make(
+        name: str,
+        dense: list[float],
+        *,
+        sparse: CodeWeaverSparseEmbedding | None = None,
+        crate: str | None = None,
+    ) -> CodeChunk:
+        chunk_id = uuid7()
+        chunk = CodeChunk(
+            chunk_id=chunk_id,
+            chunk_name=f"{name}.py:func",
+            file_path=Path(f"{name}.py"),
+            language=SemanticSearchLanguage.PYTHON,
+            ext_category=ExtCategory.from_language(SemanticSearchLanguage.PYTHON, ChunkKind.CODE),
+            content=f"def {name}():\n    return True",
+            line_range=Span(start=1, end=2, source_id=chunk_id),
+            crate=crate,
+        )
+        dense_batch_id = uuid7()
+        chunk = chunk.set_batch_keys(
+            BatchKeys(id=dense_batch_id, idx=0, sparse=False), intent="primary"
+        )
+        embeddings = ChunkEmbeddings(chunk=chunk).add(
+            EmbeddingBatchInfo.create_dense(
+                batch_id=dense_batch_id,
+                batch_index=0,
+                chunk_id=chunk.chunk_id,
+                model="test-dense-model",
+                embeddings=dense,
+                dimension=DIMENSION,
+                intent="primary",
+            )
+        )
+        if sparse is not None:
+            sparse_batch_id = uuid7()
+            chunk = chunk.set_batch_keys(
+                BatchKeys(id=sparse_batch_id, idx=0, sparse=True), intent="sparse"
+            )
+            embeddings = embeddings.add(
+                EmbeddingBatchInfo.create_sparse(
+                    batch_id=sparse_batch_id,
+                    batch_index=0,
+                    chunk_id=chunk.chunk_id,
+                    model="test-sparse-model",
+                    embeddings=sparse,
+                )
+            )
+        registry = await clean_container.resolve(EmbeddingRegistry)
+        registry[chunk.chunk_id] = embeddings
+        return chunk
+
+    return make
+
+
+@pytest.mark.async_test
+class TestVectorStoreProviderBehavior:
+    """Test that each embedded provider stores, searches and deletes chunks the same way."""
+
+    async def test_list_collections(self, provider):
+        """Test that the configured collection exists after initialization."""
+        assert provider.collection_name in await provider.list_collections()
+
+    async def test_upsert_and_search(self, provider, make_chunk):
+        """Test that search ranks chunks by dense similarity."""
+        first = await make_chunk("first", unit_vector(0))
+        second = await make_chunk("second", unit_vector(1))
+        await provider.upsert([first, second])
+
+        results = await provider.search(dense_query(unit_vector(1)))
+        assert results[0].chunk.chunk_id == second.chunk_id
+        assert results[0].score == pytest.approx(1.0)
+
+    async def test_upsert_replaces(self, provider, make_chunk):
+        """Test that upserting a chunk again replaces it rather than duplicating it."""
+        chunk = await make_chunk("replaced", unit_vector(0))
+        await provider.upsert([chunk])
+        await provider.upsert([chunk])
+
+        results = await provider.search(dense_query(unit_vector(0)))
+        assert [r.chunk.chunk_id for r in results] == [chunk.chunk_id]
+
+    async def test_delete_by_file(self, provider, make_chunk):
+        """Test delete_by_file and delete_by_files remove a file's chunks."""
+        chunks = [await make_chunk(name, unit_vector(0)) for name in ("one", "two", "three")]
+        await provider.upsert(chunks)
+        await provider.delete_by_file(chunks[0].file_path)
+        await provider.delete_by_files([chunks[1].file_path])
+
+        results = await provider.search(dense_query(unit_vector(0)))
+        assert [r.chunk.chunk_id for r in results] == [chunks[2].chunk_id]
+
+    async def test_delete_by_files_keeps_a_generation(self, provider, make_chunk):
+        """Test that delete_by_files can keep the chunks of a file's new index generation."""
+
+        def in_generation(chunk: CodeChunk, generation: str) -> CodeChunk:
+            metadata = {**chunk.metadata, "generation": generation}
+            return chunk.model_copy(update={"metadata": metadata})
+
+        old = in_generation(await make_chunk("swapped", unit_vector(0)), "old")
+        new = in_generation(await make_chunk("swapped", unit_vector(1)), "new")
+        await provider.upsert([old, new])
+        await provider.delete_by_files([new.file_path], keep_generation="new")
+
+        results = await provider.search(dense_query(unit_vector(1)))
+        assert [r.chunk.chunk_id for r in results] == [new.chunk_id]
+
+    async def test_update_payloads_keeps_vectors(self, provider, make_chunk):
+        """Test that update_payloads rewrites a chunk's line range without re-embedding it."""
+        chunk = await make_chunk("moved", unit_vector(0))
+        await provider.upsert([chunk])
+        moved = chunk.model_copy(
+            update={"line_range": Span(start=10, end=11, source_id=chunk.chunk_id)}
+        )
+        await provider.update_payloads([moved])
+
+        (result,) = await provider.search(dense_query(unit_vector(0)))
+        assert result.chunk.chunk_id == chunk.chunk_id
+        assert (result.chunk.line_range.start, result.chunk.line_range.end) == (10, 11)
+        assert result.score == pytest.approx(1.0)
+
+    async def test_update_payloads_reports_missing_chunks(self, provider, make_chunk):
+        """Test that update_payloads returns the IDs it has no stored chunk for."""
+        chunk = await make_chunk("stored", unit_vector(0))
+        unstored = await make_chunk("unstored", unit_vector(1))
+        await provider.upsert([chunk])
+
+        assert await provider.update_payloads([chunk, unstored]) == [unstored.chunk_id]
+        assert await provider.update_payloads([unstored]) == [unstored.chunk_id]
+        assert [c.chunk_id for c in await provider.get_chunks([unstored.chunk_id])] == []
+
+    async def test_update_payloads_clears_fields(self, provider, make_chunk):
+        """Test that update_payloads replaces payloads, so a field the chunk lost is cleared."""
+        chunk = await make_chunk("uncrated", unit_vector(0), crate="core")
+        await provider.upsert([chunk])
+        await provider.update_payloads([chunk.model_copy(update={"crate": None})])
+
+        in_core = Filter(must=[FieldCondition(key="crate", match=MatchAny(any=["core"]))])
+        assert await provider.search(dense_query(unit_vector(0)), in_core) == []
+        (result,) = await provider.search(dense_query(unit_vector(0)))
+        assert result.chunk.chunk_id == chunk.chunk_id
+
+    async def test_get_chunks(self, provider, make_chunk):
+        """Test that get_chunks reads stored chunks back by ID, skipping unknown IDs."""
+        chunk = await make_chunk("stored", unit_vector(0))
+        await provider.upsert([chunk])
+
+        (stored,) = await provider.get_chunks([chunk.chunk_id, uuid7()])
+        assert stored.chunk_id == chunk.chunk_id
+        assert stored.content == chunk.content
+
+    async def test_delete_by_id_and_name(self, provider, make_chunk):
+        """Test delete_by_id and delete_by_name remove chunks."""
+        by_id = await make_chunk("by_id", unit_vector(0))
+        by_name = await make_chunk("by_name", unit_vector(0))
+        await provider.upsert([by_id, by_name])
+        await provider.delete_by_id([by_id.chunk_id])
+        await provider.delete_by_name([by_name.chunk_name])
+
+        assert await provider.search(dense_query(unit_vector(0))) == []
+
+    async def test_search_with_filter(self, provider, make_chunk):
+        """Test that payload filters are applied in the store."""
+        core = await make_chunk("core", unit_vector(0), crate="core")
+        cli = await make_chunk("cli", unit_vector(0), crate="cli")
+        await provider.upsert([core, cli])
+
+        results = await provider.search(
+            dense_query(unit_vector(0)),
+            query_filter=Filter(must=[FieldCondition(key="crate", match=MatchAny(any=["cli"]))]),
+        )
+        assert [r.chunk.chunk_id for r in results] == [cli.chunk_id]
+
+    async def test_file_fields_come_from_the_chunk(self, provider, make_chunk):
+        """Test that a payload's file size is the one recorded at chunking, and unset otherwise."""
+        chunked = await make_chunk("chunked", unit_vector(0))
+        chunked = chunked.model_copy(
+            update={"metadata": {**(chunked.metadata or {}), "file_size": 4096}}
+        )
+        unknown = await make_chunk("unknown", unit_vector(0))
+        await provider.upsert([chunked, unknown])
+
+        results = await provider.search(
+            dense_query(unit_vector(0)),
+            query_filter=Filter(must=[FieldCondition(key="file_size", range=Range(gte=4000))]),
+        )
+        assert [r.chunk.chunk_id for r in results] == [chunked.chunk_id]
+
+
+@pytest.mark.async_test
+class TestSqliteVectorStoreProvider:
+    """Test what's particular to the SQLite provider: its filters' SQL, its own BM25 and
+    fusion, its database file, and the IVF index that replaces its exact scan as it grows."""
+
+    async def test_update_payloads_keeps_embedding_complete(self, sqlite_provider, make_chunk):
+        """Test that replacing a payload keeps its stored `embedding_complete`."""
+        chunk = await make_chunk(
+            "complete", unit_vector(0), sparse=CodeWeaverSparseEmbedding(indices=[1], values=[1.0])
+        )
+        await sqlite_provider.upsert([chunk])
+        await sqlite_provider.update_payloads([chunk.model_copy(update={"crate": None})])
+
+        complete = Filter(
+            must=[FieldCondition(key="embedding_complete", match=MatchValue(value=True))]
+        )
+        (result,) = await sqlite_provider.search(dense_query(unit_vector(0)), complete)
+        assert result.chunk.chunk_id == chunk.chunk_id
+
+    async def test_search_with_two_sided_values_count(self, sqlite_provider, make_chunk):
+        """Test a values count filter with both bounds, which counts the array once per bound."""
+        chunks = [
+            await make_chunk(name, unit_vector(0)) for name in ("top", "src/mid", "src/a/deep")
+        ]
+        await sqlite_provider.upsert(chunks)
+
+        results = await sqlite_provider.search(
+            dense_query(unit_vector(0)),
+            query_filter=Filter(
+                must=[FieldCondition(key="path_prefixes", values_count=ValuesCount(gt=1, lte=2))]
+            ),
+        )
+        assert [r.chunk.chunk_id for r in results] == [chunks[1].chunk_id]
+
+    async def test_hybrid_search(self, sqlite_provider, make_chunk):
+        """Test that hybrid search fuses the dense and sparse rankings."""
+        dense_match = await make_chunk(
+            "dense_match",
+            unit_vector(0),
+            sparse=CodeWeaverSparseEmbedding(indices=[7], values=[1.0]),
+        )
+        both_match = await make_chunk(
+            "both_match",
+            [0.9, 0.1] + [0.0] * (DIMENSION - 2),
+            sparse=CodeWeaverSparseEmbedding(indices=[3, 7], values=[2.0, 0.5]),
+        )
+        await sqlite_provider.upsert([dense_match, both_match])
+
+        query = CodeWeaverSparseEmbedding(indices=[3], values=[1.0])
+        sparse_only = await sqlite_provider.search(
+            StrategizedQuery(
+                query="q", dense=None, sparse=query, strategy=SearchStrategy.SPARSE_ONLY
+            )
+        )
+        assert [r.chunk.chunk_id for r in sparse_only] == [both_match.chunk_id]
+
+        hybrid = await sqlite_provider.search(
+            StrategizedQuery(
+                query="q", dense=unit_vector(0), sparse=query, strategy=SearchStrategy.HYBRID_SEARCH
+            )
+        )
+        assert [r.chunk.chunk_id for r in hybrid] == [both_match.chunk_id, dense_match.chunk_id]
+
+    async def test_persists_across_reopen(
+        self, sqlite_provider, sqlite_config, test_embedding_caps, database_path, make_chunk
+    ):
+        """Test that the index survives closing and reopening the database."""
+        chunk = await make_chunk("persisted", unit_vector(0))
+        await sqlite_provider.upsert([chunk])
+        await sqlite_provider.close()
+
+        reopened = SqliteVectorStoreProvider(
+            client=SqliteVectorClient(database_path),
+            config=sqlite_config,
+            caps=test_embedding_caps,
+        )
+        await reopened._initialize()
+        results = await reopened.search(dense_query(unit_vector(0)))
+        assert [r.chunk.chunk_id for r in results] == [chunk.chunk_id]
+        await reopened.close()
+
+    async def test_large_collections_search_through_an_index(self, database_path):
+        """Test that a collection past exact_search_points gets an IVF index, files new vectors
+        in it, retrains when it doubles, and still finds each point's own vector."""
+        rng = np.random.default_rng(7)
+        vectors = rng.normal(size=(400, 32)).astype(np.float32)
+        client = SqliteVectorClient(database_path, exact_search_points=100)
+        await client.create_collection("big")
+        points = [
+            SqlitePoint(id=f"p{i}", payload={"name": f"p{i}"}, dense={"primary": vector.tolist()})
+            for i, vector in enumerate(vectors)
+        ]
+
+        await client.upsert("big", points[:100])
+        assert await client.dense_index("big", "primary") is None
+        await client.upsert("big", points[100:150])
+        index = await client.dense_index("big", "primary")
+        assert index is not None
+        assert (index.trained_points, len(index.centroids)) == (150, 24)
+        await client.upsert("big", points[150:])
+        index = await client.dense_index("big", "primary")
+        assert index is not None
+        assert index.trained_points == 400
+
+        for i in (0, 149, 399):
+            (hit,) = await client.search_dense("big", "primary", vectors[i].tolist(), limit=1)
+            assert hit.id == f"p{i}"
+        await client.close()
+
+    async def test_indexed_search_widens_for_narrow_filters(self, database_path):
+        """Test that a filter leaving the probed lists empty still finds its matches."""
+        rng = np.random.default_rng(11)
+        vectors = rng.normal(size=(300, 32)).astype(np.float32)
+        client = SqliteVectorClient(database_path, exact_search_points=100)
+        await client.create_collection("big")
+        await client.upsert(
+            "big",
+            [
+                SqlitePoint(id=f"p{i}", payload={"name": f"p{i}"}, dense={"primary": v.tolist()})
+                for i, v in enumerate(vectors)
+            ],
+        )
+        # The point least like the query, in lists far from the ones probed for it
+        far = int(np.argmin(vectors @ vectors[0]))
+        only_far = to_sqlite_filter(
+            Filter(must=[FieldCondition(key="name", match=MatchValue(value=f"p{far}"))]),
+            payload_column="p.payload",
+            id_column="p.id",
+        )
+
+        results = await client.search_dense(
+            "big", "primary", vectors[0].tolist(), query_filter=only_far
+        )
+        assert [r.id for r in results] == [f"p{far}"]
+        await client.close()
+
+    async def test_default_database_path(self):
+        """Test that settings default to a database file in the user state directory."""
+        settings = SqliteVectorStoreProviderSettings(project_name="demo")
+        assert settings.client_options is not None
+        path = Path(settings.client_options.path)
+        assert path.name == f"{settings.collection.collection_name}.sqlite3"
+        assert settings.client_options.exact_search_points == DEFAULT_SQLITE_EXACT_SEARCH_POINTS
+        assert not settings.is_cloud()
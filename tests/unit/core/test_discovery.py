@@ -14,7 +14,9 @@ from codeweaver.core.chunks import CodeChunk
 from codeweaver.core.discovery import DiscoveredFile
 from codeweaver.core.metadata import ExtCategory
 from codeweaver.core.spans import Span
+from codeweaver.core.types import MISSING
 from codeweaver.core.utils.generation import uuid7
+from codeweaver.core.workspace import find_cargo_workspace
 
 
 pytestmark = [pytest.mark.unit]
@@ -153,3 +155,33 @@ def test_from_chunk_not_a_file(temp_project: Path) -> None:
         ValueError, match=r"CodeChunk must have a valid file_path to create a DiscoveredFile\."
     ):
         DiscoveredFile.from_chunk(chunk)
+
+
+def test_package_files_have_no_git_branch(tmp_path: Path) -> None:
+    """Test that a dependency's file records its package version, and not as a git branch."""
+    path = tmp_path / "serde-1.0.203" / "src" / "lib.rs"
+    path.parent.mkdir(parents=True)
+    path.write_text("pub trait Serialize {}\n")
+
+    with patch("codeweaver.core.discovery.get_git_branch") as get_git_branch:
+        df = DiscoveredFile(path=path, project_path=tmp_path, package_version="1.0.203")
+
+        assert df.package_version == "1.0.203"
+        assert df.git_branch is MISSING
+    get_git_branch.assert_not_called()
+
+
+def test_crate_location_is_looked_up_once(tmp_path: Path) -> None:
+    """Test that a file resolves its Cargo workspace once, however often chunks ask for it."""
+    (tmp_path / "Cargo.toml").write_text('[package]\nname = "app"\nversion = "0.1.0"\n')
+    path = tmp_path / "src" / "cache.rs"
+    path.parent.mkdir()
+    path.write_text("pub struct Cache;\n")
+    df = DiscoveredFile(path=Path("src/cache.rs"), project_path=tmp_path, git_branch="main")
+
+    with patch(
+        "codeweaver.core.workspace.find_cargo_workspace", wraps=find_cargo_workspace
+    ) as lookup:
+        assert (df.crate, df.module_path) == ("app", "crate::cache")
+        assert df.crate_location() == ("app", "crate::cache")
+    lookup.assert_called_once_with(tmp_path)
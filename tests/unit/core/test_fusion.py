@@ -0,0 +1,91 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for fusing hybrid search's dense and sparse rankings."""
+
+import pytest
+
+from codeweaver.core import FusionMethod, FusionStrategy
+
+
+pytestmark = [pytest.mark.unit]
+
+# Cosine similarities and BM25-like scores: on very different scales
+DENSE = [("a", 0.82), ("b", 0.80), ("c", 0.41)]
+SPARSE = [("c", 14.0), ("d", 9.5), ("a", 1.0)]
+
+
+def keys(strategy: FusionStrategy) -> list[str]:
+    return [fused.key for fused in strategy.fuse(DENSE, SPARSE)]
+
+
+def test_rrf_scores_by_rank() -> None:
+    fused = FusionStrategy(FusionMethod.RRF, dense_weight=0.5, rrf_k=2).fuse(DENSE, SPARSE)
+
+    scores = {result.key: result.score for result in fused}
+    assert scores["a"] == pytest.approx(0.5 / 3 + 0.5 / 5)
+    assert scores["d"] == pytest.approx(0.5 / 4)
+    # Only ranks count: first and third is first and third, whatever the scores
+    assert scores["c"] == pytest.approx(scores["a"])
+
+
+@pytest.mark.parametrize("method", list(FusionMethod))
+def test_fused_results_keep_their_raw_scores(method: FusionMethod) -> None:
+    fused = {result.key: result for result in FusionStrategy(method).fuse(DENSE, SPARSE)}
+
+    assert set(fused) == {"a", "b", "c", "d"}
+    assert (fused["a"].dense_score, fused["a"].sparse_score) == (0.82, 1.0)
+    assert (fused["d"].dense_score, fused["d"].sparse_score) == (None, 9.5)
+
+
+def test_min_max_puts_both_rankings_on_one_scale() -> None:
+    fused = {
+        result.key: result.score
+        for result in FusionStrategy(FusionMethod.MIN_MAX, dense_weight=0.5).fuse(DENSE, SPARSE)
+    }
+
+    # c is last by dense score but first by sparse score; without scaling, BM25 would swamp it
+    assert fused["a"] == pytest.approx(0.5)
+    assert fused["c"] == pytest.approx(0.5)
+    assert all(0 <= score <= 1 for score in fused.values())
+
+
+def test_dense_weight_shifts_the_ranking() -> None:
+    assert keys(FusionStrategy(FusionMethod.MIN_MAX, dense_weight=0.9))[0] in {"a", "b"}
+    assert keys(FusionStrategy(FusionMethod.MIN_MAX, dense_weight=0.1))[0] == "c"
+
+
+def test_z_score_handles_rankings_without_spread() -> None:
+    fused = FusionStrategy(FusionMethod.Z_SCORE).fuse([("a", 0.5), ("b", 0.5)], [])
+
+    assert [result.score for result in fused] == pytest.approx([0.65, 0.65])
+
+
+def test_limit_keeps_the_best() -> None:
+    assert len(FusionStrategy().fuse(DENSE, SPARSE, limit=2)) == 2
+
+
+@pytest.mark.parametrize(
+    ("strategy", "server_side"),
+    [
+        (FusionStrategy(FusionMethod.RRF), True),
+        (FusionStrategy(FusionMethod.Z_SCORE, dense_weight=0.5), True),
+        (FusionStrategy(FusionMethod.Z_SCORE, dense_weight=0.7), False),
+        (FusionStrategy(FusionMethod.MIN_MAX, dense_weight=0.5), False),
+    ],
+)
+def test_server_side_fusion(strategy: FusionStrategy, *, server_side: bool) -> None:
+    assert strategy.server_side is server_side
+
+
+def test_search_settings_weight_by_intent() -> None:
+    from codeweaver.server.config.types import SearchSettings
+
+    settings = SearchSettings(
+        fusion=FusionMethod.MIN_MAX, dense_weight=0.6, intent_dense_weights={"debug": 0.3}
+    )
+
+    assert settings.fusion_strategy("debug") == FusionStrategy(FusionMethod.MIN_MAX, 0.3)
+    assert settings.fusion_strategy("understand").dense_weight == 0.6
+    assert settings.fusion_strategy(None).dense_weight == 0.6
@@ -0,0 +1,84 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for reading a project's files from a git revision."""
+
+import shutil
+import subprocess
+
+from pathlib import Path
+
+import pytest
+
+from codeweaver.core import IndexingError
+from codeweaver.core.git_tree import GitTree, revision_collection_name
+
+
+pytestmark = [
+    pytest.mark.unit,
+    pytest.mark.skipif(shutil.which("git") is None, reason="git is not installed"),
+]
+
+
+def _git(repo: Path, *args: str) -> str:
+    return subprocess.run(
+        ["git", *args], cwd=repo, check=True, capture_output=True, text=True
+    ).stdout.strip()
+
+
+@pytest.fixture
+def repo(tmp_path: Path) -> Path:
+    """A repository with a tagged first commit and a second commit on top of it."""
+    _git(tmp_path, "init", "-q")
+    _git(tmp_path, "config", "user.email", "test@example.com")
+    _git(tmp_path, "config", "user.name", "Test")
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "lib.rs").write_text("pub fn one() {}\n")
+    (tmp_path / "README.md").write_text("# Readme\n")
+    (tmp_path / "link.rs").symlink_to("src/lib.rs")
+    _git(tmp_path, "add", "-A")
+    _git(tmp_path, "commit", "-qm", "first")
+    _git(tmp_path, "tag", "v1")
+    (tmp_path / "src" / "lib.rs").write_text("pub fn two() {}\n")
+    _git(tmp_path, "commit", "-qam", "second")
+    return tmp_path
+
+
+def test_resolve_lists_files_without_symlinks(repo: Path) -> None:
+    tree = GitTree.resolve(repo, "v1")
+
+    assert tree.commit == _git(repo, "rev-parse", "v1")
+    assert {blob.path for blob in tree} == {Path("README.md"), Path("src/lib.rs")}
+
+
+def test_read_returns_the_revision_content(repo: Path) -> None:
+    old, new = GitTree.resolve(repo, "v1"), GitTree.resolve(repo, "HEAD")
+
+    assert old.read(old)[Path("src/lib.rs")] == b"pub fn one() {}\n"
+    assert new.read_text(repo / "src" / "lib.rs") == "pub fn two() {}\n"
+    # Unchanged files keep their blob
+    assert old.blobs[Path("README.md")].blob_id == new.blobs[Path("README.md")].blob_id
+    assert old.blobs[Path("src/lib.rs")].blob_id != new.blobs[Path("src/lib.rs")].blob_id
+
+
+def test_resolve_from_a_subdirectory_lists_paths_relative_to_it(repo: Path) -> None:
+    tree = GitTree.resolve(repo / "src", "v1")
+
+    assert list(tree.blobs) == [Path("lib.rs")]
+
+
+def test_unknown_revision_is_an_indexing_error(repo: Path) -> None:
+    with pytest.raises(IndexingError, match="isn't a commit, branch or tag"):
+        GitTree.resolve(repo, "no-such-branch")
+
+
+def test_missing_file_is_not_found(repo: Path) -> None:
+    with pytest.raises(FileNotFoundError):
+        GitTree.resolve(repo, "v1").read_text(Path("src/missing.rs"))
+
+
+def test_revision_collection_name_uses_the_short_commit() -> None:
+    assert revision_collection_name("project-abc", "0123456789abcdef" * 2) == (
+        "project-abc-rev-0123456789ab"
+    )
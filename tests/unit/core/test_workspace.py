@@ -0,0 +1,116 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for Cargo workspace discovery."""
+
+import os
+
+from pathlib import Path
+
+import pytest
+
+from codeweaver.core.workspace import CargoWorkspace, find_cargo_workspace, rust_module_path
+
+
+pytestmark = [pytest.mark.unit]
+
+
+def _write_crate(directory: Path, name: str) -> None:
+    (directory / "src").mkdir(parents=True)
+    (directory / "Cargo.toml").write_text(f'[package]\nname = "{name}"\nversion = "0.1.0"\n')
+
+
+@pytest.fixture
+def cargo_workspace(tmp_path: Path) -> Path:
+    """A workspace with a root package, globbed members, a nested member and an excluded crate."""
+    (tmp_path / "Cargo.toml").write_text(
+        "[package]\n"
+        'name = "app"\n'
+        'version = "0.1.0"\n\n'
+        "[workspace]\n"
+        'members = ["crates/*", "tools/gen"]\n'
+        'exclude = ["crates/scratch"]\n'
+    )
+    (tmp_path / "src").mkdir()
+    _write_crate(tmp_path / "crates" / "store", "store-core")
+    _write_crate(tmp_path / "crates" / "scratch", "scratch")
+    _write_crate(tmp_path / "tools" / "gen", "codegen")
+    return tmp_path
+
+
+@pytest.mark.parametrize(
+    ("path", "expected"),
+    [
+        ("src/lib.rs", "crate"),
+        ("src/main.rs", "crate"),
+        ("src/cache.rs", "crate::cache"),
+        ("src/cache/mod.rs", "crate::cache"),
+        ("crates/store/src/cache/lru.rs", "crate::cache::lru"),
+        ("src/bin/cli.rs", "crate"),
+        ("tests/integration.rs", "crate"),
+        ("tests/common/mod.rs", "crate::common"),
+        ("build.rs", "crate"),
+        ("src/tests/fixtures.rs", "crate::tests::fixtures"),
+        ("examples/server/main.rs", "crate"),
+        ("src/bin/tool/main.rs", "crate"),
+        ("crates/tests/src/lib.rs", "crate"),
+    ],
+)
+def test_rust_module_path(path: str, expected: str) -> None:
+    """Test crate-relative module paths derived from file locations."""
+    assert rust_module_path(Path(path)) == expected
+
+
+def test_from_root_expands_members_and_exclude(cargo_workspace: Path) -> None:
+    """Test that member globs are expanded, excluded crates dropped, and the root package kept."""
+    workspace = CargoWorkspace.from_root(cargo_workspace)
+
+    assert workspace is not None
+    assert workspace.crate_names == {"app", "store-core", "codegen"}
+
+
+def test_locate_uses_deepest_member(cargo_workspace: Path) -> None:
+    """Test that files are attributed to the innermost crate, not the root package."""
+    workspace = CargoWorkspace.from_root(cargo_workspace)
+    assert workspace is not None
+
+    assert workspace.locate(Path("crates/store/src/cache/lru.rs")) == (
+        "store-core",
+        "crate::cache::lru",
+    )
+    assert workspace.locate(cargo_workspace / "src" / "lib.rs") == ("app", "crate")
+    assert workspace.locate(Path("crates/store/Cargo.toml")) == ("store-core", None)
+    # excluded members fall back to the root package
+    assert workspace.locate(Path("crates/scratch/src/lib.rs"))[0] == "app"
+
+
+def test_locate_outside_workspace(cargo_workspace: Path, tmp_path_factory) -> None:
+    """Test that files outside the workspace root have no crate."""
+    workspace = CargoWorkspace.from_root(cargo_workspace)
+    assert workspace is not None
+
+    elsewhere = tmp_path_factory.mktemp("elsewhere") / "lib.rs"
+    assert workspace.locate(elsewhere) == (None, None)
+
+
+def test_find_cargo_workspace_without_manifest(tmp_path: Path) -> None:
+    """Test that projects without a root Cargo.toml have no workspace."""
+    assert find_cargo_workspace(tmp_path) is None
+
+
+def test_find_cargo_workspace_sees_member_changes(cargo_workspace: Path) -> None:
+    """Test that renamed and newly added members invalidate the cached workspace."""
+    workspace = find_cargo_workspace(cargo_workspace)
+    assert workspace is not None
+    assert "store-core" in workspace.crate_names
+
+    store_manifest = cargo_workspace / "crates" / "store" / "Cargo.toml"
+    store_manifest.write_text('[package]\nname = "store"\nversion = "0.1.0"\n')
+    os.utime(store_manifest, ns=(1, 1))
+    _write_crate(cargo_workspace / "crates" / "web", "web")
+
+    workspace = find_cargo_workspace(cargo_workspace)
+    assert workspace is not None
+    assert {"store", "web"} <= workspace.crate_names
+    assert "store-core" not in workspace.crate_names
@@ -0,0 +1,125 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Tests for Rust-aware grouping in SemanticChunker (attributes, doc comments, impl context,
+macros and derives) and its file skeletons."""
+
+from __future__ import annotations
+
+import pytest
+
+from codeweaver.core import ChunkSource, CodeChunk, SemanticSearchLanguage
+from codeweaver.engine import ChunkGovernor, SemanticChunker
+from codeweaver.engine.config import ChunkerSettings, PerformanceSettings
+from codeweaver.providers import EmbeddingModelCapabilities
+
+
+pytestmark = [pytest.mark.unit]
+
+
+@pytest.fixture
+def chunker_settings(chunker_settings: ChunkerSettings) -> ChunkerSettings:
+    """Turn on file skeletons, which are off by default."""
+    return chunker_settings.model_copy(update={"skeleton_chunks": True})
+
+
+@pytest.fixture
+def rust_chunks(chunk_governor: ChunkGovernor, discovered_sample_rust_file) -> list[CodeChunk]:
+    """Chunk the sample Rust fixture with clean deduplication stores."""
+    SemanticChunker.clear_deduplication_stores()
+    chunker = SemanticChunker(chunk_governor, SemanticSearchLanguage.RUST)
+    return chunker.chunk(discovered_sample_rust_file.contents, file=discovered_sample_rust_file)
+
+
+def _chunk_named(chunks: list[CodeChunk], suffix: str) -> CodeChunk:
+    return next(c for c in chunks if c.chunk_name and c.chunk_name.endswith(suffix))
+
+
+def test_attributes_and_docs_merge_into_item(rust_chunks: list[CodeChunk]) -> None:
+    """Test that `///` docs and `#[derive]` stay with the struct they decorate."""
+    chunk = _chunk_named(rust_chunks, "::DataItem")
+
+    assert chunk.content.startswith("/// Data item with identifier and value")
+    assert "#[derive(Debug, Clone)]" in chunk.content
+    assert chunk.line_start == 16  # the doc comment's line, not the struct's
+    assert not any(c.content.strip() == "#[derive(Debug, Clone)]" for c in rust_chunks)
+
+
+def test_macro_export_attribute_stays_with_macro(rust_chunks: list[CodeChunk]) -> None:
+    """Test that `#[macro_export]` is kept with its `macro_rules!` definition."""
+    chunk = _chunk_named(rust_chunks, "::data_item")
+    assert "#[macro_export]" in chunk.content
+    assert "macro_rules! data_item" in chunk.content
+
+
+def test_inherent_impl_method_records_impl_context(rust_chunks: list[CodeChunk]) -> None:
+    """Test that a method in `impl<T: Cacheable> Cache<T>` records its impl context and path."""
+    chunk = _chunk_named(rust_chunks, "Cache<T>::insert")
+    semantic_meta = chunk.metadata["semantic_meta"]
+
+    assert chunk.chunk_name.startswith("crate::")
+    assert semantic_meta.impl_type == "Cache<T>"
+    assert semantic_meta.impl_trait is None
+    assert semantic_meta.impl_generics == "<T: Cacheable>"
+
+
+def test_trait_impl_method_uses_qualified_path(rust_chunks: list[CodeChunk]) -> None:
+    """Test that trait impl methods are named `<Type as Trait>::method`."""
+    chunk = _chunk_named(rust_chunks, "DataItem as Cacheable>::cache_key")
+    semantic_meta = chunk.metadata["semantic_meta"]
+
+    assert chunk.chunk_name.startswith("<crate::")
+    assert semantic_meta.impl_type == "DataItem"
+    assert semantic_meta.impl_trait == "Cacheable"
+
+
+def test_macro_definition_records_its_arms(rust_chunks: list[CodeChunk]) -> None:
+    """Test that a `#[macro_export]` macro is named at the crate root with its matcher arms."""
+    chunk = _chunk_named(rust_chunks, "::data_item")
+
+    assert chunk.chunk_name == "crate::data_item"
+    assert chunk.metadata["semantic_meta"].macro_arms == ("($id:expr, $value:expr)",)
+
+
+def test_macro_invocations_resolve_to_local_definitions(rust_chunks: list[CodeChunk]) -> None:
+    """Test that invocations are tagged with their macro, resolved when it's defined locally."""
+    chunk = next(c for c in rust_chunks if "let item = data_item!" in c.content)
+    invocations = chunk.metadata["semantic_meta"].macro_invocations
+
+    assert invocations == {"data_item": "crate::data_item", "assert": None}
+
+
+def test_derives_are_recorded_as_implied_impls(rust_chunks: list[CodeChunk]) -> None:
+    """Test that `#[derive(Debug, Clone)]` is recorded on the struct it decorates."""
+    chunk = _chunk_named(rust_chunks, "::DataItem")
+    assert chunk.metadata["semantic_meta"].derived_traits == ("Debug", "Clone")
+
+
+def test_skeleton_elides_bodies(rust_chunks: list[CodeChunk], discovered_sample_rust_file) -> None:
+    """Test that the file's skeleton keeps signatures, fields and docs but not bodies."""
+    [skeleton] = [c for c in rust_chunks if c.source == ChunkSource.SKELETON]
+
+    assert skeleton.chunk_name == "sample.rs (skeleton)"
+    assert skeleton.line_start == 1
+    assert "pub fn insert(&mut self, item: T) -> Option<T> { … }" in skeleton.content
+    assert "/// Generic cache with type parameter\npub struct Cache<T> {" in skeleton.content
+    assert "max_size: usize," in skeleton.content
+    assert "self.storage.insert" not in skeleton.content
+    assert len(skeleton.content) < len(discovered_sample_rust_file.contents)
+
+
+def test_skeletons_off_by_default(
+    mock_embedding_capability: EmbeddingModelCapabilities,
+    performance_settings: PerformanceSettings,
+    discovered_sample_rust_file,
+) -> None:
+    """Test that no skeleton is made unless `skeleton_chunks` is turned on."""
+    settings = ChunkerSettings(semantic_importance_threshold=0.2, performance=performance_settings)
+    assert settings.skeleton_chunks is False
+    governor = ChunkGovernor(capabilities=(mock_embedding_capability,), settings=settings)
+    SemanticChunker.clear_deduplication_stores()
+    chunker = SemanticChunker(governor, SemanticSearchLanguage.RUST)
+    chunks = chunker.chunk(discovered_sample_rust_file.contents, file=discovered_sample_rust_file)
+    assert chunks
+    assert all(c.source != ChunkSource.SKELETON for c in chunks)
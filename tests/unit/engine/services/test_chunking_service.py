@@ -0,0 +1,71 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for tagging chunks with their Cargo crate."""
+
+from __future__ import annotations
+
+import os
+
+from pathlib import Path
+from unittest.mock import MagicMock, patch
+
+import pytest
+
+from codeweaver.core import ChunkSource, CodeChunk, DiscoveredFile, Span
+from codeweaver.core.workspace import find_cargo_workspace
+from codeweaver.engine.services.chunking_service import ChunkingService
+
+
+pytestmark = [pytest.mark.unit]
+
+MODULES = ("lib", "cache", "store")
+
+
+@pytest.fixture
+def project(tmp_path: Path) -> Path:
+    (tmp_path / "Cargo.toml").write_text('[package]\nname = "app"\nversion = "0.1.0"\n')
+    (tmp_path / "src").mkdir()
+    for module in MODULES:
+        (tmp_path / "src" / f"{module}.rs").write_text("pub struct Item;\n")
+    return tmp_path
+
+
+def tag(service: ChunkingService, project: Path, module: str) -> CodeChunk:
+    file = DiscoveredFile(
+        path=Path("src") / f"{module}.rs", project_path=project, git_branch="main"
+    )
+    chunk = CodeChunk.model_validate({
+        "content": "pub struct Item;",
+        "line_range": Span(1, 1, file.source_id),
+        "file_path": file.path,
+        "source": ChunkSource.FILE,
+        "parent_id": file.source_id,
+    })
+    [tagged] = service._tag_chunks(file, [chunk], "pub struct Item;\n")
+    return tagged
+
+
+def test_workspace_resolved_once_per_run(project: Path) -> None:
+    """Test that a batch of files resolves its Cargo workspace once, until it's reset."""
+    service = ChunkingService(MagicMock(), MagicMock(), MagicMock())
+    with patch(
+        "codeweaver.engine.services.chunking_service.find_cargo_workspace",
+        wraps=find_cargo_workspace,
+    ) as lookup:
+        tagged = [tag(service, project, module) for module in MODULES]
+        assert [(c.crate, c.module_path) for c in tagged] == [
+            ("app", "crate"),
+            ("app", "crate::cache"),
+            ("app", "crate::store"),
+        ]
+        lookup.assert_called_once_with(project)
+
+        manifest = project / "Cargo.toml"
+        manifest.write_text('[package]\nname = "renamed"\nversion = "0.1.0"\n')
+        os.utime(manifest, ns=(1, 1))
+        assert tag(service, project, "cache").crate == "app"
+        service.reset_cargo_workspaces()
+        assert tag(service, project, "cache").crate == "renamed"
+        assert lookup.call_count == 2
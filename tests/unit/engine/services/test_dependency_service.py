@@ -0,0 +1,172 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for dependency extraction and the dependency graph."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from textwrap import dedent
+
+import pytest
+
+from codeweaver.engine.services.dependency_service import DependencyKind, DependencyService
+
+
+pytestmark = [pytest.mark.unit]
+
+
+def _write(path: Path, text: str) -> None:
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text(dedent(text).lstrip())
+
+
+@pytest.fixture
+def cargo_project(tmp_path: Path) -> Path:
+    """A Cargo workspace with an internal path dependency, inherited and renamed dependencies."""
+    _write(
+        tmp_path / "Cargo.toml",
+        """
+        [workspace]
+        members = ["crates/*"]
+
+        [workspace.package]
+        version = "0.3.0"
+
+        [workspace.dependencies]
+        serde_json = "1.0"
+        tokio = { version = "1", features = ["rt"] }
+        """,
+    )
+    _write(
+        tmp_path / "crates" / "auth" / "Cargo.toml",
+        """
+        [package]
+        name = "auth"
+        version.workspace = true
+
+        [dependencies]
+        serde_json.workspace = true
+        http02 = { package = "http", version = "0.2" }
+
+        [target.'cfg(unix)'.dependencies]
+        libc = "0.2"
+        """,
+    )
+    _write(
+        tmp_path / "crates" / "server" / "Cargo.toml",
+        """
+        [package]
+        name = "server"
+        version = "0.1.0"
+
+        [dependencies]
+        auth = { path = "../auth" }
+        tokio = { workspace = true, features = ["macros"] }
+
+        [dev-dependencies]
+        serde_json = { workspace = true }
+
+        [build-dependencies]
+        cc = "1"
+        """,
+    )
+    _write(
+        tmp_path / "Cargo.lock",
+        """
+        version = 3
+
+        [[package]]
+        name = "auth"
+        version = "0.3.0"
+        dependencies = ["http 0.2.12", "libc", "serde_json"]
+
+        [[package]]
+        name = "http"
+        version = "0.2.12"
+        source = "registry+https://github.com/rust-lang/crates.io-index"
+
+        [[package]]
+        name = "http"
+        version = "1.1.0"
+        source = "registry+https://github.com/rust-lang/crates.io-index"
+
+        [[package]]
+        name = "libc"
+        version = "0.2.155"
+        source = "registry+https://github.com/rust-lang/crates.io-index"
+
+        [[package]]
+        name = "serde_json"
+        version = "1.0.120"
+        source = "registry+https://github.com/rust-lang/crates.io-index"
+        """,
+    )
+    return tmp_path
+
+
+def test_cargo_workspace_graph(cargo_project: Path) -> None:
+    """Test that members, dependency groups, target tables and inheritance are all read."""
+    graph = DependencyService(cargo_project).build_graph()
+
+    assert graph.internal_packages == {"auth", "server"}
+    auth = next(p for p in graph.packages if p.name == "auth")
+    assert auth.version == "0.3.0"  # `version.workspace = true`
+
+    kinds = {(e.package, e.name): e.kind for e in graph.edges}
+    assert kinds["server", "serde_json"] == DependencyKind.DEV
+    assert kinds["server", "cc"] == DependencyKind.BUILD
+    assert kinds["auth", "libc"] == DependencyKind.NORMAL
+
+    libc = next(e for e in graph.edges if e.name == "libc")
+    assert libc.target == "cfg(unix)"
+
+
+def test_workspace_inheritance_and_lock_resolution(cargo_project: Path) -> None:
+    """Test `workspace = true` requirements and Cargo.lock versions, including renames."""
+    graph = DependencyService(cargo_project).build_graph()
+    edges = {(e.package, e.name): e for e in graph.edges}
+
+    serde = edges["auth", "serde_json"]
+    assert serde.requirement == "1.0"
+    assert serde.version == "1.0.120"
+
+    http = edges["auth", "http"]
+    assert http.alias == "http02"
+    assert http.version == "0.2.12"  # disambiguated by the lockfile, not 1.1.0
+
+    assert edges["server", "tokio"].requirement == "1"
+    assert edges["server", "auth"].internal
+
+
+def test_dependents_and_declaration_lines(cargo_project: Path) -> None:
+    """Test reverse lookups and that declarations point at their manifest lines."""
+    graph = DependencyService(cargo_project).build_graph()
+
+    dependents = graph.dependents_of("serde-json")  # `-` and `_` are interchangeable
+    assert {e.package for e in dependents} == {"auth", "server"}
+    auth_decl = next(e for e in dependents if e.package == "auth")
+    assert auth_decl.manifest == Path("crates/auth/Cargo.toml")
+    assert auth_decl.line == 6
+
+    assert [e.name for e in graph.dependencies_of("server", internal_only=True)] == ["auth"]
+
+
+def test_package_json_dependencies(tmp_path: Path) -> None:
+    """Test that JSON manifests are read through their dependency key paths."""
+    _write(
+        tmp_path / "package.json",
+        """
+        {
+          "name": "web",
+          "dependencies": {"react": "^18.2.0"},
+          "devDependencies": {"vitest": "^1.0.0"}
+        }
+        """,
+    )
+    graph = DependencyService(tmp_path).build_graph()
+
+    assert graph.internal_packages == {"web"}
+    kinds = {e.name: e.kind for e in graph.edges}
+    assert kinds == {"react": DependencyKind.NORMAL, "vitest": DependencyKind.DEV}
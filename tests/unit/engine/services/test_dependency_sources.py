@@ -0,0 +1,161 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for locating dependency sources on disk."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from textwrap import dedent
+
+import pytest
+
+from codeweaver.engine.services.dependency_sources import (
+    DependencyEcosystem,
+    DependencySource,
+    DependencySourceLocator,
+)
+
+
+pytestmark = [pytest.mark.unit]
+
+
+def _write(path: Path, text: str) -> None:
+    path.parent.mkdir(parents=True, exist_ok=True)
+    path.write_text(dedent(text).lstrip())
+
+
+def _locate(project: Path, cargo_home: Path) -> dict[str, DependencySource]:
+    locator = DependencySourceLocator(
+        project, cargo_home=cargo_home, environments=[project / ".venv"]
+    )
+    return {source.key: source for source in locator.locate()}
+
+
+@pytest.fixture
+def cargo_home(tmp_path: Path) -> Path:
+    """Cargo's unpacked registry sources, with two versions of serde."""
+    registry = tmp_path / "cargo" / "registry" / "src" / "index.crates.io-6f17d22bba15001f"
+    _write(registry / "serde-1.0.203" / "src" / "lib.rs", "pub trait Serialize {}\n")
+    _write(registry / "serde-1.0.150" / "src" / "lib.rs", "pub trait Serialize {}\n")
+    _write(registry / "serde-1.0.203" / "target" / "debug" / "build.rs", "fn main() {}\n")
+    return tmp_path / "cargo"
+
+
+@pytest.fixture
+def project(tmp_path: Path) -> Path:
+    project = tmp_path / "project"
+    _write(
+        project / "Cargo.lock",
+        """
+        version = 3
+
+        [[package]]
+        name = "app"
+        version = "0.1.0"
+
+        [[package]]
+        name = "serde"
+        version = "1.0.203"
+        source = "registry+https://github.com/rust-lang/crates.io-index"
+
+        [[package]]
+        name = "vendored"
+        version = "0.2.0"
+        source = "git+https://github.com/example/vendored#abc123"
+        """,
+    )
+    return project
+
+
+def test_cargo_sources(project: Path, cargo_home: Path) -> None:
+    """Test that only locked registry crates are found, at their locked version."""
+    sources = _locate(project, cargo_home)
+
+    assert list(sources) == ["cargo/serde@1.0.203"]
+    serde = sources["cargo/serde@1.0.203"]
+    assert serde.ecosystem == DependencyEcosystem.CARGO
+    assert serde.root.name == "serde-1.0.203"
+    files = [path.relative_to(serde.root) for path in serde.iter_files(lambda _: False)]
+    assert files == [Path("src/lib.rs")]
+
+
+def test_npm_sources(tmp_path: Path) -> None:
+    """Test that direct dependencies resolve through hoisted `node_modules` directories."""
+    project = tmp_path / "project"
+    _write(project / "package.json", '{"name": "web", "dependencies": {"react": "^18.2.0"}}')
+    _write(
+        project / "packages" / "ui" / "package.json",
+        '{"name": "ui", "dependencies": {"react": "^18.2.0", "@scope/icons": "^2.0.0"}}',
+    )
+    _write(project / "node_modules" / "react" / "package.json", '{"version": "18.2.0"}')
+    _write(project / "node_modules" / "react" / "index.js", "module.exports = {};\n")
+    _write(
+        project / "node_modules" / "react" / "node_modules" / "nested" / "index.js",
+        "module.exports = {};\n",
+    )
+    _write(
+        project / "packages" / "ui" / "node_modules" / "@scope" / "icons" / "package.json",
+        '{"version": "2.1.0"}',
+    )
+
+    sources = _locate(project, tmp_path / "cargo")
+
+    assert sorted(sources) == ["npm/@scope/icons@2.1.0", "npm/react@18.2.0"]
+    react = sources["npm/react@18.2.0"]
+    files = [path.relative_to(react.root) for path in react.iter_files(lambda _: False)]
+    assert files == [Path("index.js"), Path("package.json")]
+
+
+def test_python_sources(tmp_path: Path) -> None:
+    """Test that locked packages are read from `site-packages` by their `RECORD`."""
+    project = tmp_path / "project"
+    _write(
+        project / "pyproject.toml",
+        """
+        [project]
+        name = "my-app"
+        version = "0.1.0"
+        dependencies = ["requests>=2"]
+        """,
+    )
+    _write(
+        project / "uv.lock",
+        """
+        version = 1
+
+        [[package]]
+        name = "my-app"
+        version = "0.1.0"
+
+        [[package]]
+        name = "requests"
+        version = "2.32.3"
+        """,
+    )
+    site_packages = project / ".venv" / "lib" / "python3.12" / "site-packages"
+    _write(site_packages / "requests" / "__init__.py", "")
+    _write(site_packages / "requests" / "api.py", "def get(url): ...\n")
+    _write(site_packages / "urllib3" / "__init__.py", "")
+    _write(
+        site_packages / "requests-2.32.3.dist-info" / "RECORD",
+        """
+        requests/__init__.py,sha256=abc,10
+        requests/api.py,sha256=def,20
+        requests/__pycache__/api.cpython-312.pyc,,
+        requests-2.32.3.dist-info/METADATA,sha256=ghi,30
+        ../../../bin/requests,sha256=jkl,40
+        """,
+    )
+    _write(site_packages / "urllib3-2.2.0.dist-info" / "RECORD", "urllib3/__init__.py,,\n")
+    _write(site_packages / "my_app-0.1.0.dist-info" / "RECORD", "my_app.pth,,\n")
+
+    sources = _locate(project, tmp_path / "cargo")
+
+    assert list(sources) == ["pypi/requests@2.32.3"]
+    requests = sources["pypi/requests@2.32.3"]
+    assert requests.root == site_packages.resolve()
+    assert requests.files == (Path("requests/__init__.py"), Path("requests/api.py"))
+    files = list(requests.iter_files(lambda path: path.name == "__init__.py"))
+    assert files == [site_packages.resolve() / "requests" / "api.py"]
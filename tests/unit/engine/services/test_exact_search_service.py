@@ -0,0 +1,125 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for exact pattern, text and path search."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from textwrap import dedent
+
+import pytest
+
+from codeweaver.engine.services.exact_search_service import ExactSearchService
+from codeweaver.semantic.ast_grep import Strictness
+
+
+pytestmark = [pytest.mark.unit]
+
+CORE_SOURCE = """
+pub trait Store {
+    fn get(&self, key: &str) -> Option<u32>;
+}
+
+pub struct MemoryStore;
+
+impl Store for MemoryStore {
+    fn get(&self, key: &str) -> Option<u32> {
+        key.parse().ok()
+    }
+}
+
+pub fn load(raw: &str) -> u32 {
+    raw.parse::<u32>().unwrap()
+}
+"""
+
+CLI_SOURCE = """
+fn main() {
+    let value = std::env::args().nth(1).unwrap();
+    println!("{}", value);
+}
+"""
+
+PYTHON_SOURCE = """
+def load(raw):
+    # TODO: validate
+    return int(raw)
+"""
+
+
+@pytest.fixture
+def project(tmp_path: Path) -> Path:
+    (tmp_path / "Cargo.toml").write_text('[workspace]\nmembers = ["crates/*"]\n')
+    for crate, source in (("core", CORE_SOURCE), ("cli", CLI_SOURCE)):
+        crate_dir = tmp_path / "crates" / crate
+        (crate_dir / "src").mkdir(parents=True)
+        (crate_dir / "Cargo.toml").write_text(f'[package]\nname = "demo-{crate}"\n')
+        (crate_dir / "src" / "lib.rs").write_text(dedent(source).lstrip())
+    (tmp_path / "scripts").mkdir()
+    (tmp_path / "scripts" / "load.py").write_text(dedent(PYTHON_SOURCE).lstrip())
+    return tmp_path
+
+
+@pytest.fixture
+def service(project: Path) -> ExactSearchService:
+    return ExactSearchService(project)
+
+
+def test_pattern_matches_impl_blocks(service: ExactSearchService) -> None:
+    """Test that a structural pattern matches whole `impl Trait for Type` blocks."""
+    [hit] = service.find_pattern("impl $T for $U { $$$ }")
+    assert hit.path == Path("crates/core/src/lib.rs")
+    assert hit.content.startswith("impl Store for MemoryStore")
+    assert (hit.start_line, hit.end_line) == (7, 11)
+
+
+def test_pattern_respects_crates(service: ExactSearchService) -> None:
+    """Test that pattern search only covers the requested crates."""
+    everywhere = {hit.path for hit in service.find_pattern("$X.unwrap()")}
+    assert everywhere == {Path("crates/core/src/lib.rs"), Path("crates/cli/src/lib.rs")}
+    [hit] = service.find_pattern("$X.unwrap()", crates=["demo-cli"])
+    assert hit.path == Path("crates/cli/src/lib.rs")
+
+
+def test_pattern_strictness(service: ExactSearchService) -> None:
+    """Test that `relaxed` strictness matches through comments that `smart` doesn't skip."""
+    pattern = "def load(raw):\n    return int(raw)"
+    assert service.find_pattern(pattern, languages=["python"]) == []
+    [hit] = service.find_pattern(pattern, strictness=Strictness.RELAXED, languages=["python"])
+    assert hit.path == Path("scripts/load.py")
+
+
+def test_keyword_and_regex(service: ExactSearchService) -> None:
+    """Test literal and regex text search, with merged context and language filters."""
+    [todo] = service.find_text("TODO", context_lines=1)
+    assert todo.path == Path("scripts/load.py")
+    assert (todo.start_line, todo.end_line) == (1, 3)
+
+    parses = service.find_text(r"\.parse(::<\w+>)?\(\)", regex=True, context_lines=0)
+    assert [(hit.start_line, hit.end_line) for hit in parses] == [(9, 9), (14, 14)]
+
+    assert service.find_text("load", languages=["python"], context_lines=0)[0].path == Path(
+        "scripts/load.py"
+    )
+    with pytest.raises(ValueError, match="Invalid regular expression"):
+        service.find_text("(unclosed", regex=True)
+
+
+def test_path_glob(service: ExactSearchService) -> None:
+    """Test that path globs match project-relative paths and preview the file."""
+    hits = service.find_paths("crates/*/src/*.rs", preview_lines=2)
+    assert {hit.path for hit in hits} == {
+        Path("crates/core/src/lib.rs"),
+        Path("crates/cli/src/lib.rs"),
+    }
+    assert all(hit.content.count("\n") == 1 for hit in hits)
+
+
+def test_includes_respects_ignore_files(project: Path, service: ExactSearchService) -> None:
+    """Test that files left out of the walk by ignore files aren't included."""
+    (project / ".ignore").write_text("scripts/\n")
+    assert service.includes(Path("crates/core/src/lib.rs"))
+    assert not service.includes(Path("scripts/load.py"))
+    assert not service.includes(Path("crates/core/src/missing.rs"))
@@ -0,0 +1,196 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for symbol reference extraction and the symbol index."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from textwrap import dedent
+
+import pytest
+
+from codeweaver.engine.services.symbol_index_service import SymbolIndex, SymbolIndexService
+from codeweaver.semantic.symbols import SymbolRefKind, symbol_name
+
+
+pytestmark = [pytest.mark.unit]
+
+RUST_SOURCE = """
+use std::collections::{HashMap, hash_map::Entry};
+
+pub trait Store {
+    fn insert(&mut self, key: String);
+}
+
+pub struct MemoryStore {
+    items: HashMap<String, u32>,
+}
+
+impl Store for MemoryStore {
+    fn insert(&mut self, key: String) {
+        self.items.insert(key, 0);
+    }
+}
+
+fn handle(store: &mut impl Store) {
+    let key = make_key();
+    store.insert(key);
+}
+"""
+
+PYTHON_SOURCE = """
+from app.base import Handler
+import json
+
+
+class JsonHandler(Handler):
+    def handle(self, raw):
+        return self.render(json.loads(raw))
+
+
+def main():
+    JsonHandler().handle("{}")
+"""
+
+TYPESCRIPT_SOURCE = """
+import { Repository } from "./repository";
+
+export class UserRepository extends BaseRepository implements Repository<User> {
+  find(id: string): User {
+    return this.load(id);
+  }
+}
+"""
+
+
+@pytest.fixture
+def project(tmp_path: Path) -> Path:
+    for name, source in (
+        ("store.rs", RUST_SOURCE),
+        ("handler.py", PYTHON_SOURCE),
+        ("users.ts", TYPESCRIPT_SOURCE),
+    ):
+        (tmp_path / name).write_text(dedent(source).lstrip())
+    return tmp_path
+
+
+@pytest.fixture
+def service(project: Path) -> SymbolIndexService:
+    return SymbolIndexService(project, "demo", project / ".cache")
+
+
+@pytest.fixture
+async def index(project: Path, service: SymbolIndexService) -> SymbolIndex:
+    index = await service.get_index()
+    for name in ("store.rs", "handler.py", "users.ts"):
+        references = service.extract(project / name)
+        assert references is not None
+        index.set_file(Path(name), references)
+    return index
+
+
+def test_symbol_name_normalization() -> None:
+    """Test that paths, method calls and generics reduce to the symbol's name."""
+    assert symbol_name("Store::insert") == "insert"
+    assert symbol_name("self.render()") == "render"
+    assert symbol_name("Repository<User>") == "Repository"
+
+
+def test_unsupported_language_is_skipped(project: Path, service: SymbolIndexService) -> None:
+    """Test that files in languages without symbol rules produce no references."""
+    (project / "notes.md").write_text("# notes\n")
+    assert service.extract(project / "notes.md") is None
+
+
+async def test_rust_references(index: SymbolIndex) -> None:
+    """Test definitions, calls with their callers, trait impls and `use` imports in Rust."""
+    definitions = {(o.reference.name, o.reference.container) for o in index.definitions("insert")}
+    assert definitions == {("insert", "Store"), ("insert", "MemoryStore")}
+
+    callers = {o.reference.container for o in index.callers("Store::insert")}
+    assert callers == {"insert", "handle"}  # `self.items.insert` and `store.insert`
+
+    [implementation] = index.implementors("Store")
+    assert implementation.reference.target == "MemoryStore"
+    assert implementation.path == Path("store.rs")
+
+    assert {o.reference.name for o in index.callees("handle")} == {"make_key", "insert"}
+    assert {o.reference.target for o in index.importers("Entry")} == {
+        "std::collections::{HashMap, hash_map::Entry}"
+    }
+
+
+async def test_rust_macros_and_derives(project: Path, service: SymbolIndexService) -> None:
+    """Test that macro invocations link to their definitions and derives count as impls."""
+    (project / "entry.rs").write_text(
+        dedent("""
+            macro_rules! make_key {
+                () => { String::new() };
+            }
+
+            #[derive(Debug, serde::Serialize)]
+            pub struct Entry {
+                key: String,
+            }
+
+            fn build() -> Entry {
+                tokio::select! {}
+                Entry { key: make_key!() }
+            }
+        """).lstrip()
+    )
+    index = await service.get_index()
+    index.set_file(Path("entry.rs"), service.extract(project / "entry.rs") or ())
+
+    assert [o.reference.line for o in index.definitions("make_key")] == [1]
+    [call] = index.callers("make_key")
+    assert (call.reference.container, call.reference.target) == ("build", "make_key")
+    assert [o.reference.target for o in index.callers("select")] == ["tokio::select"]
+    assert [o.reference.target for o in index.implementors("Serialize")] == ["Entry"]
+    assert [o.reference.target for o in index.implementors("Debug")] == ["Entry"]
+
+
+async def test_python_references(index: SymbolIndex) -> None:
+    """Test base classes as implementations, method calls and `from` imports in Python."""
+    assert [o.reference.target for o in index.implementors("Handler")] == ["JsonHandler"]
+    assert {o.reference.container for o in index.callers("handle")} == {"main"}
+    assert {o.reference.name for o in index.callees("handle")} == {"render", "loads"}
+    [handler_import] = index.importers("Handler")
+    assert handler_import.reference.target == "app.base.Handler"
+
+
+async def test_typescript_heritage(index: SymbolIndex) -> None:
+    """Test `extends` and `implements` clauses, ignoring type arguments."""
+    assert [o.reference.target for o in index.implementors("BaseRepository")] == ["UserRepository"]
+    assert [o.reference.target for o in index.implementors("Repository")] == ["UserRepository"]
+    assert index.implementors("User") == ()
+    assert {o.reference.container for o in index.callers("load")} == {"find"}
+
+
+async def test_related_symbols_for_a_span(index: SymbolIndex) -> None:
+    """Test that a chunk's related symbols list its callers, callees and implementors."""
+    [trait] = [o.reference for o in index.definitions("Store")]
+    related = index.related_symbols(Path("store.rs"), trait.line, trait.end_line)
+    assert "MemoryStore" in related  # implementor of the trait
+    assert "handle" in related  # caller of the trait's `insert`
+
+    [handle] = [o.reference for o in index.definitions("handle")]
+    assert set(index.related_symbols(Path("store.rs"), handle.line, handle.end_line)) >= {
+        "make_key",
+        "insert",
+    }
+
+
+async def test_remove_file_and_persistence(
+    project: Path, service: SymbolIndexService, index: SymbolIndex
+) -> None:
+    """Test that removals update lookups and the index survives a save/load round trip."""
+    assert index.remove_file(Path("handler.py"))
+    assert index.implementors("Handler") == ()
+    assert await service.save()
+
+    reloaded = await SymbolIndexService(project, "demo", project / ".cache").get_index()
+    assert set(reloaded.files) == {"store.rs", "users.ts"}
+    assert [o.reference.kind for o in reloaded.implementors("Store")] == [SymbolRefKind.IMPL]
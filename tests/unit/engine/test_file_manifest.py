@@ -258,6 +258,22 @@ class TestFileManifestManager:
         assert loaded.total_chunks == sample_manifest.total_chunks
         assert len(loaded.files) == len(sample_manifest.files)
 
+    async def test_index_generation(self, manifest_manager, sample_manifest):
+        """Test that the index generation survives saving and changes with a file's chunks."""
+        generation = sample_manifest.index_generation()
+        await manifest_manager.save(sample_manifest)
+        assert (await manifest_manager.load()).index_generation() == generation
+
+        sample_manifest.add_files_batch([
+            {
+                "path": Path("src/main.py"),
+                "content_hash": get_blake_hash(b"def main(): pass"),
+                "chunk_ids": ["chunk4"],
+                "generation": "next",
+            }
+        ])
+        assert sample_manifest.index_generation() != generation
+
     async def test_load_nonexistent(self, manifest_manager):
         """Test loading when no manifest file exists."""
         loaded = await manifest_manager.load()
@@ -282,6 +298,31 @@ class TestFileManifestManager:
         assert manifest.total_chunks == 0
         assert len(manifest.files) == 0
 
+    async def test_revision_manifests_are_separate(self, manifest_manager, sample_manifest):
+        """Revision manifests live next to the working tree's and are loaded together."""
+        commit = "0123456789abcdef0123456789abcdef01234567"
+        revision_manager = manifest_manager.for_revision(commit, "v1.0.0")
+        assert revision_manager.manifest_file != manifest_manager.manifest_file
+        assert revision_manager.manifest_file.name.endswith("_rev_0123456789ab.json")
+
+        revision = revision_manager.create_new()
+        assert (revision.commit, revision.rev) == (commit, "v1.0.0")
+        revision.add_files_batch([
+            {
+                "path": Path("src/main.py"),
+                "content_hash": get_blake_hash(b"def main(): pass"),
+                "chunk_ids": ["chunk1"],
+                "blob_id": "b" * 40,
+            }
+        ])
+        await revision_manager.save(revision)
+        await manifest_manager.save(sample_manifest)
+
+        assert (await manifest_manager.load()).commit is None
+        revisions = await manifest_manager.load_revisions()
+        assert [r.commit for r in revisions] == [commit]
+        assert revisions[0].get_file(Path("src/main.py"))["blob_id"] == "b" * 40
+
 
 @pytest.mark.async_test
 @pytest.mark.unit
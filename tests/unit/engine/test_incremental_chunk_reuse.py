@@ -0,0 +1,536 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Tests for re-indexing changed files: reusing unchanged chunks' vectors, swapping in new
+chunks only once they're stored, carrying moved files' chunks to their new paths, and keeping
+reformatted files' embeddings."""
+
+from __future__ import annotations
+
+from asyncio import Lock, Semaphore
+from pathlib import Path
+from types import SimpleNamespace
+from typing import TYPE_CHECKING
+from unittest.mock import ANY, AsyncMock, MagicMock
+
+import pytest
+
+from codeweaver.engine import IndexFileManifest, IndexingService
+from codeweaver.engine.managers.progress_tracker import IndexingStats
+
+
+if TYPE_CHECKING:
+    from uuid import UUID
+
+    from codeweaver.core import CodeChunk
+
+
+pytestmark = [pytest.mark.unit]
+
+
+def make_chunk(
+    content: str, lines: tuple[int, int], file_path: Path = Path("module.py")
+) -> CodeChunk:
+    from codeweaver.core import ChunkKind, CodeChunk, ExtCategory, Span, uuid7
+
+    chunk_id = uuid7()
+    return CodeChunk(
+        chunk_id=chunk_id,
+        ext_category=ExtCategory.from_language("python", ChunkKind.CODE),
+        file_path=file_path,
+        language="python",
+        content=content,
+        line_range=Span(start=lines[0], end=lines[1], source_id=chunk_id),
+    )
+
+
+@pytest.fixture
+def indexer(tmp_path: Path, mock_vector_store, monkeypatch: pytest.MonkeyPatch) -> IndexingService:
+    """An indexer without embedding providers, whose chunker returns `indexer.next_chunks`.
+
+    Files the next chunks aren't from get copies of them.
+    """
+    monkeypatch.setenv("CODEWEAVER_PROJECT_PATH", str(tmp_path))
+    monkeypatch.chdir(tmp_path)
+    (tmp_path / "module.py").write_text("def first(): ...\n")
+
+    indexer = IndexingService.__new__(IndexingService)
+    indexer._project_path = tmp_path
+    indexer._file_manifest = IndexFileManifest(project_path=tmp_path)
+    indexer._vector_store = mock_vector_store
+    mock_vector_store.delete_by_files = AsyncMock()
+    mock_vector_store.delete_by_id = AsyncMock()
+    mock_vector_store.update_payloads = AsyncMock(return_value=[])
+    mock_vector_store.get_chunks = AsyncMock(return_value=[])
+    indexer._manifest_manager = MagicMock()
+    indexer._embedding_provider = None
+    indexer._sparse_provider = None
+    indexer._symbol_index = None
+    indexer._manifest_lock = Lock()
+    indexer._indexing_semaphore = Semaphore(1)
+    indexer._moved_files = {}
+    indexer._settings = MagicMock(skip_formatting_changes=False)
+    indexer._deleted_files = []
+    stats = IndexingStats()
+    indexer._progress_tracker = MagicMock()
+    indexer._progress_tracker.get_stats = MagicMock(return_value=stats)
+    indexer.next_chunks = []
+
+    async def chunk_files(files, contents=None):
+        for file in files:
+            yield file.path, [
+                chunk
+                if chunk.file_path == file.path
+                else make_chunk(
+                    chunk.content, (chunk.line_range.start, chunk.line_range.end), file.path
+                )
+                for chunk in indexer.next_chunks
+            ]
+
+    indexer._chunking_service = MagicMock()
+    indexer._chunking_service.chunk_files = chunk_files
+    return indexer
+
+
+async def index(indexer: IndexingService, *chunks: CodeChunk) -> None:
+    indexer.next_chunks = list(chunks)
+    indexer._vector_store.reset_mock()
+    await indexer._index_files_batch([(indexer._project_path / "module.py", None)], None)
+
+
+async def test_only_changed_chunks_are_embedded(indexer: IndexingService) -> None:
+    first = make_chunk("def first(): ...", (1, 1))
+    second = make_chunk("def second(): ...", (3, 3))
+    await index(indexer, first, second)
+
+    # Edit `first` and push `second` down two lines
+    edited = make_chunk("def first():\n    return 1", (1, 2))
+    moved = make_chunk("def second(): ...", (5, 5))
+    await index(indexer, edited, moved)
+
+    store = indexer._vector_store
+    store.delete_by_files.assert_not_called()
+    store.delete_by_id.assert_awaited_once_with([first.chunk_id])
+    assert [chunk.chunk_id for chunk in store.upsert.await_args.args[0]] == [edited.chunk_id]
+    (updated,) = store.update_payloads.await_args.args[0]
+    assert updated.chunk_id == second.chunk_id
+    assert updated.metadata["chunk_id"] == second.chunk_id
+    assert (updated.line_range.start, updated.line_range.end) == (5, 5)
+    assert set(indexer._file_manifest.get_chunk_ids_for_file(Path("module.py"))) == {
+        str(edited.chunk_id),
+        str(second.chunk_id),
+    }
+    assert indexer.stats.chunks_reused == 1
+
+
+async def test_identical_chunks_are_matched_once_each(indexer: IndexingService) -> None:
+    await index(indexer, make_chunk("pass", (1, 1)), make_chunk("pass", (2, 2)))
+    ids = set(indexer._file_manifest.get_chunk_ids_for_file(Path("module.py")))
+
+    await index(indexer, make_chunk("pass", (1, 1)))
+
+    (vanished,) = indexer._vector_store.delete_by_id.await_args.args[0]
+    (kept,) = indexer._vector_store.update_payloads.await_args.args[0]
+    assert {str(vanished), str(kept.chunk_id)} == ids
+
+
+async def test_manifest_records_models_and_project_relative_chunks(
+    indexer: IndexingService,
+) -> None:
+    """Test that entries record the models under the keys the manifest reads back.
+
+    Entries once recorded them under other keys, and matched chunks to files by their absolute
+    paths, so every file looked embedded with another model and had no chunks.
+    """
+    models = {
+        "dense_provider": "voyage",
+        "dense_model": "voyage-code-3",
+        "sparse_provider": "fastembed",
+        "sparse_model": "splade",
+    }
+    indexer._get_current_embedding_models = MagicMock(return_value=models)
+    chunk = make_chunk("def first(): ...", (1, 1))
+    await index(indexer, chunk)
+
+    entry = indexer._file_manifest.get_file(Path("module.py"))
+    assert entry["chunk_ids"] == [str(chunk.chunk_id)]
+    assert indexer._file_manifest.get_embedding_model_info(Path("module.py"))["dense_model"] == (
+        "voyage-code-3"
+    )
+    assert indexer._file_manifest.file_needs_reindexing(
+        Path("module.py"),
+        entry["content_hash"],
+        current_dense_provider="voyage",
+        current_dense_model="voyage-code-3",
+        current_sparse_provider="fastembed",
+        current_sparse_model="splade",
+    ) == (False, "unchanged")
+
+
+async def test_files_embedded_with_other_models_are_replaced(indexer: IndexingService) -> None:
+    chunk = make_chunk("def first(): ...", (1, 1))
+    await index(indexer, chunk)
+    indexer._file_manifest.files["module.py"]["dense_embedding_model"] = "old-model"
+
+    await index(indexer, make_chunk("def first(): ...", (1, 1)))
+
+    store = indexer._vector_store
+    store.delete_by_files.assert_awaited_once_with([Path("module.py")], keep_generation=ANY)
+    store.update_payloads.assert_not_called()
+    assert len(store.upsert.await_args.args[0]) == 1
+
+
+async def test_stores_that_cant_update_payloads_re_embed(indexer: IndexingService) -> None:
+    chunk = make_chunk("def first(): ...", (1, 1))
+    await index(indexer, chunk)
+    indexer._vector_store.update_payloads = AsyncMock(side_effect=NotImplementedError)
+
+    await index(indexer, make_chunk("def first(): ...", (2, 2)))
+
+    (stored,) = indexer._vector_store.upsert.await_args.args[0]
+    assert stored.chunk_id == chunk.chunk_id
+    assert indexer.stats.chunks_reused == 0
+
+
+async def test_unchanged_chunks_missing_from_the_store_are_embedded_again(
+    indexer: IndexingService,
+) -> None:
+    first = make_chunk("def first(): ...", (1, 1))
+    second = make_chunk("def second(): ...", (3, 3))
+    await index(indexer, first, second)
+    indexer._vector_store.update_payloads.return_value = [second.chunk_id]
+    reused_before = indexer.stats.chunks_reused
+
+    edited = make_chunk("def first():\n    return 1", (1, 2))
+    await index(indexer, edited, make_chunk("def second(): ...", (5, 5)))
+
+    store = indexer._vector_store
+    changed, lost = (call.args[0] for call in store.upsert.await_args_list)
+    assert [chunk.chunk_id for chunk in changed] == [edited.chunk_id]
+    assert [chunk.chunk_id for chunk in lost] == [second.chunk_id]
+    assert (lost[0].line_range.start, lost[0].line_range.end) == (5, 5)
+    assert set(indexer._file_manifest.get_chunk_ids_for_file(Path("module.py"))) == {
+        str(edited.chunk_id),
+        str(second.chunk_id),
+    }
+    assert indexer.stats.chunks_reused == reused_before
+
+
+async def test_new_generation_is_stored_before_the_old_one_is_removed(
+    indexer: IndexingService,
+) -> None:
+    await index(indexer, make_chunk("def first(): ...", (1, 1)))
+    # Without chunk hashes to compare, the whole file is replaced
+    indexer._file_manifest.files["module.py"].pop("chunk_hashes")
+    store = indexer._vector_store
+    calls = MagicMock()
+    calls.attach_mock(store.upsert, "upsert")
+    calls.attach_mock(store.delete_by_files, "delete_by_files")
+
+    await index(indexer, make_chunk("def first(): pass", (1, 1)))
+
+    assert [name for name, *_ in calls.mock_calls] == ["upsert", "delete_by_files"]
+    generation = indexer._file_manifest.get_file(Path("module.py"))["generation"]
+    (stored,) = store.upsert.await_args.args[0]
+    assert stored.metadata["generation"] == generation
+    store.delete_by_files.assert_awaited_once_with([Path("module.py")], keep_generation=generation)
+
+
+async def test_searches_see_one_generation_while_it_is_swapped(indexer: IndexingService) -> None:
+    """Test that a search between storing a file's new chunks and removing its old ones, or
+    after indexing stopped in between, sees only the chunks of one of them."""
+    from codeweaver.server.agent_api.search.filters import filter_live_chunks
+
+    stored: dict[UUID, CodeChunk] = {}
+
+    def search() -> set[UUID]:
+        candidates = [SimpleNamespace(content=c, file_path=c.file_path) for c in stored.values()]
+        return {c.content.chunk_id for c in filter_live_chunks(candidates, indexer._file_manifest)}
+
+    async def upsert(chunks: list[CodeChunk]) -> None:
+        stored.update((chunk.chunk_id, chunk) for chunk in chunks)
+
+    async def update_payloads(chunks: list[CodeChunk]) -> list[UUID]:
+        await upsert(chunks)
+        return []
+
+    store = indexer._vector_store
+    store.upsert.side_effect = upsert
+    store.update_payloads.side_effect = update_payloads
+    first = make_chunk("def first(): ...", (1, 1))
+    second = make_chunk("def second(): ...", (3, 3))
+    await index(indexer, first, second)
+
+    seen: list[set[UUID]] = []
+
+    async def delete_by_id(ids: list[UUID]) -> None:
+        seen.append(search())
+        for chunk_id in ids:
+            stored.pop(chunk_id)
+
+    store.delete_by_id.side_effect = delete_by_id
+    edited = make_chunk("def first():\n    return 1", (1, 2))
+    await index(indexer, edited, make_chunk("def second(): ...", (5, 5)))
+
+    assert set(stored) == {edited.chunk_id, second.chunk_id}
+    assert seen == [{first.chunk_id, second.chunk_id}]
+    assert search() == {edited.chunk_id, second.chunk_id}
+
+    # Indexing that stops before the old chunks are removed leaves the previous ones live
+    store.delete_by_id.side_effect = RuntimeError("killed")
+    with pytest.raises(RuntimeError):
+        await index(indexer, make_chunk("def first(): return 2", (1, 1)), second)
+    assert len(stored) == 3
+    assert search() == {edited.chunk_id, second.chunk_id}
+
+
+async def test_failed_store_keeps_the_previous_generation(indexer: IndexingService) -> None:
+    await index(indexer, make_chunk("def first(): ...", (1, 1)))
+    before = dict(indexer._file_manifest.get_file(Path("module.py")))
+    indexer._vector_store.upsert.side_effect = RuntimeError("embedding service is down")
+
+    edited = make_chunk("def first(): pass", (1, 1))
+    with pytest.raises(RuntimeError):
+        await index(indexer, edited)
+
+    store = indexer._vector_store
+    store.delete_by_files.assert_not_called()
+    store.delete_by_id.assert_awaited_once_with([edited.chunk_id])
+    assert indexer._file_manifest.get_file(Path("module.py")) == before
+    assert len(indexer.stats.files_with_errors) == 1
+
+
+async def test_failed_payload_update_restores_unchanged_chunks(indexer: IndexingService) -> None:
+    first = make_chunk("def first(): ...", (1, 1))
+    second = make_chunk("def second(): ...", (3, 3))
+    await index(indexer, first, second)
+    before = dict(indexer._file_manifest.get_file(Path("module.py")))
+    store = indexer._vector_store
+    store.get_chunks = AsyncMock(return_value=[second])
+    store.update_payloads = AsyncMock(side_effect=[RuntimeError("connection reset"), None])
+
+    # Edit `first` and push `second` down two lines, then fail rewriting `second`
+    edited = make_chunk("def first():\n    return 1", (1, 2))
+    with pytest.raises(RuntimeError):
+        await index(indexer, edited, make_chunk("def second(): ...", (5, 5)))
+
+    store.get_chunks.assert_awaited_once_with([second.chunk_id])
+    store.delete_by_files.assert_not_called()
+    store.delete_by_id.assert_awaited_once_with([edited.chunk_id])
+    (moved,) = store.update_payloads.await_args_list[0].args[0]
+    assert (moved.line_range.start, moved.line_range.end) == (5, 5)
+    assert store.update_payloads.await_args_list[1].args[0] == [second]
+    assert indexer._file_manifest.get_file(Path("module.py")) == before
+    assert len(indexer.stats.files_with_errors) == 1
+
+
+async def test_moved_files_keep_their_chunks(indexer: IndexingService, tmp_path: Path) -> None:
+    from watchfiles import Change
+
+    first = make_chunk("def first(): ...", (1, 1))
+    await index(indexer, first)
+    (tmp_path / "pkg").mkdir()
+    (tmp_path / "module.py").rename(tmp_path / "pkg" / "module.py")
+    new_path = Path("pkg/module.py")
+    moved = make_chunk("def first(): ...", (1, 1), new_path)
+    indexer.next_chunks = [moved]
+    indexer._vector_store.reset_mock()
+
+    await indexer.process_changes([
+        (Change.deleted, str(tmp_path / "module.py")),
+        (Change.added, str(tmp_path / new_path)),
+    ])
+
+    store = indexer._vector_store
+    store.upsert.assert_not_called()
+    store.delete_by_files.assert_not_called()
+    (updated,) = store.update_payloads.await_args.args[0]
+    assert updated.chunk_id == first.chunk_id
+    assert updated.file_path == new_path
+    manifest = indexer._file_manifest
+    assert not manifest.has_file(Path("module.py"))
+    # Recorded with the hash the chunk has at its new path, so later edits can reuse it
+    assert manifest.get_chunk_hashes_for_file(new_path) == {
+        str(first.chunk_id): str(moved.embedding_hash)
+    }
+
+
+async def test_new_files_with_other_content_are_not_moves(
+    indexer: IndexingService, tmp_path: Path
+) -> None:
+    from watchfiles import Change
+
+    await index(indexer, make_chunk("def first(): ...", (1, 1)))
+    (tmp_path / "module.py").unlink()
+    (tmp_path / "other.py").write_text("def other(): ...\n")
+    indexer.next_chunks = [make_chunk("def other(): ...", (1, 1), Path("other.py"))]
+    indexer._vector_store.reset_mock()
+
+    await indexer.process_changes([
+        (Change.deleted, str(tmp_path / "module.py")),
+        (Change.added, str(tmp_path / "other.py")),
+    ])
+
+    store = indexer._vector_store
+    store.delete_by_files.assert_any_await([Path("module.py")])
+    assert len(store.upsert.await_args.args[0]) == 1
+    store.update_payloads.assert_not_called()
+    assert not indexer._file_manifest.has_file(Path("module.py"))
+
+
+async def test_moves_whose_new_path_isnt_indexed_delete_the_old_path(
+    indexer: IndexingService, tmp_path: Path, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    from watchfiles import Change
+
+    await index(indexer, make_chunk("def first(): ...", (1, 1)))
+    (tmp_path / "module.py").rename(tmp_path / "moved.py")
+    pair_moved_files = indexer._pair_moved_files
+
+    async def pair_then_remove(deleted: list[Path], added: list[Path]) -> dict[Path, Path]:
+        moved = await pair_moved_files(deleted, added)
+        # Gone again before it's indexed
+        (tmp_path / "moved.py").unlink()
+        return moved
+
+    monkeypatch.setattr(indexer, "_pair_moved_files", pair_then_remove)
+    indexer._vector_store.reset_mock()
+
+    await indexer.process_changes([
+        (Change.deleted, str(tmp_path / "module.py")),
+        (Change.added, str(tmp_path / "moved.py")),
+    ])
+
+    store = indexer._vector_store
+    store.update_payloads.assert_not_called()
+    store.delete_by_files.assert_awaited_once_with([Path("module.py")])
+    assert not indexer._file_manifest.has_file(Path("module.py"))
+    assert indexer._moved_files == {}
+
+
+async def test_formatting_only_changes_keep_embeddings(
+    indexer: IndexingService, tmp_path: Path
+) -> None:
+    indexer._settings.skip_formatting_changes = True
+    (tmp_path / "module.py").write_text("def first( ):\n    return 1\n")
+    first = make_chunk("def first( ):\n    return 1", (1, 2))
+    await index(indexer, first)
+
+    (tmp_path / "module.py").write_text("\n\ndef first():\n    return 1\n")
+    reformatted = make_chunk("def first():\n    return 1", (3, 4))
+    await index(indexer, reformatted)
+
+    store = indexer._vector_store
+    store.upsert.assert_not_called()
+    store.delete_by_id.assert_not_called()
+    (updated,) = store.update_payloads.await_args.args[0]
+    assert updated.chunk_id == first.chunk_id
+    assert updated.content == reformatted.content
+    assert (updated.line_range.start, updated.line_range.end) == (3, 4)
+    assert indexer.stats.chunks_reused == 1
+
+
+async def test_comment_changes_are_embedded_with_formatting_changes_skipped(
+    indexer: IndexingService, tmp_path: Path
+) -> None:
+    indexer._settings.skip_formatting_changes = True
+    (tmp_path / "module.py").write_text(
+        "# Returns one.\ndef first():\n    return 1\n\ndef second(): ...\n"
+    )
+    first = make_chunk("# Returns one.\ndef first():\n    return 1", (1, 3))
+    second = make_chunk("def second(): ...", (5, 5))
+    await index(indexer, first, second)
+
+    # Only the comment changes, and the file is reformatted
+    (tmp_path / "module.py").write_text(
+        "# Returns 1.\ndef first():\n    return 1\n\n\ndef second(): ...\n"
+    )
+    edited = make_chunk("# Returns 1.\ndef first():\n    return 1", (1, 3))
+    reformatted = make_chunk("def second(): ...", (6, 6))
+    await index(indexer, edited, reformatted)
+
+    store = indexer._vector_store
+    assert [chunk.chunk_id for chunk in store.upsert.await_args.args[0]] == [edited.chunk_id]
+    store.delete_by_id.assert_awaited_once_with([first.chunk_id])
+    (updated,) = store.update_payloads.await_args.args[0]
+    assert updated.chunk_id == second.chunk_id
+    assert (updated.line_range.start, updated.line_range.end) == (6, 6)
+    assert indexer.stats.chunks_reused == 1
+
+
+async def test_syntax_changes_are_embedded_with_formatting_changes_skipped(
+    indexer: IndexingService, tmp_path: Path
+) -> None:
+    indexer._settings.skip_formatting_changes = True
+    (tmp_path / "module.py").write_text("def first():\n    return 1\n")
+    first = make_chunk("def first():\n    return 1", (1, 2))
+    await index(indexer, first)
+
+    (tmp_path / "module.py").write_text("def first():\n    return 2\n")
+    edited = make_chunk("def first():\n    return 2", (1, 2))
+    await index(indexer, edited)
+
+    store = indexer._vector_store
+    assert [chunk.chunk_id for chunk in store.upsert.await_args.args[0]] == [edited.chunk_id]
+    store.delete_by_id.assert_awaited_once_with([first.chunk_id])
+
+
+async def discover(indexer: IndexingService) -> int:
+    """Run `module.py` through discovery; the number of files it queues for indexing."""
+    from asyncio import Queue
+
+    return await indexer._process_discovery_batch(
+        [indexer._project_path / "module.py"],
+        set(),
+        indexer._get_current_embedding_models(),
+        Queue(),
+    )
+
+
+@pytest.mark.parametrize("skip_formatting_changes", [False, True])
+async def test_existing_manifests_are_not_reindexed(
+    indexer: IndexingService, tmp_path: Path, skip_formatting_changes: bool
+) -> None:
+    """Test that entries recorded before the hash of files' bytes was still match their files."""
+    from codeweaver.core.discovery import compute_semantic_file_hash
+
+    (tmp_path / "module.py").write_text("def first( ):\n    return 1\n")
+    indexer._file_manifest.add_files_batch([
+        {
+            "path": Path("module.py"),
+            "content_hash": compute_semantic_file_hash(
+                (tmp_path / "module.py").read_bytes(), tmp_path / "module.py"
+            ),
+            "chunk_ids": [str(make_chunk("def first( ):\n    return 1", (1, 2)).chunk_id)],
+        }
+    ])
+    indexer._settings.skip_formatting_changes = skip_formatting_changes
+
+    assert await discover(indexer) == 0
+    (tmp_path / "module.py").write_text("def first():\n    return 1\n")
+    assert await discover(indexer) == 0
+
+
+@pytest.mark.parametrize("skip_formatting_changes", [False, True])
+async def test_formatting_only_changes_are_reindexed(
+    indexer: IndexingService, tmp_path: Path, skip_formatting_changes: bool
+) -> None:
+    """Test that discovery queues formatting-only changes, whether or not they keep embeddings."""
+    indexer._settings.skip_formatting_changes = skip_formatting_changes
+    (tmp_path / "module.py").write_text("def first( ):\n    return 1\n")
+    await index(indexer, make_chunk("def first( ):\n    return 1", (1, 2)))
+    entry = indexer._file_manifest.get_file(Path("module.py"))
+    assert entry["raw_content_hash"] != entry["content_hash"]
+    assert await discover(indexer) == 0
+
+    (tmp_path / "module.py").write_text("def first():\n    return 1\n")
+
+    assert await discover(indexer) == 1
+
+
+def test_embedding_hash_ignores_position() -> None:
+    chunk = make_chunk("def first(): ...", (1, 1))
+
+    assert chunk.embedding_hash == make_chunk("def first(): ...", (40, 40)).embedding_hash
+    assert chunk.embedding_hash != make_chunk("def first(): pass", (1, 1)).embedding_hash
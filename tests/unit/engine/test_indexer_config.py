@@ -123,3 +123,15 @@ async def test_glob_pattern_excludes(temp_project_dir):
 
     # Check that .go files are excluded
     assert any(p.endswith(".go") for p in excluded_paths_str)
+
+
+def test_path_filter_applies_excludes_to_relative_paths():
+    """Test that files listed from a git revision are filtered like the working tree."""
+    settings = IndexerSettings(excludes=frozenset(["data/**", "**/*.go", "specs"]))
+    include = settings.construct_path_filter()
+
+    assert include(Path("src/main.py"))
+    assert not include(Path("data/example.txt"))
+    assert not include(Path("tests/fixtures/sample.go"))
+    assert not include(Path("specs/spec.md"))
+    assert not include(Path(".hidden/config.py"))
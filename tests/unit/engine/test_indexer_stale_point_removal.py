@@ -6,6 +6,7 @@
 """Tests for indexer stale point removal and orphan detection."""
 
 from pathlib import Path
+from unittest.mock import ANY
 
 import pytest
 
@@ -52,6 +53,8 @@ async def mock_indexer(tmp_path: Path, mock_vector_store, monkeypatch: pytest.Mo
     from asyncio import Lock
 
     indexer._manifest_lock = Lock()
+    indexer._moved_files = {}
+    indexer._settings = MagicMock(skip_formatting_changes=False)
 
     indexer._providers_initialized = True
 
@@ -86,10 +89,9 @@ class TestStalePointRemovalInBatchIndexing:
         await mock_indexer._index_files_batch([(modified_file, None), (new_file, None)], None)
 
         # Verify delete_by_files was called with relative paths
-        mock_indexer._vector_store.delete_by_files.assert_called_once_with([
-            rel_path,
-            Path("new.py"),
-        ])
+        mock_indexer._vector_store.delete_by_files.assert_called_once_with(
+            [rel_path, Path("new.py")], keep_generation=ANY
+        )
 
     @pytest.mark.asyncio
     async def test_batch_deletes_multiple_modified_files(
@@ -117,4 +119,6 @@ class TestStalePointRemovalInBatchIndexing:
         await mock_indexer._index_files_batch([(file1, None), (file2, None)], None)
 
         # Verify delete_by_files was called once with both relative paths
-        mock_indexer._vector_store.delete_by_files.assert_called_once_with([rel_path1, rel_path2])
+        mock_indexer._vector_store.delete_by_files.assert_called_once_with(
+            [rel_path1, rel_path2], keep_generation=ANY
+        )
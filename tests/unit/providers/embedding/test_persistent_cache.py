@@ -0,0 +1,140 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for the persistent on-disk embedding cache."""
+
+from __future__ import annotations
+
+import sqlite3
+
+from datetime import timedelta
+from pathlib import Path
+from unittest.mock import MagicMock
+
+import pytest
+
+from codeweaver.core.types.embeddings import CodeWeaverSparseEmbedding
+from codeweaver.providers.embedding.cache_manager import EmbeddingCacheManager
+from codeweaver.providers.embedding.persistent_cache import (
+    EmbeddingSpace,
+    PersistentEmbeddingCache,
+)
+
+
+pytestmark = [pytest.mark.unit]
+
+DENSE = EmbeddingSpace(provider="voyage", model="voyage-code-3", dimension=3, dtype="float32")
+SPARSE = EmbeddingSpace(provider="fastembed", model="splade", dimension=0, dtype="float32")
+
+
+@pytest.fixture
+def cache(tmp_path: Path) -> PersistentEmbeddingCache:
+    return PersistentEmbeddingCache(tmp_path / "embeddings.sqlite", max_size_bytes=1024 * 1024)
+
+
+def test_round_trips_dense_and_sparse_embeddings(cache: PersistentEmbeddingCache) -> None:
+    """Test that both vector kinds come back as they were stored."""
+    sparse = CodeWeaverSparseEmbedding(indices=[1, 7], values=[0.5, 0.25])
+    cache.put(DENSE, {"a": [0.1, 0.2, 0.3]})
+    cache.put(SPARSE, {"a": sparse})
+
+    assert cache.get(DENSE, ["a", "missing"]) == {"a": [0.1, 0.2, 0.3]}
+    assert cache.get(SPARSE, ["a"]) == {"a": sparse}
+
+
+def test_entries_are_isolated_by_embedding_space(cache: PersistentEmbeddingCache) -> None:
+    """Test that a different model, dimension or datatype never sees another space's vectors."""
+    cache.put(DENSE, {"a": [0.1, 0.2, 0.3]})
+
+    for other in (
+        DENSE._replace(model="voyage-code-2"),
+        DENSE._replace(dimension=1024),
+        DENSE._replace(dtype="int8"),
+    ):
+        assert cache.get(other, ["a"]) == {}
+
+
+def test_shared_between_instances(tmp_path: Path) -> None:
+    """Test that a second process opening the same file sees the first one's entries."""
+    path = tmp_path / "embeddings.sqlite"
+    PersistentEmbeddingCache(path, max_size_bytes=1024).put(DENSE, {"a": [1.0, 0.0, 0.0]})
+
+    assert PersistentEmbeddingCache(path, max_size_bytes=1024).get(DENSE, ["a"]) == {
+        "a": [1.0, 0.0, 0.0]
+    }
+
+
+def test_evicts_least_recently_used_entries(tmp_path: Path) -> None:
+    """Test that writes past the size limit evict the entries used longest ago."""
+    cache = PersistentEmbeddingCache(tmp_path / "embeddings.sqlite", max_size_bytes=60)
+    vector = [0.125, 0.25, 0.5]  # 17 bytes as JSON
+    cache.put(DENSE, {"old": vector, "recent": vector})
+    cache.get(DENSE, ["recent"])
+    cache.put(DENSE, {"new": vector, "newest": vector})
+
+    assert set(cache.get(DENSE, ["old", "recent", "new", "newest"])) == {
+        "recent",
+        "new",
+        "newest",
+    }
+    assert cache.stats().size_bytes <= 60
+
+
+def test_lookups_do_not_wait_for_the_write_lock(tmp_path: Path) -> None:
+    """Test that lookups only read, and their hits' use times are written in a batch later."""
+    path = tmp_path / "embeddings.sqlite"
+    cache = PersistentEmbeddingCache(path, max_size_bytes=1024, busy_timeout=0.1)
+    cache.put(DENSE, {"a": [0.1, 0.2, 0.3]})
+    stored = cache.stats().spaces[0].last_used
+
+    writer = sqlite3.connect(path, isolation_level=None)
+    writer.execute("BEGIN IMMEDIATE")
+    try:
+        assert cache.get(DENSE, ["a"]) == {"a": [0.1, 0.2, 0.3]}
+    finally:
+        writer.execute("ROLLBACK")
+        writer.close()
+    assert cache.stats().spaces[0].last_used == stored
+
+    cache.flush()
+    assert cache.stats().spaces[0].last_used > stored
+
+
+def test_stats_prune_and_clear(cache: PersistentEmbeddingCache) -> None:
+    """Test the operations behind `cw cache stats|prune|clear`."""
+    cache.put(DENSE, {"a": [0.1, 0.2, 0.3], "b": [0.3, 0.2, 0.1]})
+    cache.put(SPARSE, {"a": CodeWeaverSparseEmbedding(indices=[1], values=[1.0])})
+
+    stats = cache.stats()
+    assert stats.entries == 3
+    assert {(s.space, s.entries) for s in stats.spaces} == {(DENSE, 2), (SPARSE, 1)}
+
+    assert cache.prune(older_than=timedelta(days=1)) == 0
+    assert cache.prune(max_size_bytes=0) == 3
+
+    cache.put(DENSE, {"a": [0.1, 0.2, 0.3]})
+    cache.put(SPARSE, {"a": CodeWeaverSparseEmbedding(indices=[1], values=[1.0])})
+    assert cache.clear(model="splade") == 1
+    assert cache.clear() == 1
+    assert cache.stats().entries == 0
+
+
+async def test_cache_manager_persists_and_counts_hits(cache: PersistentEmbeddingCache) -> None:
+    """Test that the cache manager reads through to the persistent cache and tracks hits."""
+    manager = EmbeddingCacheManager(registry=MagicMock(), persistent_cache=cache)
+    namespace = manager._get_namespace("voyage", "dense")
+
+    await manager.persist(DENSE, {"a": [0.1, 0.2, 0.3]})
+    found = await manager.get_persisted(DENSE, ["a", "b"], namespace)
+
+    assert found == {"a": [0.1, 0.2, 0.3]}
+    assert manager.get_stats(namespace)[namespace]["persistent_hits"] == 1
+
+
+async def test_cache_manager_without_persistent_cache() -> None:
+    """Test that lookups are plain misses when persistence is disabled."""
+    manager = EmbeddingCacheManager(registry=MagicMock())
+
+    await manager.persist(DENSE, {"a": [0.1, 0.2, 0.3]})
+    assert await manager.get_persisted(DENSE, ["a"], "voyage.dense") == {}
@@ -0,0 +1,189 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for token-budgeted context assembly."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from textwrap import dedent
+
+import pytest
+
+from codeweaver.core import ChunkSource, CodeChunk, DiscoveredFile, Span
+from codeweaver.engine.services.symbol_index_service import SymbolIndex, SymbolIndexService
+from codeweaver.server.agent_api.search.assembly import (
+    assemble_context,
+    expand_matches,
+    merge_matches,
+    pack_matches,
+)
+from codeweaver.server.agent_api.search.types import CodeMatch, CodeMatchType
+
+
+pytestmark = [pytest.mark.unit, pytest.mark.search]
+
+SOURCE = """
+from app.config import Settings
+
+
+class Loader:
+    def __init__(self, settings: Settings):
+        self.settings = settings
+
+    def load(self, raw):
+        value = int(raw)
+        return value * self.settings.scale
+
+
+def helper():
+    return Loader(Settings()).load("1")
+"""
+
+CONFIG_SOURCE = """
+class Settings:
+    scale = 2
+"""
+
+
+class WordTokenizer:
+    """Counts whitespace-separated words, so budgets in tests are easy to reason about."""
+
+    def estimate(self, text: str | bytes) -> int:
+        return len(str(text).split())
+
+
+@pytest.fixture
+def project(tmp_path: Path) -> Path:
+    (tmp_path / "app").mkdir()
+    (tmp_path / "app" / "loader.py").write_text(dedent(SOURCE).lstrip())
+    (tmp_path / "app" / "config.py").write_text(dedent(CONFIG_SOURCE).lstrip())
+    return tmp_path
+
+
+SKELETON = """class Loader:
+    def __init__(self, settings: Settings): …
+    def load(self, raw): …
+def helper(): …"""
+
+
+def make_match(
+    project: Path,
+    start: int,
+    end: int,
+    score: float = 0.5,
+    *,
+    skeleton: str | None = None,
+) -> CodeMatch:
+    path = project / "app" / "loader.py"
+    file = DiscoveredFile.from_path(path, project_path=project)
+    assert file is not None
+    lines = path.read_text().splitlines()
+    span = Span(start, end, file.source_id)
+    chunk = CodeChunk.model_validate({
+        "content": skeleton if skeleton is not None else "\n".join(lines[start - 1 : end]),
+        "line_range": span,
+        "file_path": path,
+        "language": file.ext_category.language if file.ext_category else None,
+        "source": ChunkSource.SKELETON if skeleton is not None else ChunkSource.FILE,
+        "parent_id": file.source_id,
+    })
+    return CodeMatch(
+        file=file,
+        content=chunk,
+        span=span,
+        relevance_score=score,
+        match_type=CodeMatchType.SEMANTIC,
+        related_symbols=(f"lines {start}-{end}",),
+    )
+
+
+def test_merge_overlapping_and_adjacent(project: Path) -> None:
+    """Test that touching matches in a file merge at the best rank and distant ones don't."""
+    first, touching, distant = (
+        make_match(project, 8, 10, 0.9),
+        make_match(project, 11, 11, 0.4),
+        make_match(project, 13, 14, 0.7),
+    )
+    merged = merge_matches([first, distant, touching])
+    assert [(m.span.start, m.span.end) for m in merged] == [(8, 11), (13, 14)]
+    assert merged[0].relevance_score == 0.9
+    assert merged[0].related_symbols == ("lines 8-10", "lines 11-11")
+    assert merged[0].content.content.splitlines()[-1].strip() == "return value * self.settings.scale"
+
+
+def test_expand_to_enclosing_items(project: Path) -> None:
+    """Test growing a match to its function, then its class, then the whole file."""
+    body = make_match(project, 9, 9)
+    spans = [
+        (m.span.start, m.span.end) for levels in (1, 2, 3) for m in expand_matches([body], levels)
+    ]
+    assert spans == [(8, 10), (4, 10), (1, 14)]
+
+
+def test_skeletons_kept_apart(project: Path, monkeypatch: pytest.MonkeyPatch) -> None:
+    """Test that a whole-file skeleton doesn't merge with the matches it overlaps."""
+    skeleton = make_match(project, 1, 14, 0.8, skeleton=SKELETON)
+    body, nearby = make_match(project, 9, 9, 0.9), make_match(project, 13, 14, 0.4)
+
+    assembled = assemble_context(
+        [body, skeleton, nearby], token_limit=1000, tokenizer=WordTokenizer(), expand=1
+    )
+    assert [(m.content.source, m.span.start, m.span.end) for m in assembled.matches] == [
+        (ChunkSource.FILE, 8, 10),
+        (ChunkSource.SKELETON, 1, 14),
+        (ChunkSource.FILE, 13, 14),
+    ]
+    assert assembled.matches[1].content.content == SKELETON
+    assert "return value * self.settings.scale" in assembled.matches[0].content.content
+
+    # Cut to fit, a skeleton keeps its span, since its lines aren't the file's
+    tokenizer = WordTokenizer()
+    budget = tokenizer.estimate(SKELETON) - 1
+    monkeypatch.setattr("codeweaver.server.agent_api.search.assembly.MIN_TRUNCATED_TOKENS", 1)
+    [truncated] = pack_matches([skeleton], budget, tokenizer).matches
+    assert truncated.span == skeleton.span
+    assert truncated.content.content != SKELETON
+
+
+def test_pack_truncates_and_omits(project: Path) -> None:
+    """Test greedy packing into the budget, reporting what didn't fit."""
+    tokenizer = WordTokenizer()
+    whole_file, small = make_match(project, 1, 14), make_match(project, 13, 14)
+    full_cost = tokenizer.estimate(whole_file.content.content)
+    packed = pack_matches([whole_file, small], full_cost + 1, tokenizer)
+    assert [m.span for m in packed.matches] == [whole_file.span]
+    assert [(e.span, e.reason) for e in packed.elided] == [((13, 14), "omitted")]
+
+    # With a budget too small for anything, every match is reported as omitted
+    nothing = pack_matches([whole_file, small], 3, tokenizer)
+    assert nothing.matches == []
+    assert {e.reason for e in nothing.elided} == {"omitted"}
+    assert nothing.elided[0].tokens == full_cost
+
+
+async def test_referenced_signatures(project: Path) -> None:
+    """Test attaching the signature lines of types used but defined elsewhere."""
+    service = SymbolIndexService(project, "demo", project / ".cache")
+    index: SymbolIndex = await service.get_index()
+    for name in ("loader.py", "config.py"):
+        references = service.extract(project / "app" / name)
+        assert references is not None
+        index.set_file(Path("app") / name, references)
+
+    assembled = assemble_context(
+        [make_match(project, 13, 14)],
+        token_limit=1000,
+        tokenizer=WordTokenizer(),
+        symbols=(index, project),
+    )
+    [match] = assembled.matches
+    assert set(match.referenced_signatures) == {
+        "app/loader.py:4: class Loader:",
+        "app/config.py:1: class Settings:",
+    }
+    assert assembled.token_count == WordTokenizer().estimate(match.content.content) + sum(
+        WordTokenizer().estimate(s) for s in match.referenced_signatures
+    )
+    assert assembled.elided == ()
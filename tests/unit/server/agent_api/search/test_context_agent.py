@@ -0,0 +1,145 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for the context agent's planning, follow-up searches and pruning."""
+
+from __future__ import annotations
+
+from pathlib import Path
+
+import pytest
+
+from pydantic_ai.messages import ModelMessage, ModelResponse, ToolCallPart, ToolReturnPart
+from pydantic_ai.models.function import AgentInfo, FunctionModel
+
+from codeweaver.core import ChunkSource, CodeChunk, DiscoveredFile, Span
+from codeweaver.server.agent_api.search.context_agent import run_context_agent
+from codeweaver.server.agent_api.search.intent import IntentType
+from codeweaver.server.agent_api.search.types import CodeMatch, CodeMatchType
+
+
+pytestmark = [pytest.mark.unit, pytest.mark.search]
+
+SOURCE = "\n".join(f"line {number}" for number in range(1, 31))
+
+
+def make_match(project: Path, start: int, end: int) -> CodeMatch:
+    path = project / "app.py"
+    file = DiscoveredFile.from_path(path, project_path=project)
+    assert file is not None
+    span = Span(start, end, file.source_id)
+    chunk = CodeChunk.model_validate({
+        "content": "\n".join(SOURCE.splitlines()[start - 1 : end]),
+        "line_range": span,
+        "file_path": path,
+        "language": file.ext_category.language if file.ext_category else None,
+        "source": ChunkSource.FILE,
+        "parent_id": file.source_id,
+    })
+    return CodeMatch(
+        file=file,
+        content=chunk,
+        span=span,
+        relevance_score=0.5,
+        match_type=CodeMatchType.SEMANTIC,
+    )
+
+
+@pytest.fixture
+def project(tmp_path: Path) -> Path:
+    (tmp_path / "app.py").write_text(SOURCE)
+    return tmp_path
+
+
+def scripted_model(keep: list[int]) -> FunctionModel:
+    """Plans a debugging search, runs it once, then keeps the given match IDs."""
+
+    def respond(messages: list[ModelMessage], info: AgentInfo) -> ModelResponse:
+        output_tool = info.output_tools[0].name
+        if not info.function_tools:
+            plan = {"intent": "debug", "queries": ["where the token expiry is checked"]}
+            return ModelResponse(parts=[ToolCallPart(output_tool, plan)])
+        searched = any(
+            isinstance(part, ToolReturnPart) for message in messages for part in message.parts
+        )
+        if not searched:
+            query = {"query": "where the token expiry is checked"}
+            return ModelResponse(parts=[ToolCallPart("search_code", query)])
+        return ModelResponse(parts=[ToolCallPart(output_tool, {"keep": keep})])
+
+    return FunctionModel(respond)
+
+
+async def test_plans_searches_and_prunes(project: Path) -> None:
+    """Test that the agent's follow-up matches are added, and unkept ones are pruned."""
+    first, second, found = (
+        make_match(project, 1, 5),
+        make_match(project, 10, 14),
+        make_match(project, 20, 24),
+    )
+    queries: list[tuple[str, IntentType]] = []
+
+    async def search(query: str, intent: IntentType) -> list[CodeMatch]:
+        queries.append((query, intent))
+        return [first, found]
+
+    result = await run_context_agent(
+        "why do tokens expire early", [first, second], search, scripted_model([2, 0, 2, 7])
+    )
+
+    assert queries == [("where the token expiry is checked", IntentType.DEBUG)]
+    assert result.matches == (found, first)
+    assert result.pruned == (second,)
+    assert result.intent == IntentType.DEBUG
+    assert result.searches == ("where the token expiry is checked",)
+    assert result.tokens > 0
+
+
+async def test_keeps_the_callers_intent(project: Path) -> None:
+    """Test that an intent set by the caller overrides the agent's."""
+
+    async def search(query: str, intent: IntentType) -> list[CodeMatch]:
+        assert intent == IntentType.UNDERSTAND
+        return []
+
+    result = await run_context_agent(
+        "token refresh",
+        [make_match(project, 1, 5)],
+        search,
+        scripted_model([0]),
+        intent=IntentType.UNDERSTAND,
+    )
+    assert result.intent == IntentType.UNDERSTAND
+    assert len(result.matches) == 1
+
+
+async def test_search_limit(project: Path) -> None:
+    """Test that the agent can't search past its limit."""
+
+    async def search(query: str, intent: IntentType) -> list[CodeMatch]:
+        raise AssertionError("searched past the limit")
+
+    result = await run_context_agent(
+        "token refresh", [make_match(project, 1, 5)], search, scripted_model([0]), max_searches=0
+    )
+    assert result.searches == ()
+    assert len(result.matches) == 1
+
+
+async def test_skeleton_is_not_a_repeat(project: Path) -> None:
+    """Test that a file's skeleton isn't dropped as a repeat of a whole-file match."""
+    whole_file = make_match(project, 1, 30)
+    skeleton = whole_file.model_copy(
+        update={
+            "content": whole_file.content.model_copy(
+                update={"source": ChunkSource.SKELETON, "chunk_name": "app.py (skeleton)"}
+            )
+        }
+    )
+
+    async def search(query: str, intent: IntentType) -> list[CodeMatch]:
+        return [whole_file, skeleton]
+
+    result = await run_context_agent("token refresh", [whole_file], search, scripted_model([0, 1]))
+    assert result.matches == (whole_file, skeleton)
@@ -0,0 +1,109 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for answering dependency questions from the dependency graph."""
+
+from pathlib import Path
+from textwrap import dedent
+
+import pytest
+
+from codeweaver.core import SemanticSearchLanguage
+from codeweaver.engine.services.dependency_service import (
+    DependencyEdge,
+    DependencyGraph,
+    PackageInfo,
+)
+from codeweaver.server.agent_api.search.dependency_matches import (
+    dependency_query_targets,
+    find_dependency_matches,
+)
+from codeweaver.server.agent_api.search.filters import filter_matches
+
+
+pytestmark = [pytest.mark.unit, pytest.mark.search]
+
+RUST = SemanticSearchLanguage.RUST
+
+
+@pytest.fixture
+def graph() -> DependencyGraph:
+    """A two-crate workspace where `server` depends on `auth` and both use serde_json."""
+    auth, server = Path("crates/auth/Cargo.toml"), Path("crates/server/Cargo.toml")
+    return DependencyGraph(
+        packages=(
+            PackageInfo(name="auth", language=RUST, manifest=auth),
+            PackageInfo(name="server", language=RUST, manifest=server),
+        ),
+        edges=(
+            DependencyEdge(package="auth", name="serde_json", language=RUST, manifest=auth),
+            DependencyEdge(package="server", name="serde_json", language=RUST, manifest=server),
+            DependencyEdge(
+                package="server", name="auth", internal=True, language=RUST, manifest=server
+            ),
+        ),
+    )
+
+
+@pytest.mark.parametrize(
+    ("query", "expected"),
+    [
+        ("which crates depend on serde_json", [("serde_json", "dependents")]),
+        ("what uses our auth crate", [("auth", "dependents")]),
+        ("what does server depend on", [("server", "dependencies")]),
+        ("dependencies of `server`", [("server", "dependencies")]),
+        ("how is the session token validated", []),
+        ("how does auth use serde_json to parse tokens", []),
+        ("where does the server import auth helpers", []),
+        ("list the packages that server requires", []),
+    ],
+)
+def test_dependency_query_targets(graph: DependencyGraph, query: str, expected: list) -> None:
+    """Test detecting explicit dependency questions and the direction they ask in."""
+    assert dependency_query_targets(query, graph) == expected
+
+
+def test_graph_lookups(graph: DependencyGraph) -> None:
+    """Test reverse lookups across `-`/`_` spellings."""
+    assert {e.package for e in graph.dependents_of("serde-json")} == {"auth", "server"}
+    assert [e.name for e in graph.dependencies_of("server", internal_only=True)] == ["auth"]
+
+
+async def test_dependency_matches_go_through_filters(
+    tmp_path: Path, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    """Test that injected declarations are narrowed by the same path filters as search results."""
+    monkeypatch.setenv("CODEWEAVER_PROJECT_PATH", str(tmp_path))
+    monkeypatch.chdir(tmp_path)
+    edges = []
+    for package in ("auth", "server"):
+        manifest = Path("crates") / package / "Cargo.toml"
+        (tmp_path / manifest).parent.mkdir(parents=True)
+        (tmp_path / manifest).write_text(
+            dedent(f"""
+            [package]
+            name = "{package}"
+
+            [dependencies]
+            serde_json = "1"
+            """).lstrip()
+        )
+        edges.append(
+            DependencyEdge(
+                package=package, name="serde_json", language=RUST, manifest=manifest, line=5
+            )
+        )
+    graph = DependencyGraph(
+        packages=tuple(
+            PackageInfo(name=edge.package, language=RUST, manifest=edge.manifest) for edge in edges
+        ),
+        edges=tuple(edges),
+    )
+
+    matches = await find_dependency_matches("which crates depend on serde_json", graph, tmp_path)
+    assert len(matches) == 2
+
+    filtered = filter_matches(matches, paths=("crates/auth",))
+    assert [match.related_symbols[0] for match in filtered] == ["auth"]
+    assert filter_matches(matches, exclude_paths=("crates/**",)) == []
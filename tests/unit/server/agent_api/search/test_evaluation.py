@@ -0,0 +1,114 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for the retrieval benchmark behind `cw eval`."""
+
+from __future__ import annotations
+
+import json
+import math
+
+from pathlib import Path
+
+import pytest
+
+from codeweaver.core import ConfigurationError
+from codeweaver.server.agent_api.search.evaluation import (
+    EvalQuery,
+    EvalReport,
+    QueryScore,
+    evaluate,
+    load_eval_queries,
+    metric_deltas,
+    rank_files,
+    render_markdown,
+)
+from codeweaver.server.agent_api.search.intent import IntentType
+
+
+pytestmark = [pytest.mark.unit, pytest.mark.search]
+
+REFERENCE_QUERIES = Path(__file__).parents[4] / "fixtures" / "reference_queries.yml"
+
+QUERY = EvalQuery(
+    query="where are chunks split",
+    intent=IntentType.UNDERSTAND,
+    expected_files=("src/chunker/semantic.py", "src/chunker/base.py"),
+)
+
+
+def test_loads_the_reference_queries() -> None:
+    queries = load_eval_queries(REFERENCE_QUERIES)
+
+    assert {query.intent for query in queries} == set(IntentType)
+    assert all(query.expected_files for query in queries)
+    assert {query.precision_target for query in queries} == {3, 5}
+
+
+def test_malformed_query_file_is_a_configuration_error(tmp_path: Path) -> None:
+    path = tmp_path / "queries.yml"
+    path.write_text("queries:\n  - query: no intent\n    expected_files: [a.py]\n")
+
+    with pytest.raises(ConfigurationError, match="Couldn't load benchmark queries"):
+        load_eval_queries(path)
+
+
+def test_results_collapse_to_distinct_files() -> None:
+    results = [
+        "/repo/src/chunker/semantic.py",
+        "/repo/src/chunker/semantic.py",
+        "src/other.py",
+        "src/chunker/base.py",
+    ]
+
+    assert rank_files(results, QUERY.expected_files) == [True, False, True]
+
+
+def test_metrics_at_k() -> None:
+    score = QueryScore.score(QUERY, ["src/other.py", "src/chunker/semantic.py", "x.py"], k=3)
+
+    assert score.precision == pytest.approx(1 / 3)
+    assert score.recall == pytest.approx(1 / 2)
+    assert score.mrr == pytest.approx(1 / 2)
+    assert score.ndcg == pytest.approx((1 / math.log2(3)) / (1 + 1 / math.log2(3)))
+    assert score.missed == ("src/chunker/base.py",)
+
+
+def test_perfect_ranking_scores_one() -> None:
+    score = QueryScore.score(QUERY, ["src/chunker/semantic.py", "src/chunker/base.py"], k=5)
+
+    assert (score.recall, score.mrr, score.ndcg) == (1.0, 1.0, pytest.approx(1.0))
+    assert score.precision == pytest.approx(2 / 5)
+
+
+async def test_evaluate_and_compare_reports() -> None:
+    debug = QUERY._replace(query="why does chunking fail", intent=IntentType.DEBUG)
+
+    async def good(query: EvalQuery) -> list[str]:
+        return list(query.expected_files)
+
+    async def bad(query: EvalQuery) -> list[str]:
+        return ["src/other.py"]
+
+    baseline = await evaluate([QUERY, debug], good, label="baseline")
+    candidate = await evaluate([QUERY, debug], bad, label="candidate", k=3)
+
+    assert set(candidate.by_intent) == {"understand", "debug"}
+    assert candidate.overall.ndcg == 0.0
+    assert metric_deltas(candidate, baseline)["overall"]["ndcg"] == pytest.approx(-1.0)
+    markdown = render_markdown(candidate, baseline)
+    assert "Compared to **baseline**" in markdown
+    assert "(-1.000)" in markdown
+    assert "why does chunking fail" in markdown
+
+
+async def test_reports_round_trip_through_json() -> None:
+    async def search(query: EvalQuery) -> list[str]:
+        return ["src/chunker/base.py"]
+
+    report = await evaluate([QUERY], search, label="saved")
+
+    loaded = EvalReport.from_dict(json.loads(json.dumps(report.as_dict())))
+
+    assert loaded == report
@@ -0,0 +1,278 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for language-aware, per-chunk test detection and filtering."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+import pytest
+
+from codeweaver.semantic.test_code import find_test_only_ranges, is_test_chunk, is_test_file
+from codeweaver.server.agent_api.search.filters import (
+    apply_filters,
+    matches_path,
+    path_literal_prefix,
+)
+from codeweaver.server.agent_api.search.intent import IntentType
+from codeweaver.server.agent_api.search.pipeline import build_query_filter
+from codeweaver.server.agent_api.search.types import CodeKind, SearchFilters
+
+
+if TYPE_CHECKING:
+    from codeweaver.core import CodeChunk, SearchResult
+
+
+pytestmark = [pytest.mark.unit, pytest.mark.search]
+
+FIXTURES = Path(__file__).parents[4] / "fixtures"
+
+
+def make_chunk(
+    content: str,
+    file_path: Path,
+    *,
+    language: str = "rust",
+    lines: tuple[int, int] = (1, 1),
+    metadata: dict[str, Any] | None = None,
+) -> CodeChunk:
+    """Build a minimal chunk for filtering."""
+    from codeweaver.core import ChunkKind, CodeChunk, ExtCategory, Span, uuid7
+
+    chunk_id = uuid7()
+    return CodeChunk(
+        chunk_id=chunk_id,
+        ext_category=ExtCategory.from_language(language, ChunkKind.CODE),
+        chunk_name=f"{file_path}:chunk",
+        file_path=file_path,
+        language=language,
+        content=content,
+        line_range=Span(start=lines[0], end=lines[1], source_id=chunk_id),
+        metadata=metadata,
+    )
+
+
+def make_result(chunk: CodeChunk) -> SearchResult:
+    """Wrap a chunk in a search result."""
+    from codeweaver.core import SearchResult
+
+    return SearchResult(content=chunk, file_path=chunk.file_path, score=0.5)
+
+
+@pytest.fixture
+def rust_project(tmp_path: Path, monkeypatch: pytest.MonkeyPatch) -> Path:
+    """A project root containing the sample Rust module as src/lib.rs."""
+    (tmp_path / "src").mkdir()
+    (tmp_path / "src" / "lib.rs").write_text((FIXTURES / "sample.rs").read_text())
+    monkeypatch.setenv("CODEWEAVER_PROJECT_PATH", str(tmp_path))
+    monkeypatch.chdir(tmp_path)
+    return tmp_path
+
+
+@pytest.mark.parametrize(
+    ("path", "expected"),
+    [
+        ("tests/integration.rs", True),
+        ("crates/core/tests/common/mod.rs", True),
+        ("crates/core/benches/throughput.rs", True),
+        ("src/parser_test.rs", True),
+        ("crates/core/src/lib.rs", False),
+        ("src/latest.rs", False),
+        ("pkg/server/handler_test.go", True),
+        ("tests/test_auth.py", True),
+        ("src/auth.py", False),
+        ("/tmp/pytest-of-user/test_project/src/auth.py", False),
+        ("spec/models/user.rb", True),
+        ("docs/spec/overview.md", False),
+        ("api/specs/users.yaml", False),
+        ("crates/core/spec/parser.rs", False),
+        ("src/test_utils/fixtures.rs", False),
+        ("nix/test.nix", True),
+        ("nix/vm-test.nix", True),
+        ("pkgs/latest.nix", False),
+        ("pkgs/contest.nix", False),
+        ("src/ParserTest.cs", True),
+        ("src/Contest.cs", False),
+    ],
+)
+def test_is_test_file_uses_language_conventions(path: str, *, expected: bool) -> None:
+    """Test that file-level detection follows each language's repo conventions."""
+    assert is_test_file(Path(path)) is expected
+
+
+@pytest.mark.parametrize(
+    "attribute",
+    [
+        "#[test]",
+        "#[tokio::test]",
+        "#[tokio::test(flavor = \"multi_thread\")]",
+        "#[cfg(all(test, not(windows)))]",
+        "#[cfg(all(\n    test,\n    feature = \"x\"\n))]",
+        "#[tokio::test(\n    flavor = \"multi_thread\"\n)]",
+        "#[allow(\n    dead_code\n)]\n#[test]",
+    ],
+)
+def test_rust_chunk_with_test_attribute_is_test(attribute: str) -> None:
+    """Test that a chunk led by a Rust test attribute is test code."""
+    chunk = make_chunk(f"/// Checks things\n{attribute}\nfn checks() {{}}", Path("src/lib.rs"))
+    assert is_test_chunk(chunk, chunk.file_path)
+
+
+@pytest.mark.parametrize(
+    "attribute",
+    [
+        "#[cfg(not(test))]",
+        "#[cfg(feature = \"test-utils\")]",
+        "#[cfg(any(test, unix))]",
+        "#[cfg(any(\n    test,\n    unix\n))]",
+    ],
+)
+def test_rust_cfg_without_test_is_not_test(attribute: str) -> None:
+    """Test that cfg predicates that also hold outside tests don't mark a chunk as test code."""
+    chunk = make_chunk(f"{attribute}\nfn real() {{}}", Path("src/lib.rs"))
+    assert not is_test_chunk(chunk, chunk.file_path)
+
+
+def test_definition_test_classification_is_test() -> None:
+    """Test that the chunker's DEFINITION_TEST classification marks a chunk as test code."""
+    chunk = make_chunk(
+        "def check_login(): ...",
+        Path("src/auth.py"),
+        language="python",
+        metadata={"context": {"classification": "definition_test"}},
+    )
+    assert is_test_chunk(chunk, chunk.file_path)
+
+
+def test_chunk_inside_cfg_test_module_is_test(rust_project: Path) -> None:
+    """Test that a chunk inside `#[cfg(test)] mod tests` is test code, from its file's content."""
+    source = (rust_project / "src" / "lib.rs").read_text()
+    lines = source.splitlines()
+    test_fn_line = next(i for i, line in enumerate(lines, 1) if "fn test_cache_operations" in line)
+    insert_line = next(i for i, line in enumerate(lines, 1) if "pub fn insert" in line)
+    inner = make_chunk(
+        "fn test_cache_operations() {}",
+        Path("src/lib.rs"),
+        lines=(test_fn_line, test_fn_line + 4),
+    )
+    production = make_chunk(
+        "pub fn insert(&mut self, item: T) -> Option<T> {}",
+        Path("src/lib.rs"),
+        lines=(insert_line, insert_line + 5),
+    )
+    test_ranges = find_test_only_ranges(Path("src/lib.rs"), source)
+
+    assert is_test_chunk(inner, inner.file_path, test_ranges=test_ranges)
+    assert not is_test_chunk(production, production.file_path, test_ranges=test_ranges)
+    assert find_test_only_ranges(rust_project / "src" / "lib.rs") == test_ranges
+
+
+def test_filters_read_chunks_test_tags(rust_project: Path) -> None:
+    """Test that search results are filtered by the tag they were chunked with.
+
+    Results from a revision's or a dependency's collection aren't from the working tree's files,
+    so their files on disk say nothing about them.
+    """
+    lines = (rust_project / "src" / "lib.rs").read_text().splitlines()
+    test_fn_line = next(i for i, line in enumerate(lines, 1) if "fn test_cache_operations" in line)
+    tagged = make_chunk(
+        "fn test_cache_operations() {}",
+        Path("src/lib.rs"),
+        lines=(test_fn_line, test_fn_line + 4),
+    )
+    test = tagged.model_copy(update={"is_test": True})
+    production = tagged.model_copy(update={"is_test": False})
+
+    filtered = apply_filters([make_result(test), make_result(production)], include_tests=False)
+
+    assert [r.content for r in filtered] == [production]
+
+
+def test_include_tests_keeps_test_chunks() -> None:
+    """Test that test chunks are kept when tests are requested."""
+    chunk = make_chunk("#[test]\nfn checks() {}", Path("tests/integration.rs"))
+    filtered = apply_filters([make_result(chunk)], include_tests=True)
+    assert [r.content for r in filtered] == [chunk]
+
+
+@pytest.mark.parametrize(
+    ("include_tests", "intent", "expected"),
+    [
+        (True, IntentType.IMPLEMENT, True),
+        (None, IntentType.IMPLEMENT, False),
+        (None, IntentType.DEBUG, True),
+        (False, IntentType.TEST, False),
+    ],
+)
+def test_include_tests_defaults_by_intent(
+    include_tests: bool | None, intent: IntentType, *, expected: bool
+) -> None:
+    """Test that the intent decides whether test chunks are returned only if the caller doesn't."""
+    from codeweaver.server.agent_api.search import _filters_for_intent
+
+    chunk = make_chunk("#[test]\nfn checks() {}", Path("tests/integration.rs"))
+    filters = _filters_for_intent(SearchFilters(include_tests=include_tests), intent)
+
+    assert filters.include_tests is expected
+    filtered = apply_filters([make_result(chunk)], include_tests=filters.include_tests)
+    assert bool(filtered) is expected
+    assert (build_query_filter(filters) is None) is expected
+
+
+def test_path_filters() -> None:
+    """Test path filters as directories, files and globs, and the prefixes pushed down for them."""
+    assert matches_path(Path("crates/auth/src/lib.rs"), "crates/auth/")
+    assert matches_path(Path("crates/auth/src/lib.rs"), "./crates/auth/src/lib.rs")
+    assert not matches_path(Path("crates/authz/src/lib.rs"), "crates/auth")
+    assert matches_path(Path("crates/auth/src/token/jwt.rs"), "crates/*/src/**/*.rs")
+    assert not matches_path(Path("crates/auth/build.rs"), "crates/*/src/**/*.rs")
+    assert path_literal_prefix("crates/*/src/**/*.rs") == "crates"
+    assert path_literal_prefix("./crates/auth/") == "crates/auth"
+    assert path_literal_prefix("**/*.rs") is None
+
+    kept = make_chunk("fn verify() {}", Path("crates/auth/src/lib.rs"))
+    dropped = make_chunk("fn main() {}", Path("src/main.rs"))
+    filtered = apply_filters(
+        [make_result(kept), make_result(dropped)], include_tests=True, paths=("crates/auth",)
+    )
+    assert [r.content for r in filtered] == [kept]
+
+
+def test_kind_symbol_and_excluded_path_filters() -> None:
+    """Test kinds by semantic class and file kind, symbol prefixes, and excluded paths."""
+    parse = make_chunk(
+        "fn parse_args() {}",
+        Path("src/cli.rs"),
+        metadata={
+            "semantic_meta": {
+                "language": "rust",
+                "semantic_class": "definition_callable",
+                "symbol": "Cli::parse_args",
+            }
+        },
+    )
+    generated = make_chunk(
+        "fn parse_gen() {}",
+        Path("src/gen/parser.rs"),
+        metadata={
+            "semantic_meta": {
+                "language": "rust",
+                "semantic_class": "definition_callable",
+                "symbol": "parse_gen",
+            }
+        },
+    )
+    config = make_chunk("[package]", Path("Cargo.toml"))
+    results = [make_result(parse), make_result(generated), make_result(config)]
+
+    functions = apply_filters(results, include_tests=True, kinds=(CodeKind.FUNCTION,))
+    assert [r.content for r in functions] == [parse, generated]
+    assert [
+        r.content
+        for r in apply_filters(
+            results, include_tests=True, symbol_prefix="parse", exclude_paths=("src/gen/**",)
+        )
+    ] == [parse]
@@ -0,0 +1,85 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for inline filter operators in `find_code` queries."""
+
+from __future__ import annotations
+
+import pytest
+
+from codeweaver.server.agent_api.search.operators import parse_query_operators
+from codeweaver.server.agent_api.search.types import CodeKind, FindCodeSubmission, SearchFilters
+
+
+pytestmark = [pytest.mark.unit, pytest.mark.search]
+
+
+def test_operators_are_taken_out_of_the_query() -> None:
+    """Test that operators become filters and the rest stays the query."""
+    query, filters = parse_query_operators(
+        "lang:Rust where are tokens verified path:crates/auth/** kind:fn,type -path:**/gen/**"
+    )
+
+    assert query == "where are tokens verified"
+    assert filters.languages == ("rust",)
+    assert filters.paths == ("crates/auth/**",)
+    assert filters.exclude_paths == ("**/gen/**",)
+    assert filters.kinds == (CodeKind.FUNCTION, CodeKind.TYPE)
+
+
+def test_queries_without_operators_are_unchanged() -> None:
+    """Test that text that only looks like an operator stays in the query."""
+    query = "how does std::io read https://example.com/a:b"
+    given = SearchFilters(crates=("cli",))
+    assert parse_query_operators(query, given) == (query, given)
+
+
+@pytest.mark.parametrize(
+    ("operator", "expected"),
+    [
+        ("size:<10kb", {"max_file_size": 10 * 1024 - 1}),
+        ("size:<=2k", {"max_file_size": 2048}),
+        ("size:>=1mb", {"min_file_size": 1024 * 1024}),
+        ("size:500", {"min_file_size": 500, "max_file_size": 500}),
+        ("symbol:parse_", {"symbol_prefix": "parse_"}),
+        ("crate:store-core", {"crates": ("store-core",)}),
+        ("pkg:serde_json,tokio", {"packages": ("serde_json", "tokio")}),
+    ],
+)
+def test_single_operators(operator: str, expected: dict[str, object]) -> None:
+    """Test size, symbol, crate and package operators."""
+    _, filters = parse_query_operators(f"config loading {operator}")
+    assert filters.model_dump(include=set(expected)) == expected
+
+
+def test_operators_add_to_structured_filters() -> None:
+    """Test that operators add to list filters and override single-valued ones."""
+    given = SearchFilters(paths=("src",), symbol_prefix="load")
+    _, filters = parse_query_operators("loaders path:lib symbol:parse", given)
+    assert filters.paths == ("src", "lib")
+    assert filters.symbol_prefix == "parse"
+
+
+@pytest.mark.parametrize(
+    "query",
+    [
+        "parsers lang:klingon",
+        "parsers kind:widget",
+        "parsers size:big",
+        "parsers -lang:rust",
+        "lang:rust kind:fn",
+    ],
+)
+def test_invalid_operators(query: str) -> None:
+    """Test unknown languages and kinds, bad sizes, negated non-paths and operator-only queries."""
+    with pytest.raises(ValueError):
+        parse_query_operators(query)
+
+
+def test_submission_with_query_operators() -> None:
+    """Test that a submission moves its query's operators into its filters."""
+    submission = FindCodeSubmission(query="retry logic lang:python").with_query_operators()
+    assert submission.query == "retry logic"
+    assert submission.filters is not None
+    assert submission.filters.languages == ("python",)
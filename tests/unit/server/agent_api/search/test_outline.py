@@ -0,0 +1,184 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for file outlines, skeletons and the `get_outline` lookups."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from textwrap import dedent
+
+import pytest
+
+from ast_grep_py import SgRoot
+
+from codeweaver.core import SemanticSearchLanguage
+from codeweaver.engine.services.symbol_index_service import SymbolIndexService
+from codeweaver.semantic.ast_grep import AstThing
+from codeweaver.semantic.outline import render_skeleton
+from codeweaver.server.agent_api.search.outline import (
+    outline_file,
+    outline_symbol,
+    resolve_project_file,
+)
+
+
+pytestmark = [pytest.mark.unit, pytest.mark.search]
+
+RUST_SOURCE = """
+use std::collections::HashMap;
+
+/// A bounded cache.
+#[derive(Debug)]
+pub struct Cache<T> {
+    items: HashMap<String, T>,
+}
+
+impl<T> Cache<T> {
+    /// Insert an item, returning the one it replaced.
+    pub fn insert(&mut self, key: String, item: T) -> Option<T> {
+        self.items.insert(key, item)
+    }
+}
+"""
+
+PYTHON_SOURCE = '''
+@dataclass
+class Loader:
+    """Loads raw values."""
+
+    def load(self, raw):
+        """Parse a raw value."""
+        value = int(raw)
+        return value
+'''
+
+TYPESCRIPT_SOURCE = """
+/** Stores users. */
+export class UserRepository {
+  find(id: string): User {
+    return this.load(id);
+  }
+}
+"""
+
+GO_SOURCE = """
+package store
+
+// Store keeps items by key.
+type Store struct {
+\titems map[string]int
+}
+
+// Get returns an item.
+func (s *Store) Get(key string) int {
+\treturn s.items[key]
+}
+"""
+
+
+@pytest.fixture
+def project(tmp_path: Path) -> Path:
+    for name, source in (
+        ("cache.rs", RUST_SOURCE),
+        ("loader.py", PYTHON_SOURCE),
+        ("users.ts", TYPESCRIPT_SOURCE),
+        ("store.go", GO_SOURCE),
+    ):
+        (tmp_path / name).write_text(dedent(source).lstrip())
+    return tmp_path
+
+
+def _skeleton(project: Path, name: str, language: SemanticSearchLanguage) -> str | None:
+    root = SgRoot((project / name).read_text(), language.variable).root()
+    return render_skeleton(AstThing.from_sg_node(root, language))
+
+
+def test_rust_outline(project: Path) -> None:
+    """Test the item tree of a Rust file: types and impl blocks with their methods."""
+    outline = outline_file(project / "cache.rs", project)
+    assert outline is not None
+    assert outline.path == Path("cache.rs")
+    struct, impl = outline.items
+    assert (struct.name, struct.signature, struct.doc) == (
+        "Cache",
+        "pub struct Cache<T>",
+        "A bounded cache.",
+    )
+    assert (impl.name, impl.signature) == ("Cache<T>", "impl<T> Cache<T>")
+    [method] = impl.children
+    assert method.signature == "pub fn insert(&mut self, key: String, item: T) -> Option<T>"
+    assert method.doc == "Insert an item, returning the one it replaced."
+    assert (method.line, method.end_line) == (11, 13)
+
+
+def test_python_outline_and_skeleton(project: Path) -> None:
+    """Test decorators and docstrings in the outline, and docstrings kept in the skeleton."""
+    outline = outline_file(project / "loader.py", project)
+    assert outline is not None
+    [loader] = outline.items
+    assert (loader.signature, loader.doc) == ("@dataclass class Loader:", "Loads raw values.")
+    assert [(m.name, m.doc) for m in loader.children] == [("load", "Parse a raw value.")]
+
+    skeleton = _skeleton(project, "loader.py", SemanticSearchLanguage.PYTHON)
+    assert skeleton is not None
+    assert '    def load(self, raw):\n        """Parse a raw value."""\n        ...' in skeleton
+    assert "int(raw)" not in skeleton
+
+
+def test_typescript_and_go_outlines(project: Path) -> None:
+    """Test `export` and JSDoc in TypeScript, and wrapped type specs and doc comments in Go."""
+    typescript = outline_file(project / "users.ts", project)
+    assert typescript is not None
+    [repository] = typescript.items
+    assert (repository.signature, repository.doc) == (
+        "export class UserRepository",
+        "Stores users.",
+    )
+    assert [m.signature for m in repository.children] == ["find(id: string): User"]
+
+    go = outline_file(project / "store.go", project)
+    assert go is not None
+    assert [(i.name, i.signature, i.doc) for i in go.items] == [
+        ("Store", "type Store struct", "Store keeps items by key."),
+        ("Get", "func (s *Store) Get(key string) int", "Get returns an item."),
+    ]
+    skeleton = _skeleton(project, "store.go", SemanticSearchLanguage.GO)
+    assert skeleton is not None
+    assert "func (s *Store) Get(key string) int { … }" in skeleton
+
+
+def test_skeleton_needs_something_to_elide(tmp_path: Path) -> None:
+    """Test that files without bodies get no skeleton."""
+    (tmp_path / "types.rs").write_text("pub struct Id(u64);\n")
+    assert _skeleton(tmp_path, "types.rs", SemanticSearchLanguage.RUST) is None
+
+
+async def test_outline_symbol(project: Path) -> None:
+    """Test that a symbol's outline has its definition and its impl blocks."""
+    service = SymbolIndexService(project, "demo", project / ".cache")
+    index = await service.get_index()
+    index.set_file(Path("cache.rs"), service.extract(project / "cache.rs") or ())
+
+    response = await outline_symbol("cache::Cache", index, project)
+    [outline] = response.outlines
+    assert [(i.kind, i.name) for i in outline.items] == [
+        ("struct_item", "Cache"),
+        ("impl_item", "Cache<T>"),
+    ]
+
+    missing = await outline_symbol("Missing", index, project)
+    assert missing.outlines == ()
+    assert "Missing" in missing.summary
+
+
+def test_resolve_project_file(project: Path, tmp_path_factory: pytest.TempPathFactory) -> None:
+    """Test that only files inside the project are outlined."""
+    assert resolve_project_file("cache.rs", project) == (project / "cache.rs").resolve()
+    assert resolve_project_file(str(project / "cache.rs"), project) is not None
+    assert resolve_project_file("Cache", project) is None
+    outside = tmp_path_factory.mktemp("outside") / "secret.rs"
+    outside.write_text("fn main() {}\n")
+    assert resolve_project_file(str(outside), project) is None
+    assert resolve_project_file("../outside0/secret.rs", project) is None
@@ -0,0 +1,114 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for `find_code` continuation cursors and the ranked candidate cache."""
+
+from __future__ import annotations
+
+import pytest
+
+from codeweaver.core import QueryError
+from codeweaver.server.agent_api.search.pagination import (
+    PageCursor,
+    RankedResultsCache,
+    merged_page,
+    next_cursor,
+    query_fingerprint,
+    read_cursor,
+)
+from codeweaver.server.agent_api.search.types import SearchFilters, SearchMode
+
+
+pytestmark = [pytest.mark.unit, pytest.mark.search]
+
+
+def _fingerprint(query: str = "token refresh", **filters: object) -> str:
+    return query_fingerprint(
+        query, filters=SearchFilters(**filters), intent=None, mode=SearchMode.SEMANTIC
+    )
+
+
+def test_cursor_round_trip() -> None:
+    """Test that a cursor decodes to what it encoded and reads back its offset."""
+    fingerprint = _fingerprint()
+    cursor = next_cursor(fingerprint, "gen-1", 10, 25)
+    assert cursor is not None
+    assert PageCursor.decode(cursor) == PageCursor(fingerprint, "gen-1", 10)
+    assert read_cursor(cursor, fingerprint, "gen-1") == 10
+
+
+def test_no_cursor_after_the_last_page() -> None:
+    """Test that the last page has no cursor."""
+    assert next_cursor(_fingerprint(), "gen-1", 10, 10) is None
+
+
+def test_fingerprints() -> None:
+    """Test that fingerprints ignore spacing but not the query or its filters."""
+    assert _fingerprint("token  refresh ") == _fingerprint()
+    assert _fingerprint("token rotation") != _fingerprint()
+    assert _fingerprint(languages=("rust",)) != _fingerprint()
+
+
+@pytest.mark.parametrize(
+    ("offset", "expected"),
+    [(0, (["e0", "e1"], [])), (2, (["e2"], ["r0"])), (4, ([], ["r1", "r2"])), (8, ([], []))],
+)
+def test_merged_pages(offset: int, expected: tuple[list[str], list[str]]) -> None:
+    """Test that pages run through the exact hits, then on through the ranked candidates."""
+    assert merged_page(["e0", "e1", "e2"], ["r0", "r1", "r2", "r3"], offset, 2) == expected
+
+
+def test_stale_cursor() -> None:
+    """Test that a cursor from an earlier index generation fails as stale."""
+    fingerprint = _fingerprint()
+    cursor = PageCursor(fingerprint, "gen-1", 10).encode()
+    with pytest.raises(QueryError, match="Stale cursor"):
+        read_cursor(cursor, fingerprint, "gen-2")
+
+
+def test_unknown_generation() -> None:
+    """Test that no cursor is issued or accepted while the index generation is unknown."""
+    fingerprint = _fingerprint()
+    assert next_cursor(fingerprint, None, 10, 25) is None
+    cursor = PageCursor(fingerprint, "gen-1", 10).encode()
+    with pytest.raises(QueryError, match="generation is unknown"):
+        read_cursor(cursor, fingerprint, None)
+
+
+@pytest.mark.parametrize(
+    ("cursor", "message"),
+    [
+        ("not a cursor", "isn't one"),
+        (PageCursor("x", "gen-1", -1).encode(), "isn't one"),
+        (PageCursor(_fingerprint("other"), "gen-1", 10).encode(), "different query"),
+    ],
+)
+def test_invalid_cursors(cursor: str, message: str) -> None:
+    """Test cursors that aren't cursors, and cursors for another query."""
+    with pytest.raises(QueryError, match=message):
+        read_cursor(cursor, _fingerprint(), "gen-1")
+
+
+def test_ranked_results_cache(monkeypatch: pytest.MonkeyPatch) -> None:
+    """Test that the cache keeps one generation per query, evicts and expires entries."""
+    now = [0.0]
+    monkeypatch.setattr(
+        "codeweaver.server.agent_api.search.pagination.time.monotonic", lambda: now[0]
+    )
+    cache = RankedResultsCache(max_queries=2, ttl=60)
+
+    cache.put("a", "gen-1", ["a1", "a2"])
+    cache.put("a", "gen-2", ["a3"])
+    assert cache.get("a", "gen-1") is None
+    assert cache.get("a", "gen-2") is not None
+    assert cache.get("a", "gen-2").candidates == ("a3",)
+
+    cache.put("b", "gen-2", ["b1"])
+    cache.get("a", "gen-2")
+    cache.put("c", "gen-2", ["c1"])
+    assert len(cache) == 2
+    assert cache.get("b", "gen-2") is None
+
+    now[0] = 61.0
+    assert cache.get("a", "gen-2") is None
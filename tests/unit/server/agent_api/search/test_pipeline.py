@@ -0,0 +1,118 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for search pipeline helpers."""
+
+from datetime import UTC, datetime
+
+import pytest
+
+from codeweaver.core import ChunkKind
+from codeweaver.providers import Filter, IsEmptyCondition
+from codeweaver.semantic.classifications import SemanticClass
+from codeweaver.server.agent_api.search.pipeline import build_query_filter
+from codeweaver.server.agent_api.search.types import CodeKind, SearchFilters
+
+
+pytestmark = [pytest.mark.unit, pytest.mark.search]
+
+
+@pytest.mark.parametrize("filters", [None, SearchFilters(), SearchFilters(crates=())])
+def test_build_query_filter_without_filters(filters) -> None:
+    """Test that no filter is pushed down when nothing is restricted."""
+    assert build_query_filter(filters) is None
+
+
+def test_build_query_filter_matches_crate_name_spellings() -> None:
+    """Test that crate filters match both `-` and `_` spellings of a crate name."""
+    query_filter = build_query_filter(SearchFilters(crates=("store-core", "cli")))
+
+    assert query_filter is not None
+    (condition,) = query_filter.must
+    assert condition.key == "crate"
+    assert condition.match.any == ["cli", "store-core", "store_core"]
+
+
+def test_build_query_filter_matches_package_names() -> None:
+    """Test that dependency package filters match the `package` payload field in both spellings."""
+    query_filter = build_query_filter(SearchFilters(packages=("serde_json",)))
+
+    assert query_filter is not None
+    (condition,) = query_filter.must
+    assert condition.key == "package"
+    assert condition.match.any == ["serde-json", "serde_json"]
+
+
+def _matched_values(query_filter: Filter, key: str) -> list[str]:
+    """The values a pushed-down `field or unset` condition matches for a payload key."""
+    for condition in query_filter.must:
+        match, unset = condition.should
+        if match.key == key:
+            assert isinstance(unset, IsEmptyCondition)
+            assert unset.is_empty.key == key
+            return match.match.any
+    raise AssertionError(f"no condition on {key}")
+
+
+def test_build_query_filter_pushes_down_payload_fields() -> None:
+    """Test languages, paths, kinds and classes as matches that also pass unset fields."""
+    query_filter = build_query_filter(
+        SearchFilters(
+            languages=("Rust", "typescript"),
+            paths=("crates/auth", "crates/*/src/**/*.rs"),
+            chunk_kinds=(ChunkKind.CONFIG,),
+            semantic_classes=(SemanticClass.DEFINITION_CALLABLE,),
+        )
+    )
+
+    assert query_filter is not None
+    assert query_filter.must_not is None
+    assert _matched_values(query_filter, "language") == ["rust", "typescript"]
+    assert _matched_values(query_filter, "path_prefixes") == ["crates", "crates/auth"]
+    assert _matched_values(query_filter, "chunk_kind") == ["config"]
+    assert _matched_values(query_filter, "semantic_class") == ["definition_callable"]
+
+
+def test_build_query_filter_skips_paths_without_literal_prefix() -> None:
+    """Test that paths aren't pushed down when a glob could match anywhere."""
+    assert build_query_filter(SearchFilters(paths=("src", "**/*.rs"))) is None
+
+
+def test_build_query_filter_tests_and_modified_since() -> None:
+    """Test that test code is excluded by flag and modification time is a range."""
+    since = datetime(2026, 1, 1, tzinfo=UTC)
+    query_filter = build_query_filter(SearchFilters(include_tests=False, modified_since=since))
+
+    assert query_filter is not None
+    (excluded,) = query_filter.must_not
+    assert (excluded.key, excluded.match.value) == ("is_test", True)
+    ((in_range, unset),) = (condition.should for condition in query_filter.must)
+    assert (in_range.key, in_range.range.gte) == ("modified_at", since)
+    assert unset.is_empty.key == "modified_at"
+
+
+def test_build_query_filter_kinds_sizes_and_exclusions() -> None:
+    """Test kinds as one alternative per payload field, file sizes, and excluded subtrees."""
+    query_filter = build_query_filter(
+        SearchFilters(
+            kinds=(CodeKind.FUNCTION, CodeKind.CONFIG, CodeKind.TEST),
+            max_file_size=4096,
+            exclude_paths=("build/**", "vendor", "**/gen/*.rs"),
+        )
+    )
+
+    assert query_filter is not None
+    kinds, size = query_filter.must
+    by_class, by_chunk_kind, by_test, unset = kinds.should
+    assert (by_class.key, by_class.match.any) == (
+        "semantic_class",
+        ["definition_callable", "definition_test"],
+    )
+    assert (by_chunk_kind.key, by_chunk_kind.match.any) == ("chunk_kind", ["config"])
+    assert (by_test.key, by_test.match.value) == ("is_test", True)
+    assert unset.is_empty.key == "chunk_kind"
+    in_range, _ = size.should
+    assert (in_range.key, in_range.range.gte, in_range.range.lte) == ("file_size", None, 4096)
+    (excluded,) = query_filter.must_not
+    assert (excluded.key, excluded.match.any) == ("path_prefixes", ["build", "vendor"])
@@ -0,0 +1,104 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for reading files, line ranges and symbol definitions for `read_code`."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from textwrap import dedent
+
+import pytest
+
+from codeweaver.engine.services.symbol_index_service import SymbolIndexService
+from codeweaver.server.agent_api.search.reading import (
+    MAX_READ_LINES,
+    parse_expansion,
+    read_section,
+    read_symbol,
+)
+
+
+pytestmark = [pytest.mark.unit, pytest.mark.search]
+
+RUST_SOURCE = """
+pub struct Cache {
+    items: Vec<u32>,
+}
+
+impl Cache {
+    pub fn get(&self, index: usize) -> Option<u32> {
+        let item = self.items.get(index);
+        item.copied()
+    }
+}
+"""
+
+
+@pytest.fixture
+def project(tmp_path: Path) -> Path:
+    (tmp_path / "cache.rs").write_text(dedent(RUST_SOURCE).lstrip())
+    return tmp_path
+
+
+def test_parse_expansion() -> None:
+    """Test the `expand` argument's forms."""
+    assert parse_expansion(None) is None
+    assert parse_expansion("item") == "item"
+    assert parse_expansion("lines:3") == 3
+    with pytest.raises(ValueError, match="lines:N"):
+        parse_expansion("lines:some")
+
+
+def test_read_lines_and_expansions(project: Path) -> None:
+    """Test reading a line as is, widened to its item, and widened by lines."""
+    path = project / "cache.rs"
+    line = read_section(path, project, (7, 7))
+    assert line is not None
+    assert line.path == Path("cache.rs")
+    assert (line.span.start, line.span.end) == (7, 7)
+    assert line.content == "        let item = self.items.get(index);"
+    assert line.item is None
+
+    item = read_section(path, project, (7, 7), "item")
+    assert item is not None
+    assert (item.span.start, item.span.end) == (6, 9)
+    assert item.item == "pub fn get(&self, index: usize) -> Option<u32>"
+    assert item.content.splitlines()[-1] == "    }"
+
+    widened = read_section(path, project, (7, 7), 1)
+    assert widened is not None
+    assert (widened.span.start, widened.span.end) == (6, 8)
+
+    whole = read_section(path, project)
+    assert whole is not None
+    assert (whole.span.start, whole.span.end) == (1, 10)
+    assert not whole.truncated
+
+
+def test_long_sections_are_cut(tmp_path: Path) -> None:
+    """Test that sections stop at `MAX_READ_LINES` while the span keeps the full range."""
+    path = tmp_path / "long.py"
+    path.write_text("".join(f"x{n} = {n}\n" for n in range(MAX_READ_LINES + 10)))
+    section = read_section(path, tmp_path)
+    assert section is not None
+    assert section.truncated
+    assert section.span.end == MAX_READ_LINES + 10
+    assert len(section.content.splitlines()) == MAX_READ_LINES
+
+
+async def test_read_symbol(project: Path) -> None:
+    """Test that a symbol's definitions are read as whole items."""
+    service = SymbolIndexService(project, "demo", project / ".cache")
+    index = await service.get_index()
+    index.set_file(Path("cache.rs"), service.extract(project / "cache.rs") or ())
+
+    response = await read_symbol("Cache::get", index, project)
+    [section] = response.sections
+    assert (section.span.start, section.span.end) == (6, 9)
+    assert section.item == "pub fn get(&self, index: usize) -> Option<u32>"
+
+    missing = await read_symbol("get", index, project, in_file=Path("other.rs"))
+    assert missing.sections == ()
+    assert "other.rs" in missing.summary
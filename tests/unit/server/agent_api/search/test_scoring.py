@@ -0,0 +1,136 @@
+# SPDX-FileCopyrightText: 2026 Knitli Inc.
+#
+# SPDX-License-Identifier: MIT OR Apache-2.0
+
+"""Unit tests for task-aware semantic weighting in scoring.py."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import TYPE_CHECKING, Any
+
+import pytest
+
+from codeweaver.semantic import AgentTask
+from codeweaver.server.agent_api.search.scoring import (
+    apply_semantic_weighting,
+    process_unranked_results,
+    task_importance,
+)
+
+
+if TYPE_CHECKING:
+    from codeweaver.core import CodeChunk, SearchResult
+
+
+pytestmark = [pytest.mark.unit, pytest.mark.search]
+
+# A debugging-heavy class (think error handling) and a documentation-heavy one (think a doc block)
+DEBUGGING_SCORES = {
+    "discovery": 0.2,
+    "comprehension": 0.2,
+    "modification": 0.1,
+    "debugging": 0.9,
+    "documentation": 0.05,
+}
+DOCUMENTATION_SCORES = {
+    "discovery": 0.2,
+    "comprehension": 0.2,
+    "modification": 0.05,
+    "debugging": 0.05,
+    "documentation": 0.9,
+}
+
+
+def make_chunk(name: str, metadata: dict[str, Any] | None) -> CodeChunk:
+    """Build a minimal chunk with the given metadata."""
+    from codeweaver.core import ChunkKind, CodeChunk, ExtCategory, Span, uuid7
+
+    chunk_id = uuid7()
+    return CodeChunk(
+        chunk_id=chunk_id,
+        ext_category=ExtCategory.from_language("rust", ChunkKind.CODE),
+        chunk_name=name,
+        file_path=Path(f"src/{name}.rs"),
+        language="rust",
+        content=f"// {name}",
+        line_range=Span(start=1, end=1, source_id=chunk_id),
+        metadata=metadata,
+    )
+
+
+def classified(name: str, scores: dict[str, float], confidence: float | None = 1.0) -> CodeChunk:
+    """A chunk whose semantic metadata carries a classification, as stored in the index."""
+    return make_chunk(
+        name,
+        {
+            "semantic_meta": {
+                "language": "rust",
+                "semantic_class": "flow_control",
+                "importance_scores": scores,
+                "classification_confidence": confidence,
+            }
+        },
+    )
+
+
+def make_result(chunk: CodeChunk, score: float = 0.5) -> SearchResult:
+    """Wrap a chunk in a search result."""
+    from codeweaver.core import SearchResult
+
+    return SearchResult(content=chunk, file_path=chunk.file_path, score=score)
+
+
+def ranked_names(results: list[SearchResult]) -> list[str]:
+    """Chunk names, best first."""
+    ordered = sorted(results, key=lambda r: r.relevance_score or 0, reverse=True)
+    return [r.content.chunk_name for r in ordered]  # type: ignore[union-attr]
+
+
+def test_ranking_follows_the_agent_task() -> None:
+    candidates = [
+        make_result(classified("handle_error", DEBUGGING_SCORES)),
+        make_result(classified("module_docs", DOCUMENTATION_SCORES)),
+    ]
+
+    debug = process_unranked_results(candidates, AgentTask.DEBUG)
+    document = process_unranked_results(candidates, AgentTask.DOCUMENT)
+
+    assert ranked_names(debug) == ["handle_error", "module_docs"]
+    assert ranked_names(document) == ["module_docs", "handle_error"]
+
+
+def test_boost_is_bounded_by_the_boost_factor() -> None:
+    chunk = classified("handle_error", DEBUGGING_SCORES)
+
+    boosted = apply_semantic_weighting(1.0, chunk, AgentTask.DEBUG, boost_factor=0.2)
+
+    assert 1.0 < boosted <= 1.2
+    assert apply_semantic_weighting(1.0, chunk, AgentTask.DEBUG, boost_factor=0.0) == 1.0
+
+
+def test_low_confidence_classifications_boost_less() -> None:
+    sure = classified("sure", DEBUGGING_SCORES, confidence=1.0)
+    unsure = classified("unsure", DEBUGGING_SCORES, confidence=0.25)
+
+    assert apply_semantic_weighting(1.0, sure, AgentTask.DEBUG) > apply_semantic_weighting(
+        1.0, unsure, AgentTask.DEBUG
+    )
+
+
+def test_unclassified_chunks_keep_their_score() -> None:
+    chunk = make_chunk("plain", None)
+
+    assert task_importance(chunk, AgentTask.DEBUG) is None
+    assert apply_semantic_weighting(0.7, chunk, AgentTask.DEBUG) == 0.7
+    assert apply_semantic_weighting(0.7, None, AgentTask.DEBUG) == 0.7
+
+
+def test_older_payloads_fall_back_to_the_chunker_context() -> None:
+    chunk = make_chunk(
+        "handle_error",
+        {"context": {"classification": "flow_control", "importance_scores": DEBUGGING_SCORES}},
+    )
+
+    assert chunk.importance_scores is not None
+    assert apply_semantic_weighting(1.0, chunk, AgentTask.DEBUG) > 1.0